@@ -0,0 +1,43 @@
+// Memory-ordering bits of LR/SC and AMO instructions (aq = bit 26, rl = bit 25)
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Aqrl {
+    None = 0, // no ordering constraint
+    Rl,       // release
+    Aq,       // acquire
+    AqRl,     // sequentially consistent
+}
+
+impl Aqrl {
+    pub fn from_u8(r: u8) -> Option<Aqrl> {
+        match r {
+            0 => Some(Aqrl::None),
+            1 => Some(Aqrl::Rl),
+            2 => Some(Aqrl::Aq),
+            3 => Some(Aqrl::AqRl),
+            _ => None,
+        }
+    }
+
+    pub fn aq(&self) -> bool {
+        matches!(self, Aqrl::Aq | Aqrl::AqRl)
+    }
+
+    pub fn rl(&self) -> bool {
+        matches!(self, Aqrl::Rl | Aqrl::AqRl)
+    }
+}
+
+// Printed as the mnemonic suffix: `amoswap.w.aqrl`.
+impl std::fmt::Display for Aqrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Aqrl::None => Ok(()),
+            Aqrl::Rl => write!(f, ".rl"),
+            Aqrl::Aq => write!(f, ".aq"),
+            Aqrl::AqRl => write!(f, ".aqrl"),
+        }
+    }
+}
@@ -0,0 +1,965 @@
+// Two-pass assembler: the first pass sizes every statement and places the
+// labels, the second evaluates operands and emits bytes and relocations
+
+use crate::parse::{parse_int, parse_line, ParseError, ParseErrorKind, Tok};
+use crate::{encode, Ext, Inst, Isa};
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// Relocation type; the discriminant is the ELF `R_RISCV_*` number.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum RelocKind {
+    Abs32 = 1,       // .word
+    Abs64 = 2,       // .dword
+    Branch = 16,     // B-type offset
+    Jal = 17,        // J-type offset
+    Call = 18,       // auipc+jalr pair
+    PcrelHi20 = 23,  // %pcrel_hi
+    PcrelLo12I = 24, // %pcrel_lo, symbol is the label of the auipc
+    PcrelLo12S = 25,
+    Hi20 = 26,       // %hi
+    Lo12I = 27,      // %lo
+    Lo12S = 28,
+    RvcBranch = 44,  // c.beqz, c.bnez
+    RvcJump = 45,    // c.j, c.jal
+}
+
+/// Field the assembler left zero: it holds the address of `symbol` plus
+/// `addend`, pc-relative for the branch and pcrel kinds. `symbol` is a label,
+/// a name the source does not define, or a section name for an offset into
+/// another section.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Reloc {
+    pub offset: u64,
+    pub kind: RelocKind,
+    pub symbol: String,
+    pub addend: i64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Section {
+    pub name: String,
+    /// 0 unless the assembler was given an origin.
+    pub addr: u64,
+    pub align: u64,
+    pub data: Vec<u8>,
+    /// Instructions with their offsets in `data`.
+    pub insts: Vec<(u64, Inst)>,
+    pub relocs: Vec<Reloc>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Symbol {
+    pub name: String,
+    /// `None` for `.equ` constants.
+    pub section: Option<usize>,
+    pub value: u64,
+    pub global: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Object {
+    pub sections: Vec<Section>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl Object {
+    /// The sections at their addresses from the lowest one, gaps zero-filled;
+    /// `None` while relocations remain.
+    pub fn flat(&self) -> Option<Vec<u8>> {
+        if self.sections.iter().any(|s| !s.relocs.is_empty()) {
+            return None;
+        }
+        let used = || self.sections.iter().filter(|s| !s.data.is_empty());
+        let start = used().map(|s| s.addr).min().unwrap_or(0);
+        let mut bytes = Vec::new();
+        for s in used() {
+            let at = (s.addr - start) as usize;
+            if bytes.len() < at + s.data.len() {
+                bytes.resize(at + s.data.len(), 0);
+            }
+            bytes[at..at + s.data.len()].copy_from_slice(&s.data);
+        }
+        Some(bytes)
+    }
+}
+
+/// Assembles GNU-syntax source: labels (`name:`, and `1:` referenced as `1b`
+/// or `1f`), the `.text`, `.data`, `.section`, `.byte`, `.half`, `.word`,
+/// `.dword`, `.ascii`, `.asciz`, `.align`, `.balign`, `.org`, `.globl` and
+/// `.equ` directives, and `%hi`, `%lo`, `%pcrel_hi` and `%pcrel_lo` operands.
+/// A branch or jump target that is a plain number is an offset; one that
+/// names a symbol is an address. Pseudo-instructions expand as in `parse`,
+/// and `call`, `tail` and `la` to a symbol elsewhere leave relocations.
+///
+/// Without an origin, every section starts at 0 and references to other
+/// sections or to undefined symbols become relocations. With one, the
+/// sections follow each other from the origin and everything must resolve.
+pub struct Assembler {
+    isa: Isa,
+    origin: Option<u64>,
+}
+
+impl Assembler {
+    pub fn new(isa: Isa) -> Self {
+        Self { isa, origin: None }
+    }
+
+    /// Links at `addr`, for `Object::flat`.
+    pub fn origin(mut self, addr: u64) -> Self {
+        self.origin = Some(addr);
+        self
+    }
+
+    pub fn assemble(&self, src: &str) -> Result<Object, ParseError> {
+        let mut asm = Asm {
+            asm: self,
+            last: false,
+            sections: Vec::new(),
+            bases: Vec::new(),
+            cur: 0,
+            symbols: HashMap::new(),
+            order: Vec::new(),
+            globals: Vec::new(),
+            locals: Vec::new(),
+            nlocal: 0,
+            sizes: Vec::new(),
+            nsize: 0,
+            hi: RefCell::new(HashMap::new()),
+            symbolic: Cell::new(false),
+        };
+        asm.pass(src)?;
+        let mut at = self.origin.unwrap_or(0);
+        for s in &asm.sections {
+            at = if self.origin.is_some() { at.next_multiple_of(s.align) } else { 0 };
+            asm.bases.push(at);
+            at = at.wrapping_add(s.data.len() as u64);
+        }
+        asm.last = true;
+        asm.pass(src)?;
+
+        let mut symbols: Vec<Symbol> = asm
+            .order
+            .iter()
+            .map(|name| {
+                let (section, value) = match asm.symbols[name] {
+                    Def::Abs(v) => (None, v as u64),
+                    Def::Label(s, off) => (Some(s), asm.bases[s].wrapping_add_signed(off)),
+                };
+                Symbol { name: name.clone(), section, value, global: asm.globals.contains(name) }
+            })
+            .collect();
+        // labels that %pcrel_lo relocations refer to
+        let mut his: Vec<_> = asm.hi.take().into_iter().filter_map(|(at, hi)| Some((at, hi.err()?))).collect();
+        his.sort();
+        for ((s, off), name) in his {
+            symbols.push(Symbol { name, section: Some(s), value: asm.bases[s].wrapping_add(off), global: false });
+        }
+        Ok(Object { sections: asm.sections, symbols })
+    }
+}
+
+// Result of an expression: a number, an offset into a section, or an
+// offset from a symbol the source does not define.
+#[derive(Clone, PartialEq, Debug)]
+enum Value {
+    Abs(i64),
+    Rel(usize, i64),
+    Ext(String, i64),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Def {
+    Abs(i64),
+    Label(usize, i64),
+}
+
+// Operator around an immediate.
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Op {
+    None,
+    Hi,
+    Lo,
+    PcrelHi,
+    PcrelLo,
+    Call,  // target of call and tail
+    Pcrel, // address for la, split into %pcrel_hi and %pcrel_lo
+}
+
+// Immediate left to a relocation, `offset` bytes into the statement.
+struct Fixup {
+    op: Op,
+    symbol: String,
+    addend: i64,
+    offset: u64,
+}
+
+struct Asm<'a> {
+    asm: &'a Assembler,
+    last: bool,
+    sections: Vec<Section>,
+    bases: Vec<u64>,
+    cur: usize,
+    symbols: HashMap<String, Def>,
+    order: Vec<String>,
+    globals: Vec<String>,
+    // numeric labels in source order, complete after the first pass
+    locals: Vec<(u64, usize, u64)>,
+    nlocal: usize,
+    // size of each instruction statement in the first pass
+    sizes: Vec<u64>,
+    nsize: usize,
+    // pc-relative offset of each %pcrel_hi, or the label made for its relocation
+    hi: RefCell<HashMap<(usize, u64), Result<i64, String>>>,
+    // whether the last expression named a symbol
+    symbolic: Cell<bool>,
+}
+
+fn is_symbol_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '$'
+}
+
+fn col(text: &str, pos: usize) -> usize {
+    text[..pos].chars().count() + 1
+}
+
+fn skip_space(text: &str, pos: usize) -> usize {
+    pos + text[pos..].len() - text[pos..].trim_start().len()
+}
+
+// Drops a `#` comment, leaving string literals alone.
+fn strip_comment(text: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &text[..i],
+            _ => {}
+        }
+    }
+    text
+}
+
+// Operands of a directive, split at top-level commas.
+fn args<'t>(text: &'t str, start: usize, line: usize) -> Vec<Tok<'t>> {
+    let mut toks = Vec::new();
+    let mut depth = 0;
+    let mut from = start;
+    for (i, c) in text[start..].char_indices().map(|(i, c)| (start + i, c)) {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                let at = skip_space(text, from);
+                toks.push(Tok::at(text[at..i].trim_end(), col(text, at), line));
+                from = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let at = skip_space(text, from);
+    if at < text.len() || !toks.is_empty() {
+        toks.push(Tok::at(text[at..].trim_end(), col(text, at), line));
+    }
+    toks
+}
+
+fn hi20(v: i64) -> i64 {
+    (v + 0x800) >> 12 & 0xfffff
+}
+
+fn lo12(v: i64) -> i64 {
+    v << 52 >> 52
+}
+
+impl Asm<'_> {
+    fn pass(&mut self, src: &str) -> Result<(), ParseError> {
+        // the second pass sees the sections in the same order
+        for (s, &addr) in self.sections.iter_mut().zip(&self.bases) {
+            s.addr = addr;
+            s.data.clear();
+        }
+        self.section(".text");
+        self.nlocal = 0;
+        self.nsize = 0;
+        self.hi.borrow_mut().clear();
+        for (i, text) in src.lines().enumerate() {
+            self.statement(strip_comment(text), i + 1)?;
+        }
+        Ok(())
+    }
+
+    fn pc(&self) -> u64 {
+        self.sections[self.cur].data.len() as u64
+    }
+
+    fn flat(&self) -> bool {
+        self.asm.origin.is_some()
+    }
+
+    fn section(&mut self, name: &str) {
+        self.cur = match self.sections.iter().position(|s| s.name == name) {
+            Some(i) => i,
+            None => {
+                let code = name.starts_with(".text");
+                self.sections.push(Section {
+                    name: name.to_string(),
+                    addr: self.bases.get(self.sections.len()).copied().unwrap_or(0),
+                    align: if !code { 1 } else if self.asm.isa.has(Ext::C) { 2 } else { 4 },
+                    data: Vec::new(),
+                    insts: Vec::new(),
+                    relocs: Vec::new(),
+                });
+                self.sections.len() - 1
+            }
+        };
+    }
+
+    fn statement(&mut self, text: &str, line: usize) -> Result<(), ParseError> {
+        let mut pos = 0;
+        loop {
+            let start = skip_space(text, pos);
+            let len = text[start..].find(|c| !is_symbol_char(c)).unwrap_or(text.len() - start);
+            if len == 0 || !text[start + len..].starts_with(':') {
+                break;
+            }
+            self.label(&text[start..start + len], line, col(text, start))?;
+            pos = start + len + 1;
+        }
+        let start = skip_space(text, pos);
+        if start == text.len() {
+            return Ok(());
+        }
+        if text[start..].starts_with('.') {
+            return self.directive(text, start, line);
+        }
+        // keep the columns of the labels' text
+        let text = format!("{:w$}{}", "", &text[pos..], w = col(text, pos) - 1);
+        self.instruction(&text, line)
+    }
+
+    fn label(&mut self, name: &str, line: usize, col: usize) -> Result<(), ParseError> {
+        let here = (self.cur, self.pc());
+        if let Ok(n) = name.parse::<u64>() {
+            if !self.last {
+                self.locals.push((n, here.0, here.1));
+            }
+            self.nlocal += 1;
+            return Ok(());
+        }
+        if !self.last {
+            if self.symbols.contains_key(name) {
+                return Err(ParseError { line, col, kind: ParseErrorKind::Redefined(name.to_string()) });
+            }
+            self.order.push(name.to_string());
+        }
+        self.symbols.insert(name.to_string(), Def::Label(here.0, here.1 as i64));
+        Ok(())
+    }
+
+    fn directive(&mut self, text: &str, start: usize, line: usize) -> Result<(), ParseError> {
+        let len = text[start..].find(char::is_whitespace).unwrap_or(text.len() - start);
+        let name = text[start..start + len].to_ascii_lowercase();
+        let args = args(text, start + len, line);
+        let end = ParseError { line, col: col(text, text.len()), kind: ParseErrorKind::Expected("operand") };
+        let arg = |i: usize| args.get(i).ok_or(end.clone());
+        match &name[..] {
+            ".text" | ".data" | ".bss" => self.section(&name),
+            ".section" => self.section(arg(0)?.text),
+            ".globl" | ".global" => {
+                for tok in &args {
+                    if !self.globals.iter().any(|g| g == tok.text) {
+                        self.globals.push(tok.text.to_string());
+                    }
+                }
+            }
+            ".equ" | ".set" => {
+                let def = match self.expr(arg(1)?)? {
+                    Value::Abs(v) => Def::Abs(v),
+                    Value::Rel(s, off) => Def::Label(s, off),
+                    Value::Ext(name, _) if self.last => return Err(args[1].error(ParseErrorKind::Undefined(name))),
+                    Value::Ext(..) => return Ok(()),
+                };
+                let name = args[0].text.to_string();
+                if self.symbols.insert(name.clone(), def).is_none() {
+                    self.order.push(name);
+                }
+            }
+            ".byte" => self.data(&args, 1)?,
+            ".half" | ".2byte" | ".short" => self.data(&args, 2)?,
+            ".word" | ".4byte" | ".long" => self.data(&args, 4)?,
+            ".dword" | ".8byte" | ".quad" => self.data(&args, 8)?,
+            ".ascii" | ".asciz" | ".string" => {
+                let mut pos = skip_space(text, start + len);
+                loop {
+                    let bytes = string(text, &mut pos, line)?;
+                    let data = &mut self.sections[self.cur].data;
+                    data.extend(bytes);
+                    if name != ".ascii" {
+                        data.push(0);
+                    }
+                    pos = skip_space(text, pos);
+                    match text[pos..].chars().next() {
+                        Some(',') => pos = skip_space(text, pos + 1),
+                        None => break,
+                        Some(_) => return Err(ParseError { line, col: col(text, pos), kind: ParseErrorKind::Expected(",") }),
+                    }
+                }
+            }
+            ".align" | ".p2align" | ".balign" => {
+                let n = self.absolute(arg(0)?)?;
+                let align = match &name[..] {
+                    ".balign" if n > 0 && n & (n - 1) == 0 => n as u64,
+                    ".align" | ".p2align" if (0..16).contains(&n) => 1 << n,
+                    _ => return Err(args[0].error(ParseErrorKind::OutOfRange(n))),
+                };
+                let fill = match args.get(1) {
+                    Some(tok) => Some(self.absolute(tok)? as u8),
+                    None => None,
+                };
+                let section = &mut self.sections[self.cur];
+                section.align = section.align.max(align);
+                let end = self.pc().next_multiple_of(align);
+                self.pad(end, fill);
+            }
+            ".org" => {
+                let end = match self.expr(arg(0)?)? {
+                    Value::Abs(v) => v,
+                    Value::Rel(s, v) if s == self.cur => v,
+                    _ => return Err(args[0].expected("offset in this section")),
+                };
+                if end < self.pc() as i64 {
+                    return Err(args[0].error(ParseErrorKind::OutOfRange(end)));
+                }
+                self.pad(end as u64, Some(0));
+            }
+            _ => return Err(ParseError { line, col: col(text, start), kind: ParseErrorKind::UnknownDirective(name) }),
+        }
+        Ok(())
+    }
+
+    // Fills up to offset `end`, with nops in code unless `fill` is given.
+    fn pad(&mut self, end: u64, fill: Option<u8>) {
+        let rvc = self.asm.isa.has(Ext::C);
+        let section = &mut self.sections[self.cur];
+        let code = fill.is_none() && section.name.starts_with(".text");
+        let data = &mut section.data;
+        while (data.len() as u64) < end {
+            let (len, left) = (data.len() as u64, end - data.len() as u64);
+            match fill {
+                _ if !code => data.push(fill.unwrap_or(0)),
+                _ if len % 4 == 0 && left >= 4 => data.extend([0x13, 0, 0, 0]), // nop
+                _ if len % 2 == 0 && left >= 2 && rvc => data.extend([0x01, 0]), // c.nop
+                _ => data.push(0),
+            }
+        }
+    }
+
+    fn data(&mut self, args: &[Tok], size: usize) -> Result<(), ParseError> {
+        for tok in args {
+            let v = if !self.last {
+                0
+            } else {
+                let value = self.expr(tok)?;
+                match self.resolve(&value) {
+                    Some(v) => v,
+                    None if size >= 4 => {
+                        let (symbol, addend) = self.target(tok, value)?;
+                        let kind = if size == 4 { RelocKind::Abs32 } else { RelocKind::Abs64 };
+                        let offset = self.pc();
+                        self.sections[self.cur].relocs.push(Reloc { offset, kind, symbol, addend });
+                        0
+                    }
+                    None => return Err(tok.expected("absolute expression")),
+                }
+            };
+            let bits = 8 * size as u32;
+            if size < 8 && (v >= 1 << bits || v < -(1 << (bits - 1))) {
+                return Err(tok.error(ParseErrorKind::OutOfRange(v)));
+            }
+            self.sections[self.cur].data.extend(&v.to_le_bytes()[..size]);
+        }
+        Ok(())
+    }
+
+    fn instruction(&mut self, text: &str, line: usize) -> Result<(), ParseError> {
+        let pc = self.pc();
+        let mnemonic = text.split_whitespace().next().unwrap_or("").to_ascii_lowercase();
+        let (pcrel, plain) = match &mnemonic[..] {
+            "beq" | "bne" | "blt" | "bge" | "bltu" | "bgeu" | "jal" | "c.j" | "c.jal" | "c.beqz" | "c.bnez" => (true, Op::None),
+            "beqz" | "bnez" | "blez" | "bgez" | "bltz" | "bgtz" | "bgt" | "ble" | "bgtu" | "bleu" | "j" => (true, Op::None),
+            "call" | "tail" => (true, Op::Call),
+            "la" | "lla" => (true, Op::Pcrel),
+            _ => (false, Op::None),
+        };
+        let fixups = RefCell::new(Vec::new());
+        let imm_col = Cell::new(None);
+        let eval = |tok: &Tok| {
+            imm_col.set(imm_col.get().or(Some(tok.col)));
+            // li sizes itself by its value, which for a label the first pass
+            // does not know
+            if mnemonic == "li" && matches!(self.expr(tok), Ok(Value::Rel(..))) {
+                return Err(tok.expected("absolute constant; use la for an address"));
+            }
+            self.imm(tok, pc, pcrel, plain, &fixups)
+        };
+        let insts = parse_line(text, line, self.asm.isa.xlen(), &eval)?;
+        if insts.is_empty() {
+            return Ok(());
+        }
+        let at = imm_col.get().unwrap_or(text.len() - text.trim_start().len() + 1);
+        let size = insts.iter().map(|inst| inst.size() as u64).sum();
+        let section = &mut self.sections[self.cur];
+        if !self.last {
+            section.data.resize(section.data.len() + size as usize, 0);
+            self.sizes.push(size);
+            return Ok(());
+        }
+        // `li` sizes its expansion by a value the first pass may not have known
+        self.nsize += 1;
+        if self.sizes[self.nsize - 1] != size {
+            return Err(ParseError { line, col: at, kind: ParseErrorKind::Expected("constant defined before use") });
+        }
+        let mut placed = Vec::new();
+        for inst in insts {
+            let bytes = encode(&inst, &self.asm.isa).map_err(|e| ParseError { line, col: at, kind: ParseErrorKind::Encode(e) })?;
+            placed.push((section.data.len() as u64 - pc, inst));
+            section.data.extend(bytes.as_bytes());
+        }
+        for f in fixups.into_inner() {
+            let inst = &placed.iter().find(|(off, _)| *off == f.offset).unwrap().1;
+            let store = matches!(
+                inst,
+                Inst::SB(..) | Inst::SH(..) | Inst::SW(..) | Inst::SD(..) | Inst::SQ(..)
+                    | Inst::FSH(..) | Inst::FSW(..) | Inst::FSD(..) | Inst::FSQ(..)
+            );
+            let kind = match f.op {
+                Op::None => match inst {
+                    Inst::JAL(..) => RelocKind::Jal,
+                    Inst::CJ(..) | Inst::CJAL(..) => RelocKind::RvcJump,
+                    Inst::CBEQZ(..) | Inst::CBNEZ(..) => RelocKind::RvcBranch,
+                    _ => RelocKind::Branch,
+                },
+                Op::Hi => RelocKind::Hi20,
+                Op::Lo if store => RelocKind::Lo12S,
+                Op::Lo => RelocKind::Lo12I,
+                Op::PcrelHi | Op::Pcrel => RelocKind::PcrelHi20,
+                Op::PcrelLo if store => RelocKind::PcrelLo12S,
+                Op::PcrelLo => RelocKind::PcrelLo12I,
+                Op::Call => RelocKind::Call,
+            };
+            section.relocs.push(Reloc { offset: pc + f.offset, kind, symbol: f.symbol, addend: f.addend });
+        }
+        section.insts.extend(placed.into_iter().map(|(off, inst)| (pc + off, inst)));
+        Ok(())
+    }
+
+    // Value of an instruction's immediate operand at `pc`, under `plain`
+    // unless it has its own operator; zero and a fixup when it needs a
+    // relocation. The first pass only needs the constants `li` sizes by.
+    fn imm(&self, tok: &Tok, pc: u64, pcrel: bool, plain: Op, fixups: &RefCell<Vec<Fixup>>) -> Result<i64, ParseError> {
+        if !self.last {
+            return Ok(match self.expr(tok) {
+                Ok(Value::Abs(v)) if !pcrel => v,
+                _ => 0,
+            });
+        }
+        let (op, inner) = match tok.text.strip_prefix('%') {
+            Some(rest) => {
+                let len = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+                let op = match &rest[..len] {
+                    "hi" => Op::Hi,
+                    "lo" => Op::Lo,
+                    "pcrel_hi" => Op::PcrelHi,
+                    "pcrel_lo" => Op::PcrelLo,
+                    _ => return Err(tok.expected("%hi, %lo, %pcrel_hi or %pcrel_lo")),
+                };
+                match rest[len..].trim_start().strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+                    Some(inner) => {
+                        let at = skip_space(tok.text, tok.text.len() - inner.len() - 1);
+                        (op, Tok::at(inner.trim(), tok.col + col(tok.text, at) - 1, tok.line))
+                    }
+                    None => return Err(tok.expected("(")),
+                }
+            }
+            None => (plain, *tok),
+        };
+        let value = self.expr(&inner)?;
+        let relocate = |symbol: String, addend| {
+            fixups.borrow_mut().push(Fixup { op, symbol, addend, offset: 0 });
+            Ok(0)
+        };
+        match op {
+            Op::None | Op::Call | Op::Pcrel if pcrel && !self.symbolic.get() => {
+                self.resolve(&value).ok_or_else(|| tok.expected("integer"))
+            }
+            Op::None | Op::Call | Op::Pcrel if pcrel => match self.pcrel(&value, pc) {
+                Some(off) => Ok(off),
+                None if op == Op::Pcrel => {
+                    // the addi's %pcrel_lo refers to the auipc
+                    let (symbol, addend) = self.target(tok, value)?;
+                    let label = format!(".Lpcrel_hi{}", self.hi.borrow().len());
+                    self.hi.borrow_mut().insert((self.cur, pc), Err(label.clone()));
+                    relocate(symbol, addend)?;
+                    fixups.borrow_mut().push(Fixup { op: Op::PcrelLo, symbol: label, addend: 0, offset: 4 });
+                    Ok(0)
+                }
+                None => {
+                    let (symbol, addend) = self.target(tok, value)?;
+                    relocate(symbol, addend)
+                }
+            },
+            Op::None | Op::Call | Op::Pcrel => self.resolve(&value).ok_or_else(|| tok.expected("absolute expression")),
+            Op::Hi | Op::Lo => match self.resolve(&value) {
+                Some(v) if op == Op::Hi => Ok(hi20(v)),
+                Some(v) => Ok(lo12(v)),
+                None => {
+                    let (symbol, addend) = self.target(tok, value)?;
+                    relocate(symbol, addend)
+                }
+            },
+            Op::PcrelHi => match self.pcrel(&value, pc) {
+                Some(off) => {
+                    self.hi.borrow_mut().insert((self.cur, pc), Ok(off));
+                    Ok(hi20(off))
+                }
+                None => {
+                    let (symbol, addend) = self.target(tok, value)?;
+                    let label = format!(".Lpcrel_hi{}", self.hi.borrow().len());
+                    self.hi.borrow_mut().insert((self.cur, pc), Err(label));
+                    relocate(symbol, addend)
+                }
+            },
+            Op::PcrelLo => {
+                let hi = match value {
+                    Value::Rel(s, off) => self.hi.borrow().get(&(s, off as u64)).cloned(),
+                    _ => None,
+                };
+                match hi {
+                    Some(Ok(off)) => Ok(lo12(off)),
+                    Some(Err(label)) => relocate(label, 0),
+                    None => Err(inner.expected("label of an earlier %pcrel_hi")),
+                }
+            }
+        }
+    }
+
+    // Number or address, if known without a relocation.
+    fn resolve(&self, value: &Value) -> Option<i64> {
+        match *value {
+            Value::Abs(v) => Some(v),
+            Value::Rel(s, off) if self.flat() => Some((self.bases[s] as i64).wrapping_add(off)),
+            _ => None,
+        }
+    }
+
+    // Offset of `value` from `pc` in the current section, if known.
+    fn pcrel(&self, value: &Value, pc: u64) -> Option<i64> {
+        match *value {
+            Value::Rel(s, off) if s == self.cur => Some(off.wrapping_sub(pc as i64)),
+            _ => Some(self.resolve(value)?.wrapping_sub(self.bases[self.cur].wrapping_add(pc) as i64)).filter(|_| self.flat()),
+        }
+    }
+
+    // Symbol and addend of a relocation against `value`.
+    fn target(&self, tok: &Tok, value: Value) -> Result<(String, i64), ParseError> {
+        match value {
+            Value::Rel(s, off) => Ok((self.sections[s].name.clone(), off)),
+            Value::Ext(name, _) if self.flat() => Err(tok.error(ParseErrorKind::Undefined(name))),
+            Value::Ext(name, addend) => Ok((name, addend)),
+            Value::Abs(_) => Err(tok.expected("label")),
+        }
+    }
+
+    fn absolute(&self, tok: &Tok) -> Result<i64, ParseError> {
+        match self.expr(tok)? {
+            Value::Abs(v) => Ok(v),
+            _ => Err(tok.expected("absolute expression")),
+        }
+    }
+
+    // Whole operand as an expression: numbers, symbols, `.`, `1b`/`1f`,
+    // parentheses, unary `-` and `~`, and the C binary operators.
+    fn expr(&self, tok: &Tok) -> Result<Value, ParseError> {
+        self.symbolic.set(false);
+        let mut pos = 0;
+        let value = self.binary(tok, &mut pos, 0)?;
+        match skip_space(tok.text, pos) {
+            end if end == tok.text.len() => Ok(value),
+            end => Err(self.error(tok, end, ParseErrorKind::Expected("operator"))),
+        }
+    }
+
+    fn error(&self, tok: &Tok, pos: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { line: tok.line, col: tok.col + col(tok.text, pos) - 1, kind }
+    }
+
+    fn binary(&self, tok: &Tok, pos: &mut usize, min: u8) -> Result<Value, ParseError> {
+        let mut lhs = self.unary(tok, pos)?;
+        loop {
+            let at = skip_space(tok.text, *pos);
+            let rest = &tok.text[at..];
+            let (op, prec) = match rest.get(..2) {
+                Some(op @ ("<<" | ">>")) => (op, 4),
+                _ => match rest.get(..1) {
+                    Some(op @ "|") => (op, 1),
+                    Some(op @ "^") => (op, 2),
+                    Some(op @ "&") => (op, 3),
+                    Some(op @ ("+" | "-")) => (op, 5),
+                    Some(op @ ("*" | "/" | "%")) => (op, 6),
+                    _ => return Ok(lhs),
+                },
+            };
+            if prec <= min {
+                return Ok(lhs);
+            }
+            *pos = at + op.len();
+            let rhs = self.binary(tok, pos, prec)?;
+            // offsets from a label or symbol do not wrap
+            let offset = |v: Option<i64>, b: i64| v.ok_or_else(|| self.error(tok, at, ParseErrorKind::OutOfRange(b)));
+            lhs = match (op, lhs, rhs) {
+                (_, Value::Abs(a), Value::Abs(b)) => Value::Abs(match op {
+                    "|" => a | b,
+                    "^" => a ^ b,
+                    "&" => a & b,
+                    "<<" => a.wrapping_shl(b as u32),
+                    ">>" => a.wrapping_shr(b as u32),
+                    "+" => a.wrapping_add(b),
+                    "-" => a.wrapping_sub(b),
+                    "*" => a.wrapping_mul(b),
+                    _ if b == 0 => return Err(self.error(tok, at, ParseErrorKind::OutOfRange(0))),
+                    "/" => a.wrapping_div(b),
+                    _ => a.wrapping_rem(b),
+                }),
+                ("+", Value::Rel(s, a), Value::Abs(b)) | ("+", Value::Abs(b), Value::Rel(s, a)) => {
+                    Value::Rel(s, offset(a.checked_add(b), b)?)
+                }
+                ("-", Value::Rel(s, a), Value::Abs(b)) => Value::Rel(s, offset(a.checked_sub(b), b)?),
+                ("-", Value::Rel(s, a), Value::Rel(t, b)) if s == t => Value::Abs(offset(a.checked_sub(b), b)?),
+                ("+", Value::Ext(n, a), Value::Abs(b)) | ("+", Value::Abs(b), Value::Ext(n, a)) => {
+                    Value::Ext(n, offset(a.checked_add(b), b)?)
+                }
+                ("-", Value::Ext(n, a), Value::Abs(b)) => Value::Ext(n, offset(a.checked_sub(b), b)?),
+                _ if !self.last => Value::Abs(0), // forward references
+                _ => return Err(self.error(tok, at, ParseErrorKind::Expected("absolute expression"))),
+            };
+        }
+    }
+
+    fn unary(&self, tok: &Tok, pos: &mut usize) -> Result<Value, ParseError> {
+        *pos = skip_space(tok.text, *pos);
+        let at = *pos;
+        let rest = &tok.text[at..];
+        let c = rest.chars().next().unwrap_or(' ');
+        if c == '-' || c == '~' || c == '+' {
+            *pos += 1;
+            return match (c, self.unary(tok, pos)?) {
+                ('-', Value::Abs(v)) => Ok(Value::Abs(v.wrapping_neg())),
+                ('~', Value::Abs(v)) => Ok(Value::Abs(!v)),
+                ('+', v) => Ok(v),
+                _ if !self.last => Ok(Value::Abs(0)),
+                _ => Err(self.error(tok, at, ParseErrorKind::Expected("absolute expression"))),
+            };
+        }
+        if c == '(' {
+            *pos += 1;
+            let value = self.binary(tok, pos, 0)?;
+            *pos = skip_space(tok.text, *pos);
+            if !tok.text[*pos..].starts_with(')') {
+                return Err(self.error(tok, *pos, ParseErrorKind::Expected(")")));
+            }
+            *pos += 1;
+            return Ok(value);
+        }
+        let len = rest.find(|c| !is_symbol_char(c)).unwrap_or(rest.len());
+        let word = &rest[..len];
+        *pos += len;
+        if word == "." {
+            self.symbolic.set(true);
+            return Ok(Value::Rel(self.cur, self.pc() as i64));
+        }
+        if c.is_ascii_digit() {
+            let back = word.ends_with('b');
+            return match word[..len - 1].parse::<u64>() {
+                Ok(n) if back || word.ends_with('f') => {
+                    self.symbolic.set(true);
+                    let found = if back {
+                        self.locals[..self.nlocal].iter().rev().find(|l| l.0 == n)
+                    } else {
+                        self.locals[self.nlocal..].iter().find(|l| l.0 == n)
+                    };
+                    match found {
+                        Some(&(_, s, off)) => Ok(Value::Rel(s, off as i64)),
+                        None if self.last => Err(self.error(tok, at, ParseErrorKind::Undefined(word.to_string()))),
+                        None => Ok(Value::Abs(0)),
+                    }
+                }
+                _ => match parse_int(word) {
+                    Some(v) => Ok(Value::Abs(v)),
+                    None => Err(self.error(tok, at, ParseErrorKind::Expected("integer"))),
+                },
+            };
+        }
+        if len == 0 {
+            return Err(self.error(tok, at, ParseErrorKind::Expected("expression")));
+        }
+        self.symbolic.set(true);
+        Ok(match self.symbols.get(word) {
+            Some(&Def::Abs(v)) => Value::Abs(v),
+            Some(&Def::Label(s, off)) => Value::Rel(s, off),
+            None => Value::Ext(word.to_string(), 0),
+        })
+    }
+}
+
+// Bytes of the string literal at `pos`, with C escapes.
+fn string(text: &str, pos: &mut usize, line: usize) -> Result<Vec<u8>, ParseError> {
+    let error = |at, what| ParseError { line, col: col(text, at), kind: ParseErrorKind::Expected(what) };
+    if !text[*pos..].starts_with('"') {
+        return Err(error(*pos, "string"));
+    }
+    let mut bytes = Vec::new();
+    let mut chars = text[*pos + 1..].char_indices().map(|(i, c)| (*pos + 1 + i, c)).peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                *pos = i + 1;
+                return Ok(bytes);
+            }
+            '\\' => {
+                let (_, e) = chars.next().ok_or_else(|| error(i, "escape"))?;
+                let b = match e {
+                    'n' => b'\n',
+                    't' => b'\t',
+                    'r' => b'\r',
+                    '\\' | '"' | '\'' => e as u8,
+                    'x' => {
+                        let mut v = 0u32;
+                        while let Some(&(_, d)) = chars.peek().filter(|(_, d)| d.is_ascii_hexdigit()) {
+                            v = v << 4 | d.to_digit(16).unwrap();
+                            chars.next();
+                        }
+                        v as u8
+                    }
+                    '0'..='7' => {
+                        let mut v = e.to_digit(8).unwrap();
+                        for _ in 0..2 {
+                            match chars.peek() {
+                                Some(&(_, d)) if d.is_digit(8) => {
+                                    v = v << 3 | d.to_digit(8).unwrap();
+                                    chars.next();
+                                }
+                                _ => break,
+                            }
+                        }
+                        v as u8
+                    }
+                    _ => return Err(error(i, "escape")),
+                };
+                bytes.push(b);
+            }
+            _ => bytes.extend(c.to_string().as_bytes()),
+        }
+    }
+    Err(error(text.len(), "\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Assembler, EncodeError, Gpr, Inst, Object, ParseError, ParseErrorKind, Reloc, RelocKind};
+
+    fn assemble(src: &str) -> Result<Object, ParseError> {
+        Assembler::new("rv64gc".parse().unwrap()).assemble(src)
+    }
+
+    fn reloc(offset: u64, kind: RelocKind, symbol: &str, addend: i64) -> Reloc {
+        Reloc { offset, kind, symbol: symbol.to_string(), addend }
+    }
+
+    #[test]
+    fn local_labels() {
+        let src = "1: addi a0,a0,1\nbne a0,a1,1b\nbeq a0,zero,1f\naddi a0,a0,2\n1: addi a0,a0,3\njal zero,1b\n";
+        let insts: Vec<_> = assemble(src).unwrap().sections[0].insts.iter().map(|(_, inst)| inst.clone()).collect();
+        assert_eq!(insts[1], Inst::BNE(Gpr::a0, Gpr::a1, -4));
+        assert_eq!(insts[2], Inst::BEQ(Gpr::a0, Gpr::zero, 8));
+        assert_eq!(insts[5], Inst::JAL(Gpr::zero, -4));
+    }
+
+    #[test]
+    fn pcrel_pairs() {
+        // within the section the pair resolves
+        let obj = assemble("hi: auipc a0,%pcrel_hi(x)\naddi a0,a0,%pcrel_lo(hi)\naddi zero,zero,0\nx: addi zero,zero,0\n").unwrap();
+        let text = &obj.sections[0];
+        assert!(text.relocs.is_empty());
+        assert_eq!(text.insts[..2], [(0, Inst::AUIPC(Gpr::a0, 0)), (4, Inst::ADDI(Gpr::a0, Gpr::a0, 12))]);
+
+        // across sections it leaves relocations, the low parts against a
+        // label of their own at the auipc
+        let src = ".data\n.word 0\nmsg: .word 0\n.text\nhi: auipc a0,%pcrel_hi(msg)\naddi a1,a0,%pcrel_lo(hi)\nsw a2,%pcrel_lo(hi)(a0)\n";
+        let obj = assemble(src).unwrap();
+        let text = obj.sections.iter().find(|s| s.name == ".text").unwrap();
+        let want = [
+            reloc(0, RelocKind::PcrelHi20, ".data", 4),
+            reloc(4, RelocKind::PcrelLo12I, ".Lpcrel_hi0", 0),
+            reloc(8, RelocKind::PcrelLo12S, ".Lpcrel_hi0", 0),
+        ];
+        assert_eq!(text.relocs, want);
+    }
+
+    #[test]
+    fn pseudo_relocs() {
+        let obj = assemble(".data\n.word 0\nmsg: .word 0\n.text\nla a0, msg\ncall f\ntail f\n").unwrap();
+        let text = obj.sections.iter().find(|s| s.name == ".text").unwrap();
+        let want = [
+            reloc(0, RelocKind::PcrelHi20, ".data", 4),
+            reloc(4, RelocKind::PcrelLo12I, ".Lpcrel_hi0", 0),
+            reloc(8, RelocKind::Call, "f", 0),
+            reloc(16, RelocKind::Call, "f", 0),
+        ];
+        assert_eq!(text.relocs, want);
+        let label = obj.symbols.iter().find(|s| s.name == ".Lpcrel_hi0").unwrap();
+        assert_eq!((label.value, label.global), (0, false));
+
+        // li sizes itself in the first pass, before labels are known
+        let asm = Assembler::new("rv64gc".parse().unwrap()).origin(0x80000000);
+        let err = asm.assemble("x: li a0, x\n").unwrap_err();
+        assert_eq!((err.line, err.col), (1, 11));
+        assert!(err.to_string().contains("use la"), "{}", err);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let err = assemble("addi zero,zero,0\n  beq a0,a1,far\n.org 0x1008\nfar: addi zero,zero,0\n").unwrap_err();
+        assert_eq!(err, ParseError { line: 2, col: 13, kind: ParseErrorKind::Encode(EncodeError::ImmOutOfRange(0x1004)) });
+        assert_eq!(err.to_string(), "2:13: immediate 4100 out of range");
+    }
+
+    #[test]
+    fn padding() {
+        let obj = assemble(".data\n.byte 1\n.align 2\n.word 0x12345678\n.org 10\n.byte 2\n.balign 4, 0xff\n").unwrap();
+        let data = obj.sections.iter().find(|s| s.name == ".data").unwrap();
+        assert_eq!(data.data, [1, 0, 0, 0, 0x78, 0x56, 0x34, 0x12, 0, 0, 2, 0xff]);
+        assert_eq!(data.align, 4);
+        // code pads with nops, c.nop where a 4-byte one does not fit
+        let obj = assemble(".text\nc.addi a0,1\n.align 3\n").unwrap();
+        assert_eq!(obj.sections[0].data, [0x05, 0x05, 0x01, 0, 0x13, 0, 0, 0]);
+    }
+
+    #[test]
+    fn offsets() {
+        let asm = Assembler::new("rv64gc".parse().unwrap()).origin(0x80000000);
+        let obj = asm.assemble(".equ x, . - 8\naddi zero,zero,0\n").unwrap();
+        assert_eq!(obj.symbols[0].value, 0x80000000 - 8);
+        let obj = assemble(".equ x, . - 8\n").unwrap();
+        assert_eq!(obj.symbols[0].value, 0u64.wrapping_sub(8));
+        for src in [".word y + 0x7fffffffffffffff + 1\n", "x:\n.word x - 0x7fffffffffffffff - 2\n"] {
+            let err = assemble(src).unwrap_err();
+            assert!(matches!(err.kind, ParseErrorKind::OutOfRange(_)), "{}: {:?}", src, err);
+        }
+    }
+}
@@ -16,7 +16,7 @@ impl<'a> ByteSlice<'a> {
 
 impl<'a> ByteReader for ByteSlice<'a> {
     fn next(&mut self) -> Option<u8> {
-        if self.a.len() == 0 {
+        if self.a.is_empty() {
             return None;
         }
         let b = self.a[0];
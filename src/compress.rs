@@ -0,0 +1,347 @@
+// Compression: 32-bit instructions rewritten into the RVC instructions that do
+// the same, one at a time or over a whole run of code, and expansion back
+
+use crate::disasm::written;
+use crate::display::uncompress;
+use crate::{encode, Gpr, Inst, Isa};
+
+/// The compressed instruction that does what `inst` does, if `isa` has one
+/// for its registers and immediate: `ADDI(sp, sp, -16)` becomes
+/// `CADDI16SP(-16)` and `LW(a0, fp, 8)` becomes `CLW(a0, fp, 8)`. A
+/// compressed `inst` is returned as it is.
+pub fn compress(inst: &Inst, isa: &Isa) -> Option<Inst> {
+    candidates(inst).into_iter().find(|c| encode(c, isa).is_ok())
+}
+
+/// The base instruction that does what `inst` does: `CADDI(a0, 1)` becomes
+/// `ADDI(a0, a0, 1)` and `CJ(off)` becomes `JAL(zero, off)`. Base
+/// instructions, and the Zcmp and Zcmt ones that have no single base form,
+/// are returned as they are.
+pub fn expand(inst: &Inst) -> Inst {
+    uncompress(inst).unwrap_or_else(|| inst.clone())
+}
+
+// Compressed forms of `inst`; `encode` decides which of them fit.
+fn candidates(inst: &Inst) -> Vec<Inst> {
+    // the other source of a commutative operation whose rd is one of them
+    let other = |rd, rs1, rs2| if rd == rs1 { rs2 } else { rs1 };
+    match *inst {
+        _ if inst.size() == 2 => vec![inst.clone()],
+
+        Inst::ADDI(Gpr::zero, Gpr::zero, 0) => vec![Inst::CNOP],
+        Inst::ADDI(rd, Gpr::zero, imm) => vec![Inst::CLI(rd, imm)],
+        Inst::ADDI(rd, rs1, 0) => vec![Inst::CMV(rd, rs1)],
+        Inst::ADDI(Gpr::sp, Gpr::sp, imm) => vec![Inst::CADDI16SP(imm), Inst::CADDI(Gpr::sp, imm)],
+        Inst::ADDI(rd, Gpr::sp, imm) => vec![Inst::CADDI4SPN(rd, imm as u32)],
+        Inst::ADDI(rd, rs1, imm) if rd == rs1 => vec![Inst::CADDI(rd, imm)],
+        Inst::ADDIW(rd, Gpr::zero, imm) => vec![Inst::CLI(rd, imm)],
+        Inst::ADDIW(rd, rs1, imm) if rd == rs1 => vec![Inst::CADDIW(rd, imm)],
+        Inst::LUI(rd, imm) => vec![Inst::CLUI(rd, imm)],
+        Inst::SLLI(rd, rs1, 64) if rd == rs1 => vec![Inst::CSLLI64(rd)],
+        Inst::SLLI(rd, rs1, shamt) if rd == rs1 => vec![Inst::CSLLI(rd, shamt)],
+        Inst::SRLI(rd, rs1, 64) if rd == rs1 => vec![Inst::CSRLI64(rd)],
+        Inst::SRLI(rd, rs1, shamt) if rd == rs1 => vec![Inst::CSRLI(rd, shamt)],
+        Inst::SRAI(rd, rs1, 64) if rd == rs1 => vec![Inst::CSRAI64(rd)],
+        Inst::SRAI(rd, rs1, shamt) if rd == rs1 => vec![Inst::CSRAI(rd, shamt)],
+        Inst::ANDI(rd, rs1, 255) if rd == rs1 => vec![Inst::CZEXTB(rd)],
+        Inst::ANDI(rd, rs1, imm) if rd == rs1 => vec![Inst::CANDI(rd, imm)],
+        Inst::XORI(rd, rs1, -1) if rd == rs1 => vec![Inst::CNOT(rd)],
+
+        Inst::ADD(rd, Gpr::zero, rs2) | Inst::ADD(rd, rs2, Gpr::zero) => vec![Inst::CMV(rd, rs2)],
+        Inst::ADD(rd, rs1, rs2) if rd == rs1 || rd == rs2 => vec![Inst::CADD(rd, other(rd, rs1, rs2))],
+        Inst::SUB(rd, rs1, rs2) if rd == rs1 => vec![Inst::CSUB(rd, rs2)],
+        Inst::XOR(rd, rs1, rs2) if rd == rs1 || rd == rs2 => vec![Inst::CXOR(rd, other(rd, rs1, rs2))],
+        Inst::OR(rd, rs1, rs2) if rd == rs1 || rd == rs2 => vec![Inst::COR(rd, other(rd, rs1, rs2))],
+        Inst::AND(rd, rs1, rs2) if rd == rs1 || rd == rs2 => vec![Inst::CAND(rd, other(rd, rs1, rs2))],
+        Inst::SUBW(rd, rs1, rs2) if rd == rs1 => vec![Inst::CSUBW(rd, rs2)],
+        Inst::ADDW(rd, rs1, rs2) if rd == rs1 || rd == rs2 => vec![Inst::CADDW(rd, other(rd, rs1, rs2))],
+        Inst::MUL(rd, rs1, rs2) if rd == rs1 || rd == rs2 => vec![Inst::CMUL(rd, other(rd, rs1, rs2))],
+        Inst::ADDUW(rd, rs1, Gpr::zero) if rd == rs1 => vec![Inst::CZEXTW(rd)],
+        Inst::SEXTB(rd, rs1) if rd == rs1 => vec![Inst::CSEXTB(rd)],
+        Inst::SEXTH(rd, rs1) if rd == rs1 => vec![Inst::CSEXTH(rd)],
+        Inst::ZEXTH(rd, rs1) if rd == rs1 => vec![Inst::CZEXTH(rd)],
+
+        Inst::LW(rd, Gpr::sp, imm) => vec![Inst::CLWSP(rd, imm as u32)],
+        Inst::LD(rd, Gpr::sp, imm) => vec![Inst::CLDSP(rd, imm as u32)],
+        Inst::LQ(rd, Gpr::sp, imm) => vec![Inst::CLQSP(rd, imm as u32)],
+        Inst::FLW(rd, Gpr::sp, imm) => vec![Inst::CFLWSP(rd, imm as u32)],
+        Inst::FLD(rd, Gpr::sp, imm) => vec![Inst::CFLDSP(rd, imm as u32)],
+        Inst::SW(rs2, Gpr::sp, imm) => vec![Inst::CSWSP(rs2, imm as u32)],
+        Inst::SD(rs2, Gpr::sp, imm) => vec![Inst::CSDSP(rs2, imm as u32)],
+        Inst::SQ(rs2, Gpr::sp, imm) => vec![Inst::CSQSP(rs2, imm as u32)],
+        Inst::FSW(rs2, Gpr::sp, imm) => vec![Inst::CFSWSP(rs2, imm as u32)],
+        Inst::FSD(rs2, Gpr::sp, imm) => vec![Inst::CFSDSP(rs2, imm as u32)],
+        Inst::LW(rd, rs1, imm) => vec![Inst::CLW(rd, rs1, imm as u32)],
+        Inst::LD(rd, rs1, imm) => vec![Inst::CLD(rd, rs1, imm as u32)],
+        Inst::LQ(rd, rs1, imm) => vec![Inst::CLQ(rd, rs1, imm as u32)],
+        Inst::FLW(rd, rs1, imm) => vec![Inst::CFLW(rd, rs1, imm as u32)],
+        Inst::FLD(rd, rs1, imm) => vec![Inst::CFLD(rd, rs1, imm as u32)],
+        Inst::SW(rs2, rs1, imm) => vec![Inst::CSW(rs2, rs1, imm as u32)],
+        Inst::SD(rs2, rs1, imm) => vec![Inst::CSD(rs2, rs1, imm as u32)],
+        Inst::SQ(rs2, rs1, imm) => vec![Inst::CSQ(rs2, rs1, imm as u32)],
+        Inst::FSW(rs2, rs1, imm) => vec![Inst::CFSW(rs2, rs1, imm as u32)],
+        Inst::FSD(rs2, rs1, imm) => vec![Inst::CFSD(rs2, rs1, imm as u32)],
+        Inst::LBU(rd, rs1, imm) => vec![Inst::CLBU(rd, rs1, imm as u32)],
+        Inst::LHU(rd, rs1, imm) => vec![Inst::CLHU(rd, rs1, imm as u32)],
+        Inst::LH(rd, rs1, imm) => vec![Inst::CLH(rd, rs1, imm as u32)],
+        Inst::SB(rs2, rs1, imm) => vec![Inst::CSB(rs2, rs1, imm as u32)],
+        Inst::SH(rs2, rs1, imm) => vec![Inst::CSH(rs2, rs1, imm as u32)],
+
+        Inst::JAL(Gpr::zero, imm) => vec![Inst::CJ(imm)],
+        Inst::JAL(Gpr::ra, imm) => vec![Inst::CJAL(imm)],
+        Inst::JALR(Gpr::zero, rs1, 0) => vec![Inst::CJR(rs1)],
+        Inst::JALR(Gpr::ra, rs1, 0) => vec![Inst::CJALR(rs1)],
+        Inst::BEQ(rs1, Gpr::zero, imm) | Inst::BEQ(Gpr::zero, rs1, imm) => vec![Inst::CBEQZ(rs1, imm)],
+        Inst::BNE(rs1, Gpr::zero, imm) | Inst::BNE(Gpr::zero, rs1, imm) => vec![Inst::CBNEZ(rs1, imm)],
+        Inst::EBREAK => vec![Inst::CEBREAK],
+        _ => vec![],
+    }
+}
+
+/// Compresses a run of code laid out back to back from offset 0, returning
+/// one instruction for each of `insts`. Branch and jump offsets are
+/// recomputed for the new layout, as are those of `auipc` pairs: an `auipc`
+/// and the `addi`, `jalr`, loads and stores that add a low part to its
+/// register before the next branch or jump, or before the register is
+/// written again. Branches are relaxed: each takes its compressed form where
+/// its new distance allows. Targets outside the run keep their distance from
+/// it.
+///
+/// `None` if an `auipc` register is read some other way, or its low parts
+/// no longer share one upper part, since the address it makes could not be
+/// kept.
+pub fn compress_all(insts: &[Inst], isa: &Isa) -> Option<Vec<Inst>> {
+    let n = insts.len();
+    let mut old = vec![0i64];
+    for inst in insts {
+        old.push(old[old.len() - 1] + inst.size() as i64);
+    }
+    let mut out: Vec<Inst> = insts.iter().map(expand).collect();
+
+    // old offset each branch refers to
+    let mut targets = vec![None; n];
+    let mut small = vec![false; n];
+    for i in 0..n {
+        if let Some(&mut off) = branch_offset(&mut out[i]) {
+            targets[i] = Some(old[i] + off as i64);
+            small[i] = !candidates(&out[i]).is_empty();
+        } else {
+            small[i] = compress(&out[i], isa).is_some();
+        }
+    }
+    // each auipc with the old offsets its low parts refer to; those stay
+    // 32-bit
+    let mut pairs = Vec::new();
+    for i in 0..n {
+        let Inst::AUIPC(rd, hi) = out[i] else {
+            continue;
+        };
+        if rd == Gpr::zero {
+            continue;
+        }
+        let base = old[i] + (hi << 12) as i32 as i64;
+        let mut lows = Vec::new();
+        for j in i + 1..n {
+            let w = encode(&out[j], isa).ok().filter(|enc| enc.size() == 4)?.bits();
+            match low_part(&mut out[j].clone()) {
+                Some((rs1, &mut lo)) if rs1 == rd => {
+                    lows.push((j, base + lo as i64));
+                    small[j] = false;
+                }
+                // the address used some other way
+                _ if reads(w, rd) => return None,
+                _ => {}
+            }
+            if written(w) == Some(rd as usize) || branch_offset(&mut out[j].clone()).is_some() || matches!(out[j], Inst::JALR(..)) {
+                break;
+            }
+        }
+        pairs.push((i, lows));
+    }
+
+    let layout = |small: &[bool]| {
+        let mut new = vec![0i64];
+        for (inst, &small) in out.iter().zip(small) {
+            new.push(new[new.len() - 1] + if small { 2 } else { inst.size() as i64 });
+        }
+        new
+    };
+    let locate = |target: i64, new: &[i64]| match old.binary_search(&target) {
+        Ok(k) => new[k],
+        Err(0) => target,
+        Err(k) if k > n => new[n] + target - old[n],
+        Err(k) => new[k - 1] + target - old[k - 1],
+    };
+
+    // shrinking only brings targets closer, so expanding the branches that
+    // no longer reach settles
+    let new = loop {
+        let new = layout(&small);
+        let mut settled = true;
+        for i in 0..n {
+            if let (true, Some(target)) = (small[i], targets[i]) {
+                let mut inst = out[i].clone();
+                *branch_offset(&mut inst).unwrap() = (locate(target, &new) - new[i]) as i32;
+                if compress(&inst, isa).is_none() {
+                    small[i] = false;
+                    settled = false;
+                }
+            }
+        }
+        if settled {
+            break new;
+        }
+    };
+
+    for i in 0..n {
+        if let (Some(target), Some(imm)) = (targets[i], branch_offset(&mut out[i])) {
+            *imm = (locate(target, &new) - new[i]) as i32;
+        }
+    }
+    for (i, lows) in pairs {
+        let Some(&(_, first)) = lows.first() else {
+            continue;
+        };
+        let off = locate(first, &new) - new[i];
+        let hi = (off + 0x800) >> 12;
+        if let Inst::AUIPC(_, imm) = &mut out[i] {
+            *imm = hi as u32 & 0xfffff;
+        }
+        for (j, target) in lows {
+            let lo = locate(target, &new) - new[i] - (hi << 12);
+            if !(-0x800..0x800).contains(&lo) {
+                return None;
+            }
+            *low_part(&mut out[j]).unwrap().1 = lo as i32;
+        }
+    }
+    Some(out.iter().zip(small).map(|(inst, small)| if small { compress(inst, isa).unwrap() } else { inst.clone() }).collect())
+}
+
+// Whether the 32-bit word `w` may read `reg`: the rs1 and rs2 fields are
+// taken for GPRs unless the opcode puts something else there.
+fn reads(w: u32, reg: Gpr) -> bool {
+    let (rs1, rs2) = (w >> 15 & 0x1f == reg as u32, w >> 20 & 0x1f == reg as u32);
+    let f3 = w >> 12 & 7;
+    match w & 0x7f {
+        0x17 | 0x37 | 0x6f | 0x43 | 0x47 | 0x4b | 0x4f => false,
+        0x03 | 0x0f | 0x13 | 0x1b | 0x5b | 0x67 => rs1,
+        // csrr*i hold an immediate in rs1
+        0x73 if f3 >= 5 => false,
+        0x73 if f3 != 0 => rs1,
+        // fcvt from an integer and fmv.w.x
+        0x53 => rs1 && matches!(w >> 27, 0b11010 | 0b11110),
+        // floating-point loads and stores; vector ones may take a stride
+        0x07 | 0x27 => rs1 || (rs2 && !(1..=4).contains(&f3)),
+        // OPIVX, OPMVX and vsetvl{i}
+        0x57 => rs1 && matches!(f3, 0b100 | 0b110 | 0b111) || rs2 && f3 == 0b111,
+        _ => rs1 || rs2,
+    }
+}
+
+// Offset field of a branch or jump.
+fn branch_offset(inst: &mut Inst) -> Option<&mut i32> {
+    match inst {
+        Inst::BEQ(_, _, imm)
+        | Inst::BNE(_, _, imm)
+        | Inst::BLT(_, _, imm)
+        | Inst::BGE(_, _, imm)
+        | Inst::BLTU(_, _, imm)
+        | Inst::BGEU(_, _, imm)
+        | Inst::JAL(_, imm) => Some(imm),
+        _ => None,
+    }
+}
+
+// Base register and offset field of an instruction that can complete an
+// auipc pair.
+fn low_part(inst: &mut Inst) -> Option<(Gpr, &mut i32)> {
+    match inst {
+        Inst::ADDI(_, rs1, imm)
+        | Inst::JALR(_, rs1, imm)
+        | Inst::LB(_, rs1, imm)
+        | Inst::LH(_, rs1, imm)
+        | Inst::LW(_, rs1, imm)
+        | Inst::LD(_, rs1, imm)
+        | Inst::LQ(_, rs1, imm)
+        | Inst::LBU(_, rs1, imm)
+        | Inst::LHU(_, rs1, imm)
+        | Inst::LWU(_, rs1, imm)
+        | Inst::LDU(_, rs1, imm)
+        | Inst::SB(_, rs1, imm)
+        | Inst::SH(_, rs1, imm)
+        | Inst::SW(_, rs1, imm)
+        | Inst::SD(_, rs1, imm)
+        | Inst::SQ(_, rs1, imm)
+        | Inst::FLH(_, rs1, imm)
+        | Inst::FLW(_, rs1, imm)
+        | Inst::FLD(_, rs1, imm)
+        | Inst::FLQ(_, rs1, imm)
+        | Inst::FSH(_, rs1, imm)
+        | Inst::FSW(_, rs1, imm)
+        | Inst::FSD(_, rs1, imm)
+        | Inst::FSQ(_, rs1, imm) => Some((*rs1, imm)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compress, compress_all, parse, Gpr, Inst, Isa};
+
+    fn rv64() -> Isa {
+        "rv64gc".parse().unwrap()
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(compress(&Inst::ADDI(Gpr::sp, Gpr::sp, -16), &rv64()), Some(Inst::CADDI16SP(-16)));
+        assert_eq!(compress(&Inst::LW(Gpr::a0, Gpr::fp, 8), &rv64()), Some(Inst::CLW(Gpr::a0, Gpr::fp, 8)));
+        assert_eq!(compress(&Inst::LW(Gpr::a0, Gpr::t0, 8), &rv64()), None);
+        assert_eq!(compress(&Inst::LW(Gpr::a0, Gpr::fp, 8), &"rv64g".parse().unwrap()), None);
+    }
+
+    #[test]
+    fn branches() {
+        let code = parse("addi a0,a0,1\nadd a1,a1,a0\nlw a2,8(s0)\nbeq a0,zero,-12\nret", &rv64()).unwrap();
+        let want = [
+            Inst::CADDI(Gpr::a0, 1),
+            Inst::CADD(Gpr::a1, Gpr::a0),
+            Inst::CLW(Gpr::a2, Gpr::fp, 8),
+            Inst::CBEQZ(Gpr::a0, -6),
+            Inst::CJR(Gpr::ra),
+        ];
+        assert_eq!(compress_all(&code, &rv64()), Some(want.to_vec()));
+
+        // the forward branch stays 32-bit over 256 incompressible subs
+        let mut src = "bne a0,zero,1032\n".to_string();
+        src += &"sub t0,t1,t2\n".repeat(256);
+        src += "addi a0,a0,1\nbne a0,zero,-4\nj -8\n";
+        let out = compress_all(&parse(&src, &rv64()).unwrap(), &rv64()).unwrap();
+        assert_eq!(out[0], Inst::BNE(Gpr::a0, Gpr::zero, 1030));
+        assert_eq!(out[258..], [Inst::CBNEZ(Gpr::a0, -2), Inst::CJ(-4)]);
+
+        // this one comes into c.beqz range once the adds shrink
+        let src = "beq a0,zero,404\n".to_string() + &"add a0,a0,a1\n".repeat(100) + "ret\n";
+        let out = compress_all(&parse(&src, &rv64()).unwrap(), &rv64()).unwrap();
+        assert_eq!(out[0], Inst::CBEQZ(Gpr::a0, 202));
+    }
+
+    #[test]
+    fn auipc_pairs() {
+        let isa = rv64();
+        let code = parse("auipc a0,0\naddi a0,a0,20\naddi a1,a1,1\naddi a1,a1,1\naddi a1,a1,1\nnop", &isa).unwrap();
+        let out = compress_all(&code, &isa).unwrap();
+        assert_eq!(out[..2], [Inst::AUIPC(Gpr::a0, 0), Inst::ADDI(Gpr::a0, Gpr::a0, 14)]);
+        assert_eq!(compress_all(&out, &isa).as_ref(), Some(&out));
+
+        // scheduled apart, and with two low parts
+        let code = parse("addi a1,a1,1\nauipc a0,0\naddi t0,t0,10\nlw a2,16(a0)\nsw a3,20(a0)\naddi a1,a1,1\nnop", &isa).unwrap();
+        let out = compress_all(&code, &isa).unwrap();
+        assert_eq!(out[3..5], [Inst::LW(Gpr::a2, Gpr::a0, 14), Inst::SW(Gpr::a3, Gpr::a0, 16)]);
+
+        // the address goes somewhere we cannot follow
+        let code = parse("addi a1,a1,1\nauipc a0,0\nadd t0,a0,t1\nlw a2,16(a0)\nnop", &isa).unwrap();
+        assert_eq!(compress_all(&code, &isa), None);
+    }
+}
@@ -7,7 +7,12 @@ pub enum Csr {
 
     fflags = 0x001,
     frm = 0x002,
-    fcr = 0x003,
+    fcsr = 0x003,
+
+    vstart = 0x008,
+    vxsat = 0x009,
+    vxrm = 0x00a,
+    vcsr = 0x00f,
 
     cycle = 0xc00,
     time = 0xc01,
@@ -15,6 +20,10 @@ pub enum Csr {
 
     hpmcounter(u16), // n: 3 [0xc00] -> 31 [0xc1f]
 
+    vl = 0xc20,
+    vtype = 0xc21,
+    vlenb = 0xc22,
+
     cycleh = 0xc80,
     timeh = 0xc81,
     instreth = 0xc82,
@@ -99,7 +108,8 @@ pub enum Csr {
     mseccfg = 0x747,
     mseccfgh = 0x757,
 
-    pmpcfg(u16), // n = 0 [0x3a0] -> 63 [0x3ef]
+    pmpcfg(u16), // n = 0 [0x3a0] -> 15 [0x3af]
+    pmpaddr(u16), // n = 0 [0x3b0] -> 63 [0x3ef]
 
     mcycle = 0xb00,
     minstret = 0xb02,
@@ -129,11 +139,18 @@ impl Csr {
         match r {
             0x001 => Csr::fflags,
             0x002 => Csr::frm,
-            0x003 => Csr::fcr,
+            0x003 => Csr::fcsr,
+            0x008 => Csr::vstart,
+            0x009 => Csr::vxsat,
+            0x00a => Csr::vxrm,
+            0x00f => Csr::vcsr,
             0xc00 => Csr::cycle,
             0xc01 => Csr::time,
             0xc02 => Csr::instret,
             0xc03 .. 0xc20 => Csr::hpmcounter(r-0xc00), // n: 3 [0xc03] -> 31 [0xc1f]
+            0xc20 => Csr::vl,
+            0xc21 => Csr::vtype,
+            0xc22 => Csr::vlenb,
             0xc80 => Csr::cycleh,
             0xc81 => Csr::timeh,
             0xc82 => Csr::instreth,
@@ -200,10 +217,11 @@ impl Csr {
             0x31a => Csr::menvcfgh,
             0x747 => Csr::mseccfg,
             0x757 => Csr::mseccfgh,
-            0x3a0 .. 0x3f0 => Csr::pmpcfg(r - 0x3a0), // n = 0 [0x3a0] -> 63 [0x3ef]
+            0x3a0 .. 0x3b0 => Csr::pmpcfg(r - 0x3a0), // n = 0 [0x3a0] -> 15 [0x3af]
+            0x3b0 .. 0x3f0 => Csr::pmpaddr(r - 0x3b0), // n = 0 [0x3b0] -> 63 [0x3ef]
             0xb00 => Csr::mcycle,
             0xb02 => Csr::minstret,
-            0xb03 .. 0xb1f => Csr::mhpmcounter(r-0xb00), // n = 3 [0xb03] -> 31 [0xb1f]
+            0xb03 .. 0xb20 => Csr::mhpmcounter(r-0xb00), // n = 3 [0xb03] -> 31 [0xb1f]
             0xb80 => Csr::mcycleh,
             0xb82 => Csr::minstreth,
             0xb83 .. 0xba0 => Csr::mhpmcounterh(r-0xb80), // n = 3 [0xb83] -> 31 [0xb9f]
@@ -221,14 +239,35 @@ impl Csr {
             _ => Csr::unknown(r),
         }
     }
+
+    pub fn to_u16(&self) -> u16 {
+        match *self {
+            Csr::unknown(r) => r,
+            Csr::hpmcounter(n) => 0xc00 + n,
+            Csr::hpmcounterh(n) => 0xc80 + n,
+            Csr::pmpcfg(n) => 0x3a0 + n,
+            Csr::pmpaddr(n) => 0x3b0 + n,
+            Csr::mhpmcounter(n) => 0xb00 + n,
+            Csr::mhpmcounterh(n) => 0xb80 + n,
+            Csr::mhpmevent(n) => 0x320 + n,
+            // SAFETY: `Csr` is `repr(u16)`, so every variant starts with its u16 discriminant.
+            _ => unsafe { *(self as *const Self as *const u16) },
+        }
+    }
 }
 
 impl std::fmt::Display for Csr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Csr::unknown(v) = self {
-            write!(f, "0x{:x}", v)    
-        } else {
-            write!(f, "{:?}", self)
+        match self {
+            Csr::unknown(v) => write!(f, "0x{:x}", v),
+            Csr::hpmcounter(n) => write!(f, "hpmcounter{}", n),
+            Csr::hpmcounterh(n) => write!(f, "hpmcounter{}h", n),
+            Csr::pmpcfg(n) => write!(f, "pmpcfg{}", n),
+            Csr::pmpaddr(n) => write!(f, "pmpaddr{}", n),
+            Csr::mhpmcounter(n) => write!(f, "mhpmcounter{}", n),
+            Csr::mhpmcounterh(n) => write!(f, "mhpmcounter{}h", n),
+            Csr::mhpmevent(n) => write!(f, "mhpmevent{}", n),
+            _ => write!(f, "{:?}", self),
         }
     }
 }
@@ -1,485 +1,1652 @@
-use crate::{ByteReader, Csr, Gpr, Inst};
+use crate::{expand, Aqrl, ByteReader, Csr, Ext, Fence, Fli, Fm, Fpr, Gpr, Inst, Isa, Rlist, Rm, Sew, Vm, Vr, Vtype};
 
 use crate::extr::{
     b_imm_bits,
+    bs_bits,
+    c_addi16sp_imm_bits,
+    c_addi4spn_imm_bits,
+    c_b_imm_bits,
+    c_ca_f2_bits,
+    c_cb_f2_bits,
+    c_cmpp_f5_bits,
+    c_cu_f5_bits,
     c_f3_bits,
+    c_f4_bits,
+    c_f6_bits,
+    c_i_imm_bits,
+    c_index_bits,
+    c_j_imm_bits,
+    c_lbu_imm_bits,
+    c_ld_imm_bits,
+    c_ldsp_imm_bits,
+    c_lh_imm_bits,
+    c_lq_imm_bits,
+    c_lqsp_imm_bits,
+    c_lui_imm_bits,
+    c_lw_imm_bits,
+    c_lwsp_imm_bits,
+    c_r1s_bits,
+    c_r2s_bits,
+    c_rd_bits,
+    c_rdp_bits,
+    c_rlist_bits,
+    c_rs1p_bits,
+    c_rs2_bits,
+    c_rs2p_bits,
+    c_sdsp_imm_bits,
+    c_shamt_bits,
+    c_spimm_bits,
+    c_sqsp_imm_bits,
+    c_swsp_imm_bits,
+    csr_bits,
+    f12_bits,
+    f2_bits,
     f3_bits,
-    //    rs3_bits,
-    //    f2_bits,
     f5_bits,
     f6_bits,
     f7_bits,
+    fm_bits,
     i_imm_bits,
     j_imm_bits,
+    mew_bits,
+    mop_bits,
+    nf_bits,
     op_bits,
+    pred_bits,
     rd_bits,
+    rnum_bits,
     rs1_bits,
     rs2_bits,
+    rs3_bits,
     s_imm_bits,
+    shamt128_imm_bits,
     shamt32_imm_bits,
     shamt64_imm_bits,
+    succ_bits,
     u_imm_bits,
+    v_simm5_bits,
+    vm_bits,
+    vtype_bits,
+    vtypei_bits,
 };
 
-#[allow(dead_code)]
-pub fn decode(bytes: &mut dyn ByteReader, bits: u8) -> (Inst, u8) {
+#[derive(Clone, PartialEq, Debug)]
+pub struct Decoded {
+    pub inst: Inst,
+    /// Instruction length in bytes (2 or 4).
+    pub size: u8,
+    /// Raw instruction bits, 16-bit encodings zero-extended.
+    pub raw: u32,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum DecodeError {
+    /// Input ended inside an instruction; `raw` holds the `size` bytes read so far.
+    Truncated { raw: u32, size: u8, missing: u8 },
+    /// The all-zero 16-bit word, defined to be illegal.
+    Illegal { raw: u32, size: u8 },
+    /// Encoding reserved by the specification.
+    Reserved { raw: u32, size: u8 },
+    /// HINT encoding: architecturally a no-op, not a real instruction.
+    Hint { raw: u32, size: u8 },
+    /// Valid encoding from an extension or XLEN that is not enabled.
+    Disabled { raw: u32, size: u8 },
+    /// Encoding not defined by any supported extension.
+    Unknown { raw: u32, size: u8 },
+}
+
+impl DecodeError {
+    pub fn raw(&self) -> u32 {
+        match *self {
+            DecodeError::Truncated { raw, .. }
+            | DecodeError::Illegal { raw, .. }
+            | DecodeError::Reserved { raw, .. }
+            | DecodeError::Hint { raw, .. }
+            | DecodeError::Disabled { raw, .. }
+            | DecodeError::Unknown { raw, .. } => raw,
+        }
+    }
+
+    /// Number of bytes consumed; skip this many to resynchronise.
+    pub fn size(&self) -> u8 {
+        match *self {
+            DecodeError::Truncated { size, .. }
+            | DecodeError::Illegal { size, .. }
+            | DecodeError::Reserved { size, .. }
+            | DecodeError::Hint { size, .. }
+            | DecodeError::Disabled { size, .. }
+            | DecodeError::Unknown { size, .. } => size,
+        }
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let w = if self.size() == 2 { 4 } else { 8 };
+        match *self {
+            DecodeError::Truncated { missing, .. } => write!(f, "truncated instruction, {} byte(s) missing", missing),
+            DecodeError::Illegal { raw, .. } => write!(f, "illegal instruction {:0w$x}", raw, w = w),
+            DecodeError::Reserved { raw, .. } => write!(f, "reserved encoding {:0w$x}", raw, w = w),
+            DecodeError::Hint { raw, .. } => write!(f, "hint encoding {:0w$x}", raw, w = w),
+            DecodeError::Disabled { raw, .. } => write!(f, "encoding {:0w$x} from a disabled extension", raw, w = w),
+            DecodeError::Unknown { raw, .. } => write!(f, "unknown encoding {:0w$x}", raw, w = w),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+pub fn decode(bytes: &mut dyn ByteReader, isa: &Isa) -> Result<Decoded, DecodeError> {
     let b0 = match bytes.next() {
-        None => return (Inst::ERROR, 0),
+        None => return Err(DecodeError::Truncated { raw: 0, size: 0, missing: 2 }),
         Some(b) => b,
     };
 
     match b0 & 0b11 {
-        0b00 => decode_quadrant0(bytes, b0, bits),
-        0b01 => decode_quadrant1(bytes, b0, bits),
-        0b10 => decode_quadrant2(bytes, b0, bits),
-        0b11 => decode_quadrant3(bytes, b0, bits),
+        0b00 => decode_quadrant0(bytes, b0, isa),
+        0b01 => decode_quadrant1(bytes, b0, isa),
+        0b10 => decode_quadrant2(bytes, b0, isa),
+        0b11 => decode_quadrant3(bytes, b0, isa).or_else(|e| decode_vendor(e, isa)),
         _ => unreachable!(),
     }
 }
 
-fn decode_quadrant0(bytes: &mut dyn ByteReader, b0: u8, bits: u8) -> (Inst, u8) {
+/// Like `decode`, but a compressed instruction comes back as the base
+/// instruction it expands to (see `expand`); `size` and `raw` still
+/// describe the 16-bit encoding that was read.
+pub fn decode_expanded(bytes: &mut dyn ByteReader, isa: &Isa) -> Result<Decoded, DecodeError> {
+    let d = decode(bytes, isa)?;
+    Ok(Decoded { inst: expand(&d.inst), ..d })
+}
+
+// Words no enabled standard extension defines go to the vendor extensions,
+// first match wins.
+fn decode_vendor(e: DecodeError, isa: &Isa) -> Result<Decoded, DecodeError> {
+    let w = match e {
+        DecodeError::Unknown { raw, size: 4 } | DecodeError::Disabled { raw, size: 4 } => raw,
+        _ => return Err(e),
+    };
+    isa.vendors()
+        .find_map(|v| v.decode(w, isa))
+        .map(|c| Decoded { inst: Inst::Custom(c), size: 4, raw: w })
+        .ok_or(e)
+}
+
+fn decode_quadrant0(bytes: &mut dyn ByteReader, b0: u8, isa: &Isa) -> Result<Decoded, DecodeError> {
+    let bits = isa.xlen();
     let mut w = b0 as u16;
     let wn = match bytes.next() {
-        None => return (Inst::ERROR, 0),
+        None => return Err(DecodeError::Truncated { raw: w as u32, size: 1, missing: 1 }),
         Some(b) => b,
     };
     w |= (wn as u16) << 8;
 
-    (
-        match c_f3_bits(w) {
-            0b000 => {
-                if w == 0 {
-                    Inst::CILLEGAL
-                } else {
-                    Inst::CADDI4SPN // (crd_gpr(w), nzuimm_bits(w))
-                }
-            }
-            0b001 => {
-                if bits != 128 {
-                    Inst::CFLD // 001 uimm[5:3] rs1′ uimm[7:6] rd′ 00  (RV32/64)
-                } else {
-                    Inst::CLQ // 001 uimm[5:4|8] rs1′ uimm[7:6] rd′ 00  (RV128)
-                }
+    if !isa.has(Ext::C) {
+        return Err(DecodeError::Disabled { raw: w as u32, size: 2 });
+    }
+
+    let inst = match c_f3_bits(w) {
+        0b000 => {
+            if w == 0 {
+                return Err(DecodeError::Illegal { raw: 0, size: 2 })
+            } else if c_addi4spn_imm_bits(w) == 0 {
+                return Err(c_reserved(w)) // 000 0 0 00  (RES)
+            } else {
+                Inst::CADDI4SPN(c_rdp(w), c_addi4spn_imm_bits(w)) // 000 nzuimm[5:4|9:6|2|3] rd′ 00
             }
-            0b010 => {
-                Inst::CLW // 010 uimm[5:3] rs1′ uimm[2|6] rd′ 00
+        }
+        0b001 => {
+            if bits != 128 {
+                Inst::CFLD(c_frdp(w), c_rs1p(w), c_ld_imm_bits(w)) // 001 uimm[5:3] rs1′ uimm[7:6] rd′ 00  (RV32/64)
+            } else {
+                Inst::CLQ(c_rdp(w), c_rs1p(w), c_lq_imm_bits(w)) // 001 uimm[5:4|8] rs1′ uimm[7:6] rd′ 00  (RV128)
             }
-            0b011 => {
-                if bits == 32 {
-                    Inst::CFLW // 011 uimm[5:3] rs1′ uimm[2|6] rd′ 00  (RV32)
-                } else {
-                    Inst::CLD // 011 uimm[5:3] rs1′ uimm[7:6] rd′ 00  (RV64/128)
-                }
+        }
+        0b010 => Inst::CLW(c_rdp(w), c_rs1p(w), c_lw_imm_bits(w)), // 010 uimm[5:3] rs1′ uimm[2|6] rd′ 00
+        0b011 => {
+            if bits == 32 {
+                Inst::CFLW(c_frdp(w), c_rs1p(w), c_lw_imm_bits(w)) // 011 uimm[5:3] rs1′ uimm[2|6] rd′ 00  (RV32)
+            } else {
+                Inst::CLD(c_rdp(w), c_rs1p(w), c_ld_imm_bits(w)) // 011 uimm[5:3] rs1′ uimm[7:6] rd′ 00  (RV64/128)
             }
-            0b101 => {
-                if bits != 128 {
-                    Inst::CFSD // 101 uimm[5:3] rs1′ uimm[7:6] rs2′ 00  (RV32/64)
-                } else {
-                    Inst::CSQ // 101 uimm[5:4|8] rs1′ uimm[7:6] rs2′ 00  (RV128)
-                }
+        }
+        0b100 => match (c_f6_bits(w) & 0b111, c_ca_f2_bits(w) >> 1) {
+            (0b000, _) => Inst::CLBU(c_rdp(w), c_rs1p(w), c_lbu_imm_bits(w)), // 100 000 rs1′ uimm[0|1] rd′ 00
+            (0b001, 0) => Inst::CLHU(c_rdp(w), c_rs1p(w), c_lh_imm_bits(w)), // 100 001 rs1′ 0 uimm[1] rd′ 00
+            (0b001, 1) => Inst::CLH(c_rdp(w), c_rs1p(w), c_lh_imm_bits(w)), // 100 001 rs1′ 1 uimm[1] rd′ 00
+            (0b010, _) => Inst::CSB(c_rs2p(w), c_rs1p(w), c_lbu_imm_bits(w)), // 100 010 rs1′ uimm[0|1] rs2′ 00
+            (0b011, 0) => Inst::CSH(c_rs2p(w), c_rs1p(w), c_lh_imm_bits(w)), // 100 011 rs1′ 0 uimm[1] rs2′ 00
+            _ => return Err(c_reserved(w)), // 100 1 — 00, 100 011 — 1 — 00  (RES)
+        },
+        0b101 => {
+            if bits != 128 {
+                Inst::CFSD(c_frs2p(w), c_rs1p(w), c_ld_imm_bits(w)) // 101 uimm[5:3] rs1′ uimm[7:6] rs2′ 00  (RV32/64)
+            } else {
+                Inst::CSQ(c_rs2p(w), c_rs1p(w), c_lq_imm_bits(w)) // 101 uimm[5:4|8] rs1′ uimm[7:6] rs2′ 00  (RV128)
             }
-            0b110 => {
-                Inst::CSW // 110 uimm[5:3] rs1′ uimm[2|6] rs2′ 00
+        }
+        0b110 => Inst::CSW(c_rs2p(w), c_rs1p(w), c_lw_imm_bits(w)), // 110 uimm[5:3] rs1′ uimm[2|6] rs2′ 00
+        0b111 => {
+            if bits == 32 {
+                Inst::CFSW(c_frs2p(w), c_rs1p(w), c_lw_imm_bits(w)) // 111 uimm[5:3] rs1′ uimm[2|6] rs2′ 00  (RV32)
+            } else {
+                Inst::CSD(c_rs2p(w), c_rs1p(w), c_ld_imm_bits(w)) // 111 uimm[5:3] rs1′ uimm[7:6] rs2′ 00  (RV64/128)
             }
-            0b111 => {
-                if bits == 32 {
-                    Inst::CFSW // 111 uimm[5:3] rs1′ uimm[2|6] rs2′ 00  (RV32)
-                } else {
-                    Inst::CSD // 111 uimm[5:3] rs1′ uimm[7:6] rs2′ 00  (RV64/128)
-                }
-            }
-            _ => Inst::CUNDEF,
-        },
-        2,
-    )
-}
-
-/*
-fn crd_gpr(_w: u16) -> Gpr {
-    Gpr::zero
-}
+        }
+        _ => unreachable!(),
+    };
 
-fn nzuimm_bits(_w: u16) -> u8 {
-    0
+    enabled(inst, 2, w as u32, isa)
 }
-*/
 
-fn decode_quadrant1(bytes: &mut dyn ByteReader, b0: u8, bits: u8) -> (Inst, u8) {
+fn decode_quadrant1(bytes: &mut dyn ByteReader, b0: u8, isa: &Isa) -> Result<Decoded, DecodeError> {
+    let bits = isa.xlen();
     let mut w = b0 as u16;
     let wn = match bytes.next() {
-        None => return (Inst::ERROR, 0),
+        None => return Err(DecodeError::Truncated { raw: w as u32, size: 1, missing: 1 }),
         Some(b) => b,
     };
     w |= (wn as u16) << 8;
 
-    (
-        match c_f3_bits(w) {
-            0b000 => {
-                // CNOP // 000 nzimm[5] 0 nzimm[4:0] 01  (HINT, nzimm̸=0)
-                Inst::CADDI // 000 nzimm[5] rs1/rd̸=0 nzimm[4:0] 01  (HINT, nzimm=0)
-            }
-
-            0b001 => {
-                if bits == 32 {
-                    Inst::CJAL // 001 imm[11|4|9:8|10|6|7|3:1|5] 01  (RV32)
-                } else {
-                    Inst::CADDIW // 001 imm[5] rs1/rd̸=0 imm[4:0] 01  (RV64/128; RES, rd=0)
-                }
-            }
+    if !isa.has(Ext::C) {
+        return Err(DecodeError::Disabled { raw: w as u32, size: 2 });
+    }
 
-            0b010 => Inst::CLI, // 010 imm[5] rd̸=0 imm[4:0] 01  (HINT, rd=0)
+    let inst = match c_f3_bits(w) {
+        0b000 => match (c_rd_bits(w), c_i_imm_bits(w)) {
+            (0, 0) => Inst::CNOP,       // 000 0 0 0 01
+            (0, _) => return Err(c_hint(w)),   // 000 nzimm[5] 0 nzimm[4:0] 01  (HINT, nzimm̸=0)
+            (_, 0) => return Err(c_hint(w)),   // 000 0 rs1/rd̸=0 0 01  (HINT, nzimm=0)
+            (_, imm) => Inst::CADDI(c_rd(w), imm), // 000 nzimm[5] rs1/rd̸=0 nzimm[4:0] 01
+        },
 
-            0b011 => {
-                // Inst::CADDI16SP // 011 nzimm[9] 2 nzimm[4|6|8:7|5] 01  (RES, nzimm=0)
-                Inst::CLUI // 011 nzimm[17] rd̸={0, 2} nzimm[16:12] 01  (RES, nzimm=0; HINT, rd=0)
+        0b001 => {
+            if bits == 32 {
+                Inst::CJAL(c_j_imm_bits(w)) // 001 imm[11|4|9:8|10|6|7|3:1|5] 01  (RV32)
+            } else if c_rd_bits(w) == 0 {
+                return Err(c_reserved(w)) // 001 imm[5] 0 imm[4:0] 01  (RV64/128; RES, rd=0)
+            } else {
+                Inst::CADDIW(c_rd(w), c_i_imm_bits(w)) // 001 imm[5] rs1/rd̸=0 imm[4:0] 01  (RV64/128)
             }
+        }
 
-            0b100 => {
-                // Inst::CSRLI               100 nzuimm[5] 00 rs1′/rd′ nzuimm[4:0] 01  (RV32 NSE, nzuimm[5]=1)
-                // Inst::CSRLI64             100 0 00 rs1′/rd′ 0 01  (RV128; RV32/64 HINT)
-                // Inst::CSRAI               100 nzuimm[5] 01 rs1′/rd′ nzuimm[4:0] 01  (RV32 NSE, nzuimm[5]=1)
-                // Inst::CSRAI64             100 0 01 rs1′/rd′ 0 01  (RV128; RV32/64 HINT)
-                // Inst::CANDI               100 imm[5] 10 rs1′/rd′ imm[4:0] 01
-                // Inst::CSUB                100 0 11 rs1′/rd′ 00 rs2′ 01
-                // Inst::CXOR                100 0 11 rs1′/rd′ 01 rs2′ 01
-                // Inst::COR                 100 0 11 rs1′/rd′ 10 rs2′ 01
-                // Inst::CAND                100 0 11 rs1′/rd′ 11 rs2′ 01
-                // Inst::CSUBW               100 1 11 rs1′/rd′ 00 rs2′ 01  (RV64/128; RV32 RES)
-                // Inst::CADDW               100 1 11 rs1′/rd′ 01 rs2′ 01  (RV64/128; RV32 RES)
-                Inst::CUNDEF
+        0b010 => {
+            if c_rd_bits(w) == 0 {
+                return Err(c_hint(w)) // 010 imm[5] 0 imm[4:0] 01  (HINT, rd=0)
+            } else {
+                Inst::CLI(c_rd(w), c_i_imm_bits(w)) // 010 imm[5] rd̸=0 imm[4:0] 01
             }
+        }
+
+        0b011 => match c_rd_bits(w) {
+            2 => match c_addi16sp_imm_bits(w) {
+                0 => return Err(c_reserved(w)), // 011 0 2 0 01  (RES, nzimm=0)
+                imm => Inst::CADDI16SP(imm), // 011 nzimm[9] 2 nzimm[4|6|8:7|5] 01
+            },
+            rd => match c_lui_imm_bits(w) {
+                0 => return Err(c_reserved(w)), // 011 0 rd 0 01  (RES, nzimm=0)
+                _ if rd == 0 => return Err(c_hint(w)), // 011 nzimm[17] 0 nzimm[16:12] 01  (HINT, rd=0)
+                imm => Inst::CLUI(c_rd(w), imm), // 011 nzimm[17] rd̸={0, 2} nzimm[16:12] 01
+            },
+        },
 
-            0b101 => Inst::CJ,    // 101 imm[11|4|9:8|10|6|7|3:1|5] 01
-            0b110 => Inst::CBEQZ, // 110 imm[8|4:3] rs1′ imm[7:6|2:1|5] 01
-            0b111 => Inst::CBNEZ, // 111 imm[8|4:3] rs1′ imm[7:6|2:1|5] 01
-            _ => Inst::CUNDEF,
+        0b100 => match c_cb_f2_bits(w) {
+            0b00 => match c_shamt_bits(w) {
+                0 if bits == 128 => Inst::CSRLI64(c_rs1p(w)), // 100 0 00 rs1′/rd′ 0 01  (RV128)
+                0 => return Err(c_hint(w)), // 100 0 00 rs1′/rd′ 0 01  (RV32/64 HINT)
+                32.. if bits == 32 => return Err(c_reserved(w)), // (RV32 NSE, nzuimm[5]=1)
+                shamt @ 32.. if bits == 128 => Inst::CSRLI(c_rs1p(w), shamt + 64), // (RV128, shamt sign-extended)
+                shamt => Inst::CSRLI(c_rs1p(w), shamt), // 100 nzuimm[5] 00 rs1′/rd′ nzuimm[4:0] 01
+            },
+            0b01 => match c_shamt_bits(w) {
+                0 if bits == 128 => Inst::CSRAI64(c_rs1p(w)), // 100 0 01 rs1′/rd′ 0 01  (RV128)
+                0 => return Err(c_hint(w)), // 100 0 01 rs1′/rd′ 0 01  (RV32/64 HINT)
+                32.. if bits == 32 => return Err(c_reserved(w)), // (RV32 NSE, nzuimm[5]=1)
+                shamt @ 32.. if bits == 128 => Inst::CSRAI(c_rs1p(w), shamt + 64), // (RV128, shamt sign-extended)
+                shamt => Inst::CSRAI(c_rs1p(w), shamt), // 100 nzuimm[5] 01 rs1′/rd′ nzuimm[4:0] 01
+            },
+            0b10 => Inst::CANDI(c_rs1p(w), c_i_imm_bits(w)), // 100 imm[5] 10 rs1′/rd′ imm[4:0] 01
+            0b11 => match (c_f4_bits(w) & 1, c_ca_f2_bits(w)) {
+                (0, 0b00) => Inst::CSUB(c_rs1p(w), c_rs2p(w)), // 100 0 11 rs1′/rd′ 00 rs2′ 01
+                (0, 0b01) => Inst::CXOR(c_rs1p(w), c_rs2p(w)), // 100 0 11 rs1′/rd′ 01 rs2′ 01
+                (0, 0b10) => Inst::COR(c_rs1p(w), c_rs2p(w)),  // 100 0 11 rs1′/rd′ 10 rs2′ 01
+                (0, 0b11) => Inst::CAND(c_rs1p(w), c_rs2p(w)), // 100 0 11 rs1′/rd′ 11 rs2′ 01
+                (1, 0b00) if bits != 32 => Inst::CSUBW(c_rs1p(w), c_rs2p(w)), // 100 1 11 rs1′/rd′ 00 rs2′ 01  (RV64/128)
+                (1, 0b01) if bits != 32 => Inst::CADDW(c_rs1p(w), c_rs2p(w)), // 100 1 11 rs1′/rd′ 01 rs2′ 01  (RV64/128)
+                (1, 0b10) => Inst::CMUL(c_rs1p(w), c_rs2p(w)), // 100 1 11 rs1′/rd′ 10 rs2′ 01
+                (1, 0b11) => match c_cu_f5_bits(w) {
+                    0b11000 => Inst::CZEXTB(c_rs1p(w)), // 100 1 11 rd′ 11 000 01
+                    0b11001 => Inst::CSEXTB(c_rs1p(w)), // 100 1 11 rd′ 11 001 01
+                    0b11010 => Inst::CZEXTH(c_rs1p(w)), // 100 1 11 rd′ 11 010 01
+                    0b11011 => Inst::CSEXTH(c_rs1p(w)), // 100 1 11 rd′ 11 011 01
+                    0b11100 if bits == 64 => Inst::CZEXTW(c_rs1p(w)), // 100 1 11 rd′ 11 100 01  (RV64)
+                    0b11101 => Inst::CNOT(c_rs1p(w)), // 100 1 11 rd′ 11 101 01
+                    _ => return Err(c_reserved(w)),
+                },
+                _ => return Err(c_reserved(w)), // (RV32 RES)
+            },
+            _ => unreachable!(),
         },
-        2,
-    )
+
+        0b101 => Inst::CJ(c_j_imm_bits(w)), // 101 imm[11|4|9:8|10|6|7|3:1|5] 01
+        0b110 => Inst::CBEQZ(c_rs1p(w), c_b_imm_bits(w)), // 110 imm[8|4:3] rs1′ imm[7:6|2:1|5] 01
+        0b111 => Inst::CBNEZ(c_rs1p(w), c_b_imm_bits(w)), // 111 imm[8|4:3] rs1′ imm[7:6|2:1|5] 01
+        _ => unreachable!(),
+    };
+
+    enabled(inst, 2, w as u32, isa)
 }
 
-fn decode_quadrant2(bytes: &mut dyn ByteReader, b0: u8, bits: u8) -> (Inst, u8) {
+fn decode_quadrant2(bytes: &mut dyn ByteReader, b0: u8, isa: &Isa) -> Result<Decoded, DecodeError> {
+    let bits = isa.xlen();
     let mut w = b0 as u16;
     let wn = match bytes.next() {
-        None => return (Inst::ERROR, 0),
+        None => return Err(DecodeError::Truncated { raw: w as u32, size: 1, missing: 1 }),
         Some(b) => b,
     };
     w |= (wn as u16) << 8;
 
-    (
-        match c_f3_bits(w) {
-            0b000 => {
-                if bits == 32 {
-                    Inst::CSLLI // 000 nzuimm[5] rs1/rd̸=0 nzuimm[4:0] 10  (HINT, rd=0; RV32 NSE, nzuimm[5]=1)
-                } else {
-                    Inst::CSLLI64 // 000 0 rs1/rd̸=0 0 10  (RV128; RV32/64 HINT; HINT, rd=0)
-                }
-            }
-            0b001 => {
-                Inst::CFLDSP // 001 uimm[5] rd uimm[4:3|8:6] 10  (RV32/64)
-                             // Inst::CLQSP // 001 uimm[5] rd̸=0 uimm[4|9:6] 10  (RV128; RES, rd=0)
-            }
-            0b010 => {
-                Inst::CLWSP // 010 uimm[5] rd̸=0 uimm[4:2|7:6] 10  (RES, rd=0)
-            }
-            0b011 => {
-                Inst::CFLWSP // 011 uimm[5] rd uimm[4:2|7:6] 10  (RV32)
-                             // Inst::CLDSP // 011 uimm[5] rd̸=0 uimm[4:3|8:6] 10  (RV64/128; RES, rd=0)
+    if !isa.has(Ext::C) {
+        return Err(DecodeError::Disabled { raw: w as u32, size: 2 });
+    }
+
+    let inst = match c_f3_bits(w) {
+        0b000 => match (c_rd_bits(w), c_shamt_bits(w)) {
+            (0, _) => return Err(c_hint(w)), // 000 nzuimm[5] 0 nzuimm[4:0] 10  (HINT, rd=0)
+            (_, 0) if bits == 128 => Inst::CSLLI64(c_rd(w)), // 000 0 rs1/rd̸=0 0 10  (RV128)
+            (_, 0) => return Err(c_hint(w)), // 000 0 rs1/rd̸=0 0 10  (RV32/64 HINT)
+            (_, 32..) if bits == 32 => return Err(c_reserved(w)), // (RV32 NSE, nzuimm[5]=1)
+            (_, shamt @ 32..) if bits == 128 => Inst::CSLLI(c_rd(w), shamt + 64), // (RV128, shamt sign-extended)
+            (_, shamt) => Inst::CSLLI(c_rd(w), shamt), // 000 nzuimm[5] rs1/rd̸=0 nzuimm[4:0] 10
+        },
+        0b001 => {
+            if bits != 128 {
+                Inst::CFLDSP(c_frd(w), c_ldsp_imm_bits(w)) // 001 uimm[5] rd uimm[4:3|8:6] 10  (RV32/64)
+            } else if c_rd_bits(w) == 0 {
+                return Err(c_reserved(w)) // 001 uimm[5] 0 uimm[4|9:6] 10  (RV128; RES, rd=0)
+            } else {
+                Inst::CLQSP(c_rd(w), c_lqsp_imm_bits(w)) // 001 uimm[5] rd̸=0 uimm[4|9:6] 10  (RV128)
             }
-            0b100 => {
-                Inst::CJR // 100 0 rs1̸=0 0 10  (RES, rs1=0)
-                          // Inst::CMV  // 100 0 rd̸=0 rs2̸=0 10  (HINT, rd=0)
-                          // Inst::CEBREAK  // 100 1 0 0 10
-                          // Inst::CJALR  // 100 1 rs1̸=0 0 10
-                          // Inst::CADD  // 100 1 rs1/rd̸=0 rs2̸=0 10  (HINT, rd=0)
+        }
+        0b010 => {
+            if c_rd_bits(w) == 0 {
+                return Err(c_reserved(w)) // 010 uimm[5] 0 uimm[4:2|7:6] 10  (RES, rd=0)
+            } else {
+                Inst::CLWSP(c_rd(w), c_lwsp_imm_bits(w)) // 010 uimm[5] rd̸=0 uimm[4:2|7:6] 10
             }
-            0b101 => {
-                Inst::CFSDSP // 101 uimm[5:3|8:6] rs2 10  (RV32/64)
-                             // Inst::CSQSP // 101 uimm[5:4|9:6] rs2 10  (RV128)
+        }
+        0b011 => {
+            if bits == 32 {
+                Inst::CFLWSP(c_frd(w), c_lwsp_imm_bits(w)) // 011 uimm[5] rd uimm[4:2|7:6] 10  (RV32)
+            } else if c_rd_bits(w) == 0 {
+                return Err(c_reserved(w)) // 011 uimm[5] 0 uimm[4:3|8:6] 10  (RV64/128; RES, rd=0)
+            } else {
+                Inst::CLDSP(c_rd(w), c_ldsp_imm_bits(w)) // 011 uimm[5] rd̸=0 uimm[4:3|8:6] 10  (RV64/128)
             }
-            0b110 => {
-                Inst::CSWSP // 110 uimm[5:2|7:6] rs2 10
+        }
+        0b100 => match (c_f4_bits(w), c_rd_bits(w), c_rs2_bits(w)) {
+            (0b1000, 0, 0) => return Err(c_reserved(w)), // 100 0 0 0 10  (RES, rs1=0)
+            (0b1000, _, 0) => Inst::CJR(c_rd(w)), // 100 0 rs1̸=0 0 10
+            (0b1000, 0, _) => return Err(c_hint(w)), // 100 0 0 rs2̸=0 10  (HINT, rd=0)
+            (0b1000, _, _) => Inst::CMV(c_rd(w), c_rs2(w)), // 100 0 rd̸=0 rs2̸=0 10
+            (0b1001, 0, 0) => Inst::CEBREAK, // 100 1 0 0 10
+            (0b1001, _, 0) => Inst::CJALR(c_rd(w)), // 100 1 rs1̸=0 0 10
+            (0b1001, 0, _) => return Err(c_hint(w)), // 100 1 0 rs2̸=0 10  (HINT, rd=0)
+            (0b1001, _, _) => Inst::CADD(c_rd(w), c_rs2(w)), // 100 1 rs1/rd̸=0 rs2̸=0 10
+            _ => unreachable!(),
+        },
+        0b101 => {
+            if bits != 128 && (isa.has(Ext::Zcmp) || isa.has(Ext::Zcmt)) {
+                decode_zcmp(w, bits)?
+            } else if bits != 128 {
+                Inst::CFSDSP(c_frs2(w), c_sdsp_imm_bits(w)) // 101 uimm[5:3|8:6] rs2 10  (RV32/64)
+            } else {
+                Inst::CSQSP(c_rs2(w), c_sqsp_imm_bits(w)) // 101 uimm[5:4|9:6] rs2 10  (RV128)
             }
-            0b111 => {
-                Inst::CFSWSP // 111 uimm[5:2|7:6] rs2 10  (RV32)
-                             // Inst::CSDSP // 111 uimm[5:3|8:6] rs2 10  (RV64/128)
+        }
+        0b110 => Inst::CSWSP(c_rs2(w), c_swsp_imm_bits(w)), // 110 uimm[5:2|7:6] rs2 10
+        0b111 => {
+            if bits == 32 {
+                Inst::CFSWSP(c_frs2(w), c_swsp_imm_bits(w)) // 111 uimm[5:2|7:6] rs2 10  (RV32)
+            } else {
+                Inst::CSDSP(c_rs2(w), c_sdsp_imm_bits(w)) // 111 uimm[5:3|8:6] rs2 10  (RV64/128)
             }
-            _ => Inst::CUNDEF,
+        }
+        _ => unreachable!(),
+    };
+
+    enabled(inst, 2, w as u32, isa)
+}
+
+// Zcmp and Zcmt reuse the C.FSDSP encoding space.
+fn decode_zcmp(w: u16, bits: u8) -> Result<Inst, DecodeError> {
+    let push_pop = || {
+        let rlist = Rlist::from_u8(c_rlist_bits(w)).ok_or(c_reserved(w))?;
+        Ok((rlist, rlist.stack_adj_base(bits) + c_spimm_bits(w)))
+    };
+    let inst = match (c_f6_bits(w) & 0b111, c_ca_f2_bits(w), c_cmpp_f5_bits(w)) {
+        (0b000, _, _) => match c_index_bits(w) {
+            index @ 0..32 => Inst::CMJT(index), // 101 000 index 10
+            index => Inst::CMJALT(index), // 101 000 index 10
         },
-        2,
-    )
+        (0b011, 0b01, _) if c_r1s_bits(w) == c_r2s_bits(w) => return Err(c_reserved(w)), // (RES, r1s′=r2s′)
+        (0b011, 0b01, _) => Inst::CMMVSA01(c_sreg(c_r1s_bits(w)), c_sreg(c_r2s_bits(w))), // 101 011 r1s′ 01 r2s′ 10
+        (0b011, 0b11, _) => Inst::CMMVA01S(c_sreg(c_r1s_bits(w)), c_sreg(c_r2s_bits(w))), // 101 011 r1s′ 11 r2s′ 10
+        (_, _, 0b11000) => {
+            let (rlist, adj) = push_pop()?;
+            Inst::CMPUSH(rlist, adj) // 101 11000 rlist spimm 10
+        }
+        (_, _, 0b11010) => {
+            let (rlist, adj) = push_pop()?;
+            Inst::CMPOP(rlist, adj) // 101 11010 rlist spimm 10
+        }
+        (_, _, 0b11100) => {
+            let (rlist, adj) = push_pop()?;
+            Inst::CMPOPRETZ(rlist, adj) // 101 11100 rlist spimm 10
+        }
+        (_, _, 0b11110) => {
+            let (rlist, adj) = push_pop()?;
+            Inst::CMPOPRET(rlist, adj) // 101 11110 rlist spimm 10
+        }
+        _ => return Err(c_reserved(w)),
+    };
+    Ok(inst)
 }
 
-fn decode_quadrant3(bytes: &mut dyn ByteReader, b0: u8, bits: u8) -> (Inst, u8) {
+fn decode_quadrant3(bytes: &mut dyn ByteReader, b0: u8, isa: &Isa) -> Result<Decoded, DecodeError> {
+    let bits = isa.xlen();
     let mut w = b0 as u32;
     for n in 1..4 {
         let wn = match bytes.next() {
-            None => return (Inst::ERROR, 0),
+            None => return Err(DecodeError::Truncated { raw: w, size: n, missing: 4 - n }),
             Some(b) => b,
         };
-        w |= (wn as u32) << n * 8;
+        w |= (wn as u32) << (n * 8);
     }
 
-    (
-        match op_bits(w) {
-            0b00000 => match f3_bits(w) {
-                0b000 => Inst::LB(rd(w), rs1(w), i_imm_bits(w)),
-                0b001 => Inst::LH(rd(w), rs1(w), i_imm_bits(w)),
-                0b010 => Inst::LW(rd(w), rs1(w), i_imm_bits(w)),
-                0b011 => Inst::LD(rd(w), rs1(w), i_imm_bits(w)),
-                0b100 => Inst::LBU(rd(w), rs1(w), i_imm_bits(w)),
-                0b101 => Inst::LHU(rd(w), rs1(w), i_imm_bits(w)),
-                0b110 => Inst::LWU(rd(w), rs1(w), i_imm_bits(w)),
-                _ => Inst::UNDEF(w),
-            },
-            0b00011 => match f3_bits(w) {
-                0b000 => Inst::FENCE(rd(w), rs1(w), i_imm_bits(w)),
-                0b001 => Inst::FENCEI(rd(w), rs1(w), i_imm_bits(w)),
-                _ => Inst::UNDEF(w),
-            },
-            0b00100 => match f3_bits(w) {
-                0b000 => Inst::ADDI(rd(w), rs1(w), i_imm_bits(w)),
-                0b001 => {
-                    if bits == 64 {
-                        match f6_bits(w) {
-                            00 => Inst::SLLI(rd(w), rs1(w), shamt64_imm_bits(w)),
-                            _ => Inst::UNDEF(w),
-                        }
-                    } else {
-                        match f7_bits(w) {
-                            00 => Inst::SLLI(rd(w), rs1(w), shamt32_imm_bits(w)),
-                            _ => Inst::UNDEF(w),
-                        }
+    let inst = match op_bits(w) {
+        0b00000 => match f3_bits(w) {
+            0b011 | 0b110 if bits == 32 => return Err(disabled(w)),
+            0b111 if bits != 128 => return Err(disabled(w)),
+            0b000 => Inst::LB(rd(w), rs1(w), i_imm_bits(w)),
+            0b001 => Inst::LH(rd(w), rs1(w), i_imm_bits(w)),
+            0b010 => Inst::LW(rd(w), rs1(w), i_imm_bits(w)),
+            0b011 => Inst::LD(rd(w), rs1(w), i_imm_bits(w)),
+            0b100 => Inst::LBU(rd(w), rs1(w), i_imm_bits(w)),
+            0b101 => Inst::LHU(rd(w), rs1(w), i_imm_bits(w)),
+            0b110 => Inst::LWU(rd(w), rs1(w), i_imm_bits(w)),
+            0b111 => Inst::LDU(rd(w), rs1(w), i_imm_bits(w)),
+            _ => unreachable!(),
+        },
+        0b00001 => match f3_bits(w) {
+            0b000 | 0b101 | 0b110 | 0b111 => vload(w)?,
+            0b010 => Inst::FLW(frd(w), rs1(w), i_imm_bits(w)),
+            0b011 => Inst::FLD(frd(w), rs1(w), i_imm_bits(w)),
+            0b100 => Inst::FLQ(frd(w), rs1(w), i_imm_bits(w)),
+            0b001 => Inst::FLH(frd(w), rs1(w), i_imm_bits(w)),
+            _ => return Err(undef(w)),
+        },
+        0b00011 => match f3_bits(w) {
+            0b000 if rd_bits(w) != 0 || rs1_bits(w) != 0 => return Err(reserved(w)),
+            0b000 => match (fm_bits(w), pred_bits(w), succ_bits(w)) {
+                (0b0000, 0b0001, 0b0000) if isa.has(Ext::Zihintpause) => Inst::PAUSE,
+                (0b0000, pred, succ) => Inst::FENCE(Fm::None, fence(pred), fence(succ)),
+                (0b1000, 0b0011, 0b0011) => Inst::FENCE(Fm::Tso, Fence::RW, Fence::RW),
+                _ => return Err(reserved(w)),
+            },
+            0b001 if w != 0x0000100f => return Err(reserved(w)),
+            0b001 => Inst::FENCEI,
+            // RV128 places LQ where RV32/64 have the cache-block operations
+            0b010 if bits == 128 => Inst::LQ(rd(w), rs1(w), i_imm_bits(w)),
+            0b010 => match (f12_bits(w), rd_bits(w)) {
+                (0x000, 0b00000) => Inst::CBOINVAL(rs1(w)),
+                (0x001, 0b00000) => Inst::CBOCLEAN(rs1(w)),
+                (0x002, 0b00000) => Inst::CBOFLUSH(rs1(w)),
+                (0x004, 0b00000) => Inst::CBOZERO(rs1(w)),
+                _ => return Err(undef(w)),
+            },
+            _ => return Err(undef(w)),
+        },
+        0b00100 => match f3_bits(w) {
+            0b000 => Inst::ADDI(rd(w), rs1(w), i_imm_bits(w)),
+            0b001 if f7_bits(w) == 0x30 => match rs2_bits(w) {
+                0b00000 => Inst::CLZ(rd(w), rs1(w)),
+                0b00001 => Inst::CTZ(rd(w), rs1(w)),
+                0b00010 => Inst::CPOP(rd(w), rs1(w)),
+                0b00100 => Inst::SEXTB(rd(w), rs1(w)),
+                0b00101 => Inst::SEXTH(rd(w), rs1(w)),
+                _ => return Err(undef(w)),
+            },
+            0b001 if f7_bits(w) == 0x08 => match rs2_bits(w) {
+                0b00000 => Inst::SHA256SUM0(rd(w), rs1(w)),
+                0b00001 => Inst::SHA256SUM1(rd(w), rs1(w)),
+                0b00010 => Inst::SHA256SIG0(rd(w), rs1(w)),
+                0b00011 => Inst::SHA256SIG1(rd(w), rs1(w)),
+                0b00100 if bits == 64 => Inst::SHA512SUM0(rd(w), rs1(w)),
+                0b00101 if bits == 64 => Inst::SHA512SUM1(rd(w), rs1(w)),
+                0b00110 if bits == 64 => Inst::SHA512SIG0(rd(w), rs1(w)),
+                0b00111 if bits == 64 => Inst::SHA512SIG1(rd(w), rs1(w)),
+                0b01000 => Inst::SM3P0(rd(w), rs1(w)),
+                0b01001 => Inst::SM3P1(rd(w), rs1(w)),
+                _ => return Err(undef(w)),
+            },
+            0b001 if bits == 64 && f7_bits(w) == 0x18 => match rs2_bits(w) {
+                0b00000 => Inst::AES64IM(rd(w), rs1(w)),
+                0b10000..=0b11010 => Inst::AES64KS1I(rd(w), rs1(w), rnum_bits(w)),
+                0b11011..=0b11111 => return Err(reserved(w)),
+                _ => return Err(undef(w)),
+            },
+            0b001 if bits == 32 && f12_bits(w) == 0x08f => Inst::ZIP(rd(w), rs1(w)),
+            0b001 => {
+                if bits == 128 {
+                    match f5_bits(w) {
+                        0x00 => Inst::SLLI(rd(w), rs1(w), shamt128_imm_bits(w)),
+                        _ => return Err(undef(w)),
                     }
-                }
-                0b010 => Inst::SLTI(rd(w), rs1(w), i_imm_bits(w)),
-                0b011 => Inst::SLTUI(rd(w), rs1(w), i_imm_bits(w) as u32),
-                0b100 => Inst::XORI(rd(w), rs1(w), i_imm_bits(w)),
-                0b101 => {
-                    if bits == 64 {
-                        match f6_bits(w) {
-                            0x00 => Inst::SRLI(rd(w), rs1(w), shamt64_imm_bits(w)),
-                            0x10 => Inst::SRAI(rd(w), rs1(w), shamt64_imm_bits(w)),
-                            _ => Inst::UNDEF(w),
-                        }
-                    } else {
-                        match f7_bits(w) {
-                            0x00 => Inst::SRLI(rd(w), rs1(w), shamt32_imm_bits(w)),
-                            0x20 => Inst::SRAI(rd(w), rs1(w), shamt32_imm_bits(w)),
-                            _ => Inst::UNDEF(w),
-                        }
+                } else if bits == 64 {
+                    match f6_bits(w) {
+                        0x00 => Inst::SLLI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                        0x0a => Inst::BSETI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                        0x12 => Inst::BCLRI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                        0x1a => Inst::BINVI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                        _ => return Err(undef(w)),
+                    }
+                } else {
+                    match f7_bits(w) {
+                        0x00 => Inst::SLLI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                        0x14 => Inst::BSETI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                        0x24 => Inst::BCLRI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                        0x34 => Inst::BINVI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                        _ => return Err(undef(w)),
                     }
                 }
-                0b110 => Inst::ORI(rd(w), rs1(w), i_imm_bits(w)),
-                0b111 => Inst::ANDI(rd(w), rs1(w), i_imm_bits(w)),
-                _ => unreachable!(),
-            },
-            0b00101 => Inst::AUIPC(rd(w), u_imm_bits(w)),
-            0b00110 => match f3_bits(w) {
-                0x00 => Inst::ADDIW(rd(w), rs1(w), i_imm_bits(w)),
-                0x01 => Inst::SLLIW(rd(w), rs1(w), shamt32_imm_bits(w)),
-                0x05 => match f7_bits(w) {
-                    0x00 => Inst::SRLIW(rd(w), rs1(w), shamt32_imm_bits(w)),
-                    0x20 => Inst::SRAIW(rd(w), rs1(w), shamt32_imm_bits(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                _ => Inst::UNDEF(w),
-            },
-            0b01000 => match f3_bits(w) {
-                0b001 => Inst::SH(rd(w), rs1(w), s_imm_bits(w)),
-                0b010 => Inst::SW(rd(w), rs1(w), s_imm_bits(w)),
-                0b000 => Inst::SB(rd(w), rs1(w), s_imm_bits(w)),
-                0b011 => Inst::SD(rd(w), rs1(w), s_imm_bits(w)),
-                _ => Inst::UNDEF(w),
-            },
-            0b01011 => match f3_bits(w) {
-                0b010 => match f5_bits(w) {
-                    0b00010 => match rs2_bits(w) {
-                        0b00000 => Inst::LRW(rd(w), rs1(w)),
-                        _ => Inst::UNDEF(w),
-                    },
-                    0b00011 => Inst::SCW(rd(w), rs1(w), rs2(w)),
-                    0b00001 => Inst::AMOSWAPW(rd(w), rs1(w), rs2(w)),
-                    0b00000 => Inst::AMOADDW(rd(w), rs1(w), rs2(w)),
-                    0b00100 => Inst::AMOXORW(rd(w), rs1(w), rs2(w)),
-                    0b01100 => Inst::AMOANDW(rd(w), rs1(w), rs2(w)),
-                    0b01000 => Inst::AMOORW(rd(w), rs1(w), rs2(w)),
-                    0b10000 => Inst::AMOMINW(rd(w), rs1(w), rs2(w)),
-                    0b10100 => Inst::AMOMAXW(rd(w), rs1(w), rs2(w)),
-                    0b11000 => Inst::AMOMINUW(rd(w), rs1(w), rs2(w)),
-                    0b11100 => Inst::AMOMAXUW(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b011 => match f5_bits(w) {
-                    0b00010 => match rs2_bits(w) {
-                        0b00000 => Inst::LRD(rd(w), rs1(w)),
-                        _ => Inst::UNDEF(w),
-                    },
-                    0b00011 => Inst::SCD(rd(w), rs1(w), rs2(w)),
-                    0b00001 => Inst::AMOSWAPD(rd(w), rs1(w), rs2(w)),
-                    0b00000 => Inst::AMOADDD(rd(w), rs1(w), rs2(w)),
-                    0b00100 => Inst::AMOXORD(rd(w), rs1(w), rs2(w)),
-                    0b01100 => Inst::AMOANDD(rd(w), rs1(w), rs2(w)),
-                    0b01000 => Inst::AMOORD(rd(w), rs1(w), rs2(w)),
-                    0b10000 => Inst::AMOMIND(rd(w), rs1(w), rs2(w)),
-                    0b10100 => Inst::AMOMAXD(rd(w), rs1(w), rs2(w)),
-                    0b11000 => Inst::AMOMINUD(rd(w), rs1(w), rs2(w)),
-                    0b11100 => Inst::AMOMAXUD(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                _ => Inst::UNDEF(w),
-            },
-            0b01100 => match f3_bits(w) {
-                0b000 => match f7_bits(w) {
-                    0x00 => Inst::ADD(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::MUL(rd(w), rs1(w), rs2(w)),
-                    0x20 => Inst::SUB(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b001 => match f7_bits(w) {
-                    0x00 => Inst::SLL(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::MULH(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b010 => match f7_bits(w) {
-                    0x00 => Inst::SLT(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::MULSU(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b011 => match f7_bits(w) {
-                    0x00 => Inst::SLTU(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::MULU(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b100 => match f7_bits(w) {
-                    0x00 => Inst::XOR(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::DIV(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b101 => match f7_bits(w) {
-                    0x00 => Inst::SRL(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::DIVU(rd(w), rs1(w), rs2(w)),
-                    0x20 => Inst::SRA(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b110 => match f7_bits(w) {
-                    0x00 => Inst::OR(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::REM(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b111 => match f7_bits(w) {
-                    0x00 => Inst::AND(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::REMU(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                _ => unreachable!(),
-            },
-            0b01101 => Inst::LUI(rd(w), u_imm_bits(w)),
-            0b01110 => match f3_bits(w) {
-                0b000 => match f7_bits(w) {
-                    0x00 => Inst::ADDW(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::MULW(rd(w), rs1(w), rs2(w)),
-                    0x20 => Inst::SUBW(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
-                },
-                0b001 => match f7_bits(w) {
-                    0x00 => Inst::SLL(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
+            }
+            0b010 => Inst::SLTI(rd(w), rs1(w), i_imm_bits(w)),
+            0b011 => Inst::SLTUI(rd(w), rs1(w), i_imm_bits(w) as u32),
+            0b100 => Inst::XORI(rd(w), rs1(w), i_imm_bits(w)),
+            0b101 => match f12_bits(w) {
+                0x287 => Inst::ORCB(rd(w), rs1(w)),
+                0x687 => Inst::BREV8(rd(w), rs1(w)),
+                0x08f if bits == 32 => Inst::UNZIP(rd(w), rs1(w)),
+                0x698 if bits == 32 => Inst::REV8(rd(w), rs1(w)),
+                0x6b8 if bits == 64 => Inst::REV8(rd(w), rs1(w)),
+                _ if bits == 128 => match f5_bits(w) {
+                    0x00 => Inst::SRLI(rd(w), rs1(w), shamt128_imm_bits(w)),
+                    0x08 => Inst::SRAI(rd(w), rs1(w), shamt128_imm_bits(w)),
+                    _ => return Err(undef(w)),
                 },
-                0b100 => match f7_bits(w) {
-                    0x01 => Inst::DIVW(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
+                _ if bits == 64 => match f6_bits(w) {
+                    0x00 => Inst::SRLI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                    0x10 => Inst::SRAI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                    0x12 => Inst::BEXTI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                    0x18 => Inst::RORI(rd(w), rs1(w), shamt64_imm_bits(w)),
+                    _ => return Err(undef(w)),
                 },
-                0b101 => match f7_bits(w) {
-                    0x00 => Inst::SRLW(rd(w), rs1(w), rs2(w)),
-                    0x01 => Inst::DIVUW(rd(w), rs1(w), rs2(w)),
-                    0x20 => Inst::SRAW(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
+                _ => match f7_bits(w) {
+                    0x00 => Inst::SRLI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                    0x20 => Inst::SRAI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                    0x24 => Inst::BEXTI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                    0x30 => Inst::RORI(rd(w), rs1(w), shamt32_imm_bits(w)),
+                    _ => return Err(undef(w)),
                 },
-                0b110 => match f7_bits(w) {
-                    0x01 => Inst::REMW(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
+            },
+            // prefetches are `ori` hints to x0; imm[4:0] selects the kind
+            0b110 if rd_bits(w) == 0 && rs2_bits(w) == 0b00000 && isa.has(Ext::Zicbop) => {
+                Inst::PREFETCHI(rs1(w), i_imm_bits(w) & !0x1f)
+            }
+            0b110 if rd_bits(w) == 0 && rs2_bits(w) == 0b00001 && isa.has(Ext::Zicbop) => {
+                Inst::PREFETCHR(rs1(w), i_imm_bits(w) & !0x1f)
+            }
+            0b110 if rd_bits(w) == 0 && rs2_bits(w) == 0b00011 && isa.has(Ext::Zicbop) => {
+                Inst::PREFETCHW(rs1(w), i_imm_bits(w) & !0x1f)
+            }
+            0b110 => Inst::ORI(rd(w), rs1(w), i_imm_bits(w)),
+            0b111 => Inst::ANDI(rd(w), rs1(w), i_imm_bits(w)),
+            _ => unreachable!(),
+        },
+        0b00101 => Inst::AUIPC(rd(w), u_imm_bits(w)),
+        0b00110 if bits == 32 => return Err(disabled(w)),
+        0b00110 => match f3_bits(w) {
+            0x00 => Inst::ADDIW(rd(w), rs1(w), i_imm_bits(w)),
+            0x01 => match f7_bits(w) {
+                0x00 => Inst::SLLIW(rd(w), rs1(w), shamt32_imm_bits(w)),
+                0x04 | 0x05 => Inst::SLLIUW(rd(w), rs1(w), shamt64_imm_bits(w)),
+                0x30 => match rs2_bits(w) {
+                    0b00000 => Inst::CLZW(rd(w), rs1(w)),
+                    0b00001 => Inst::CTZW(rd(w), rs1(w)),
+                    0b00010 => Inst::CPOPW(rd(w), rs1(w)),
+                    _ => return Err(undef(w)),
                 },
-                0b111 => match f7_bits(w) {
-                    0x01 => Inst::REMUW(rd(w), rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
+                _ => return Err(undef(w)),
+            },
+            0x05 => match f7_bits(w) {
+                0x00 => Inst::SRLIW(rd(w), rs1(w), shamt32_imm_bits(w)),
+                0x20 => Inst::SRAIW(rd(w), rs1(w), shamt32_imm_bits(w)),
+                0x30 => Inst::RORIW(rd(w), rs1(w), shamt32_imm_bits(w)),
+                _ => return Err(undef(w)),
+            },
+            _ => return Err(undef(w)),
+        },
+        0b01000 => match f3_bits(w) {
+            0b011 if bits == 32 => return Err(disabled(w)),
+            0b100 if bits != 128 => return Err(disabled(w)),
+            0b001 => Inst::SH(rs2(w), rs1(w), s_imm_bits(w)),
+            0b010 => Inst::SW(rs2(w), rs1(w), s_imm_bits(w)),
+            0b000 => Inst::SB(rs2(w), rs1(w), s_imm_bits(w)),
+            0b011 => Inst::SD(rs2(w), rs1(w), s_imm_bits(w)),
+            0b100 => Inst::SQ(rs2(w), rs1(w), s_imm_bits(w)),
+            _ => return Err(undef(w)),
+        },
+        0b01001 => match f3_bits(w) {
+            0b000 | 0b101 | 0b110 | 0b111 => vstore(w)?,
+            0b010 => Inst::FSW(frs2(w), rs1(w), s_imm_bits(w)),
+            0b011 => Inst::FSD(frs2(w), rs1(w), s_imm_bits(w)),
+            0b100 => Inst::FSQ(frs2(w), rs1(w), s_imm_bits(w)),
+            0b001 => Inst::FSH(frs2(w), rs1(w), s_imm_bits(w)),
+            _ => return Err(undef(w)),
+        },
+        0b01011 => match f3_bits(w) {
+            0b011 if bits == 32 && f5_bits(w) != 0b00101 => return Err(disabled(w)),
+            0b100 if bits == 32 => return Err(disabled(w)),
+            0b000 => match f5_bits(w) {
+                0b00001 => Inst::AMOSWAPB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00000 => Inst::AMOADDB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00100 => Inst::AMOXORB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01100 => Inst::AMOANDB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01000 => Inst::AMOORB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10000 => Inst::AMOMINB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10100 => Inst::AMOMAXB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11000 => Inst::AMOMINUB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11100 => Inst::AMOMAXUB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00101 => Inst::AMOCASB(rd(w), rs1(w), rs2(w), aqrl(w)),
+                _ => return Err(undef(w)),
+            },
+            0b001 => match f5_bits(w) {
+                0b00001 => Inst::AMOSWAPH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00000 => Inst::AMOADDH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00100 => Inst::AMOXORH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01100 => Inst::AMOANDH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01000 => Inst::AMOORH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10000 => Inst::AMOMINH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10100 => Inst::AMOMAXH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11000 => Inst::AMOMINUH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11100 => Inst::AMOMAXUH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00101 => Inst::AMOCASH(rd(w), rs1(w), rs2(w), aqrl(w)),
+                _ => return Err(undef(w)),
+            },
+            0b010 => match f5_bits(w) {
+                0b00010 => match rs2_bits(w) {
+                    0b00000 => Inst::LRW(rd(w), rs1(w), aqrl(w)),
+                    _ => return Err(undef(w)),
                 },
-                _ => Inst::UNDEF(w),
-            },
-            0b11000 => match f3_bits(w) {
-                0b000 => Inst::BEQ(rs1(w), rs2(w), b_imm_bits(w)),
-                0b001 => Inst::BNE(rs1(w), rs2(w), b_imm_bits(w)),
-                0b100 => Inst::BLT(rs1(w), rs2(w), b_imm_bits(w)),
-                0b101 => Inst::BGE(rs1(w), rs2(w), b_imm_bits(w)),
-                0b110 => Inst::BLTU(rs1(w), rs2(w), b_imm_bits(w)),
-                0b111 => Inst::BGEU(rs1(w), rs2(w), b_imm_bits(w)),
-                _ => Inst::UNDEF(w),
-            },
-            0b11001 => Inst::JALR(rd(w), rs1(w), i_imm_bits(w)),
-            0b11011 => Inst::JAL(rd(w), j_imm_bits(w)),
-            0b11100 => match f3_bits(w) {
-                0b000 => match (f7_bits(w), rs2_bits(w), rs1_bits(w), rd_bits(w)) {
-                    (0b0000000, 0b00000, 0b00000, 0b00000) => Inst::ECALL,
-                    (0b0000000, 0b00001, 0b00000, 0b00000) => Inst::EBREAK,
-                    (0b0001000, 0b00010, 0b00000, 0b00000) => Inst::SRET,
-                    (0b0011000, 0b00010, 0b00000, 0b00000) => Inst::MRET,
-                    (0b0001000, 0b00101, 0b00000, 0b00000) => Inst::WFI,
-                    (0b0001001, _, _, 0b00000) => Inst::SFENCEVMA(rs1(w), rs2(w)),
-                    (0b0001011, _, _, 0b00000) => Inst::SINVALVMA(rs1(w), rs2(w)),
-                    (0b0001100, 0b00000, 0b00000, 0b00000) => Inst::SFENCEWINVAL,
-                    (0b0001100, 0b00001, 0b00000, 0b00000) => Inst::SFENCEINVALIR,
-                    (0b0010001, _, _, 0b00000) => Inst::HFENCEVVMA(rs1(w), rs2(w)),
-                    (0b0110001, _, _, 0b00000) => Inst::HFENCEGVMA(rs1(w), rs2(w)),
-                    (0b0010011, _, _, 0b00000) => Inst::HINVALVVMA(rs1(w), rs2(w)),
-                    (0b0110011, _, _, 0b00000) => Inst::HINVALGVMA(rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
+                0b00011 => Inst::SCW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00001 => Inst::AMOSWAPW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00000 => Inst::AMOADDW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00100 => Inst::AMOXORW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01100 => Inst::AMOANDW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01000 => Inst::AMOORW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10000 => Inst::AMOMINW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10100 => Inst::AMOMAXW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11000 => Inst::AMOMINUW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11100 => Inst::AMOMAXUW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00101 => Inst::AMOCASW(rd(w), rs1(w), rs2(w), aqrl(w)),
+                _ => return Err(undef(w)),
+            },
+            0b011 => match f5_bits(w) {
+                0b00010 => match rs2_bits(w) {
+                    0b00000 => Inst::LRD(rd(w), rs1(w), aqrl(w)),
+                    _ => return Err(undef(w)),
                 },
-                0b100 => match (f7_bits(w), rs2_bits(w), rs1_bits(w), rd_bits(w)) {
-                    (0b0110000, 0b00000, _, _) => Inst::HLVB(rd(w), rs1(w)),
-                    (0b0110000, 0b00001, _, _) => Inst::HLVBU(rd(w), rs1(w)),
-                    (0b0110010, 0b00000, _, _) => Inst::HLVH(rd(w), rs1(w)),
-                    (0b0110010, 0b00001, _, _) => Inst::HLVHU(rd(w), rs1(w)),
-                    (0b0110010, 0b00011, _, _) => Inst::HLVXHU(rd(w), rs1(w)),
-                    (0b0110100, 0b00000, _, _) => Inst::HLVW(rd(w), rs1(w)),
-                    (0b0110100, 0b00011, _, _) => Inst::HLVXWU(rd(w), rs1(w)),
-                    (0b0110001, _, _, 0b00000) => Inst::HSVB(rs1(w), rs2(w)),
-                    (0b0110011, _, _, 0b00000) => Inst::HSVH(rs1(w), rs2(w)),
-                    (0b0110101, _, _, 0b00000) => Inst::HSVW(rs1(w), rs2(w)),
-                    (0b0110100, 0b00001, _, _) => Inst::HLVWU(rd(w), rs1(w)),
-                    (0b0110110, 0b00000, _, _) => Inst::HLVD(rd(w), rs1(w)),
-                    (0b0110111, _, _, 0b00000) => Inst::HSVD(rs1(w), rs2(w)),
-                    _ => Inst::UNDEF(w),
+                0b00011 => Inst::SCD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00001 => Inst::AMOSWAPD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00000 => Inst::AMOADDD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00100 => Inst::AMOXORD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01100 => Inst::AMOANDD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b01000 => Inst::AMOORD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10000 => Inst::AMOMIND(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b10100 => Inst::AMOMAXD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11000 => Inst::AMOMINUD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b11100 => Inst::AMOMAXUD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                0b00101 if bits == 32 && !reg_pairs(w) => return Err(reserved(w)),
+                0b00101 => Inst::AMOCASD(rd(w), rs1(w), rs2(w), aqrl(w)),
+                _ => return Err(undef(w)),
+            },
+            0b100 => match f5_bits(w) {
+                0b00101 if bits == 64 && !reg_pairs(w) => return Err(reserved(w)),
+                0b00101 => Inst::AMOCASQ(rd(w), rs1(w), rs2(w), aqrl(w)),
+                _ => return Err(undef(w)),
+            },
+            _ => return Err(undef(w)),
+        },
+        0b01100 => match f3_bits(w) {
+            0b000 => match f7_bits(w) {
+                // `add x0,x0,rs2` hints
+                0x00 if rd_bits(w) == 0 && rs1_bits(w) == 0 && isa.has(Ext::Zihintntl) => match rs2_bits(w) {
+                    0b00010 => Inst::NTLP1,
+                    0b00011 => Inst::NTLPALL,
+                    0b00100 => Inst::NTLS1,
+                    0b00101 => Inst::NTLALL,
+                    _ => Inst::ADD(rd(w), rs1(w), rs2(w)),
                 },
-                0b001 => Inst::CSRRW(rd(w), rs1(w), csr(w)),
-                0b010 => Inst::CSRRS(rd(w), rs1(w), csr(w)),
-                0b011 => Inst::CSRRC(rd(w), rs1(w), csr(w)),
-                0b101 => Inst::CSRRWI(rd(w), rs1_bits(w), csr(w)),
-                0b110 => Inst::CSRRSI(rd(w), rs1_bits(w), csr(w)),
-                0b111 => Inst::CSRRCI(rd(w), rs1_bits(w), csr(w)),
-                _ => Inst::UNDEF(w),
-            },
-            _ => Inst::UNDEF(w),
+                0x00 => Inst::ADD(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::MUL(rd(w), rs1(w), rs2(w)),
+                0x20 => Inst::SUB(rd(w), rs1(w), rs2(w)),
+                0x11 | 0x31 | 0x51 | 0x71 if bits == 32 => Inst::AES32ESI(rd(w), rs1(w), rs2(w), bs_bits(w)),
+                0x13 | 0x33 | 0x53 | 0x73 if bits == 32 => Inst::AES32ESMI(rd(w), rs1(w), rs2(w), bs_bits(w)),
+                0x15 | 0x35 | 0x55 | 0x75 if bits == 32 => Inst::AES32DSI(rd(w), rs1(w), rs2(w), bs_bits(w)),
+                0x17 | 0x37 | 0x57 | 0x77 if bits == 32 => Inst::AES32DSMI(rd(w), rs1(w), rs2(w), bs_bits(w)),
+                0x19 if bits == 64 => Inst::AES64ES(rd(w), rs1(w), rs2(w)),
+                0x1b if bits == 64 => Inst::AES64ESM(rd(w), rs1(w), rs2(w)),
+                0x1d if bits == 64 => Inst::AES64DS(rd(w), rs1(w), rs2(w)),
+                0x1f if bits == 64 => Inst::AES64DSM(rd(w), rs1(w), rs2(w)),
+                0x3f if bits == 64 => Inst::AES64KS2(rd(w), rs1(w), rs2(w)),
+                0x18 | 0x38 | 0x58 | 0x78 => Inst::SM4ED(rd(w), rs1(w), rs2(w), bs_bits(w)),
+                0x1a | 0x3a | 0x5a | 0x7a => Inst::SM4KS(rd(w), rs1(w), rs2(w), bs_bits(w)),
+                0x28 if bits == 32 => Inst::SHA512SUM0R(rd(w), rs1(w), rs2(w)),
+                0x29 if bits == 32 => Inst::SHA512SUM1R(rd(w), rs1(w), rs2(w)),
+                0x2a if bits == 32 => Inst::SHA512SIG0L(rd(w), rs1(w), rs2(w)),
+                0x2b if bits == 32 => Inst::SHA512SIG1L(rd(w), rs1(w), rs2(w)),
+                0x2e if bits == 32 => Inst::SHA512SIG0H(rd(w), rs1(w), rs2(w)),
+                0x2f if bits == 32 => Inst::SHA512SIG1H(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b001 => match f7_bits(w) {
+                0x00 => Inst::SLL(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::MULH(rd(w), rs1(w), rs2(w)),
+                0x05 => Inst::CLMUL(rd(w), rs1(w), rs2(w)),
+                0x14 => Inst::BSET(rd(w), rs1(w), rs2(w)),
+                0x24 => Inst::BCLR(rd(w), rs1(w), rs2(w)),
+                0x30 => Inst::ROL(rd(w), rs1(w), rs2(w)),
+                0x34 => Inst::BINV(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b010 => match f7_bits(w) {
+                0x00 => Inst::SLT(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::MULSU(rd(w), rs1(w), rs2(w)),
+                0x05 => Inst::CLMULR(rd(w), rs1(w), rs2(w)),
+                0x10 => Inst::SH1ADD(rd(w), rs1(w), rs2(w)),
+                0x14 => Inst::XPERM4(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b011 => match f7_bits(w) {
+                0x00 => Inst::SLTU(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::MULU(rd(w), rs1(w), rs2(w)),
+                0x05 => Inst::CLMULH(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b100 => match f7_bits(w) {
+                0x00 => Inst::XOR(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::DIV(rd(w), rs1(w), rs2(w)),
+                // zext.h is pack with rs2 = zero on RV32
+                0x04 if bits == 32 && rs2_bits(w) == 0 && isa.has(Ext::Zbb) => Inst::ZEXTH(rd(w), rs1(w)),
+                0x04 => Inst::PACK(rd(w), rs1(w), rs2(w)),
+                0x05 => Inst::MIN(rd(w), rs1(w), rs2(w)),
+                0x10 => Inst::SH2ADD(rd(w), rs1(w), rs2(w)),
+                0x14 => Inst::XPERM8(rd(w), rs1(w), rs2(w)),
+                0x20 => Inst::XNOR(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b101 => match f7_bits(w) {
+                0x00 => Inst::SRL(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::DIVU(rd(w), rs1(w), rs2(w)),
+                0x05 => Inst::MINU(rd(w), rs1(w), rs2(w)),
+                0x07 => Inst::CZEROEQZ(rd(w), rs1(w), rs2(w)),
+                0x20 => Inst::SRA(rd(w), rs1(w), rs2(w)),
+                0x24 => Inst::BEXT(rd(w), rs1(w), rs2(w)),
+                0x30 => Inst::ROR(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b110 => match f7_bits(w) {
+                0x00 => Inst::OR(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::REM(rd(w), rs1(w), rs2(w)),
+                0x05 => Inst::MAX(rd(w), rs1(w), rs2(w)),
+                0x10 => Inst::SH3ADD(rd(w), rs1(w), rs2(w)),
+                0x20 => Inst::ORN(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b111 => match f7_bits(w) {
+                0x00 => Inst::AND(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::REMU(rd(w), rs1(w), rs2(w)),
+                0x04 => Inst::PACKH(rd(w), rs1(w), rs2(w)),
+                0x05 => Inst::MAXU(rd(w), rs1(w), rs2(w)),
+                0x07 => Inst::CZERONEZ(rd(w), rs1(w), rs2(w)),
+                0x20 => Inst::ANDN(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            _ => unreachable!(),
+        },
+        0b01101 => Inst::LUI(rd(w), u_imm_bits(w)),
+        0b01110 if bits == 32 => return Err(disabled(w)),
+        0b10110 if bits != 128 => return Err(disabled(w)),
+        0b10110 => match f3_bits(w) {
+            0b000 => Inst::ADDID(rd(w), rs1(w), i_imm_bits(w)),
+            0b001 => match f6_bits(w) {
+                0x00 => Inst::SLLID(rd(w), rs1(w), shamt64_imm_bits(w)),
+                _ => return Err(undef(w)),
+            },
+            0b101 => match f6_bits(w) {
+                0x00 => Inst::SRLID(rd(w), rs1(w), shamt64_imm_bits(w)),
+                0x10 => Inst::SRAID(rd(w), rs1(w), shamt64_imm_bits(w)),
+                _ => return Err(undef(w)),
+            },
+            _ => return Err(undef(w)),
         },
-        4,
-    )
+        0b11110 if bits != 128 => return Err(disabled(w)),
+        0b11110 => match (f3_bits(w), f7_bits(w)) {
+            (0b000, 0x00) => Inst::ADDD(rd(w), rs1(w), rs2(w)),
+            (0b000, 0x01) => Inst::MULD(rd(w), rs1(w), rs2(w)),
+            (0b000, 0x20) => Inst::SUBD(rd(w), rs1(w), rs2(w)),
+            (0b001, 0x00) => Inst::SLLD(rd(w), rs1(w), rs2(w)),
+            (0b100, 0x01) => Inst::DIVD(rd(w), rs1(w), rs2(w)),
+            (0b101, 0x00) => Inst::SRLD(rd(w), rs1(w), rs2(w)),
+            (0b101, 0x01) => Inst::DIVUD(rd(w), rs1(w), rs2(w)),
+            (0b101, 0x20) => Inst::SRAD(rd(w), rs1(w), rs2(w)),
+            (0b110, 0x01) => Inst::REMD(rd(w), rs1(w), rs2(w)),
+            (0b111, 0x01) => Inst::REMUD(rd(w), rs1(w), rs2(w)),
+            _ => return Err(undef(w)),
+        },
+        0b01110 => match f3_bits(w) {
+            0b000 => match f7_bits(w) {
+                0x00 => Inst::ADDW(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::MULW(rd(w), rs1(w), rs2(w)),
+                0x04 => Inst::ADDUW(rd(w), rs1(w), rs2(w)),
+                0x20 => Inst::SUBW(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b001 => match f7_bits(w) {
+                0x00 => Inst::SLLW(rd(w), rs1(w), rs2(w)),
+                0x30 => Inst::ROLW(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b010 => match f7_bits(w) {
+                0x10 => Inst::SH1ADDUW(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b100 => match f7_bits(w) {
+                0x01 => Inst::DIVW(rd(w), rs1(w), rs2(w)),
+                // and packw with rs2 = zero on RV64
+                0x04 if rs2_bits(w) == 0 && isa.has(Ext::Zbb) => Inst::ZEXTH(rd(w), rs1(w)),
+                0x04 => Inst::PACKW(rd(w), rs1(w), rs2(w)),
+                0x10 => Inst::SH2ADDUW(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b101 => match f7_bits(w) {
+                0x00 => Inst::SRLW(rd(w), rs1(w), rs2(w)),
+                0x01 => Inst::DIVUW(rd(w), rs1(w), rs2(w)),
+                0x20 => Inst::SRAW(rd(w), rs1(w), rs2(w)),
+                0x30 => Inst::RORW(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b110 => match f7_bits(w) {
+                0x01 => Inst::REMW(rd(w), rs1(w), rs2(w)),
+                0x10 => Inst::SH3ADDUW(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b111 => match f7_bits(w) {
+                0x01 => Inst::REMUW(rd(w), rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            _ => return Err(undef(w)),
+        },
+        0b10000 => match f2_bits(w) {
+            0b00 => Inst::FMADDS(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b01 => Inst::FMADDD(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b10 => Inst::FMADDH(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b11 => Inst::FMADDQ(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            _ => return Err(undef(w)),
+        },
+        0b10001 => match f2_bits(w) {
+            0b00 => Inst::FMSUBS(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b01 => Inst::FMSUBD(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b10 => Inst::FMSUBH(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b11 => Inst::FMSUBQ(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            _ => return Err(undef(w)),
+        },
+        0b10010 => match f2_bits(w) {
+            0b00 => Inst::FNMSUBS(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b01 => Inst::FNMSUBD(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b10 => Inst::FNMSUBH(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b11 => Inst::FNMSUBQ(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            _ => return Err(undef(w)),
+        },
+        0b10011 => match f2_bits(w) {
+            0b00 => Inst::FNMADDS(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b01 => Inst::FNMADDD(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b10 => Inst::FNMADDH(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            0b11 => Inst::FNMADDQ(frd(w), frs1(w), frs2(w), frs3(w), rm(w)?),
+            _ => return Err(undef(w)),
+        },
+        0b10100 => match (f7_bits(w), rs2_bits(w), f3_bits(w)) {
+            (0b1100000 | 0b1100001 | 0b1100010 | 0b1100011 | 0b1101000 | 0b1101001 | 0b1101010 | 0b1101011, 0b00010 | 0b00011, _)
+            | (0b1110001 | 0b1111001, 0b00000, 0b000)
+                if bits == 32 =>
+            {
+                return Err(disabled(w))
+            }
+            (0b0000000, _, _) => Inst::FADDS(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0000100, _, _) => Inst::FSUBS(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001000, _, _) => Inst::FMULS(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001100, _, _) => Inst::FDIVS(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0101100, 0b00000, _) => Inst::FSQRTS(frd(w), frs1(w), rm(w)?),
+            (0b0010000, _, 0b000) => Inst::FSGNJS(frd(w), frs1(w), frs2(w)),
+            (0b0010000, _, 0b001) => Inst::FSGNJNS(frd(w), frs1(w), frs2(w)),
+            (0b0010000, _, 0b010) => Inst::FSGNJXS(frd(w), frs1(w), frs2(w)),
+            (0b0010100, _, 0b000) => Inst::FMINS(frd(w), frs1(w), frs2(w)),
+            (0b0010100, _, 0b001) => Inst::FMAXS(frd(w), frs1(w), frs2(w)),
+            (0b1010000, _, 0b010) => Inst::FEQS(rd(w), frs1(w), frs2(w)),
+            (0b1010000, _, 0b001) => Inst::FLTS(rd(w), frs1(w), frs2(w)),
+            (0b1010000, _, 0b000) => Inst::FLES(rd(w), frs1(w), frs2(w)),
+            (0b1110000, 0b00000, 0b001) => Inst::FCLASSS(rd(w), frs1(w)),
+            (0b1100000, 0b00000, _) => Inst::FCVTWS(rd(w), frs1(w), rm(w)?),
+            (0b1100000, 0b00001, _) => Inst::FCVTWUS(rd(w), frs1(w), rm(w)?),
+            (0b1101000, 0b00000, _) => Inst::FCVTSW(frd(w), rs1(w), rm(w)?),
+            (0b1101000, 0b00001, _) => Inst::FCVTSWU(frd(w), rs1(w), rm(w)?),
+            (0b1110000, 0b00000, 0b000) => Inst::FMVXW(rd(w), frs1(w)),
+            (0b1111000, 0b00000, 0b000) => Inst::FMVWX(frd(w), rs1(w)),
+            (0b1100000, 0b00010, _) => Inst::FCVTLS(rd(w), frs1(w), rm(w)?),
+            (0b1100000, 0b00011, _) => Inst::FCVTLUS(rd(w), frs1(w), rm(w)?),
+            (0b1101000, 0b00010, _) => Inst::FCVTSL(frd(w), rs1(w), rm(w)?),
+            (0b1101000, 0b00011, _) => Inst::FCVTSLU(frd(w), rs1(w), rm(w)?),
+            (0b0000001, _, _) => Inst::FADDD(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0000101, _, _) => Inst::FSUBD(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001001, _, _) => Inst::FMULD(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001101, _, _) => Inst::FDIVD(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0101101, 0b00000, _) => Inst::FSQRTD(frd(w), frs1(w), rm(w)?),
+            (0b0010001, _, 0b000) => Inst::FSGNJD(frd(w), frs1(w), frs2(w)),
+            (0b0010001, _, 0b001) => Inst::FSGNJND(frd(w), frs1(w), frs2(w)),
+            (0b0010001, _, 0b010) => Inst::FSGNJXD(frd(w), frs1(w), frs2(w)),
+            (0b0010101, _, 0b000) => Inst::FMIND(frd(w), frs1(w), frs2(w)),
+            (0b0010101, _, 0b001) => Inst::FMAXD(frd(w), frs1(w), frs2(w)),
+            (0b0100000, 0b00001, _) => Inst::FCVTSD(frd(w), frs1(w), rm(w)?),
+            (0b0100001, 0b00000, _) => Inst::FCVTDS(frd(w), frs1(w), rm(w)?),
+            (0b1010001, _, 0b010) => Inst::FEQD(rd(w), frs1(w), frs2(w)),
+            (0b1010001, _, 0b001) => Inst::FLTD(rd(w), frs1(w), frs2(w)),
+            (0b1010001, _, 0b000) => Inst::FLED(rd(w), frs1(w), frs2(w)),
+            (0b1110001, 0b00000, 0b001) => Inst::FCLASSD(rd(w), frs1(w)),
+            (0b1100001, 0b00000, _) => Inst::FCVTWD(rd(w), frs1(w), rm(w)?),
+            (0b1100001, 0b00001, _) => Inst::FCVTWUD(rd(w), frs1(w), rm(w)?),
+            (0b1101001, 0b00000, _) => Inst::FCVTDW(frd(w), rs1(w), rm(w)?),
+            (0b1101001, 0b00001, _) => Inst::FCVTDWU(frd(w), rs1(w), rm(w)?),
+            (0b1100001, 0b00010, _) => Inst::FCVTLD(rd(w), frs1(w), rm(w)?),
+            (0b1100001, 0b00011, _) => Inst::FCVTLUD(rd(w), frs1(w), rm(w)?),
+            (0b1101001, 0b00010, _) => Inst::FCVTDL(frd(w), rs1(w), rm(w)?),
+            (0b1101001, 0b00011, _) => Inst::FCVTDLU(frd(w), rs1(w), rm(w)?),
+            (0b1110001, 0b00000, 0b000) => Inst::FMVXD(rd(w), frs1(w)),
+            (0b1111001, 0b00000, 0b000) => Inst::FMVDX(frd(w), rs1(w)),
+            (0b0000011, _, _) => Inst::FADDQ(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0000111, _, _) => Inst::FSUBQ(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001011, _, _) => Inst::FMULQ(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001111, _, _) => Inst::FDIVQ(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0101111, 0b00000, _) => Inst::FSQRTQ(frd(w), frs1(w), rm(w)?),
+            (0b0010011, _, 0b000) => Inst::FSGNJQ(frd(w), frs1(w), frs2(w)),
+            (0b0010011, _, 0b001) => Inst::FSGNJNQ(frd(w), frs1(w), frs2(w)),
+            (0b0010011, _, 0b010) => Inst::FSGNJXQ(frd(w), frs1(w), frs2(w)),
+            (0b0010111, _, 0b000) => Inst::FMINQ(frd(w), frs1(w), frs2(w)),
+            (0b0010111, _, 0b001) => Inst::FMAXQ(frd(w), frs1(w), frs2(w)),
+            (0b0100000, 0b00011, _) => Inst::FCVTSQ(frd(w), frs1(w), rm(w)?),
+            (0b0100011, 0b00000, _) => Inst::FCVTQS(frd(w), frs1(w), rm(w)?),
+            (0b0100001, 0b00011, _) => Inst::FCVTDQ(frd(w), frs1(w), rm(w)?),
+            (0b0100011, 0b00001, _) => Inst::FCVTQD(frd(w), frs1(w), rm(w)?),
+            (0b1010011, _, 0b010) => Inst::FEQQ(rd(w), frs1(w), frs2(w)),
+            (0b1010011, _, 0b001) => Inst::FLTQ(rd(w), frs1(w), frs2(w)),
+            (0b1010011, _, 0b000) => Inst::FLEQ(rd(w), frs1(w), frs2(w)),
+            (0b1110011, 0b00000, 0b001) => Inst::FCLASSQ(rd(w), frs1(w)),
+            (0b1100011, 0b00000, _) => Inst::FCVTWQ(rd(w), frs1(w), rm(w)?),
+            (0b1100011, 0b00001, _) => Inst::FCVTWUQ(rd(w), frs1(w), rm(w)?),
+            (0b1101011, 0b00000, _) => Inst::FCVTQW(frd(w), rs1(w), rm(w)?),
+            (0b1101011, 0b00001, _) => Inst::FCVTQWU(frd(w), rs1(w), rm(w)?),
+            (0b1100011, 0b00010, _) => Inst::FCVTLQ(rd(w), frs1(w), rm(w)?),
+            (0b1100011, 0b00011, _) => Inst::FCVTLUQ(rd(w), frs1(w), rm(w)?),
+            (0b1101011, 0b00010, _) => Inst::FCVTQL(frd(w), rs1(w), rm(w)?),
+            (0b1101011, 0b00011, _) => Inst::FCVTQLU(frd(w), rs1(w), rm(w)?),
+            (0b0000010, _, _) => Inst::FADDH(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0000110, _, _) => Inst::FSUBH(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001010, _, _) => Inst::FMULH(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0001110, _, _) => Inst::FDIVH(frd(w), frs1(w), frs2(w), rm(w)?),
+            (0b0101110, 0b00000, _) => Inst::FSQRTH(frd(w), frs1(w), rm(w)?),
+            (0b0010010, _, 0b000) => Inst::FSGNJH(frd(w), frs1(w), frs2(w)),
+            (0b0010010, _, 0b001) => Inst::FSGNJNH(frd(w), frs1(w), frs2(w)),
+            (0b0010010, _, 0b010) => Inst::FSGNJXH(frd(w), frs1(w), frs2(w)),
+            (0b0010110, _, 0b000) => Inst::FMINH(frd(w), frs1(w), frs2(w)),
+            (0b0010110, _, 0b001) => Inst::FMAXH(frd(w), frs1(w), frs2(w)),
+            (0b0100000, 0b00010, _) => Inst::FCVTSH(frd(w), frs1(w), rm(w)?),
+            (0b0100010, 0b00000, _) => Inst::FCVTHS(frd(w), frs1(w), rm(w)?),
+            (0b0100001, 0b00010, _) => Inst::FCVTDH(frd(w), frs1(w), rm(w)?),
+            (0b0100010, 0b00001, _) => Inst::FCVTHD(frd(w), frs1(w), rm(w)?),
+            (0b0100011, 0b00010, _) => Inst::FCVTQH(frd(w), frs1(w), rm(w)?),
+            (0b0100010, 0b00011, _) => Inst::FCVTHQ(frd(w), frs1(w), rm(w)?),
+            (0b1010010, _, 0b010) => Inst::FEQH(rd(w), frs1(w), frs2(w)),
+            (0b1010010, _, 0b001) => Inst::FLTH(rd(w), frs1(w), frs2(w)),
+            (0b1010010, _, 0b000) => Inst::FLEH(rd(w), frs1(w), frs2(w)),
+            (0b1110010, 0b00000, 0b001) => Inst::FCLASSH(rd(w), frs1(w)),
+            (0b1100010, 0b00000, _) => Inst::FCVTWH(rd(w), frs1(w), rm(w)?),
+            (0b1100010, 0b00001, _) => Inst::FCVTWUH(rd(w), frs1(w), rm(w)?),
+            (0b1101010, 0b00000, _) => Inst::FCVTHW(frd(w), rs1(w), rm(w)?),
+            (0b1101010, 0b00001, _) => Inst::FCVTHWU(frd(w), rs1(w), rm(w)?),
+            (0b1110010, 0b00000, 0b000) => Inst::FMVXH(rd(w), frs1(w)),
+            (0b1111010, 0b00000, 0b000) => Inst::FMVHX(frd(w), rs1(w)),
+            (0b1100010, 0b00010, _) => Inst::FCVTLH(rd(w), frs1(w), rm(w)?),
+            (0b1100010, 0b00011, _) => Inst::FCVTLUH(rd(w), frs1(w), rm(w)?),
+            (0b1101010, 0b00010, _) => Inst::FCVTHL(frd(w), rs1(w), rm(w)?),
+            (0b1101010, 0b00011, _) => Inst::FCVTHLU(frd(w), rs1(w), rm(w)?),
+            (0b1111000, 0b00001, 0b000) => Inst::FLIS(frd(w), fli(w)),
+            (0b1111001, 0b00001, 0b000) => Inst::FLID(frd(w), fli(w)),
+            (0b1111010, 0b00001, 0b000) => Inst::FLIH(frd(w), fli(w)),
+            (0b1111011, 0b00001, 0b000) => Inst::FLIQ(frd(w), fli(w)),
+            (0b0010100, _, 0b010) => Inst::FMINMS(frd(w), frs1(w), frs2(w)),
+            (0b0010100, _, 0b011) => Inst::FMAXMS(frd(w), frs1(w), frs2(w)),
+            (0b0100000, 0b00100, _) => Inst::FROUNDS(frd(w), frs1(w), rm(w)?),
+            (0b0100000, 0b00101, _) => Inst::FROUNDNXS(frd(w), frs1(w), rm(w)?),
+            (0b1010000, _, 0b100) => Inst::FLEQS(rd(w), frs1(w), frs2(w)),
+            (0b1010000, _, 0b101) => Inst::FLTQS(rd(w), frs1(w), frs2(w)),
+            (0b0010101, _, 0b010) => Inst::FMINMD(frd(w), frs1(w), frs2(w)),
+            (0b0010101, _, 0b011) => Inst::FMAXMD(frd(w), frs1(w), frs2(w)),
+            (0b0100001, 0b00100, _) => Inst::FROUNDD(frd(w), frs1(w), rm(w)?),
+            (0b0100001, 0b00101, _) => Inst::FROUNDNXD(frd(w), frs1(w), rm(w)?),
+            (0b1010001, _, 0b100) => Inst::FLEQD(rd(w), frs1(w), frs2(w)),
+            (0b1010001, _, 0b101) => Inst::FLTQD(rd(w), frs1(w), frs2(w)),
+            (0b0010110, _, 0b010) => Inst::FMINMH(frd(w), frs1(w), frs2(w)),
+            (0b0010110, _, 0b011) => Inst::FMAXMH(frd(w), frs1(w), frs2(w)),
+            (0b0100010, 0b00100, _) => Inst::FROUNDH(frd(w), frs1(w), rm(w)?),
+            (0b0100010, 0b00101, _) => Inst::FROUNDNXH(frd(w), frs1(w), rm(w)?),
+            (0b1010010, _, 0b100) => Inst::FLEQH(rd(w), frs1(w), frs2(w)),
+            (0b1010010, _, 0b101) => Inst::FLTQH(rd(w), frs1(w), frs2(w)),
+            (0b0010111, _, 0b010) => Inst::FMINMQ(frd(w), frs1(w), frs2(w)),
+            (0b0010111, _, 0b011) => Inst::FMAXMQ(frd(w), frs1(w), frs2(w)),
+            (0b0100011, 0b00100, _) => Inst::FROUNDQ(frd(w), frs1(w), rm(w)?),
+            (0b0100011, 0b00101, _) => Inst::FROUNDNXQ(frd(w), frs1(w), rm(w)?),
+            (0b1010011, _, 0b100) => Inst::FLEQQ(rd(w), frs1(w), frs2(w)),
+            (0b1010011, _, 0b101) => Inst::FLTQQ(rd(w), frs1(w), frs2(w)),
+            (0b1100001, 0b01000, 0b001) => Inst::FCVTMODWD(rd(w), frs1(w)),
+            (0b1110001, 0b00001, 0b000) if bits == 32 => Inst::FMVHXD(rd(w), frs1(w)),
+            (0b1011001, _, 0b000) if bits == 32 => Inst::FMVPDX(frd(w), rs1(w), rs2(w)),
+            (0b1110011, 0b00001, 0b000) if bits == 64 => Inst::FMVHXQ(rd(w), frs1(w)),
+            (0b1011011, _, 0b000) if bits == 64 => Inst::FMVPQX(frd(w), rs1(w), rs2(w)),
+            _ => return Err(undef(w)),
+        },
+        0b10101 => match (f3_bits(w), f6_bits(w), vm_bits(w), rs2_bits(w), rs1_bits(w)) {
+            (0b111, 0b000000..=0b011111, _, _, _) => Inst::VSETVLI(rd(w), rs1(w), vtype(vtype_bits(w))),
+            (0b111, 0b110000..=0b111111, _, _, _) => Inst::VSETIVLI(rd(w), rs1_bits(w) as u32, vtype(vtypei_bits(w))),
+            (0b111, 0b100000, 0, _, _) => Inst::VSETVL(rd(w), rs1(w), rs2(w)),
+            (0b000, 0b000000, _, _, _) => Inst::VADDVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b000010, _, _, _) => Inst::VSUBVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b000100, _, _, _) => Inst::VMINUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b000101, _, _, _) => Inst::VMINVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b000110, _, _, _) => Inst::VMAXUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b000111, _, _, _) => Inst::VMAXVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b001001, _, _, _) => Inst::VANDVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b001010, _, _, _) => Inst::VORVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b001011, _, _, _) => Inst::VXORVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b001100, _, _, _) => Inst::VRGATHERVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b001110, _, _, _) => Inst::VRGATHEREI16VV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b010000, 0, _, _) => Inst::VADCVVM(vd(w), vs2(w), vs1(w)),
+            (0b000, 0b010001, 0, _, _) => Inst::VMADCVVM(vd(w), vs2(w), vs1(w)),
+            (0b000, 0b010001, 1, _, _) => Inst::VMADCVV(vd(w), vs2(w), vs1(w)),
+            (0b000, 0b010010, 0, _, _) => Inst::VSBCVVM(vd(w), vs2(w), vs1(w)),
+            (0b000, 0b010011, 0, _, _) => Inst::VMSBCVVM(vd(w), vs2(w), vs1(w)),
+            (0b000, 0b010011, 1, _, _) => Inst::VMSBCVV(vd(w), vs2(w), vs1(w)),
+            (0b000, 0b010111, 1, 0b00000, _) => Inst::VMVVV(vd(w), vs1(w)),
+            (0b000, 0b010111, 0, _, _) => Inst::VMERGEVVM(vd(w), vs2(w), vs1(w)),
+            (0b000, 0b011000, _, _, _) => Inst::VMSEQVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b011001, _, _, _) => Inst::VMSNEVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b011010, _, _, _) => Inst::VMSLTUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b011011, _, _, _) => Inst::VMSLTVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b011100, _, _, _) => Inst::VMSLEUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b011101, _, _, _) => Inst::VMSLEVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b100000, _, _, _) => Inst::VSADDUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b100001, _, _, _) => Inst::VSADDVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b100010, _, _, _) => Inst::VSSUBUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b100011, _, _, _) => Inst::VSSUBVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b100101, _, _, _) => Inst::VSLLVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b100111, _, _, _) => Inst::VSMULVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101000, _, _, _) => Inst::VSRLVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101001, _, _, _) => Inst::VSRAVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101010, _, _, _) => Inst::VSSRLVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101011, _, _, _) => Inst::VSSRAVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101100, _, _, _) => Inst::VNSRLWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101101, _, _, _) => Inst::VNSRAWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101110, _, _, _) => Inst::VNCLIPUWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b101111, _, _, _) => Inst::VNCLIPWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b110000, _, _, _) => Inst::VWREDSUMUVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b000, 0b110001, _, _, _) => Inst::VWREDSUMVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000000, _, _, _) => Inst::VFADDVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000001, _, _, _) => Inst::VFREDUSUMVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000010, _, _, _) => Inst::VFSUBVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000011, _, _, _) => Inst::VFREDOSUMVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000100, _, _, _) => Inst::VFMINVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000101, _, _, _) => Inst::VFREDMINVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000110, _, _, _) => Inst::VFMAXVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b000111, _, _, _) => Inst::VFREDMAXVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b001000, _, _, _) => Inst::VFSGNJVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b001001, _, _, _) => Inst::VFSGNJNVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b001010, _, _, _) => Inst::VFSGNJXVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b010000, 1, _, 0b00000) => Inst::VFMVFS(frd(w), vs2(w)),
+            (0b001, 0b010010, _, _, 0b00000) => Inst::VFCVTXUFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b00001) => Inst::VFCVTXFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b00010) => Inst::VFCVTFXUV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b00011) => Inst::VFCVTFXV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b00110) => Inst::VFCVTRTZXUFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b00111) => Inst::VFCVTRTZXFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b01000) => Inst::VFWCVTXUFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b01001) => Inst::VFWCVTXFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b01010) => Inst::VFWCVTFXUV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b01011) => Inst::VFWCVTFXV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b01100) => Inst::VFWCVTFFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b01110) => Inst::VFWCVTRTZXUFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b01111) => Inst::VFWCVTRTZXFV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10000) => Inst::VFNCVTXUFW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10001) => Inst::VFNCVTXFW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10010) => Inst::VFNCVTFXUW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10011) => Inst::VFNCVTFXW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10100) => Inst::VFNCVTFFW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10101) => Inst::VFNCVTRODFFW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10110) => Inst::VFNCVTRTZXUFW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010010, _, _, 0b10111) => Inst::VFNCVTRTZXFW(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010011, _, _, 0b00000) => Inst::VFSQRTV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010011, _, _, 0b00100) => Inst::VFRSQRT7V(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010011, _, _, 0b00101) => Inst::VFREC7V(vd(w), vs2(w), vm(w)),
+            (0b001, 0b010011, _, _, 0b10000) => Inst::VFCLASSV(vd(w), vs2(w), vm(w)),
+            (0b001, 0b011000, _, _, _) => Inst::VMFEQVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b011001, _, _, _) => Inst::VMFLEVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b011011, _, _, _) => Inst::VMFLTVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b011100, _, _, _) => Inst::VMFNEVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b100000, _, _, _) => Inst::VFDIVVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b100100, _, _, _) => Inst::VFMULVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b101000, _, _, _) => Inst::VFMADDVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b101001, _, _, _) => Inst::VFNMADDVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b101010, _, _, _) => Inst::VFMSUBVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b101011, _, _, _) => Inst::VFNMSUBVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b101100, _, _, _) => Inst::VFMACCVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b101101, _, _, _) => Inst::VFNMACCVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b101110, _, _, _) => Inst::VFMSACVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b101111, _, _, _) => Inst::VFNMSACVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b110000, _, _, _) => Inst::VFWADDVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b110001, _, _, _) => Inst::VFWREDUSUMVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b110010, _, _, _) => Inst::VFWSUBVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b110011, _, _, _) => Inst::VFWREDOSUMVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b110100, _, _, _) => Inst::VFWADDWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b110110, _, _, _) => Inst::VFWSUBWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b111000, _, _, _) => Inst::VFWMULVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b001, 0b111100, _, _, _) => Inst::VFWMACCVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b111101, _, _, _) => Inst::VFWNMACCVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b111110, _, _, _) => Inst::VFWMSACVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b001, 0b111111, _, _, _) => Inst::VFWNMSACVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b010, 0b000000, _, _, _) => Inst::VREDSUMVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b000001, _, _, _) => Inst::VREDANDVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b000010, _, _, _) => Inst::VREDORVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b000011, _, _, _) => Inst::VREDXORVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b000100, _, _, _) => Inst::VREDMINUVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b000101, _, _, _) => Inst::VREDMINVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b000110, _, _, _) => Inst::VREDMAXUVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b000111, _, _, _) => Inst::VREDMAXVS(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b001000, _, _, _) => Inst::VAADDUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b001001, _, _, _) => Inst::VAADDVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b001010, _, _, _) => Inst::VASUBUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b001011, _, _, _) => Inst::VASUBVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b010000, 1, _, 0b00000) => Inst::VMVXS(rd(w), vs2(w)),
+            (0b010, 0b010000, _, _, 0b10000) => Inst::VCPOPM(rd(w), vs2(w), vm(w)),
+            (0b010, 0b010000, _, _, 0b10001) => Inst::VFIRSTM(rd(w), vs2(w), vm(w)),
+            (0b010, 0b010010, _, _, 0b00010) => Inst::VZEXTVF8(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010010, _, _, 0b00011) => Inst::VSEXTVF8(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010010, _, _, 0b00100) => Inst::VZEXTVF4(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010010, _, _, 0b00101) => Inst::VSEXTVF4(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010010, _, _, 0b00110) => Inst::VZEXTVF2(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010010, _, _, 0b00111) => Inst::VSEXTVF2(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010100, _, 0b00000, 0b10001) => Inst::VIDV(vd(w), vm(w)),
+            (0b010, 0b010100, _, _, 0b00001) => Inst::VMSBFM(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010100, _, _, 0b00010) => Inst::VMSOFM(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010100, _, _, 0b00011) => Inst::VMSIFM(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010100, _, _, 0b10000) => Inst::VIOTAM(vd(w), vs2(w), vm(w)),
+            (0b010, 0b010111, 1, _, _) => Inst::VCOMPRESSVM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011000, 1, _, _) => Inst::VMANDNMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011001, 1, _, _) => Inst::VMANDMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011010, 1, _, _) => Inst::VMORMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011011, 1, _, _) => Inst::VMXORMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011100, 1, _, _) => Inst::VMORNMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011101, 1, _, _) => Inst::VMNANDMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011110, 1, _, _) => Inst::VMNORMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b011111, 1, _, _) => Inst::VMXNORMM(vd(w), vs2(w), vs1(w)),
+            (0b010, 0b100000, _, _, _) => Inst::VDIVUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b100001, _, _, _) => Inst::VDIVVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b100010, _, _, _) => Inst::VREMUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b100011, _, _, _) => Inst::VREMVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b100100, _, _, _) => Inst::VMULHUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b100101, _, _, _) => Inst::VMULVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b100110, _, _, _) => Inst::VMULHSUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b100111, _, _, _) => Inst::VMULHVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b101001, _, _, _) => Inst::VMADDVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b010, 0b101011, _, _, _) => Inst::VNMSUBVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b010, 0b101101, _, _, _) => Inst::VMACCVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b010, 0b101111, _, _, _) => Inst::VNMSACVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b010, 0b110000, _, _, _) => Inst::VWADDUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b110001, _, _, _) => Inst::VWADDVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b110010, _, _, _) => Inst::VWSUBUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b110011, _, _, _) => Inst::VWSUBVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b110100, _, _, _) => Inst::VWADDUWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b110101, _, _, _) => Inst::VWADDWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b110110, _, _, _) => Inst::VWSUBUWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b110111, _, _, _) => Inst::VWSUBWV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b111000, _, _, _) => Inst::VWMULUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b111010, _, _, _) => Inst::VWMULSUVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b111011, _, _, _) => Inst::VWMULVV(vd(w), vs2(w), vs1(w), vm(w)),
+            (0b010, 0b111100, _, _, _) => Inst::VWMACCUVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b010, 0b111101, _, _, _) => Inst::VWMACCVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b010, 0b111111, _, _, _) => Inst::VWMACCSUVV(vd(w), vs1(w), vs2(w), vm(w)),
+            (0b011, 0b000000, _, _, _) => Inst::VADDVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b000011, _, _, _) => Inst::VRSUBVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b001001, _, _, _) => Inst::VANDVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b001010, _, _, _) => Inst::VORVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b001011, _, _, _) => Inst::VXORVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b001100, _, _, _) => Inst::VRGATHERVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b001110, _, _, _) => Inst::VSLIDEUPVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b001111, _, _, _) => Inst::VSLIDEDOWNVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b010000, 0, _, _) => Inst::VADCVIM(vd(w), vs2(w), v_simm5_bits(w)),
+            (0b011, 0b010001, 0, _, _) => Inst::VMADCVIM(vd(w), vs2(w), v_simm5_bits(w)),
+            (0b011, 0b010001, 1, _, _) => Inst::VMADCVI(vd(w), vs2(w), v_simm5_bits(w)),
+            (0b011, 0b010111, 1, 0b00000, _) => Inst::VMVVI(vd(w), v_simm5_bits(w)),
+            (0b011, 0b010111, 0, _, _) => Inst::VMERGEVIM(vd(w), vs2(w), v_simm5_bits(w)),
+            (0b011, 0b011000, _, _, _) => Inst::VMSEQVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b011001, _, _, _) => Inst::VMSNEVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b011100, _, _, _) => Inst::VMSLEUVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b011101, _, _, _) => Inst::VMSLEVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b011110, _, _, _) => Inst::VMSGTUVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b011111, _, _, _) => Inst::VMSGTVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b100000, _, _, _) => Inst::VSADDUVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b100001, _, _, _) => Inst::VSADDVI(vd(w), vs2(w), v_simm5_bits(w), vm(w)),
+            (0b011, 0b100101, _, _, _) => Inst::VSLLVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b100111, 1, _, 0b00000 | 0b00001 | 0b00011 | 0b00111)
+                if !(rd_bits(w) | rs2_bits(w)).is_multiple_of(rs1_bits(w) + 1) =>
+            {
+                return Err(reserved(w))
+            }
+            (0b011, 0b100111, 1, _, 0b00000 | 0b00001 | 0b00011 | 0b00111) => Inst::VMVNRV(vd(w), vs2(w), rs1_bits(w) + 1),
+            (0b011, 0b101000, _, _, _) => Inst::VSRLVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b101001, _, _, _) => Inst::VSRAVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b101010, _, _, _) => Inst::VSSRLVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b101011, _, _, _) => Inst::VSSRAVI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b101100, _, _, _) => Inst::VNSRLWI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b101101, _, _, _) => Inst::VNSRAWI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b101110, _, _, _) => Inst::VNCLIPUWI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b011, 0b101111, _, _, _) => Inst::VNCLIPWI(vd(w), vs2(w), rs1_bits(w) as u32, vm(w)),
+            (0b100, 0b000000, _, _, _) => Inst::VADDVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b000010, _, _, _) => Inst::VSUBVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b000011, _, _, _) => Inst::VRSUBVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b000100, _, _, _) => Inst::VMINUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b000101, _, _, _) => Inst::VMINVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b000110, _, _, _) => Inst::VMAXUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b000111, _, _, _) => Inst::VMAXVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b001001, _, _, _) => Inst::VANDVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b001010, _, _, _) => Inst::VORVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b001011, _, _, _) => Inst::VXORVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b001100, _, _, _) => Inst::VRGATHERVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b001110, _, _, _) => Inst::VSLIDEUPVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b001111, _, _, _) => Inst::VSLIDEDOWNVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b010000, 0, _, _) => Inst::VADCVXM(vd(w), vs2(w), rs1(w)),
+            (0b100, 0b010001, 0, _, _) => Inst::VMADCVXM(vd(w), vs2(w), rs1(w)),
+            (0b100, 0b010001, 1, _, _) => Inst::VMADCVX(vd(w), vs2(w), rs1(w)),
+            (0b100, 0b010010, 0, _, _) => Inst::VSBCVXM(vd(w), vs2(w), rs1(w)),
+            (0b100, 0b010011, 0, _, _) => Inst::VMSBCVXM(vd(w), vs2(w), rs1(w)),
+            (0b100, 0b010011, 1, _, _) => Inst::VMSBCVX(vd(w), vs2(w), rs1(w)),
+            (0b100, 0b010111, 1, 0b00000, _) => Inst::VMVVX(vd(w), rs1(w)),
+            (0b100, 0b010111, 0, _, _) => Inst::VMERGEVXM(vd(w), vs2(w), rs1(w)),
+            (0b100, 0b011000, _, _, _) => Inst::VMSEQVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b011001, _, _, _) => Inst::VMSNEVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b011010, _, _, _) => Inst::VMSLTUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b011011, _, _, _) => Inst::VMSLTVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b011100, _, _, _) => Inst::VMSLEUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b011101, _, _, _) => Inst::VMSLEVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b011110, _, _, _) => Inst::VMSGTUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b011111, _, _, _) => Inst::VMSGTVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b100000, _, _, _) => Inst::VSADDUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b100001, _, _, _) => Inst::VSADDVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b100010, _, _, _) => Inst::VSSUBUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b100011, _, _, _) => Inst::VSSUBVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b100101, _, _, _) => Inst::VSLLVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b100111, _, _, _) => Inst::VSMULVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101000, _, _, _) => Inst::VSRLVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101001, _, _, _) => Inst::VSRAVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101010, _, _, _) => Inst::VSSRLVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101011, _, _, _) => Inst::VSSRAVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101100, _, _, _) => Inst::VNSRLWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101101, _, _, _) => Inst::VNSRAWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101110, _, _, _) => Inst::VNCLIPUWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b100, 0b101111, _, _, _) => Inst::VNCLIPWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b101, 0b000000, _, _, _) => Inst::VFADDVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b000010, _, _, _) => Inst::VFSUBVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b000100, _, _, _) => Inst::VFMINVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b000110, _, _, _) => Inst::VFMAXVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b001000, _, _, _) => Inst::VFSGNJVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b001001, _, _, _) => Inst::VFSGNJNVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b001010, _, _, _) => Inst::VFSGNJXVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b001110, _, _, _) => Inst::VFSLIDE1UPVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b001111, _, _, _) => Inst::VFSLIDE1DOWNVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b010000, 1, 0b00000, _) => Inst::VFMVSF(vd(w), frs1(w)),
+            (0b101, 0b010111, 1, 0b00000, _) => Inst::VFMVVF(vd(w), frs1(w)),
+            (0b101, 0b010111, 0, _, _) => Inst::VFMERGEVFM(vd(w), vs2(w), frs1(w)),
+            (0b101, 0b011000, _, _, _) => Inst::VMFEQVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b011001, _, _, _) => Inst::VMFLEVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b011011, _, _, _) => Inst::VMFLTVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b011100, _, _, _) => Inst::VMFNEVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b011101, _, _, _) => Inst::VMFGTVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b011111, _, _, _) => Inst::VMFGEVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b100000, _, _, _) => Inst::VFDIVVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b100001, _, _, _) => Inst::VFRDIVVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b100100, _, _, _) => Inst::VFMULVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b100111, _, _, _) => Inst::VFRSUBVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b101000, _, _, _) => Inst::VFMADDVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b101001, _, _, _) => Inst::VFNMADDVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b101010, _, _, _) => Inst::VFMSUBVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b101011, _, _, _) => Inst::VFNMSUBVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b101100, _, _, _) => Inst::VFMACCVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b101101, _, _, _) => Inst::VFNMACCVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b101110, _, _, _) => Inst::VFMSACVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b101111, _, _, _) => Inst::VFNMSACVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b110000, _, _, _) => Inst::VFWADDVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b110010, _, _, _) => Inst::VFWSUBVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b110100, _, _, _) => Inst::VFWADDWF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b110110, _, _, _) => Inst::VFWSUBWF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b111000, _, _, _) => Inst::VFWMULVF(vd(w), vs2(w), frs1(w), vm(w)),
+            (0b101, 0b111100, _, _, _) => Inst::VFWMACCVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b111101, _, _, _) => Inst::VFWNMACCVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b111110, _, _, _) => Inst::VFWMSACVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b101, 0b111111, _, _, _) => Inst::VFWNMSACVF(vd(w), frs1(w), vs2(w), vm(w)),
+            (0b110, 0b001000, _, _, _) => Inst::VAADDUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b001001, _, _, _) => Inst::VAADDVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b001010, _, _, _) => Inst::VASUBUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b001011, _, _, _) => Inst::VASUBVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b001110, _, _, _) => Inst::VSLIDE1UPVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b001111, _, _, _) => Inst::VSLIDE1DOWNVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b010000, 1, 0b00000, _) => Inst::VMVSX(vd(w), rs1(w)),
+            (0b110, 0b100000, _, _, _) => Inst::VDIVUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b100001, _, _, _) => Inst::VDIVVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b100010, _, _, _) => Inst::VREMUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b100011, _, _, _) => Inst::VREMVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b100100, _, _, _) => Inst::VMULHUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b100101, _, _, _) => Inst::VMULVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b100110, _, _, _) => Inst::VMULHSUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b100111, _, _, _) => Inst::VMULHVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b101001, _, _, _) => Inst::VMADDVX(vd(w), rs1(w), vs2(w), vm(w)),
+            (0b110, 0b101011, _, _, _) => Inst::VNMSUBVX(vd(w), rs1(w), vs2(w), vm(w)),
+            (0b110, 0b101101, _, _, _) => Inst::VMACCVX(vd(w), rs1(w), vs2(w), vm(w)),
+            (0b110, 0b101111, _, _, _) => Inst::VNMSACVX(vd(w), rs1(w), vs2(w), vm(w)),
+            (0b110, 0b110000, _, _, _) => Inst::VWADDUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b110001, _, _, _) => Inst::VWADDVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b110010, _, _, _) => Inst::VWSUBUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b110011, _, _, _) => Inst::VWSUBVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b110100, _, _, _) => Inst::VWADDUWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b110101, _, _, _) => Inst::VWADDWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b110110, _, _, _) => Inst::VWSUBUWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b110111, _, _, _) => Inst::VWSUBWX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b111000, _, _, _) => Inst::VWMULUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b111010, _, _, _) => Inst::VWMULSUVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b111011, _, _, _) => Inst::VWMULVX(vd(w), vs2(w), rs1(w), vm(w)),
+            (0b110, 0b111100, _, _, _) => Inst::VWMACCUVX(vd(w), rs1(w), vs2(w), vm(w)),
+            (0b110, 0b111101, _, _, _) => Inst::VWMACCVX(vd(w), rs1(w), vs2(w), vm(w)),
+            (0b110, 0b111110, _, _, _) => Inst::VWMACCUSVX(vd(w), rs1(w), vs2(w), vm(w)),
+            (0b110, 0b111111, _, _, _) => Inst::VWMACCSUVX(vd(w), rs1(w), vs2(w), vm(w)),
+            _ => return Err(undef(w)),
+        },
+        0b11000 => match f3_bits(w) {
+            0b000 => Inst::BEQ(rs1(w), rs2(w), b_imm_bits(w)),
+            0b001 => Inst::BNE(rs1(w), rs2(w), b_imm_bits(w)),
+            0b100 => Inst::BLT(rs1(w), rs2(w), b_imm_bits(w)),
+            0b101 => Inst::BGE(rs1(w), rs2(w), b_imm_bits(w)),
+            0b110 => Inst::BLTU(rs1(w), rs2(w), b_imm_bits(w)),
+            0b111 => Inst::BGEU(rs1(w), rs2(w), b_imm_bits(w)),
+            _ => return Err(undef(w)),
+        },
+        0b11001 => match f3_bits(w) {
+            0b000 => Inst::JALR(rd(w), rs1(w), i_imm_bits(w)),
+            _ => return Err(undef(w)),
+        },
+        0b11011 => Inst::JAL(rd(w), j_imm_bits(w)),
+        0b11100 => match f3_bits(w) {
+            0b000 => match (f7_bits(w), rs2_bits(w), rs1_bits(w), rd_bits(w)) {
+                (0b0000000, 0b00000, 0b00000, 0b00000) => Inst::ECALL,
+                (0b0000000, 0b00001, 0b00000, 0b00000) => Inst::EBREAK,
+                (0b0001000, 0b00010, 0b00000, 0b00000) => Inst::SRET,
+                (0b0011000, 0b00010, 0b00000, 0b00000) => Inst::MRET,
+                (0b0001000, 0b00101, 0b00000, 0b00000) => Inst::WFI,
+                (0b0000000, 0b01101, 0b00000, 0b00000) => Inst::WRSNTO,
+                (0b0000000, 0b11101, 0b00000, 0b00000) => Inst::WRSSTO,
+                (0b0001001, _, _, 0b00000) => Inst::SFENCEVMA(rs1(w), rs2(w)),
+                (0b0001011, _, _, 0b00000) => Inst::SINVALVMA(rs1(w), rs2(w)),
+                (0b0001100, 0b00000, 0b00000, 0b00000) => Inst::SFENCEWINVAL,
+                (0b0001100, 0b00001, 0b00000, 0b00000) => Inst::SFENCEINVALIR,
+                (0b0010001, _, _, 0b00000) => Inst::HFENCEVVMA(rs1(w), rs2(w)),
+                (0b0110001, _, _, 0b00000) => Inst::HFENCEGVMA(rs1(w), rs2(w)),
+                (0b0010011, _, _, 0b00000) => Inst::HINVALVVMA(rs1(w), rs2(w)),
+                (0b0110011, _, _, 0b00000) => Inst::HINVALGVMA(rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b100 => match (f7_bits(w), rs2_bits(w), rs1_bits(w), rd_bits(w)) {
+                (0b0110100, 0b00001, _, _) | (0b0110110, 0b00000, _, _) | (0b0110111, _, _, 0b00000)
+                    if bits == 32 =>
+                {
+                    return Err(disabled(w))
+                }
+                (0b0110000, 0b00000, _, _) => Inst::HLVB(rd(w), rs1(w)),
+                (0b0110000, 0b00001, _, _) => Inst::HLVBU(rd(w), rs1(w)),
+                (0b0110010, 0b00000, _, _) => Inst::HLVH(rd(w), rs1(w)),
+                (0b0110010, 0b00001, _, _) => Inst::HLVHU(rd(w), rs1(w)),
+                (0b0110010, 0b00011, _, _) => Inst::HLVXHU(rd(w), rs1(w)),
+                (0b0110100, 0b00000, _, _) => Inst::HLVW(rd(w), rs1(w)),
+                (0b0110100, 0b00011, _, _) => Inst::HLVXWU(rd(w), rs1(w)),
+                (0b0110001, _, _, 0b00000) => Inst::HSVB(rs1(w), rs2(w)),
+                (0b0110011, _, _, 0b00000) => Inst::HSVH(rs1(w), rs2(w)),
+                (0b0110101, _, _, 0b00000) => Inst::HSVW(rs1(w), rs2(w)),
+                (0b0110100, 0b00001, _, _) => Inst::HLVWU(rd(w), rs1(w)),
+                (0b0110110, 0b00000, _, _) => Inst::HLVD(rd(w), rs1(w)),
+                (0b0110111, _, _, 0b00000) => Inst::HSVD(rs1(w), rs2(w)),
+                _ => return Err(undef(w)),
+            },
+            0b001 => Inst::CSRRW(rd(w), rs1(w), csr(w)),
+            0b010 => Inst::CSRRS(rd(w), rs1(w), csr(w)),
+            0b011 => Inst::CSRRC(rd(w), rs1(w), csr(w)),
+            0b101 => Inst::CSRRWI(rd(w), rs1_bits(w), csr(w)),
+            0b110 => Inst::CSRRSI(rd(w), rs1_bits(w), csr(w)),
+            0b111 => Inst::CSRRCI(rd(w), rs1_bits(w), csr(w)),
+            _ => return Err(undef(w)),
+        },
+        _ => return Err(undef(w)),
+    };
+
+    enabled(inst, 4, w, isa)
 }
 
 fn rd(w: u32) -> Gpr {
-    Gpr::from_u8(rd_bits(w) as u8).unwrap()
+    Gpr::from_u8(rd_bits(w)).unwrap()
 }
 
 fn rs1(w: u32) -> Gpr {
-    Gpr::from_u8(rs1_bits(w) as u8).unwrap()
+    Gpr::from_u8(rs1_bits(w)).unwrap()
 }
 
 fn rs2(w: u32) -> Gpr {
-    Gpr::from_u8(rs2_bits(w) as u8).unwrap()
+    Gpr::from_u8(rs2_bits(w)).unwrap()
+}
+
+fn frd(w: u32) -> Fpr {
+    Fpr::from_u8(rd_bits(w)).unwrap()
+}
+
+fn frs1(w: u32) -> Fpr {
+    Fpr::from_u8(rs1_bits(w)).unwrap()
+}
+
+fn frs2(w: u32) -> Fpr {
+    Fpr::from_u8(rs2_bits(w)).unwrap()
+}
+
+fn frs3(w: u32) -> Fpr {
+    Fpr::from_u8(rs3_bits(w)).unwrap()
+}
+
+fn fli(w: u32) -> Fli {
+    Fli::from_u8(rs1_bits(w)).unwrap()
+}
+
+fn aqrl(w: u32) -> Aqrl {
+    Aqrl::from_u8(f2_bits(w)).unwrap()
+}
+
+// Rounding modes 101 and 110 are reserved.
+fn rm(w: u32) -> Result<Rm, DecodeError> {
+    match Rm::from_u8(f3_bits(w)) {
+        Some(rm) => Ok(rm),
+        None => Err(reserved(w)),
+    }
+}
+
+fn fence(r: u8) -> Fence {
+    Fence::from_u8(r).unwrap()
 }
 
-// fn rs3(w: u32) -> Gpr {
-//     Gpr::from_u8(rs3_bits(w) as u8).unwrap()
-// }
+fn vd(w: u32) -> Vr {
+    Vr::from_u8(rd_bits(w)).unwrap()
+}
+
+fn vs1(w: u32) -> Vr {
+    Vr::from_u8(rs1_bits(w)).unwrap()
+}
+
+fn vs2(w: u32) -> Vr {
+    Vr::from_u8(rs2_bits(w)).unwrap()
+}
+
+fn vm(w: u32) -> Vm {
+    Vm::from_u8(vm_bits(w)).unwrap()
+}
+
+fn vtype(r: u16) -> Vtype {
+    Vtype::from_u16(r).unwrap()
+}
+
+// Vector memory width 000/101/110/111 = EEW 8/16/32/64; mew = 1 is reserved
+// for wider elements.
+fn eew(w: u32) -> Result<Sew, DecodeError> {
+    match (mew_bits(w), f3_bits(w)) {
+        (0, 0b000) => Ok(Sew::E8),
+        (0, 0b101) => Ok(Sew::E16),
+        (0, 0b110) => Ok(Sew::E32),
+        (0, 0b111) => Ok(Sew::E64),
+        _ => Err(reserved(w)),
+    }
+}
+
+// Whole-register accesses move 1, 2, 4 or 8 registers starting at a multiple of that.
+fn whole_regs(w: u32, nf: u8) -> bool {
+    nf.is_power_of_two() && rd_bits(w).is_multiple_of(nf)
+}
+
+fn vload(w: u32) -> Result<Inst, DecodeError> {
+    let (eew, nf) = (eew(w)?, nf_bits(w) + 1);
+    let inst = match (mop_bits(w), rs2_bits(w), vm_bits(w)) {
+        (0b00, 0b00000, _) => Inst::VLE(vd(w), rs1(w), eew, nf, vm(w)),
+        (0b00, 0b10000, _) => Inst::VLEFF(vd(w), rs1(w), eew, nf, vm(w)),
+        (0b00, 0b01000, 1) if whole_regs(w, nf) => Inst::VLRE(vd(w), rs1(w), eew, nf),
+        (0b00, 0b01011, 1) if nf == 1 && eew == Sew::E8 => Inst::VLM(vd(w), rs1(w)),
+        (0b00, 0b01000 | 0b01011, 1) => return Err(reserved(w)),
+        (0b00, _, _) => return Err(undef(w)),
+        (0b01, _, _) => Inst::VLUXEI(vd(w), rs1(w), vs2(w), eew, nf, vm(w)),
+        (0b10, _, _) => Inst::VLSE(vd(w), rs1(w), rs2(w), eew, nf, vm(w)),
+        (0b11, _, _) => Inst::VLOXEI(vd(w), rs1(w), vs2(w), eew, nf, vm(w)),
+        _ => unreachable!(),
+    };
+    Ok(inst)
+}
+
+fn vstore(w: u32) -> Result<Inst, DecodeError> {
+    let (eew, nf) = (eew(w)?, nf_bits(w) + 1);
+    let inst = match (mop_bits(w), rs2_bits(w), vm_bits(w)) {
+        (0b00, 0b00000, _) => Inst::VSE(vd(w), rs1(w), eew, nf, vm(w)),
+        (0b00, 0b01000, 1) if whole_regs(w, nf) && eew == Sew::E8 => Inst::VSR(vd(w), rs1(w), nf),
+        (0b00, 0b01011, 1) if nf == 1 && eew == Sew::E8 => Inst::VSM(vd(w), rs1(w)),
+        (0b00, 0b01000 | 0b01011, 1) => return Err(reserved(w)),
+        (0b00, _, _) => return Err(undef(w)),
+        (0b01, _, _) => Inst::VSUXEI(vd(w), rs1(w), vs2(w), eew, nf, vm(w)),
+        (0b10, _, _) => Inst::VSSE(vd(w), rs1(w), rs2(w), eew, nf, vm(w)),
+        (0b11, _, _) => Inst::VSOXEI(vd(w), rs1(w), vs2(w), eew, nf, vm(w)),
+        _ => unreachable!(),
+    };
+    Ok(inst)
+}
+
+// Instructions from extensions missing in `isa` are reported as disabled.
+fn enabled(inst: Inst, size: u8, raw: u32, isa: &Isa) -> Result<Decoded, DecodeError> {
+    if inst.enabled(isa) {
+        Ok(Decoded { inst, size, raw })
+    } else {
+        Err(DecodeError::Disabled { raw, size })
+    }
+}
+
+fn undef(w: u32) -> DecodeError {
+    DecodeError::Unknown { raw: w, size: 4 }
+}
+
+fn reserved(w: u32) -> DecodeError {
+    DecodeError::Reserved { raw: w, size: 4 }
+}
+
+// amocas.d on RV32 and amocas.q on RV64 operate on even-odd register pairs.
+fn reg_pairs(w: u32) -> bool {
+    rd_bits(w) & 1 == 0 && rs2_bits(w) & 1 == 0
+}
+
+fn disabled(w: u32) -> DecodeError {
+    DecodeError::Disabled { raw: w, size: 4 }
+}
+
+fn c_reserved(w: u16) -> DecodeError {
+    DecodeError::Reserved { raw: w as u32, size: 2 }
+}
+
+fn c_hint(w: u16) -> DecodeError {
+    DecodeError::Hint { raw: w as u32, size: 2 }
+}
 
 fn csr(w: u32) -> Csr {
-    Csr::from_u16(i_imm_bits(w) as u16)
+    Csr::from_u16(csr_bits(w))
+}
+
+fn c_rd(w: u16) -> Gpr {
+    Gpr::from_u8(c_rd_bits(w)).unwrap()
+}
+
+fn c_rs2(w: u16) -> Gpr {
+    Gpr::from_u8(c_rs2_bits(w)).unwrap()
+}
+
+fn c_rdp(w: u16) -> Gpr {
+    Gpr::from_u8(c_rdp_bits(w)).unwrap()
+}
+
+fn c_rs1p(w: u16) -> Gpr {
+    Gpr::from_u8(c_rs1p_bits(w)).unwrap()
+}
+
+fn c_rs2p(w: u16) -> Gpr {
+    Gpr::from_u8(c_rs2p_bits(w)).unwrap()
+}
+
+// s0-s1, s2-s7
+fn c_sreg(r: u8) -> Gpr {
+    Gpr::from_u8(if r < 2 { r + 8 } else { r + 16 }).unwrap()
+}
+
+fn c_frd(w: u16) -> Fpr {
+    Fpr::from_u8(c_rd_bits(w)).unwrap()
+}
+
+fn c_frs2(w: u16) -> Fpr {
+    Fpr::from_u8(c_rs2_bits(w)).unwrap()
+}
+
+fn c_frdp(w: u16) -> Fpr {
+    Fpr::from_u8(c_rdp_bits(w)).unwrap()
+}
+
+fn c_frs2p(w: u16) -> Fpr {
+    Fpr::from_u8(c_rs2p_bits(w)).unwrap()
 }
@@ -0,0 +1,259 @@
+// Listings at real addresses: absolute branch targets, symbol names, and
+// the addresses materialised by AUIPC/LUI pairs
+
+use crate::display::{Addr, Ctx, Listing};
+use crate::{decode, encode, expand, ByteSlice, DecodeError, Gpr, Inst, Isa};
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+pub trait Symbols {
+    /// Nearest symbol at or below `addr`, as its name and start address.
+    fn lookup(&self, addr: u64) -> Option<(&str, u64)>;
+}
+
+impl Symbols for BTreeMap<u64, String> {
+    fn lookup(&self, addr: u64) -> Option<(&str, u64)> {
+        self.range(..=addr).next_back().map(|(&start, name)| (name.as_str(), start))
+    }
+}
+
+/// Formats instructions the way `objdump -d` does for a linked image.
+///
+/// Like objdump it remembers, per register, the value left by the last
+/// `auipc`/`lui`, so that a following `addi`, `jalr`, load or store through
+/// that register is annotated with the full address (`# 80002010 <buf>`).
+/// Feed instructions in program order and call `reset` at discontinuities.
+pub struct Disassembler<'a> {
+    isa: Isa,
+    symbols: Option<&'a dyn Symbols>,
+    aliases: bool,
+    hi: [Option<u64>; 32],
+}
+
+/// One decoded (or undecodable) instruction of a listing.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Line {
+    pub addr: u64,
+    /// Instruction length in bytes; 1 or 3 only for a truncated tail.
+    pub size: u8,
+    pub raw: u32,
+    pub inst: Result<Inst, DecodeError>,
+    /// Disassembly text, without address and raw bytes.
+    pub text: String,
+}
+
+impl std::fmt::Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let raw = format!("{:0w$x}", self.raw, w = 2 * self.size as usize);
+        write!(f, "{:8x}:\t{:<10}\t{}", self.addr, raw, self.text)
+    }
+}
+
+impl<'a> Disassembler<'a> {
+    pub fn new(isa: Isa) -> Self {
+        Self {
+            isa,
+            symbols: None,
+            aliases: true,
+            hi: [None; 32],
+        }
+    }
+
+    pub fn symbols(mut self, symbols: &'a dyn Symbols) -> Self {
+        self.symbols = Some(symbols);
+        self
+    }
+
+    /// `false` prints every instruction as itself (`-M no-aliases`).
+    pub fn aliases(mut self, aliases: bool) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Forgets pending `auipc`/`lui` values, e.g. at a new function.
+    pub fn reset(&mut self) {
+        self.hi = [None; 32];
+    }
+
+    /// Text for `inst` located at `addr`.
+    pub fn format(&mut self, addr: u64, inst: &Inst) -> String {
+        let cx = Ctx { pc: addr, mask: self.mask(), symbols: self.symbols };
+        let mut s = Listing(inst, self.aliases, &cx).to_string();
+        if let Some(target) = self.track(addr, inst) {
+            let _ = write!(s, "\t# {}", Addr(target, &cx));
+        }
+        s
+    }
+
+    /// Decodes and formats all of `bytes`, the first byte being at `addr`.
+    pub fn disassemble(&mut self, addr: u64, bytes: &[u8]) -> Vec<Line> {
+        let mut lines = Vec::new();
+        let mut off = 0;
+        while off < bytes.len() {
+            let pc = addr.wrapping_add(off as u64) & self.mask();
+            let line = match decode(&mut ByteSlice::from(&bytes[off..]), &self.isa) {
+                Ok(d) => Line {
+                    addr: pc,
+                    size: d.size,
+                    raw: d.raw,
+                    text: self.format(pc, &d.inst),
+                    inst: Ok(d.inst),
+                },
+                Err(e) => Line {
+                    addr: pc,
+                    size: e.size(),
+                    raw: e.raw(),
+                    text: self.data(&e),
+                    inst: Err(e),
+                },
+            };
+            off += line.size as usize;
+            lines.push(line);
+        }
+        lines
+    }
+
+    fn mask(&self) -> u64 {
+        if self.isa.xlen() == 32 { 0xffff_ffff } else { u64::MAX }
+    }
+
+    // objdump shows what it cannot decode as data directives.
+    fn data(&self, e: &DecodeError) -> String {
+        match (*e, e.size()) {
+            (DecodeError::Illegal { .. }, _) if self.aliases => "unimp".to_string(),
+            (DecodeError::Illegal { .. }, _) => "c.unimp".to_string(),
+            (_, 2) => format!(".2byte\t0x{:x}", e.raw()),
+            (_, 4) => format!(".4byte\t0x{:x}", e.raw()),
+            (_, size) => {
+                let bytes: Vec<String> = (0..size).map(|i| format!("0x{:x}", (e.raw() >> (8 * i)) & 0xff)).collect();
+                format!(".byte\t{}", bytes.join(","))
+            }
+        }
+    }
+
+    // Records the upper part of an address, or returns the full address
+    // when `inst` completes one. Whatever else `inst` writes loses its
+    // pending value.
+    fn track(&mut self, pc: u64, inst: &Inst) -> Option<u64> {
+        let inst = expand(inst);
+        let addr = self.complete(pc, &inst);
+        match (&inst, encode(&inst, &self.isa)) {
+            (Inst::AUIPC(..) | Inst::LUI(..), _) => {}
+            (_, Ok(enc)) if enc.size() == 4 => {
+                if let Some(rd) = written(enc.bits()) {
+                    self.hi[rd] = None;
+                }
+            }
+            // nothing this ISA encodes as one 32-bit word
+            _ => self.reset(),
+        }
+        addr
+    }
+
+    fn complete(&mut self, pc: u64, inst: &Inst) -> Option<u64> {
+        let sext = |imm: u32| (imm << 12) as i32 as i64 as u64;
+        let (base, off, wide) = match *inst {
+            Inst::AUIPC(rd, imm) if rd != Gpr::zero => {
+                self.hi[rd as usize] = Some(pc.wrapping_add(sext(imm)));
+                return None;
+            }
+            Inst::LUI(rd, imm) if rd != Gpr::zero => {
+                self.hi[rd as usize] = Some(sext(imm));
+                return None;
+            }
+
+            Inst::ADDI(_, rs1, imm) | Inst::JALR(_, rs1, imm) => {
+                let hi = self.hi[rs1 as usize].take()?;
+                return Some(hi.wrapping_add(imm as i64 as u64) & self.mask());
+            }
+            Inst::ADDIW(_, rs1, imm) if self.isa.xlen() > 32 => (rs1, imm, true),
+
+            Inst::LB(_, rs1, imm)
+            | Inst::LH(_, rs1, imm)
+            | Inst::LW(_, rs1, imm)
+            | Inst::LD(_, rs1, imm)
+            | Inst::LBU(_, rs1, imm)
+            | Inst::LHU(_, rs1, imm)
+            | Inst::LWU(_, rs1, imm)
+            | Inst::LDU(_, rs1, imm)
+            | Inst::LQ(_, rs1, imm)
+            | Inst::SB(_, rs1, imm)
+            | Inst::SH(_, rs1, imm)
+            | Inst::SW(_, rs1, imm)
+            | Inst::SD(_, rs1, imm)
+            | Inst::SQ(_, rs1, imm) => (rs1, imm, false),
+            Inst::FLW(_, rs1, imm)
+            | Inst::FLD(_, rs1, imm)
+            | Inst::FLQ(_, rs1, imm)
+            | Inst::FSW(_, rs1, imm)
+            | Inst::FSD(_, rs1, imm)
+            | Inst::FSQ(_, rs1, imm) => (rs1, imm, false),
+            Inst::Custom(ref c) => {
+                let (rs1, imm) = c.vendor.mem(c)?;
+                (rs1, imm, false)
+            }
+            _ => return None,
+        };
+
+        let addr = match self.hi[base as usize].take() {
+            Some(hi) => hi.wrapping_add(off as i64 as u64),
+            // absolute and thread-pointer-relative accesses need no pair
+            None if !wide && (base == Gpr::zero || base == Gpr::tp) => off as i64 as u64,
+            None => return None,
+        };
+        let addr = if wide { addr as i32 as i64 as u64 } else { addr };
+        Some(addr & self.mask())
+    }
+}
+
+// The rd field of a 32-bit word, where it names a GPR the instruction
+// writes; stores and branches keep immediate bits there, floating-point and
+// vector instructions mostly write their own registers.
+pub(crate) fn written(w: u32) -> Option<usize> {
+    let gpr = match w & 0x7f {
+        0x07 | 0x23 | 0x27 | 0x43 | 0x47 | 0x4b | 0x4f | 0x63 => false,
+        // comparisons, conversions to integer, fmv.x and fclass
+        0x53 => matches!(w >> 27, 0b10100 | 0b11000 | 0b11100),
+        // vset{i}vl{i}, and vmv.x.s, vcpop.m and vfirst.m
+        0x57 => w >> 12 & 7 == 0b111 || (w >> 12 & 7 == 0b010 && w >> 26 == 0b010000),
+        _ => true,
+    };
+    Some((w >> 7 & 0x1f) as usize).filter(|_| gpr)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{encode, Disassembler, Fpr, Gpr, Inst, Isa, Rm};
+
+    fn listing(insts: &[Inst]) -> Vec<String> {
+        let isa: Isa = "rv64gc".parse().unwrap();
+        let mut d = Disassembler::new(isa);
+        let mut pc = 0x80000000;
+        insts
+            .iter()
+            .map(|inst| {
+                let s = d.format(pc, inst);
+                pc += encode(inst, &isa).unwrap().size() as u64;
+                s
+            })
+            .collect()
+    }
+
+    #[test]
+    fn annotations() {
+        let auipc = Inst::AUIPC(Gpr::a1, 2);
+        let lw = Inst::LW(Gpr::a2, Gpr::a1, 8);
+        assert_eq!(listing(&[auipc.clone(), lw.clone()])[1], "lw\ta2,8(a1)\t# 80002008");
+        // a floating-point result in fa1 leaves a1 alone
+        let fadd = Inst::FADDS(Fpr::fa1, Fpr::fa2, Fpr::fa3, Rm::Dyn);
+        assert_eq!(listing(&[auipc.clone(), fadd, lw.clone()])[2], "lw\ta2,8(a1)\t# 80002008");
+        // once a1 is overwritten it no longer holds the auipc value
+        let li = Inst::ADDI(Gpr::a1, Gpr::zero, 5);
+        assert_eq!(listing(&[auipc.clone(), li, lw.clone()])[2], "lw\ta2,8(a1)");
+        assert_eq!(listing(&[auipc.clone(), Inst::CLI(Gpr::a1, 5), lw.clone()])[2], "lw\ta2,8(a1)");
+        // stores and branches keep immediate bits where rd would be
+        let sw = Inst::SW(Gpr::a2, Gpr::sp, 11);
+        assert_eq!(listing(&[auipc, sw, lw])[2], "lw\ta2,8(a1)\t# 80002008");
+    }
+}
@@ -0,0 +1,1106 @@
+use crate::{Csr, Fence, Fm, Gpr, Inst, Rm, Sew, Symbols};
+
+use std::fmt::{Formatter, Result};
+
+// Where an instruction sits: branch and jump targets are printed as absolute
+// addresses, with a `<symbol+offset>` annotation when a resolver knows one.
+pub(crate) struct Ctx<'a> {
+    pub pc: u64,
+    pub mask: u64,
+    pub symbols: Option<&'a dyn Symbols>,
+}
+
+impl Ctx<'_> {
+    // objdump on an object file: address 0, 64-bit wrap-around, no symbols.
+    const ZERO: Ctx<'static> = Ctx { pc: 0, mask: u64::MAX, symbols: None };
+}
+
+// Absolute address, `80001234 <main+0x20>` when a symbol covers it.
+pub(crate) struct Addr<'a>(pub u64, pub &'a Ctx<'a>);
+
+impl std::fmt::Display for Addr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let addr = self.0 & self.1.mask;
+        write!(f, "{:x}", addr)?;
+        match self.1.symbols.and_then(|s| s.lookup(addr)) {
+            Some((name, start)) if start == addr => write!(f, " <{}>", name),
+            Some((name, start)) => write!(f, " <{}+0x{:x}>", name, addr.wrapping_sub(start)),
+            None => Ok(()),
+        }
+    }
+}
+
+struct Target<'a>(i32, &'a Ctx<'a>);
+
+impl std::fmt::Display for Target<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Addr(self.1.pc.wrapping_add(self.0 as i64 as u64), self.1).fmt(f)
+    }
+}
+
+// Rounding-mode operand, omitted when it is the instruction's default.
+struct Frm(Rm, Rm);
+
+impl std::fmt::Display for Frm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.0 == self.1 {
+            Ok(())
+        } else {
+            write!(f, ",{}", self.0)
+        }
+    }
+}
+
+// GNU objdump syntax. `{}` prints canonical aliases (`li`, `ret`, `beqz`, and
+// compressed instructions under their base names) like plain `objdump -d`;
+// `{:#}` prints every instruction as itself, like `objdump -d -M no-aliases`.
+// Targets are relative to address 0; see `Disassembler` for real addresses.
+impl std::fmt::Display for Inst {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_inst(f, self, !f.alternate(), &Ctx::ZERO)
+    }
+}
+
+// An instruction printed at `cx.pc`, with or without aliases.
+pub(crate) struct Listing<'a>(pub &'a Inst, pub bool, pub &'a Ctx<'a>);
+
+impl std::fmt::Display for Listing<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_inst(f, self.0, self.1, self.2)
+    }
+}
+
+fn write_inst(f: &mut Formatter<'_>, inst: &Inst, aliases: bool, cx: &Ctx) -> Result {
+    if aliases {
+        if let Some(r) = alias(inst, f, cx) {
+            return r;
+        }
+    }
+    no_aliases(inst, f, cx)
+}
+
+fn no_aliases(inst: &Inst, f: &mut Formatter<'_>, cx: &Ctx) -> Result {
+    match *inst {
+        // not an alias: objdump prints it under either setting
+        Inst::CSRRW(Gpr::zero, Gpr::zero, Csr::cycle) => write!(f, "unimp"),
+        Inst::ADD(rd, rs1, rs2) => write!(f, "add\t{},{},{}", rd, rs1, rs2),
+        Inst::SUB(rd, rs1, rs2) => write!(f, "sub\t{},{},{}", rd, rs1, rs2),
+        Inst::XOR(rd, rs1, rs2) => write!(f, "xor\t{},{},{}", rd, rs1, rs2),
+        Inst::OR(rd, rs1, rs2) => write!(f, "or\t{},{},{}", rd, rs1, rs2),
+        Inst::AND(rd, rs1, rs2) => write!(f, "and\t{},{},{}", rd, rs1, rs2),
+        Inst::SLL(rd, rs1, rs2) => write!(f, "sll\t{},{},{}", rd, rs1, rs2),
+        Inst::SRL(rd, rs1, rs2) => write!(f, "srl\t{},{},{}", rd, rs1, rs2),
+        Inst::SRA(rd, rs1, rs2) => write!(f, "sra\t{},{},{}", rd, rs1, rs2),
+        Inst::SLT(rd, rs1, rs2) => write!(f, "slt\t{},{},{}", rd, rs1, rs2),
+        Inst::SLTU(rd, rs1, rs2) => write!(f, "sltu\t{},{},{}", rd, rs1, rs2),
+        Inst::ADDI(rd, rs1, imm) => write!(f, "addi\t{},{},{}", rd, rs1, imm),
+        Inst::XORI(rd, rs1, imm) => write!(f, "xori\t{},{},{}", rd, rs1, imm),
+        Inst::ORI(rd, rs1, imm) => write!(f, "ori\t{},{},{}", rd, rs1, imm),
+        Inst::ANDI(rd, rs1, imm) => write!(f, "andi\t{},{},{}", rd, rs1, imm),
+        Inst::SLLI(rd, rs1, shamt) => write!(f, "slli\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::SRLI(rd, rs1, shamt) => write!(f, "srli\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::SRAI(rd, rs1, shamt) => write!(f, "srai\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::SLTI(rd, rs1, imm) => write!(f, "slti\t{},{},{}", rd, rs1, imm),
+        Inst::SLTUI(rd, rs1, imm) => write!(f, "sltiu\t{},{},{}", rd, rs1, imm as i32),
+        Inst::LB(rd, rs1, imm) => write!(f, "lb\t{},{}({})", rd, imm, rs1),
+        Inst::LH(rd, rs1, imm) => write!(f, "lh\t{},{}({})", rd, imm, rs1),
+        Inst::LW(rd, rs1, imm) => write!(f, "lw\t{},{}({})", rd, imm, rs1),
+        Inst::LBU(rd, rs1, imm) => write!(f, "lbu\t{},{}({})", rd, imm, rs1),
+        Inst::LHU(rd, rs1, imm) => write!(f, "lhu\t{},{}({})", rd, imm, rs1),
+        Inst::SB(rs2, rs1, imm) => write!(f, "sb\t{},{}({})", rs2, imm, rs1),
+        Inst::SH(rs2, rs1, imm) => write!(f, "sh\t{},{}({})", rs2, imm, rs1),
+        Inst::SW(rs2, rs1, imm) => write!(f, "sw\t{},{}({})", rs2, imm, rs1),
+        Inst::BEQ(rs1, rs2, imm) => write!(f, "beq\t{},{},{}", rs1, rs2, Target(imm, cx)),
+        Inst::BNE(rs1, rs2, imm) => write!(f, "bne\t{},{},{}", rs1, rs2, Target(imm, cx)),
+        Inst::BLT(rs1, rs2, imm) => write!(f, "blt\t{},{},{}", rs1, rs2, Target(imm, cx)),
+        Inst::BGE(rs1, rs2, imm) => write!(f, "bge\t{},{},{}", rs1, rs2, Target(imm, cx)),
+        Inst::BLTU(rs1, rs2, imm) => write!(f, "bltu\t{},{},{}", rs1, rs2, Target(imm, cx)),
+        Inst::BGEU(rs1, rs2, imm) => write!(f, "bgeu\t{},{},{}", rs1, rs2, Target(imm, cx)),
+        Inst::JAL(rd, imm) => write!(f, "jal\t{},{}", rd, Target(imm, cx)),
+        Inst::JALR(rd, rs1, imm) => write!(f, "jalr\t{},{}({})", rd, imm, rs1),
+        Inst::LUI(rd, imm) => write!(f, "lui\t{},0x{:x}", rd, imm),
+        Inst::AUIPC(rd, imm) => write!(f, "auipc\t{},0x{:x}", rd, imm),
+        Inst::ECALL => write!(f, "ecall"),
+        Inst::EBREAK => write!(f, "ebreak"),
+        Inst::FENCE(Fm::Tso, _, _) => write!(f, "fence.tso"),
+        Inst::FENCE(Fm::None, pred, succ) => write!(f, "fence\t{},{}", pred, succ),
+        Inst::PAUSE => write!(f, "pause"),
+        Inst::FENCEI => write!(f, "fence.i"),
+        Inst::CSRRW(rd, rs1, csr) => write!(f, "csrrw\t{},{},{}", rd, csr, rs1),
+        Inst::CSRRS(rd, rs1, csr) => write!(f, "csrrs\t{},{},{}", rd, csr, rs1),
+        Inst::CSRRC(rd, rs1, csr) => write!(f, "csrrc\t{},{},{}", rd, csr, rs1),
+        Inst::CSRRWI(rd, imm, csr) => write!(f, "csrrwi\t{},{},{}", rd, csr, imm),
+        Inst::CSRRSI(rd, imm, csr) => write!(f, "csrrsi\t{},{},{}", rd, csr, imm),
+        Inst::CSRRCI(rd, imm, csr) => write!(f, "csrrci\t{},{},{}", rd, csr, imm),
+        Inst::SRET => write!(f, "sret"),
+        Inst::MRET => write!(f, "mret"),
+        Inst::WFI => write!(f, "wfi"),
+        Inst::LD(rd, rs1, imm) => write!(f, "ld\t{},{}({})", rd, imm, rs1),
+        Inst::LWU(rd, rs1, imm) => write!(f, "lwu\t{},{}({})", rd, imm, rs1),
+        Inst::LDU(rd, rs1, imm) => write!(f, "ldu\t{},{}({})", rd, imm, rs1),
+        Inst::LQ(rd, rs1, imm) => write!(f, "lq\t{},{}({})", rd, imm, rs1),
+        Inst::SQ(rs2, rs1, imm) => write!(f, "sq\t{},{}({})", rs2, imm, rs1),
+        Inst::ADDID(rd, rs1, imm) => write!(f, "addid\t{},{},{}", rd, rs1, imm),
+        Inst::SLLID(rd, rs1, shamt) => write!(f, "sllid\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::SRLID(rd, rs1, shamt) => write!(f, "srlid\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::SRAID(rd, rs1, shamt) => write!(f, "sraid\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::ADDD(rd, rs1, rs2) => write!(f, "addd\t{},{},{}", rd, rs1, rs2),
+        Inst::SUBD(rd, rs1, rs2) => write!(f, "subd\t{},{},{}", rd, rs1, rs2),
+        Inst::SLLD(rd, rs1, rs2) => write!(f, "slld\t{},{},{}", rd, rs1, rs2),
+        Inst::SRLD(rd, rs1, rs2) => write!(f, "srld\t{},{},{}", rd, rs1, rs2),
+        Inst::SRAD(rd, rs1, rs2) => write!(f, "srad\t{},{},{}", rd, rs1, rs2),
+        Inst::MULD(rd, rs1, rs2) => write!(f, "muld\t{},{},{}", rd, rs1, rs2),
+        Inst::DIVD(rd, rs1, rs2) => write!(f, "divd\t{},{},{}", rd, rs1, rs2),
+        Inst::DIVUD(rd, rs1, rs2) => write!(f, "divud\t{},{},{}", rd, rs1, rs2),
+        Inst::REMD(rd, rs1, rs2) => write!(f, "remd\t{},{},{}", rd, rs1, rs2),
+        Inst::REMUD(rd, rs1, rs2) => write!(f, "remud\t{},{},{}", rd, rs1, rs2),
+        Inst::SD(rs2, rs1, imm) => write!(f, "sd\t{},{}({})", rs2, imm, rs1),
+        Inst::ADDIW(rd, rs1, imm) => write!(f, "addiw\t{},{},{}", rd, rs1, imm),
+        Inst::SLLIW(rd, rs1, shamt) => write!(f, "slliw\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::SRLIW(rd, rs1, shamt) => write!(f, "srliw\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::SRAIW(rd, rs1, shamt) => write!(f, "sraiw\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::ADDW(rd, rs1, rs2) => write!(f, "addw\t{},{},{}", rd, rs1, rs2),
+        Inst::SUBW(rd, rs1, rs2) => write!(f, "subw\t{},{},{}", rd, rs1, rs2),
+        Inst::SLLW(rd, rs1, rs2) => write!(f, "sllw\t{},{},{}", rd, rs1, rs2),
+        Inst::SRLW(rd, rs1, rs2) => write!(f, "srlw\t{},{},{}", rd, rs1, rs2),
+        Inst::SRAW(rd, rs1, rs2) => write!(f, "sraw\t{},{},{}", rd, rs1, rs2),
+        Inst::MUL(rd, rs1, rs2) => write!(f, "mul\t{},{},{}", rd, rs1, rs2),
+        Inst::MULH(rd, rs1, rs2) => write!(f, "mulh\t{},{},{}", rd, rs1, rs2),
+        Inst::MULSU(rd, rs1, rs2) => write!(f, "mulhsu\t{},{},{}", rd, rs1, rs2),
+        Inst::MULU(rd, rs1, rs2) => write!(f, "mulhu\t{},{},{}", rd, rs1, rs2),
+        Inst::MULW(rd, rs1, rs2) => write!(f, "mulw\t{},{},{}", rd, rs1, rs2),
+        Inst::DIV(rd, rs1, rs2) => write!(f, "div\t{},{},{}", rd, rs1, rs2),
+        Inst::DIVU(rd, rs1, rs2) => write!(f, "divu\t{},{},{}", rd, rs1, rs2),
+        Inst::REM(rd, rs1, rs2) => write!(f, "rem\t{},{},{}", rd, rs1, rs2),
+        Inst::REMU(rd, rs1, rs2) => write!(f, "remu\t{},{},{}", rd, rs1, rs2),
+        Inst::DIVW(rd, rs1, rs2) => write!(f, "divw\t{},{},{}", rd, rs1, rs2),
+        Inst::DIVUW(rd, rs1, rs2) => write!(f, "divuw\t{},{},{}", rd, rs1, rs2),
+        Inst::REMW(rd, rs1, rs2) => write!(f, "remw\t{},{},{}", rd, rs1, rs2),
+        Inst::REMUW(rd, rs1, rs2) => write!(f, "remuw\t{},{},{}", rd, rs1, rs2),
+        Inst::LRW(rd, rs1, aqrl) => write!(f, "lr.w{}\t{},({})", aqrl, rd, rs1),
+        Inst::SCW(rd, rs1, rs2, aqrl) => write!(f, "sc.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::LRD(rd, rs1, aqrl) => write!(f, "lr.d{}\t{},({})", aqrl, rd, rs1),
+        Inst::SCD(rd, rs1, rs2, aqrl) => write!(f, "sc.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOSWAPW(rd, rs1, rs2, aqrl) => write!(f, "amoswap.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOADDW(rd, rs1, rs2, aqrl) => write!(f, "amoadd.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOXORW(rd, rs1, rs2, aqrl) => write!(f, "amoxor.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOANDW(rd, rs1, rs2, aqrl) => write!(f, "amoand.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOORW(rd, rs1, rs2, aqrl) => write!(f, "amoor.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMINW(rd, rs1, rs2, aqrl) => write!(f, "amomin.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXW(rd, rs1, rs2, aqrl) => write!(f, "amomax.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMINUW(rd, rs1, rs2, aqrl) => write!(f, "amominu.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXUW(rd, rs1, rs2, aqrl) => write!(f, "amomaxu.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOSWAPD(rd, rs1, rs2, aqrl) => write!(f, "amoswap.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOADDD(rd, rs1, rs2, aqrl) => write!(f, "amoadd.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOXORD(rd, rs1, rs2, aqrl) => write!(f, "amoxor.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOANDD(rd, rs1, rs2, aqrl) => write!(f, "amoand.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOORD(rd, rs1, rs2, aqrl) => write!(f, "amoor.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMIND(rd, rs1, rs2, aqrl) => write!(f, "amomin.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXD(rd, rs1, rs2, aqrl) => write!(f, "amomax.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMINUD(rd, rs1, rs2, aqrl) => write!(f, "amominu.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXUD(rd, rs1, rs2, aqrl) => write!(f, "amomaxu.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOCASW(rd, rs1, rs2, aqrl) => write!(f, "amocas.w{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOCASD(rd, rs1, rs2, aqrl) => write!(f, "amocas.d{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOCASQ(rd, rs1, rs2, aqrl) => write!(f, "amocas.q{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOSWAPB(rd, rs1, rs2, aqrl) => write!(f, "amoswap.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOADDB(rd, rs1, rs2, aqrl) => write!(f, "amoadd.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOXORB(rd, rs1, rs2, aqrl) => write!(f, "amoxor.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOANDB(rd, rs1, rs2, aqrl) => write!(f, "amoand.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOORB(rd, rs1, rs2, aqrl) => write!(f, "amoor.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMINB(rd, rs1, rs2, aqrl) => write!(f, "amomin.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXB(rd, rs1, rs2, aqrl) => write!(f, "amomax.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMINUB(rd, rs1, rs2, aqrl) => write!(f, "amominu.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXUB(rd, rs1, rs2, aqrl) => write!(f, "amomaxu.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOCASB(rd, rs1, rs2, aqrl) => write!(f, "amocas.b{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOSWAPH(rd, rs1, rs2, aqrl) => write!(f, "amoswap.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOADDH(rd, rs1, rs2, aqrl) => write!(f, "amoadd.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOXORH(rd, rs1, rs2, aqrl) => write!(f, "amoxor.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOANDH(rd, rs1, rs2, aqrl) => write!(f, "amoand.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOORH(rd, rs1, rs2, aqrl) => write!(f, "amoor.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMINH(rd, rs1, rs2, aqrl) => write!(f, "amomin.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXH(rd, rs1, rs2, aqrl) => write!(f, "amomax.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMINUH(rd, rs1, rs2, aqrl) => write!(f, "amominu.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOMAXUH(rd, rs1, rs2, aqrl) => write!(f, "amomaxu.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::AMOCASH(rd, rs1, rs2, aqrl) => write!(f, "amocas.h{}\t{},{},({})", aqrl, rd, rs2, rs1),
+        Inst::FLW(rd, rs1, imm) => write!(f, "flw\t{},{}({})", rd, imm, rs1),
+        Inst::FSW(rs2, rs1, imm) => write!(f, "fsw\t{},{}({})", rs2, imm, rs1),
+        Inst::FMADDS(rd, rs1, rs2, rs3, rm) => write!(f, "fmadd.s\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FMSUBS(rd, rs1, rs2, rs3, rm) => write!(f, "fmsub.s\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMSUBS(rd, rs1, rs2, rs3, rm) => write!(f, "fnmsub.s\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMADDS(rd, rs1, rs2, rs3, rm) => write!(f, "fnmadd.s\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FADDS(rd, rs1, rs2, rm) => write!(f, "fadd.s\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSUBS(rd, rs1, rs2, rm) => write!(f, "fsub.s\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FMULS(rd, rs1, rs2, rm) => write!(f, "fmul.s\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FDIVS(rd, rs1, rs2, rm) => write!(f, "fdiv.s\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSQRTS(rd, rs1, rm) => write!(f, "fsqrt.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FSGNJS(rd, rs1, rs2) => write!(f, "fsgnj.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJNS(rd, rs1, rs2) => write!(f, "fsgnjn.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJXS(rd, rs1, rs2) => write!(f, "fsgnjx.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FMINS(rd, rs1, rs2) => write!(f, "fmin.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXS(rd, rs1, rs2) => write!(f, "fmax.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FEQS(rd, rs1, rs2) => write!(f, "feq.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTS(rd, rs1, rs2) => write!(f, "flt.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FLES(rd, rs1, rs2) => write!(f, "fle.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FCLASSS(rd, rs1) => write!(f, "fclass.s\t{},{}", rd, rs1),
+        Inst::FCVTWS(rd, rs1, rm) => write!(f, "fcvt.w.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTWUS(rd, rs1, rm) => write!(f, "fcvt.wu.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTSW(rd, rs1, rm) => write!(f, "fcvt.s.w\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTSWU(rd, rs1, rm) => write!(f, "fcvt.s.wu\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FMVXW(rd, rs1) => write!(f, "fmv.x.w\t{},{}", rd, rs1),
+        Inst::FMVWX(rd, rs1) => write!(f, "fmv.w.x\t{},{}", rd, rs1),
+        Inst::FCVTLS(rd, rs1, rm) => write!(f, "fcvt.l.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTLUS(rd, rs1, rm) => write!(f, "fcvt.lu.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTSL(rd, rs1, rm) => write!(f, "fcvt.s.l\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTSLU(rd, rs1, rm) => write!(f, "fcvt.s.lu\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FLD(rd, rs1, imm) => write!(f, "fld\t{},{}({})", rd, imm, rs1),
+        Inst::FSD(rs2, rs1, imm) => write!(f, "fsd\t{},{}({})", rs2, imm, rs1),
+        Inst::FMADDD(rd, rs1, rs2, rs3, rm) => write!(f, "fmadd.d\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FMSUBD(rd, rs1, rs2, rs3, rm) => write!(f, "fmsub.d\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMSUBD(rd, rs1, rs2, rs3, rm) => write!(f, "fnmsub.d\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMADDD(rd, rs1, rs2, rs3, rm) => write!(f, "fnmadd.d\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FADDD(rd, rs1, rs2, rm) => write!(f, "fadd.d\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSUBD(rd, rs1, rs2, rm) => write!(f, "fsub.d\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FMULD(rd, rs1, rs2, rm) => write!(f, "fmul.d\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FDIVD(rd, rs1, rs2, rm) => write!(f, "fdiv.d\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSQRTD(rd, rs1, rm) => write!(f, "fsqrt.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FSGNJD(rd, rs1, rs2) => write!(f, "fsgnj.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJND(rd, rs1, rs2) => write!(f, "fsgnjn.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJXD(rd, rs1, rs2) => write!(f, "fsgnjx.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FMIND(rd, rs1, rs2) => write!(f, "fmin.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXD(rd, rs1, rs2) => write!(f, "fmax.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FCVTSD(rd, rs1, rm) => write!(f, "fcvt.s.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTDS(rd, rs1, rm) => write!(f, "fcvt.d.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FEQD(rd, rs1, rs2) => write!(f, "feq.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTD(rd, rs1, rs2) => write!(f, "flt.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FLED(rd, rs1, rs2) => write!(f, "fle.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FCLASSD(rd, rs1) => write!(f, "fclass.d\t{},{}", rd, rs1),
+        Inst::FCVTWD(rd, rs1, rm) => write!(f, "fcvt.w.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTWUD(rd, rs1, rm) => write!(f, "fcvt.wu.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTDW(rd, rs1, rm) => write!(f, "fcvt.d.w\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTDWU(rd, rs1, rm) => write!(f, "fcvt.d.wu\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTLD(rd, rs1, rm) => write!(f, "fcvt.l.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTLUD(rd, rs1, rm) => write!(f, "fcvt.lu.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTDL(rd, rs1, rm) => write!(f, "fcvt.d.l\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTDLU(rd, rs1, rm) => write!(f, "fcvt.d.lu\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FMVXD(rd, rs1) => write!(f, "fmv.x.d\t{},{}", rd, rs1),
+        Inst::FMVDX(rd, rs1) => write!(f, "fmv.d.x\t{},{}", rd, rs1),
+        Inst::FLQ(rd, rs1, imm) => write!(f, "flq\t{},{}({})", rd, imm, rs1),
+        Inst::FSQ(rs2, rs1, imm) => write!(f, "fsq\t{},{}({})", rs2, imm, rs1),
+        Inst::FMADDQ(rd, rs1, rs2, rs3, rm) => write!(f, "fmadd.q\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FMSUBQ(rd, rs1, rs2, rs3, rm) => write!(f, "fmsub.q\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMSUBQ(rd, rs1, rs2, rs3, rm) => write!(f, "fnmsub.q\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMADDQ(rd, rs1, rs2, rs3, rm) => write!(f, "fnmadd.q\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FADDQ(rd, rs1, rs2, rm) => write!(f, "fadd.q\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSUBQ(rd, rs1, rs2, rm) => write!(f, "fsub.q\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FMULQ(rd, rs1, rs2, rm) => write!(f, "fmul.q\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FDIVQ(rd, rs1, rs2, rm) => write!(f, "fdiv.q\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSQRTQ(rd, rs1, rm) => write!(f, "fsqrt.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FSGNJQ(rd, rs1, rs2) => write!(f, "fsgnj.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJNQ(rd, rs1, rs2) => write!(f, "fsgnjn.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJXQ(rd, rs1, rs2) => write!(f, "fsgnjx.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FMINQ(rd, rs1, rs2) => write!(f, "fmin.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXQ(rd, rs1, rs2) => write!(f, "fmax.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FCVTSQ(rd, rs1, rm) => write!(f, "fcvt.s.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTQS(rd, rs1, rm) => write!(f, "fcvt.q.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTDQ(rd, rs1, rm) => write!(f, "fcvt.d.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTQD(rd, rs1, rm) => write!(f, "fcvt.q.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FEQQ(rd, rs1, rs2) => write!(f, "feq.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTQ(rd, rs1, rs2) => write!(f, "flt.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FLEQ(rd, rs1, rs2) => write!(f, "fle.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FCLASSQ(rd, rs1) => write!(f, "fclass.q\t{},{}", rd, rs1),
+        Inst::FCVTWQ(rd, rs1, rm) => write!(f, "fcvt.w.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTWUQ(rd, rs1, rm) => write!(f, "fcvt.wu.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTQW(rd, rs1, rm) => write!(f, "fcvt.q.w\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTQWU(rd, rs1, rm) => write!(f, "fcvt.q.wu\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTLQ(rd, rs1, rm) => write!(f, "fcvt.l.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTLUQ(rd, rs1, rm) => write!(f, "fcvt.lu.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTQL(rd, rs1, rm) => write!(f, "fcvt.q.l\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTQLU(rd, rs1, rm) => write!(f, "fcvt.q.lu\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FLH(rd, rs1, imm) => write!(f, "flh\t{},{}({})", rd, imm, rs1),
+        Inst::FSH(rs2, rs1, imm) => write!(f, "fsh\t{},{}({})", rs2, imm, rs1),
+        Inst::FMADDH(rd, rs1, rs2, rs3, rm) => write!(f, "fmadd.h\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FMSUBH(rd, rs1, rs2, rs3, rm) => write!(f, "fmsub.h\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMSUBH(rd, rs1, rs2, rs3, rm) => write!(f, "fnmsub.h\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FNMADDH(rd, rs1, rs2, rs3, rm) => write!(f, "fnmadd.h\t{},{},{},{}{}", rd, rs1, rs2, rs3, Frm(rm, Rm::Dyn)),
+        Inst::FADDH(rd, rs1, rs2, rm) => write!(f, "fadd.h\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSUBH(rd, rs1, rs2, rm) => write!(f, "fsub.h\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FMULH(rd, rs1, rs2, rm) => write!(f, "fmul.h\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FDIVH(rd, rs1, rs2, rm) => write!(f, "fdiv.h\t{},{},{}{}", rd, rs1, rs2, Frm(rm, Rm::Dyn)),
+        Inst::FSQRTH(rd, rs1, rm) => write!(f, "fsqrt.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FSGNJH(rd, rs1, rs2) => write!(f, "fsgnj.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJNH(rd, rs1, rs2) => write!(f, "fsgnjn.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FSGNJXH(rd, rs1, rs2) => write!(f, "fsgnjx.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FMINH(rd, rs1, rs2) => write!(f, "fmin.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXH(rd, rs1, rs2) => write!(f, "fmax.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FCVTSH(rd, rs1, rm) => write!(f, "fcvt.s.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTHS(rd, rs1, rm) => write!(f, "fcvt.h.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTDH(rd, rs1, rm) => write!(f, "fcvt.d.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTHD(rd, rs1, rm) => write!(f, "fcvt.h.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTQH(rd, rs1, rm) => write!(f, "fcvt.q.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Rne)),
+        Inst::FCVTHQ(rd, rs1, rm) => write!(f, "fcvt.h.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FEQH(rd, rs1, rs2) => write!(f, "feq.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTH(rd, rs1, rs2) => write!(f, "flt.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FLEH(rd, rs1, rs2) => write!(f, "fle.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FCLASSH(rd, rs1) => write!(f, "fclass.h\t{},{}", rd, rs1),
+        Inst::FCVTWH(rd, rs1, rm) => write!(f, "fcvt.w.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTWUH(rd, rs1, rm) => write!(f, "fcvt.wu.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTHW(rd, rs1, rm) => write!(f, "fcvt.h.w\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTHWU(rd, rs1, rm) => write!(f, "fcvt.h.wu\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FMVXH(rd, rs1) => write!(f, "fmv.x.h\t{},{}", rd, rs1),
+        Inst::FMVHX(rd, rs1) => write!(f, "fmv.h.x\t{},{}", rd, rs1),
+        Inst::FCVTLH(rd, rs1, rm) => write!(f, "fcvt.l.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTLUH(rd, rs1, rm) => write!(f, "fcvt.lu.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTHL(rd, rs1, rm) => write!(f, "fcvt.h.l\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FCVTHLU(rd, rs1, rm) => write!(f, "fcvt.h.lu\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FLIS(rd, fli) => write!(f, "fli.s\t{},{}", rd, fli),
+        Inst::FLID(rd, fli) => write!(f, "fli.d\t{},{}", rd, fli),
+        Inst::FLIH(rd, fli) => write!(f, "fli.h\t{},{}", rd, fli),
+        Inst::FLIQ(rd, fli) => write!(f, "fli.q\t{},{}", rd, fli),
+        Inst::FMINMS(rd, rs1, rs2) => write!(f, "fminm.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXMS(rd, rs1, rs2) => write!(f, "fmaxm.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FROUNDS(rd, rs1, rm) => write!(f, "fround.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FROUNDNXS(rd, rs1, rm) => write!(f, "froundnx.s\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FLEQS(rd, rs1, rs2) => write!(f, "fleq.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTQS(rd, rs1, rs2) => write!(f, "fltq.s\t{},{},{}", rd, rs1, rs2),
+        Inst::FMINMD(rd, rs1, rs2) => write!(f, "fminm.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXMD(rd, rs1, rs2) => write!(f, "fmaxm.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FROUNDD(rd, rs1, rm) => write!(f, "fround.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FROUNDNXD(rd, rs1, rm) => write!(f, "froundnx.d\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FLEQD(rd, rs1, rs2) => write!(f, "fleq.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTQD(rd, rs1, rs2) => write!(f, "fltq.d\t{},{},{}", rd, rs1, rs2),
+        Inst::FMINMH(rd, rs1, rs2) => write!(f, "fminm.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXMH(rd, rs1, rs2) => write!(f, "fmaxm.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FROUNDH(rd, rs1, rm) => write!(f, "fround.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FROUNDNXH(rd, rs1, rm) => write!(f, "froundnx.h\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FLEQH(rd, rs1, rs2) => write!(f, "fleq.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTQH(rd, rs1, rs2) => write!(f, "fltq.h\t{},{},{}", rd, rs1, rs2),
+        Inst::FMINMQ(rd, rs1, rs2) => write!(f, "fminm.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FMAXMQ(rd, rs1, rs2) => write!(f, "fmaxm.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FROUNDQ(rd, rs1, rm) => write!(f, "fround.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FROUNDNXQ(rd, rs1, rm) => write!(f, "froundnx.q\t{},{}{}", rd, rs1, Frm(rm, Rm::Dyn)),
+        Inst::FLEQQ(rd, rs1, rs2) => write!(f, "fleq.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FLTQQ(rd, rs1, rs2) => write!(f, "fltq.q\t{},{},{}", rd, rs1, rs2),
+        Inst::FCVTMODWD(rd, rs1) => write!(f, "fcvtmod.w.d\t{},{},rtz", rd, rs1),
+        Inst::FMVHXD(rd, rs1) => write!(f, "fmvh.x.d\t{},{}", rd, rs1),
+        Inst::FMVPDX(rd, rs1, rs2) => write!(f, "fmvp.d.x\t{},{},{}", rd, rs1, rs2),
+        Inst::FMVHXQ(rd, rs1) => write!(f, "fmvh.x.q\t{},{}", rd, rs1),
+        Inst::FMVPQX(rd, rs1, rs2) => write!(f, "fmvp.q.x\t{},{},{}", rd, rs1, rs2),
+        Inst::SFENCEVMA(rs1, rs2) => write!(f, "sfence.vma\t{},{}", rs1, rs2),
+        Inst::SINVALVMA(rs1, rs2) => write!(f, "sinval.vma\t{},{}", rs1, rs2),
+        Inst::SFENCEWINVAL => write!(f, "sfence.w.inval"),
+        Inst::SFENCEINVALIR => write!(f, "sfence.inval.ir"),
+        Inst::HFENCEVVMA(rs1, rs2) => write!(f, "hfence.vvma\t{},{}", rs1, rs2),
+        Inst::HFENCEGVMA(rs1, rs2) => write!(f, "hfence.gvma\t{},{}", rs1, rs2),
+        Inst::HINVALVVMA(rs1, rs2) => write!(f, "hinval.vvma\t{},{}", rs1, rs2),
+        Inst::HINVALGVMA(rs1, rs2) => write!(f, "hinval.gvma\t{},{}", rs1, rs2),
+        Inst::HLVB(rd, rs1) => write!(f, "hlv.b\t{},({})", rd, rs1),
+        Inst::HLVBU(rd, rs1) => write!(f, "hlv.bu\t{},({})", rd, rs1),
+        Inst::HLVH(rd, rs1) => write!(f, "hlv.h\t{},({})", rd, rs1),
+        Inst::HLVHU(rd, rs1) => write!(f, "hlv.hu\t{},({})", rd, rs1),
+        Inst::HLVXHU(rd, rs1) => write!(f, "hlvx.hu\t{},({})", rd, rs1),
+        Inst::HLVW(rd, rs1) => write!(f, "hlv.w\t{},({})", rd, rs1),
+        Inst::HLVXWU(rd, rs1) => write!(f, "hlvx.wu\t{},({})", rd, rs1),
+        Inst::HSVB(rs1, rs2) => write!(f, "hsv.b\t{},({})", rs2, rs1),
+        Inst::HSVH(rs1, rs2) => write!(f, "hsv.h\t{},({})", rs2, rs1),
+        Inst::HSVW(rs1, rs2) => write!(f, "hsv.w\t{},({})", rs2, rs1),
+        Inst::HLVWU(rd, rs1) => write!(f, "hlv.wu\t{},({})", rd, rs1),
+        Inst::HLVD(rd, rs1) => write!(f, "hlv.d\t{},({})", rd, rs1),
+        Inst::HSVD(rs1, rs2) => write!(f, "hsv.d\t{},({})", rs2, rs1),
+        Inst::CADDI4SPN(rd, imm) => write!(f, "c.addi4spn\t{},sp,{}", rd, imm),
+        Inst::CFLD(r, rs1, imm) => write!(f, "c.fld\t{},{}({})", r, imm, rs1),
+        Inst::CLQ(r, rs1, imm) => write!(f, "c.lq\t{},{}({})", r, imm, rs1),
+        Inst::CLW(r, rs1, imm) => write!(f, "c.lw\t{},{}({})", r, imm, rs1),
+        Inst::CFLW(r, rs1, imm) => write!(f, "c.flw\t{},{}({})", r, imm, rs1),
+        Inst::CLD(r, rs1, imm) => write!(f, "c.ld\t{},{}({})", r, imm, rs1),
+        Inst::CFSD(r, rs1, imm) => write!(f, "c.fsd\t{},{}({})", r, imm, rs1),
+        Inst::CSQ(r, rs1, imm) => write!(f, "c.sq\t{},{}({})", r, imm, rs1),
+        Inst::CSW(r, rs1, imm) => write!(f, "c.sw\t{},{}({})", r, imm, rs1),
+        Inst::CFSW(r, rs1, imm) => write!(f, "c.fsw\t{},{}({})", r, imm, rs1),
+        Inst::CSD(r, rs1, imm) => write!(f, "c.sd\t{},{}({})", r, imm, rs1),
+        Inst::CNOP => write!(f, "c.nop"),
+        Inst::CADDI(rd, imm) => write!(f, "c.addi\t{},{}", rd, imm),
+        Inst::CJAL(imm) => write!(f, "c.jal\t{}", Target(imm, cx)),
+        Inst::CADDIW(rd, imm) => write!(f, "c.addiw\t{},{}", rd, imm),
+        Inst::CLI(rd, imm) => write!(f, "c.li\t{},{}", rd, imm),
+        Inst::CADDI16SP(imm) => write!(f, "c.addi16sp\tsp,{}", imm),
+        Inst::CLUI(rd, imm) => write!(f, "c.lui\t{},0x{:x}", rd, imm),
+        Inst::CSRLI(rd, shamt) => write!(f, "c.srli\t{},0x{:x}", rd, shamt),
+        Inst::CSRLI64(rd) => write!(f, "c.srli64\t{}", rd),
+        Inst::CSRAI(rd, shamt) => write!(f, "c.srai\t{},0x{:x}", rd, shamt),
+        Inst::CSRAI64(rd) => write!(f, "c.srai64\t{}", rd),
+        Inst::CANDI(rd, imm) => write!(f, "c.andi\t{},{}", rd, imm),
+        Inst::CSUB(rd, rs2) => write!(f, "c.sub\t{},{}", rd, rs2),
+        Inst::CXOR(rd, rs2) => write!(f, "c.xor\t{},{}", rd, rs2),
+        Inst::COR(rd, rs2) => write!(f, "c.or\t{},{}", rd, rs2),
+        Inst::CAND(rd, rs2) => write!(f, "c.and\t{},{}", rd, rs2),
+        Inst::CSUBW(rd, rs2) => write!(f, "c.subw\t{},{}", rd, rs2),
+        Inst::CADDW(rd, rs2) => write!(f, "c.addw\t{},{}", rd, rs2),
+        Inst::CJ(imm) => write!(f, "c.j\t{}", Target(imm, cx)),
+        Inst::CBEQZ(rs1, imm) => write!(f, "c.beqz\t{},{}", rs1, Target(imm, cx)),
+        Inst::CBNEZ(rs1, imm) => write!(f, "c.bnez\t{},{}", rs1, Target(imm, cx)),
+        Inst::CSLLI(rd, shamt) => write!(f, "c.slli\t{},0x{:x}", rd, shamt),
+        Inst::CSLLI64(rd) => write!(f, "c.slli64\t{}", rd),
+        Inst::CFLDSP(r, imm) => write!(f, "c.fldsp\t{},{}(sp)", r, imm),
+        Inst::CLQSP(r, imm) => write!(f, "c.lqsp\t{},{}(sp)", r, imm),
+        Inst::CLWSP(r, imm) => write!(f, "c.lwsp\t{},{}(sp)", r, imm),
+        Inst::CFLWSP(r, imm) => write!(f, "c.flwsp\t{},{}(sp)", r, imm),
+        Inst::CLDSP(r, imm) => write!(f, "c.ldsp\t{},{}(sp)", r, imm),
+        Inst::CJR(rd) => write!(f, "c.jr\t{}", rd),
+        Inst::CMV(rd, rs2) => write!(f, "c.mv\t{},{}", rd, rs2),
+        Inst::CEBREAK => write!(f, "c.ebreak"),
+        Inst::CJALR(rd) => write!(f, "c.jalr\t{}", rd),
+        Inst::CADD(rd, rs2) => write!(f, "c.add\t{},{}", rd, rs2),
+        Inst::CFSDSP(r, imm) => write!(f, "c.fsdsp\t{},{}(sp)", r, imm),
+        Inst::CSQSP(r, imm) => write!(f, "c.sqsp\t{},{}(sp)", r, imm),
+        Inst::CSWSP(r, imm) => write!(f, "c.swsp\t{},{}(sp)", r, imm),
+        Inst::CFSWSP(r, imm) => write!(f, "c.fswsp\t{},{}(sp)", r, imm),
+        Inst::CSDSP(r, imm) => write!(f, "c.sdsp\t{},{}(sp)", r, imm),
+        Inst::CLBU(r, rs1, imm) => write!(f, "c.lbu\t{},{}({})", r, imm, rs1),
+        Inst::CLHU(r, rs1, imm) => write!(f, "c.lhu\t{},{}({})", r, imm, rs1),
+        Inst::CLH(r, rs1, imm) => write!(f, "c.lh\t{},{}({})", r, imm, rs1),
+        Inst::CSB(r, rs1, imm) => write!(f, "c.sb\t{},{}({})", r, imm, rs1),
+        Inst::CSH(r, rs1, imm) => write!(f, "c.sh\t{},{}({})", r, imm, rs1),
+        Inst::CZEXTB(rd) => write!(f, "c.zext.b\t{}", rd),
+        Inst::CSEXTB(rd) => write!(f, "c.sext.b\t{}", rd),
+        Inst::CZEXTH(rd) => write!(f, "c.zext.h\t{}", rd),
+        Inst::CSEXTH(rd) => write!(f, "c.sext.h\t{}", rd),
+        Inst::CZEXTW(rd) => write!(f, "c.zext.w\t{}", rd),
+        Inst::CNOT(rd) => write!(f, "c.not\t{}", rd),
+        Inst::CMUL(rd, rs2) => write!(f, "c.mul\t{},{}", rd, rs2),
+        Inst::CMPUSH(rlist, adj) => write!(f, "cm.push\t{},-{}", rlist, adj),
+        Inst::CMPOP(rlist, adj) => write!(f, "cm.pop\t{},{}", rlist, adj),
+        Inst::CMPOPRETZ(rlist, adj) => write!(f, "cm.popretz\t{},{}", rlist, adj),
+        Inst::CMPOPRET(rlist, adj) => write!(f, "cm.popret\t{},{}", rlist, adj),
+        Inst::CMMVSA01(r1s, r2s) => write!(f, "cm.mvsa01\t{},{}", r1s, r2s),
+        Inst::CMMVA01S(r1s, r2s) => write!(f, "cm.mva01s\t{},{}", r1s, r2s),
+        Inst::CMJT(index) => write!(f, "cm.jt\t{}", index),
+        Inst::CMJALT(index) => write!(f, "cm.jalt\t{}", index),
+
+        Inst::SH1ADD(rd, rs1, rs2) => write!(f, "sh1add\t{},{},{}", rd, rs1, rs2),
+        Inst::SH2ADD(rd, rs1, rs2) => write!(f, "sh2add\t{},{},{}", rd, rs1, rs2),
+        Inst::SH3ADD(rd, rs1, rs2) => write!(f, "sh3add\t{},{},{}", rd, rs1, rs2),
+        Inst::ADDUW(rd, rs1, rs2) => write!(f, "add.uw\t{},{},{}", rd, rs1, rs2),
+        Inst::SH1ADDUW(rd, rs1, rs2) => write!(f, "sh1add.uw\t{},{},{}", rd, rs1, rs2),
+        Inst::SH2ADDUW(rd, rs1, rs2) => write!(f, "sh2add.uw\t{},{},{}", rd, rs1, rs2),
+        Inst::SH3ADDUW(rd, rs1, rs2) => write!(f, "sh3add.uw\t{},{},{}", rd, rs1, rs2),
+        Inst::SLLIUW(rd, rs1, shamt) => write!(f, "slli.uw\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::ANDN(rd, rs1, rs2) => write!(f, "andn\t{},{},{}", rd, rs1, rs2),
+        Inst::ORN(rd, rs1, rs2) => write!(f, "orn\t{},{},{}", rd, rs1, rs2),
+        Inst::XNOR(rd, rs1, rs2) => write!(f, "xnor\t{},{},{}", rd, rs1, rs2),
+        Inst::CLZ(rd, rs1) => write!(f, "clz\t{},{}", rd, rs1),
+        Inst::CTZ(rd, rs1) => write!(f, "ctz\t{},{}", rd, rs1),
+        Inst::CPOP(rd, rs1) => write!(f, "cpop\t{},{}", rd, rs1),
+        Inst::MAX(rd, rs1, rs2) => write!(f, "max\t{},{},{}", rd, rs1, rs2),
+        Inst::MAXU(rd, rs1, rs2) => write!(f, "maxu\t{},{},{}", rd, rs1, rs2),
+        Inst::MIN(rd, rs1, rs2) => write!(f, "min\t{},{},{}", rd, rs1, rs2),
+        Inst::MINU(rd, rs1, rs2) => write!(f, "minu\t{},{},{}", rd, rs1, rs2),
+        Inst::SEXTB(rd, rs1) => write!(f, "sext.b\t{},{}", rd, rs1),
+        Inst::SEXTH(rd, rs1) => write!(f, "sext.h\t{},{}", rd, rs1),
+        Inst::ZEXTH(rd, rs1) => write!(f, "zext.h\t{},{}", rd, rs1),
+        Inst::ROL(rd, rs1, rs2) => write!(f, "rol\t{},{},{}", rd, rs1, rs2),
+        Inst::ROR(rd, rs1, rs2) => write!(f, "ror\t{},{},{}", rd, rs1, rs2),
+        Inst::RORI(rd, rs1, shamt) => write!(f, "rori\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::ORCB(rd, rs1) => write!(f, "orc.b\t{},{}", rd, rs1),
+        Inst::REV8(rd, rs1) => write!(f, "rev8\t{},{}", rd, rs1),
+        Inst::CLZW(rd, rs1) => write!(f, "clzw\t{},{}", rd, rs1),
+        Inst::CTZW(rd, rs1) => write!(f, "ctzw\t{},{}", rd, rs1),
+        Inst::CPOPW(rd, rs1) => write!(f, "cpopw\t{},{}", rd, rs1),
+        Inst::ROLW(rd, rs1, rs2) => write!(f, "rolw\t{},{},{}", rd, rs1, rs2),
+        Inst::RORW(rd, rs1, rs2) => write!(f, "rorw\t{},{},{}", rd, rs1, rs2),
+        Inst::RORIW(rd, rs1, shamt) => write!(f, "roriw\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::CLMUL(rd, rs1, rs2) => write!(f, "clmul\t{},{},{}", rd, rs1, rs2),
+        Inst::CLMULH(rd, rs1, rs2) => write!(f, "clmulh\t{},{},{}", rd, rs1, rs2),
+        Inst::CLMULR(rd, rs1, rs2) => write!(f, "clmulr\t{},{},{}", rd, rs1, rs2),
+        Inst::BCLR(rd, rs1, rs2) => write!(f, "bclr\t{},{},{}", rd, rs1, rs2),
+        Inst::BCLRI(rd, rs1, shamt) => write!(f, "bclri\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::BEXT(rd, rs1, rs2) => write!(f, "bext\t{},{},{}", rd, rs1, rs2),
+        Inst::BEXTI(rd, rs1, shamt) => write!(f, "bexti\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::BINV(rd, rs1, rs2) => write!(f, "binv\t{},{},{}", rd, rs1, rs2),
+        Inst::BINVI(rd, rs1, shamt) => write!(f, "binvi\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::BSET(rd, rs1, rs2) => write!(f, "bset\t{},{},{}", rd, rs1, rs2),
+        Inst::BSETI(rd, rs1, shamt) => write!(f, "bseti\t{},{},0x{:x}", rd, rs1, shamt),
+        Inst::PACK(rd, rs1, rs2) => write!(f, "pack\t{},{},{}", rd, rs1, rs2),
+        Inst::PACKH(rd, rs1, rs2) => write!(f, "packh\t{},{},{}", rd, rs1, rs2),
+        Inst::BREV8(rd, rs1) => write!(f, "brev8\t{},{}", rd, rs1),
+        Inst::PACKW(rd, rs1, rs2) => write!(f, "packw\t{},{},{}", rd, rs1, rs2),
+        Inst::ZIP(rd, rs1) => write!(f, "zip\t{},{}", rd, rs1),
+        Inst::UNZIP(rd, rs1) => write!(f, "unzip\t{},{}", rd, rs1),
+        Inst::XPERM4(rd, rs1, rs2) => write!(f, "xperm4\t{},{},{}", rd, rs1, rs2),
+        Inst::XPERM8(rd, rs1, rs2) => write!(f, "xperm8\t{},{},{}", rd, rs1, rs2),
+        Inst::AES32DSI(rd, rs1, rs2, bs) => write!(f, "aes32dsi\t{},{},{},0x{:x}", rd, rs1, rs2, bs),
+        Inst::AES32DSMI(rd, rs1, rs2, bs) => write!(f, "aes32dsmi\t{},{},{},0x{:x}", rd, rs1, rs2, bs),
+        Inst::AES32ESI(rd, rs1, rs2, bs) => write!(f, "aes32esi\t{},{},{},0x{:x}", rd, rs1, rs2, bs),
+        Inst::AES32ESMI(rd, rs1, rs2, bs) => write!(f, "aes32esmi\t{},{},{},0x{:x}", rd, rs1, rs2, bs),
+        Inst::AES64DS(rd, rs1, rs2) => write!(f, "aes64ds\t{},{},{}", rd, rs1, rs2),
+        Inst::AES64DSM(rd, rs1, rs2) => write!(f, "aes64dsm\t{},{},{}", rd, rs1, rs2),
+        Inst::AES64IM(rd, rs1) => write!(f, "aes64im\t{},{}", rd, rs1),
+        Inst::AES64ES(rd, rs1, rs2) => write!(f, "aes64es\t{},{},{}", rd, rs1, rs2),
+        Inst::AES64ESM(rd, rs1, rs2) => write!(f, "aes64esm\t{},{},{}", rd, rs1, rs2),
+        Inst::AES64KS1I(rd, rs1, rnum) => write!(f, "aes64ks1i\t{},{},0x{:x}", rd, rs1, rnum),
+        Inst::AES64KS2(rd, rs1, rs2) => write!(f, "aes64ks2\t{},{},{}", rd, rs1, rs2),
+        Inst::SHA256SIG0(rd, rs1) => write!(f, "sha256sig0\t{},{}", rd, rs1),
+        Inst::SHA256SIG1(rd, rs1) => write!(f, "sha256sig1\t{},{}", rd, rs1),
+        Inst::SHA256SUM0(rd, rs1) => write!(f, "sha256sum0\t{},{}", rd, rs1),
+        Inst::SHA256SUM1(rd, rs1) => write!(f, "sha256sum1\t{},{}", rd, rs1),
+        Inst::SHA512SIG0H(rd, rs1, rs2) => write!(f, "sha512sig0h\t{},{},{}", rd, rs1, rs2),
+        Inst::SHA512SIG0L(rd, rs1, rs2) => write!(f, "sha512sig0l\t{},{},{}", rd, rs1, rs2),
+        Inst::SHA512SIG1H(rd, rs1, rs2) => write!(f, "sha512sig1h\t{},{},{}", rd, rs1, rs2),
+        Inst::SHA512SIG1L(rd, rs1, rs2) => write!(f, "sha512sig1l\t{},{},{}", rd, rs1, rs2),
+        Inst::SHA512SUM0R(rd, rs1, rs2) => write!(f, "sha512sum0r\t{},{},{}", rd, rs1, rs2),
+        Inst::SHA512SUM1R(rd, rs1, rs2) => write!(f, "sha512sum1r\t{},{},{}", rd, rs1, rs2),
+        Inst::SHA512SIG0(rd, rs1) => write!(f, "sha512sig0\t{},{}", rd, rs1),
+        Inst::SHA512SIG1(rd, rs1) => write!(f, "sha512sig1\t{},{}", rd, rs1),
+        Inst::SHA512SUM0(rd, rs1) => write!(f, "sha512sum0\t{},{}", rd, rs1),
+        Inst::SHA512SUM1(rd, rs1) => write!(f, "sha512sum1\t{},{}", rd, rs1),
+        Inst::SM4ED(rd, rs1, rs2, bs) => write!(f, "sm4ed\t{},{},{},0x{:x}", rd, rs1, rs2, bs),
+        Inst::SM4KS(rd, rs1, rs2, bs) => write!(f, "sm4ks\t{},{},{},0x{:x}", rd, rs1, rs2, bs),
+        Inst::SM3P0(rd, rs1) => write!(f, "sm3p0\t{},{}", rd, rs1),
+        Inst::SM3P1(rd, rs1) => write!(f, "sm3p1\t{},{}", rd, rs1),
+        Inst::CZEROEQZ(rd, rs1, rs2) => write!(f, "czero.eqz\t{},{},{}", rd, rs1, rs2),
+        Inst::CZERONEZ(rd, rs1, rs2) => write!(f, "czero.nez\t{},{},{}", rd, rs1, rs2),
+        Inst::CBOCLEAN(rs1) => write!(f, "cbo.clean\t0({})", rs1),
+        Inst::CBOFLUSH(rs1) => write!(f, "cbo.flush\t0({})", rs1),
+        Inst::CBOINVAL(rs1) => write!(f, "cbo.inval\t0({})", rs1),
+        Inst::CBOZERO(rs1) => write!(f, "cbo.zero\t0({})", rs1),
+        Inst::PREFETCHI(rs1, imm) => write!(f, "prefetch.i\t{}({})", imm, rs1),
+        Inst::PREFETCHR(rs1, imm) => write!(f, "prefetch.r\t{}({})", imm, rs1),
+        Inst::PREFETCHW(rs1, imm) => write!(f, "prefetch.w\t{}({})", imm, rs1),
+        Inst::WRSNTO => write!(f, "wrs.nto"),
+        Inst::WRSSTO => write!(f, "wrs.sto"),
+        Inst::NTLP1 => write!(f, "ntl.p1"),
+        Inst::NTLPALL => write!(f, "ntl.pall"),
+        Inst::NTLS1 => write!(f, "ntl.s1"),
+        Inst::NTLALL => write!(f, "ntl.all"),
+
+        Inst::VSETVLI(rd, rs1, vtype) => write!(f, "vsetvli\t{},{},{}", rd, rs1, vtype),
+        Inst::VSETIVLI(rd, avl, vtype) => write!(f, "vsetivli\t{},{},{}", rd, avl, vtype),
+        Inst::VSETVL(rd, rs1, rs2) => write!(f, "vsetvl\t{},{},{}", rd, rs1, rs2),
+        Inst::VLE(vd, rs1, eew, 1, vm) => write!(f, "vle{}.v\t{},({}){}", eew.bits(), vd, rs1, vm),
+        Inst::VLE(vd, rs1, eew, nf, vm) => write!(f, "vlseg{}e{}.v\t{},({}){}", nf, eew.bits(), vd, rs1, vm),
+        Inst::VLEFF(vd, rs1, eew, 1, vm) => write!(f, "vle{}ff.v\t{},({}){}", eew.bits(), vd, rs1, vm),
+        Inst::VLEFF(vd, rs1, eew, nf, vm) => write!(f, "vlseg{}e{}ff.v\t{},({}){}", nf, eew.bits(), vd, rs1, vm),
+        Inst::VLM(vd, rs1) => write!(f, "vlm.v\t{},({})", vd, rs1),
+        Inst::VLSE(vd, rs1, rs2, eew, 1, vm) => write!(f, "vlse{}.v\t{},({}),{}{}", eew.bits(), vd, rs1, rs2, vm),
+        Inst::VLSE(vd, rs1, rs2, eew, nf, vm) => {
+            write!(f, "vlsseg{}e{}.v\t{},({}),{}{}", nf, eew.bits(), vd, rs1, rs2, vm)
+        }
+        Inst::VLUXEI(vd, rs1, vs2, eew, 1, vm) => write!(f, "vluxei{}.v\t{},({}),{}{}", eew.bits(), vd, rs1, vs2, vm),
+        Inst::VLUXEI(vd, rs1, vs2, eew, nf, vm) => {
+            write!(f, "vluxseg{}ei{}.v\t{},({}),{}{}", nf, eew.bits(), vd, rs1, vs2, vm)
+        }
+        Inst::VLOXEI(vd, rs1, vs2, eew, 1, vm) => write!(f, "vloxei{}.v\t{},({}),{}{}", eew.bits(), vd, rs1, vs2, vm),
+        Inst::VLOXEI(vd, rs1, vs2, eew, nf, vm) => {
+            write!(f, "vloxseg{}ei{}.v\t{},({}),{}{}", nf, eew.bits(), vd, rs1, vs2, vm)
+        }
+        Inst::VLRE(vd, rs1, eew, nf) => write!(f, "vl{}re{}.v\t{},({})", nf, eew.bits(), vd, rs1),
+        Inst::VSE(vs3, rs1, eew, 1, vm) => write!(f, "vse{}.v\t{},({}){}", eew.bits(), vs3, rs1, vm),
+        Inst::VSE(vs3, rs1, eew, nf, vm) => write!(f, "vsseg{}e{}.v\t{},({}){}", nf, eew.bits(), vs3, rs1, vm),
+        Inst::VSM(vs3, rs1) => write!(f, "vsm.v\t{},({})", vs3, rs1),
+        Inst::VSSE(vs3, rs1, rs2, eew, 1, vm) => write!(f, "vsse{}.v\t{},({}),{}{}", eew.bits(), vs3, rs1, rs2, vm),
+        Inst::VSSE(vs3, rs1, rs2, eew, nf, vm) => {
+            write!(f, "vssseg{}e{}.v\t{},({}),{}{}", nf, eew.bits(), vs3, rs1, rs2, vm)
+        }
+        Inst::VSUXEI(vs3, rs1, vs2, eew, 1, vm) => write!(f, "vsuxei{}.v\t{},({}),{}{}", eew.bits(), vs3, rs1, vs2, vm),
+        Inst::VSUXEI(vs3, rs1, vs2, eew, nf, vm) => {
+            write!(f, "vsuxseg{}ei{}.v\t{},({}),{}{}", nf, eew.bits(), vs3, rs1, vs2, vm)
+        }
+        Inst::VSOXEI(vs3, rs1, vs2, eew, 1, vm) => write!(f, "vsoxei{}.v\t{},({}),{}{}", eew.bits(), vs3, rs1, vs2, vm),
+        Inst::VSOXEI(vs3, rs1, vs2, eew, nf, vm) => {
+            write!(f, "vsoxseg{}ei{}.v\t{},({}),{}{}", nf, eew.bits(), vs3, rs1, vs2, vm)
+        }
+        Inst::VSR(vs3, rs1, nf) => write!(f, "vs{}r.v\t{},({})", nf, vs3, rs1),
+        Inst::VADDVV(vd, vs2, vs1, vm) => write!(f, "vadd.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VADDVX(vd, vs2, rs1, vm) => write!(f, "vadd.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VADDVI(vd, vs2, imm, vm) => write!(f, "vadd.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSUBVV(vd, vs2, vs1, vm) => write!(f, "vsub.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSUBVX(vd, vs2, rs1, vm) => write!(f, "vsub.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VRSUBVX(vd, vs2, rs1, vm) => write!(f, "vrsub.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VRSUBVI(vd, vs2, imm, vm) => write!(f, "vrsub.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VMINUVV(vd, vs2, vs1, vm) => write!(f, "vminu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMINUVX(vd, vs2, rs1, vm) => write!(f, "vminu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMINVV(vd, vs2, vs1, vm) => write!(f, "vmin.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMINVX(vd, vs2, rs1, vm) => write!(f, "vmin.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMAXUVV(vd, vs2, vs1, vm) => write!(f, "vmaxu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMAXUVX(vd, vs2, rs1, vm) => write!(f, "vmaxu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMAXVV(vd, vs2, vs1, vm) => write!(f, "vmax.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMAXVX(vd, vs2, rs1, vm) => write!(f, "vmax.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VANDVV(vd, vs2, vs1, vm) => write!(f, "vand.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VANDVX(vd, vs2, rs1, vm) => write!(f, "vand.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VANDVI(vd, vs2, imm, vm) => write!(f, "vand.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VORVV(vd, vs2, vs1, vm) => write!(f, "vor.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VORVX(vd, vs2, rs1, vm) => write!(f, "vor.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VORVI(vd, vs2, imm, vm) => write!(f, "vor.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VXORVV(vd, vs2, vs1, vm) => write!(f, "vxor.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VXORVX(vd, vs2, rs1, vm) => write!(f, "vxor.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VXORVI(vd, vs2, imm, vm) => write!(f, "vxor.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VRGATHERVV(vd, vs2, vs1, vm) => write!(f, "vrgather.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VRGATHERVX(vd, vs2, rs1, vm) => write!(f, "vrgather.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VRGATHERVI(vd, vs2, imm, vm) => write!(f, "vrgather.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VRGATHEREI16VV(vd, vs2, vs1, vm) => write!(f, "vrgatherei16.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSLIDEUPVX(vd, vs2, rs1, vm) => write!(f, "vslideup.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSLIDEUPVI(vd, vs2, imm, vm) => write!(f, "vslideup.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSLIDEDOWNVX(vd, vs2, rs1, vm) => write!(f, "vslidedown.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSLIDEDOWNVI(vd, vs2, imm, vm) => write!(f, "vslidedown.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VADCVVM(vd, vs2, vs1) => write!(f, "vadc.vvm\t{},{},{},v0", vd, vs2, vs1),
+        Inst::VADCVXM(vd, vs2, rs1) => write!(f, "vadc.vxm\t{},{},{},v0", vd, vs2, rs1),
+        Inst::VADCVIM(vd, vs2, imm) => write!(f, "vadc.vim\t{},{},{},v0", vd, vs2, imm),
+        Inst::VMADCVVM(vd, vs2, vs1) => write!(f, "vmadc.vvm\t{},{},{},v0", vd, vs2, vs1),
+        Inst::VMADCVXM(vd, vs2, rs1) => write!(f, "vmadc.vxm\t{},{},{},v0", vd, vs2, rs1),
+        Inst::VMADCVIM(vd, vs2, imm) => write!(f, "vmadc.vim\t{},{},{},v0", vd, vs2, imm),
+        Inst::VMADCVV(vd, vs2, vs1) => write!(f, "vmadc.vv\t{},{},{}", vd, vs2, vs1),
+        Inst::VMADCVX(vd, vs2, rs1) => write!(f, "vmadc.vx\t{},{},{}", vd, vs2, rs1),
+        Inst::VMADCVI(vd, vs2, imm) => write!(f, "vmadc.vi\t{},{},{}", vd, vs2, imm),
+        Inst::VSBCVVM(vd, vs2, vs1) => write!(f, "vsbc.vvm\t{},{},{},v0", vd, vs2, vs1),
+        Inst::VSBCVXM(vd, vs2, rs1) => write!(f, "vsbc.vxm\t{},{},{},v0", vd, vs2, rs1),
+        Inst::VMSBCVVM(vd, vs2, vs1) => write!(f, "vmsbc.vvm\t{},{},{},v0", vd, vs2, vs1),
+        Inst::VMSBCVXM(vd, vs2, rs1) => write!(f, "vmsbc.vxm\t{},{},{},v0", vd, vs2, rs1),
+        Inst::VMSBCVV(vd, vs2, vs1) => write!(f, "vmsbc.vv\t{},{},{}", vd, vs2, vs1),
+        Inst::VMSBCVX(vd, vs2, rs1) => write!(f, "vmsbc.vx\t{},{},{}", vd, vs2, rs1),
+        Inst::VMERGEVVM(vd, vs2, vs1) => write!(f, "vmerge.vvm\t{},{},{},v0", vd, vs2, vs1),
+        Inst::VMERGEVXM(vd, vs2, rs1) => write!(f, "vmerge.vxm\t{},{},{},v0", vd, vs2, rs1),
+        Inst::VMERGEVIM(vd, vs2, imm) => write!(f, "vmerge.vim\t{},{},{},v0", vd, vs2, imm),
+        Inst::VMVVV(vd, vs1) => write!(f, "vmv.v.v\t{},{}", vd, vs1),
+        Inst::VMVVX(vd, rs1) => write!(f, "vmv.v.x\t{},{}", vd, rs1),
+        Inst::VMVVI(vd, imm) => write!(f, "vmv.v.i\t{},{}", vd, imm),
+        Inst::VMSEQVV(vd, vs2, vs1, vm) => write!(f, "vmseq.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMSEQVX(vd, vs2, rs1, vm) => write!(f, "vmseq.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSEQVI(vd, vs2, imm, vm) => write!(f, "vmseq.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VMSNEVV(vd, vs2, vs1, vm) => write!(f, "vmsne.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMSNEVX(vd, vs2, rs1, vm) => write!(f, "vmsne.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSNEVI(vd, vs2, imm, vm) => write!(f, "vmsne.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VMSLTUVV(vd, vs2, vs1, vm) => write!(f, "vmsltu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMSLTUVX(vd, vs2, rs1, vm) => write!(f, "vmsltu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSLTVV(vd, vs2, vs1, vm) => write!(f, "vmslt.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMSLTVX(vd, vs2, rs1, vm) => write!(f, "vmslt.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSLEUVV(vd, vs2, vs1, vm) => write!(f, "vmsleu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMSLEUVX(vd, vs2, rs1, vm) => write!(f, "vmsleu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSLEUVI(vd, vs2, imm, vm) => write!(f, "vmsleu.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VMSLEVV(vd, vs2, vs1, vm) => write!(f, "vmsle.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMSLEVX(vd, vs2, rs1, vm) => write!(f, "vmsle.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSLEVI(vd, vs2, imm, vm) => write!(f, "vmsle.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VMSGTUVX(vd, vs2, rs1, vm) => write!(f, "vmsgtu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSGTUVI(vd, vs2, imm, vm) => write!(f, "vmsgtu.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VMSGTVX(vd, vs2, rs1, vm) => write!(f, "vmsgt.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMSGTVI(vd, vs2, imm, vm) => write!(f, "vmsgt.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSADDUVV(vd, vs2, vs1, vm) => write!(f, "vsaddu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSADDUVX(vd, vs2, rs1, vm) => write!(f, "vsaddu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSADDUVI(vd, vs2, imm, vm) => write!(f, "vsaddu.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSADDVV(vd, vs2, vs1, vm) => write!(f, "vsadd.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSADDVX(vd, vs2, rs1, vm) => write!(f, "vsadd.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSADDVI(vd, vs2, imm, vm) => write!(f, "vsadd.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSSUBUVV(vd, vs2, vs1, vm) => write!(f, "vssubu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSSUBUVX(vd, vs2, rs1, vm) => write!(f, "vssubu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSSUBVV(vd, vs2, vs1, vm) => write!(f, "vssub.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSSUBVX(vd, vs2, rs1, vm) => write!(f, "vssub.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSLLVV(vd, vs2, vs1, vm) => write!(f, "vsll.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSLLVX(vd, vs2, rs1, vm) => write!(f, "vsll.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSLLVI(vd, vs2, imm, vm) => write!(f, "vsll.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSMULVV(vd, vs2, vs1, vm) => write!(f, "vsmul.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSMULVX(vd, vs2, rs1, vm) => write!(f, "vsmul.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMVNRV(vd, vs2, nr) => write!(f, "vmv{}r.v\t{},{}", nr, vd, vs2),
+        Inst::VSRLVV(vd, vs2, vs1, vm) => write!(f, "vsrl.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSRLVX(vd, vs2, rs1, vm) => write!(f, "vsrl.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSRLVI(vd, vs2, imm, vm) => write!(f, "vsrl.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSRAVV(vd, vs2, vs1, vm) => write!(f, "vsra.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSRAVX(vd, vs2, rs1, vm) => write!(f, "vsra.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSRAVI(vd, vs2, imm, vm) => write!(f, "vsra.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSSRLVV(vd, vs2, vs1, vm) => write!(f, "vssrl.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSSRLVX(vd, vs2, rs1, vm) => write!(f, "vssrl.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSSRLVI(vd, vs2, imm, vm) => write!(f, "vssrl.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VSSRAVV(vd, vs2, vs1, vm) => write!(f, "vssra.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VSSRAVX(vd, vs2, rs1, vm) => write!(f, "vssra.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSSRAVI(vd, vs2, imm, vm) => write!(f, "vssra.vi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VNSRLWV(vd, vs2, vs1, vm) => write!(f, "vnsrl.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VNSRLWX(vd, vs2, rs1, vm) => write!(f, "vnsrl.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VNSRLWI(vd, vs2, imm, vm) => write!(f, "vnsrl.wi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VNSRAWV(vd, vs2, vs1, vm) => write!(f, "vnsra.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VNSRAWX(vd, vs2, rs1, vm) => write!(f, "vnsra.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VNSRAWI(vd, vs2, imm, vm) => write!(f, "vnsra.wi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VNCLIPUWV(vd, vs2, vs1, vm) => write!(f, "vnclipu.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VNCLIPUWX(vd, vs2, rs1, vm) => write!(f, "vnclipu.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VNCLIPUWI(vd, vs2, imm, vm) => write!(f, "vnclipu.wi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VNCLIPWV(vd, vs2, vs1, vm) => write!(f, "vnclip.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VNCLIPWX(vd, vs2, rs1, vm) => write!(f, "vnclip.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VNCLIPWI(vd, vs2, imm, vm) => write!(f, "vnclip.wi\t{},{},{}{}", vd, vs2, imm, vm),
+        Inst::VWREDSUMUVS(vd, vs2, vs1, vm) => write!(f, "vwredsumu.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWREDSUMVS(vd, vs2, vs1, vm) => write!(f, "vwredsum.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDSUMVS(vd, vs2, vs1, vm) => write!(f, "vredsum.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDANDVS(vd, vs2, vs1, vm) => write!(f, "vredand.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDORVS(vd, vs2, vs1, vm) => write!(f, "vredor.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDXORVS(vd, vs2, vs1, vm) => write!(f, "vredxor.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDMINUVS(vd, vs2, vs1, vm) => write!(f, "vredminu.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDMINVS(vd, vs2, vs1, vm) => write!(f, "vredmin.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDMAXUVS(vd, vs2, vs1, vm) => write!(f, "vredmaxu.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREDMAXVS(vd, vs2, vs1, vm) => write!(f, "vredmax.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VAADDUVV(vd, vs2, vs1, vm) => write!(f, "vaaddu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VAADDUVX(vd, vs2, rs1, vm) => write!(f, "vaaddu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VAADDVV(vd, vs2, vs1, vm) => write!(f, "vaadd.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VAADDVX(vd, vs2, rs1, vm) => write!(f, "vaadd.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VASUBUVV(vd, vs2, vs1, vm) => write!(f, "vasubu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VASUBUVX(vd, vs2, rs1, vm) => write!(f, "vasubu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VASUBVV(vd, vs2, vs1, vm) => write!(f, "vasub.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VASUBVX(vd, vs2, rs1, vm) => write!(f, "vasub.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSLIDE1UPVX(vd, vs2, rs1, vm) => write!(f, "vslide1up.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VSLIDE1DOWNVX(vd, vs2, rs1, vm) => write!(f, "vslide1down.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMVXS(rd, vs2) => write!(f, "vmv.x.s\t{},{}", rd, vs2),
+        Inst::VMVSX(vd, rs1) => write!(f, "vmv.s.x\t{},{}", vd, rs1),
+        Inst::VCPOPM(rd, vs2, vm) => write!(f, "vcpop.m\t{},{}{}", rd, vs2, vm),
+        Inst::VFIRSTM(rd, vs2, vm) => write!(f, "vfirst.m\t{},{}{}", rd, vs2, vm),
+        Inst::VZEXTVF8(vd, vs2, vm) => write!(f, "vzext.vf8\t{},{}{}", vd, vs2, vm),
+        Inst::VSEXTVF8(vd, vs2, vm) => write!(f, "vsext.vf8\t{},{}{}", vd, vs2, vm),
+        Inst::VZEXTVF4(vd, vs2, vm) => write!(f, "vzext.vf4\t{},{}{}", vd, vs2, vm),
+        Inst::VSEXTVF4(vd, vs2, vm) => write!(f, "vsext.vf4\t{},{}{}", vd, vs2, vm),
+        Inst::VZEXTVF2(vd, vs2, vm) => write!(f, "vzext.vf2\t{},{}{}", vd, vs2, vm),
+        Inst::VSEXTVF2(vd, vs2, vm) => write!(f, "vsext.vf2\t{},{}{}", vd, vs2, vm),
+        Inst::VMSBFM(vd, vs2, vm) => write!(f, "vmsbf.m\t{},{}{}", vd, vs2, vm),
+        Inst::VMSOFM(vd, vs2, vm) => write!(f, "vmsof.m\t{},{}{}", vd, vs2, vm),
+        Inst::VMSIFM(vd, vs2, vm) => write!(f, "vmsif.m\t{},{}{}", vd, vs2, vm),
+        Inst::VIOTAM(vd, vs2, vm) => write!(f, "viota.m\t{},{}{}", vd, vs2, vm),
+        Inst::VIDV(vd, vm) => write!(f, "vid.v\t{}{}", vd, vm),
+        Inst::VCOMPRESSVM(vd, vs2, vs1) => write!(f, "vcompress.vm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMANDNMM(vd, vs2, vs1) => write!(f, "vmandn.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMANDMM(vd, vs2, vs1) => write!(f, "vmand.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMORMM(vd, vs2, vs1) => write!(f, "vmor.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMXORMM(vd, vs2, vs1) => write!(f, "vmxor.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMORNMM(vd, vs2, vs1) => write!(f, "vmorn.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMNANDMM(vd, vs2, vs1) => write!(f, "vmnand.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMNORMM(vd, vs2, vs1) => write!(f, "vmnor.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VMXNORMM(vd, vs2, vs1) => write!(f, "vmxnor.mm\t{},{},{}", vd, vs2, vs1),
+        Inst::VDIVUVV(vd, vs2, vs1, vm) => write!(f, "vdivu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VDIVUVX(vd, vs2, rs1, vm) => write!(f, "vdivu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VDIVVV(vd, vs2, vs1, vm) => write!(f, "vdiv.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VDIVVX(vd, vs2, rs1, vm) => write!(f, "vdiv.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VREMUVV(vd, vs2, vs1, vm) => write!(f, "vremu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREMUVX(vd, vs2, rs1, vm) => write!(f, "vremu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VREMVV(vd, vs2, vs1, vm) => write!(f, "vrem.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VREMVX(vd, vs2, rs1, vm) => write!(f, "vrem.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMULHUVV(vd, vs2, vs1, vm) => write!(f, "vmulhu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMULHUVX(vd, vs2, rs1, vm) => write!(f, "vmulhu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMULVV(vd, vs2, vs1, vm) => write!(f, "vmul.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMULVX(vd, vs2, rs1, vm) => write!(f, "vmul.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMULHSUVV(vd, vs2, vs1, vm) => write!(f, "vmulhsu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMULHSUVX(vd, vs2, rs1, vm) => write!(f, "vmulhsu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMULHVV(vd, vs2, vs1, vm) => write!(f, "vmulh.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMULHVX(vd, vs2, rs1, vm) => write!(f, "vmulh.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMADDVV(vd, vs1, vs2, vm) => write!(f, "vmadd.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VMADDVX(vd, rs1, vs2, vm) => write!(f, "vmadd.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VNMSUBVV(vd, vs1, vs2, vm) => write!(f, "vnmsub.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VNMSUBVX(vd, rs1, vs2, vm) => write!(f, "vnmsub.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VMACCVV(vd, vs1, vs2, vm) => write!(f, "vmacc.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VMACCVX(vd, rs1, vs2, vm) => write!(f, "vmacc.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VNMSACVV(vd, vs1, vs2, vm) => write!(f, "vnmsac.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VNMSACVX(vd, rs1, vs2, vm) => write!(f, "vnmsac.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VWADDUVV(vd, vs2, vs1, vm) => write!(f, "vwaddu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWADDUVX(vd, vs2, rs1, vm) => write!(f, "vwaddu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWADDVV(vd, vs2, vs1, vm) => write!(f, "vwadd.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWADDVX(vd, vs2, rs1, vm) => write!(f, "vwadd.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWSUBUVV(vd, vs2, vs1, vm) => write!(f, "vwsubu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWSUBUVX(vd, vs2, rs1, vm) => write!(f, "vwsubu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWSUBVV(vd, vs2, vs1, vm) => write!(f, "vwsub.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWSUBVX(vd, vs2, rs1, vm) => write!(f, "vwsub.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWADDUWV(vd, vs2, vs1, vm) => write!(f, "vwaddu.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWADDUWX(vd, vs2, rs1, vm) => write!(f, "vwaddu.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWADDWV(vd, vs2, vs1, vm) => write!(f, "vwadd.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWADDWX(vd, vs2, rs1, vm) => write!(f, "vwadd.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWSUBUWV(vd, vs2, vs1, vm) => write!(f, "vwsubu.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWSUBUWX(vd, vs2, rs1, vm) => write!(f, "vwsubu.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWSUBWV(vd, vs2, vs1, vm) => write!(f, "vwsub.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWSUBWX(vd, vs2, rs1, vm) => write!(f, "vwsub.wx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWMULUVV(vd, vs2, vs1, vm) => write!(f, "vwmulu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWMULUVX(vd, vs2, rs1, vm) => write!(f, "vwmulu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWMULSUVV(vd, vs2, vs1, vm) => write!(f, "vwmulsu.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWMULSUVX(vd, vs2, rs1, vm) => write!(f, "vwmulsu.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWMULVV(vd, vs2, vs1, vm) => write!(f, "vwmul.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VWMULVX(vd, vs2, rs1, vm) => write!(f, "vwmul.vx\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VWMACCUVV(vd, vs1, vs2, vm) => write!(f, "vwmaccu.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VWMACCUVX(vd, rs1, vs2, vm) => write!(f, "vwmaccu.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VWMACCVV(vd, vs1, vs2, vm) => write!(f, "vwmacc.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VWMACCVX(vd, rs1, vs2, vm) => write!(f, "vwmacc.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VWMACCUSVX(vd, rs1, vs2, vm) => write!(f, "vwmaccus.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VWMACCSUVV(vd, vs1, vs2, vm) => write!(f, "vwmaccsu.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VWMACCSUVX(vd, rs1, vs2, vm) => write!(f, "vwmaccsu.vx\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFADDVV(vd, vs2, vs1, vm) => write!(f, "vfadd.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFADDVF(vd, vs2, rs1, vm) => write!(f, "vfadd.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFREDUSUMVS(vd, vs2, vs1, vm) => write!(f, "vfredusum.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFSUBVV(vd, vs2, vs1, vm) => write!(f, "vfsub.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFSUBVF(vd, vs2, rs1, vm) => write!(f, "vfsub.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFREDOSUMVS(vd, vs2, vs1, vm) => write!(f, "vfredosum.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFMINVV(vd, vs2, vs1, vm) => write!(f, "vfmin.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFMINVF(vd, vs2, rs1, vm) => write!(f, "vfmin.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFREDMINVS(vd, vs2, vs1, vm) => write!(f, "vfredmin.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFMAXVV(vd, vs2, vs1, vm) => write!(f, "vfmax.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFMAXVF(vd, vs2, rs1, vm) => write!(f, "vfmax.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFREDMAXVS(vd, vs2, vs1, vm) => write!(f, "vfredmax.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFSGNJVV(vd, vs2, vs1, vm) => write!(f, "vfsgnj.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFSGNJVF(vd, vs2, rs1, vm) => write!(f, "vfsgnj.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFSGNJNVV(vd, vs2, vs1, vm) => write!(f, "vfsgnjn.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFSGNJNVF(vd, vs2, rs1, vm) => write!(f, "vfsgnjn.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFSGNJXVV(vd, vs2, vs1, vm) => write!(f, "vfsgnjx.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFSGNJXVF(vd, vs2, rs1, vm) => write!(f, "vfsgnjx.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFSLIDE1UPVF(vd, vs2, rs1, vm) => write!(f, "vfslide1up.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFSLIDE1DOWNVF(vd, vs2, rs1, vm) => write!(f, "vfslide1down.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFMVFS(rd, vs2) => write!(f, "vfmv.f.s\t{},{}", rd, vs2),
+        Inst::VFMVSF(vd, rs1) => write!(f, "vfmv.s.f\t{},{}", vd, rs1),
+        Inst::VFCVTXUFV(vd, vs2, vm) => write!(f, "vfcvt.xu.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFCVTXFV(vd, vs2, vm) => write!(f, "vfcvt.x.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFCVTFXUV(vd, vs2, vm) => write!(f, "vfcvt.f.xu.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFCVTFXV(vd, vs2, vm) => write!(f, "vfcvt.f.x.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFCVTRTZXUFV(vd, vs2, vm) => write!(f, "vfcvt.rtz.xu.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFCVTRTZXFV(vd, vs2, vm) => write!(f, "vfcvt.rtz.x.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFWCVTXUFV(vd, vs2, vm) => write!(f, "vfwcvt.xu.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFWCVTXFV(vd, vs2, vm) => write!(f, "vfwcvt.x.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFWCVTFXUV(vd, vs2, vm) => write!(f, "vfwcvt.f.xu.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFWCVTFXV(vd, vs2, vm) => write!(f, "vfwcvt.f.x.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFWCVTFFV(vd, vs2, vm) => write!(f, "vfwcvt.f.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFWCVTRTZXUFV(vd, vs2, vm) => write!(f, "vfwcvt.rtz.xu.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFWCVTRTZXFV(vd, vs2, vm) => write!(f, "vfwcvt.rtz.x.f.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTXUFW(vd, vs2, vm) => write!(f, "vfncvt.xu.f.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTXFW(vd, vs2, vm) => write!(f, "vfncvt.x.f.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTFXUW(vd, vs2, vm) => write!(f, "vfncvt.f.xu.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTFXW(vd, vs2, vm) => write!(f, "vfncvt.f.x.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTFFW(vd, vs2, vm) => write!(f, "vfncvt.f.f.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTRODFFW(vd, vs2, vm) => write!(f, "vfncvt.rod.f.f.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTRTZXUFW(vd, vs2, vm) => write!(f, "vfncvt.rtz.xu.f.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFNCVTRTZXFW(vd, vs2, vm) => write!(f, "vfncvt.rtz.x.f.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFSQRTV(vd, vs2, vm) => write!(f, "vfsqrt.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFRSQRT7V(vd, vs2, vm) => write!(f, "vfrsqrt7.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFREC7V(vd, vs2, vm) => write!(f, "vfrec7.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFCLASSV(vd, vs2, vm) => write!(f, "vfclass.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFMERGEVFM(vd, vs2, rs1) => write!(f, "vfmerge.vfm\t{},{},{},v0", vd, vs2, rs1),
+        Inst::VFMVVF(vd, rs1) => write!(f, "vfmv.v.f\t{},{}", vd, rs1),
+        Inst::VMFEQVV(vd, vs2, vs1, vm) => write!(f, "vmfeq.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMFEQVF(vd, vs2, rs1, vm) => write!(f, "vmfeq.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMFLEVV(vd, vs2, vs1, vm) => write!(f, "vmfle.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMFLEVF(vd, vs2, rs1, vm) => write!(f, "vmfle.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMFLTVV(vd, vs2, vs1, vm) => write!(f, "vmflt.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMFLTVF(vd, vs2, rs1, vm) => write!(f, "vmflt.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMFNEVV(vd, vs2, vs1, vm) => write!(f, "vmfne.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VMFNEVF(vd, vs2, rs1, vm) => write!(f, "vmfne.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMFGTVF(vd, vs2, rs1, vm) => write!(f, "vmfgt.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VMFGEVF(vd, vs2, rs1, vm) => write!(f, "vmfge.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFDIVVV(vd, vs2, vs1, vm) => write!(f, "vfdiv.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFDIVVF(vd, vs2, rs1, vm) => write!(f, "vfdiv.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFRDIVVF(vd, vs2, rs1, vm) => write!(f, "vfrdiv.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFMULVV(vd, vs2, vs1, vm) => write!(f, "vfmul.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFMULVF(vd, vs2, rs1, vm) => write!(f, "vfmul.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFRSUBVF(vd, vs2, rs1, vm) => write!(f, "vfrsub.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFMADDVV(vd, vs1, vs2, vm) => write!(f, "vfmadd.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFMADDVF(vd, rs1, vs2, vm) => write!(f, "vfmadd.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFNMADDVV(vd, vs1, vs2, vm) => write!(f, "vfnmadd.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFNMADDVF(vd, rs1, vs2, vm) => write!(f, "vfnmadd.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFMSUBVV(vd, vs1, vs2, vm) => write!(f, "vfmsub.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFMSUBVF(vd, rs1, vs2, vm) => write!(f, "vfmsub.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFNMSUBVV(vd, vs1, vs2, vm) => write!(f, "vfnmsub.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFNMSUBVF(vd, rs1, vs2, vm) => write!(f, "vfnmsub.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFMACCVV(vd, vs1, vs2, vm) => write!(f, "vfmacc.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFMACCVF(vd, rs1, vs2, vm) => write!(f, "vfmacc.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFNMACCVV(vd, vs1, vs2, vm) => write!(f, "vfnmacc.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFNMACCVF(vd, rs1, vs2, vm) => write!(f, "vfnmacc.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFMSACVV(vd, vs1, vs2, vm) => write!(f, "vfmsac.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFMSACVF(vd, rs1, vs2, vm) => write!(f, "vfmsac.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFNMSACVV(vd, vs1, vs2, vm) => write!(f, "vfnmsac.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFNMSACVF(vd, rs1, vs2, vm) => write!(f, "vfnmsac.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFWADDVV(vd, vs2, vs1, vm) => write!(f, "vfwadd.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFWADDVF(vd, vs2, rs1, vm) => write!(f, "vfwadd.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFWREDUSUMVS(vd, vs2, vs1, vm) => write!(f, "vfwredusum.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFWSUBVV(vd, vs2, vs1, vm) => write!(f, "vfwsub.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFWSUBVF(vd, vs2, rs1, vm) => write!(f, "vfwsub.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFWREDOSUMVS(vd, vs2, vs1, vm) => write!(f, "vfwredosum.vs\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFWADDWV(vd, vs2, vs1, vm) => write!(f, "vfwadd.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFWADDWF(vd, vs2, rs1, vm) => write!(f, "vfwadd.wf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFWSUBWV(vd, vs2, vs1, vm) => write!(f, "vfwsub.wv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFWSUBWF(vd, vs2, rs1, vm) => write!(f, "vfwsub.wf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFWMULVV(vd, vs2, vs1, vm) => write!(f, "vfwmul.vv\t{},{},{}{}", vd, vs2, vs1, vm),
+        Inst::VFWMULVF(vd, vs2, rs1, vm) => write!(f, "vfwmul.vf\t{},{},{}{}", vd, vs2, rs1, vm),
+        Inst::VFWMACCVV(vd, vs1, vs2, vm) => write!(f, "vfwmacc.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFWMACCVF(vd, rs1, vs2, vm) => write!(f, "vfwmacc.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFWNMACCVV(vd, vs1, vs2, vm) => write!(f, "vfwnmacc.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFWNMACCVF(vd, rs1, vs2, vm) => write!(f, "vfwnmacc.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFWMSACVV(vd, vs1, vs2, vm) => write!(f, "vfwmsac.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFWMSACVF(vd, rs1, vs2, vm) => write!(f, "vfwmsac.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+        Inst::VFWNMSACVV(vd, vs1, vs2, vm) => write!(f, "vfwnmsac.vv\t{},{},{}{}", vd, vs1, vs2, vm),
+        Inst::VFWNMSACVF(vd, rs1, vs2, vm) => write!(f, "vfwnmsac.vf\t{},{},{}{}", vd, rs1, vs2, vm),
+
+        Inst::Custom(ref c) => c.vendor.fmt(c, f),
+    }
+}
+
+// Canonical alias of `inst`, if objdump has one. Compressed instructions are
+// printed as the base instruction they expand to, aliased in turn.
+fn alias(inst: &Inst, f: &mut Formatter<'_>, cx: &Ctx) -> Option<Result> {
+    let r = match *inst {
+        Inst::CSRRW(Gpr::zero, Gpr::zero, Csr::cycle) => return None, // unimp
+        Inst::ADDI(Gpr::zero, Gpr::zero, 0) => write!(f, "nop"),
+        Inst::ADDI(rd, Gpr::zero, imm) => write!(f, "li\t{},{}", rd, imm),
+        Inst::ADDI(rd, rs1, 0) => write!(f, "mv\t{},{}", rd, rs1),
+        Inst::XORI(rd, rs1, -1) => write!(f, "not\t{},{}", rd, rs1),
+        Inst::ANDI(rd, rs1, 255) => write!(f, "zext.b\t{},{}", rd, rs1),
+        Inst::SUB(rd, Gpr::zero, rs2) => write!(f, "neg\t{},{}", rd, rs2),
+        Inst::SUBW(rd, Gpr::zero, rs2) => write!(f, "negw\t{},{}", rd, rs2),
+        Inst::ADDIW(rd, rs1, 0) => write!(f, "sext.w\t{},{}", rd, rs1),
+        Inst::ADDUW(rd, rs1, Gpr::zero) => write!(f, "zext.w\t{},{}", rd, rs1),
+        Inst::SLTUI(rd, rs1, 1) => write!(f, "seqz\t{},{}", rd, rs1),
+        Inst::SLTU(rd, Gpr::zero, rs2) => write!(f, "snez\t{},{}", rd, rs2),
+        Inst::SLT(rd, rs1, Gpr::zero) => write!(f, "sltz\t{},{}", rd, rs1),
+        Inst::SLT(rd, Gpr::zero, rs2) => write!(f, "sgtz\t{},{}", rd, rs2),
+
+        Inst::BEQ(rs1, Gpr::zero, imm) => write!(f, "beqz\t{},{}", rs1, Target(imm, cx)),
+        Inst::BNE(rs1, Gpr::zero, imm) => write!(f, "bnez\t{},{}", rs1, Target(imm, cx)),
+        Inst::BGE(Gpr::zero, rs2, imm) => write!(f, "blez\t{},{}", rs2, Target(imm, cx)),
+        Inst::BGE(rs1, Gpr::zero, imm) => write!(f, "bgez\t{},{}", rs1, Target(imm, cx)),
+        Inst::BLT(rs1, Gpr::zero, imm) => write!(f, "bltz\t{},{}", rs1, Target(imm, cx)),
+        Inst::BLT(Gpr::zero, rs2, imm) => write!(f, "bgtz\t{},{}", rs2, Target(imm, cx)),
+        Inst::JAL(Gpr::zero, imm) => write!(f, "j\t{}", Target(imm, cx)),
+        Inst::JAL(Gpr::ra, imm) => write!(f, "jal\t{}", Target(imm, cx)),
+        Inst::JALR(Gpr::zero, Gpr::ra, 0) => write!(f, "ret"),
+        Inst::JALR(Gpr::zero, rs1, 0) => write!(f, "jr\t{}", rs1),
+        Inst::JALR(Gpr::zero, rs1, imm) => write!(f, "jr\t{}({})", imm, rs1),
+        Inst::JALR(Gpr::ra, rs1, 0) => write!(f, "jalr\t{}", rs1),
+        Inst::JALR(Gpr::ra, rs1, imm) => write!(f, "jalr\t{}({})", imm, rs1),
+        Inst::JALR(rd, rs1, 0) => write!(f, "jalr\t{},{}", rd, rs1),
+
+        Inst::FENCE(Fm::None, Fence::IORW, Fence::IORW) => write!(f, "fence"),
+        Inst::SFENCEVMA(Gpr::zero, Gpr::zero) => write!(f, "sfence.vma"),
+        Inst::SFENCEVMA(rs1, Gpr::zero) => write!(f, "sfence.vma\t{}", rs1),
+        Inst::HFENCEVVMA(Gpr::zero, Gpr::zero) => write!(f, "hfence.vvma"),
+        Inst::HFENCEVVMA(rs1, Gpr::zero) => write!(f, "hfence.vvma\t{}", rs1),
+        Inst::HFENCEGVMA(Gpr::zero, Gpr::zero) => write!(f, "hfence.gvma"),
+        Inst::HFENCEGVMA(rs1, Gpr::zero) => write!(f, "hfence.gvma\t{}", rs1),
+
+        Inst::CSRRS(rd, Gpr::zero, Csr::instret) => write!(f, "rdinstret\t{}", rd),
+        Inst::CSRRS(rd, Gpr::zero, Csr::instreth) => write!(f, "rdinstreth\t{}", rd),
+        Inst::CSRRS(rd, Gpr::zero, Csr::cycle) => write!(f, "rdcycle\t{}", rd),
+        Inst::CSRRS(rd, Gpr::zero, Csr::cycleh) => write!(f, "rdcycleh\t{}", rd),
+        Inst::CSRRS(rd, Gpr::zero, Csr::time) => write!(f, "rdtime\t{}", rd),
+        Inst::CSRRS(rd, Gpr::zero, Csr::timeh) => write!(f, "rdtimeh\t{}", rd),
+        Inst::CSRRS(rd, Gpr::zero, Csr::fcsr) => write!(f, "frcsr\t{}", rd),
+        Inst::CSRRW(Gpr::zero, rs1, Csr::fcsr) => write!(f, "fscsr\t{}", rs1),
+        Inst::CSRRW(rd, rs1, Csr::fcsr) => write!(f, "fscsr\t{},{}", rd, rs1),
+        Inst::CSRRS(rd, Gpr::zero, Csr::frm) => write!(f, "frrm\t{}", rd),
+        Inst::CSRRW(Gpr::zero, rs1, Csr::frm) => write!(f, "fsrm\t{}", rs1),
+        Inst::CSRRW(rd, rs1, Csr::frm) => write!(f, "fsrm\t{},{}", rd, rs1),
+        Inst::CSRRWI(Gpr::zero, imm, Csr::frm) => write!(f, "fsrmi\t{}", imm),
+        Inst::CSRRWI(rd, imm, Csr::frm) => write!(f, "fsrmi\t{},{}", rd, imm),
+        Inst::CSRRS(rd, Gpr::zero, Csr::fflags) => write!(f, "frflags\t{}", rd),
+        Inst::CSRRW(Gpr::zero, rs1, Csr::fflags) => write!(f, "fsflags\t{}", rs1),
+        Inst::CSRRW(rd, rs1, Csr::fflags) => write!(f, "fsflags\t{},{}", rd, rs1),
+        Inst::CSRRWI(Gpr::zero, imm, Csr::fflags) => write!(f, "fsflagsi\t{}", imm),
+        Inst::CSRRWI(rd, imm, Csr::fflags) => write!(f, "fsflagsi\t{},{}", rd, imm),
+        Inst::CSRRS(rd, Gpr::zero, csr) => write!(f, "csrr\t{},{}", rd, csr),
+        Inst::CSRRW(Gpr::zero, rs1, csr) => write!(f, "csrw\t{},{}", csr, rs1),
+        Inst::CSRRS(Gpr::zero, rs1, csr) => write!(f, "csrs\t{},{}", csr, rs1),
+        Inst::CSRRC(Gpr::zero, rs1, csr) => write!(f, "csrc\t{},{}", csr, rs1),
+        Inst::CSRRWI(Gpr::zero, imm, csr) => write!(f, "csrwi\t{},{}", csr, imm),
+        Inst::CSRRSI(Gpr::zero, imm, csr) => write!(f, "csrsi\t{},{}", csr, imm),
+        Inst::CSRRCI(Gpr::zero, imm, csr) => write!(f, "csrci\t{},{}", csr, imm),
+
+        Inst::FSGNJH(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fmv.h\t{},{}", rd, rs1),
+        Inst::FSGNJNH(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fneg.h\t{},{}", rd, rs1),
+        Inst::FSGNJXH(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fabs.h\t{},{}", rd, rs1),
+        Inst::FSGNJS(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fmv.s\t{},{}", rd, rs1),
+        Inst::FSGNJNS(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fneg.s\t{},{}", rd, rs1),
+        Inst::FSGNJXS(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fabs.s\t{},{}", rd, rs1),
+        Inst::FSGNJD(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fmv.d\t{},{}", rd, rs1),
+        Inst::FSGNJND(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fneg.d\t{},{}", rd, rs1),
+        Inst::FSGNJXD(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fabs.d\t{},{}", rd, rs1),
+        Inst::FSGNJQ(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fmv.q\t{},{}", rd, rs1),
+        Inst::FSGNJNQ(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fneg.q\t{},{}", rd, rs1),
+        Inst::FSGNJXQ(rd, rs1, rs2) if rs1 == rs2 => write!(f, "fabs.q\t{},{}", rd, rs1),
+
+        Inst::VXORVI(vd, vs2, -1, vm) => write!(f, "vnot.v\t{},{}{}", vd, vs2, vm),
+        Inst::VRSUBVX(vd, vs2, Gpr::zero, vm) => write!(f, "vneg.v\t{},{}{}", vd, vs2, vm),
+        Inst::VWADDVX(vd, vs2, Gpr::zero, vm) => write!(f, "vwcvt.x.x.v\t{},{}{}", vd, vs2, vm),
+        Inst::VWADDUVX(vd, vs2, Gpr::zero, vm) => write!(f, "vwcvtu.x.x.v\t{},{}{}", vd, vs2, vm),
+        Inst::VNSRLWX(vd, vs2, Gpr::zero, vm) => write!(f, "vncvt.x.x.w\t{},{}{}", vd, vs2, vm),
+        Inst::VFSGNJNVV(vd, vs2, vs1, vm) if vs1 == vs2 => write!(f, "vfneg.v\t{},{}{}", vd, vs2, vm),
+        Inst::VFSGNJXVV(vd, vs2, vs1, vm) if vs1 == vs2 => write!(f, "vfabs.v\t{},{}{}", vd, vs2, vm),
+        Inst::VMXORMM(vd, vs2, vs1) if vd == vs2 && vs2 == vs1 => write!(f, "vmclr.m\t{}", vd),
+        Inst::VMXNORMM(vd, vs2, vs1) if vd == vs2 && vs2 == vs1 => write!(f, "vmset.m\t{}", vd),
+        Inst::VMANDMM(vd, vs2, vs1) if vs2 == vs1 => write!(f, "vmmv.m\t{},{}", vd, vs2),
+        Inst::VMNANDMM(vd, vs2, vs1) if vs2 == vs1 => write!(f, "vmnot.m\t{},{}", vd, vs2),
+        Inst::VLRE(vd, rs1, Sew::E8, nf) => write!(f, "vl{}r.v\t{},({})", nf, vd, rs1),
+
+        // c.mv expands to `add rd,zero,rs2`, which objdump does not alias
+        Inst::CMV(rd, rs2) => write!(f, "mv\t{},{}", rd, rs2),
+
+        _ => {
+            let base = uncompress(inst)?;
+            return Some(alias(&base, f, cx).unwrap_or_else(|| no_aliases(&base, f, cx)));
+        }
+    };
+    Some(r)
+}
+
+// Base instruction a compressed instruction expands to.
+pub(crate) fn uncompress(inst: &Inst) -> Option<Inst> {
+    let base = match *inst {
+        Inst::CADDI4SPN(rd, imm) => Inst::ADDI(rd, Gpr::sp, imm as i32),
+        Inst::CFLD(rd, rs1, imm) => Inst::FLD(rd, rs1, imm as i32),
+        Inst::CLQ(rd, rs1, imm) => Inst::LQ(rd, rs1, imm as i32),
+        Inst::CLW(rd, rs1, imm) => Inst::LW(rd, rs1, imm as i32),
+        Inst::CFLW(rd, rs1, imm) => Inst::FLW(rd, rs1, imm as i32),
+        Inst::CLD(rd, rs1, imm) => Inst::LD(rd, rs1, imm as i32),
+        Inst::CFSD(rs2, rs1, imm) => Inst::FSD(rs2, rs1, imm as i32),
+        Inst::CSQ(rs2, rs1, imm) => Inst::SQ(rs2, rs1, imm as i32),
+        Inst::CSW(rs2, rs1, imm) => Inst::SW(rs2, rs1, imm as i32),
+        Inst::CFSW(rs2, rs1, imm) => Inst::FSW(rs2, rs1, imm as i32),
+        Inst::CSD(rs2, rs1, imm) => Inst::SD(rs2, rs1, imm as i32),
+
+        Inst::CNOP => Inst::ADDI(Gpr::zero, Gpr::zero, 0),
+        Inst::CADDI(rd, imm) => Inst::ADDI(rd, rd, imm),
+        Inst::CJAL(imm) => Inst::JAL(Gpr::ra, imm),
+        Inst::CADDIW(rd, imm) => Inst::ADDIW(rd, rd, imm),
+        Inst::CLI(rd, imm) => Inst::ADDI(rd, Gpr::zero, imm),
+        Inst::CADDI16SP(imm) => Inst::ADDI(Gpr::sp, Gpr::sp, imm),
+        Inst::CLUI(rd, imm) => Inst::LUI(rd, imm),
+        Inst::CSRLI(rd, shamt) => Inst::SRLI(rd, rd, shamt),
+        Inst::CSRLI64(rd) => Inst::SRLI(rd, rd, 64),
+        Inst::CSRAI(rd, shamt) => Inst::SRAI(rd, rd, shamt),
+        Inst::CSRAI64(rd) => Inst::SRAI(rd, rd, 64),
+        Inst::CANDI(rd, imm) => Inst::ANDI(rd, rd, imm),
+        Inst::CSUB(rd, rs2) => Inst::SUB(rd, rd, rs2),
+        Inst::CXOR(rd, rs2) => Inst::XOR(rd, rd, rs2),
+        Inst::COR(rd, rs2) => Inst::OR(rd, rd, rs2),
+        Inst::CAND(rd, rs2) => Inst::AND(rd, rd, rs2),
+        Inst::CSUBW(rd, rs2) => Inst::SUBW(rd, rd, rs2),
+        Inst::CADDW(rd, rs2) => Inst::ADDW(rd, rd, rs2),
+        Inst::CJ(imm) => Inst::JAL(Gpr::zero, imm),
+        Inst::CBEQZ(rs1, imm) => Inst::BEQ(rs1, Gpr::zero, imm),
+        Inst::CBNEZ(rs1, imm) => Inst::BNE(rs1, Gpr::zero, imm),
+
+        Inst::CSLLI(rd, shamt) => Inst::SLLI(rd, rd, shamt),
+        Inst::CSLLI64(rd) => Inst::SLLI(rd, rd, 64),
+        Inst::CFLDSP(rd, imm) => Inst::FLD(rd, Gpr::sp, imm as i32),
+        Inst::CLQSP(rd, imm) => Inst::LQ(rd, Gpr::sp, imm as i32),
+        Inst::CLWSP(rd, imm) => Inst::LW(rd, Gpr::sp, imm as i32),
+        Inst::CFLWSP(rd, imm) => Inst::FLW(rd, Gpr::sp, imm as i32),
+        Inst::CLDSP(rd, imm) => Inst::LD(rd, Gpr::sp, imm as i32),
+        Inst::CJR(rs1) => Inst::JALR(Gpr::zero, rs1, 0),
+        Inst::CMV(rd, rs2) => Inst::ADD(rd, Gpr::zero, rs2),
+        Inst::CEBREAK => Inst::EBREAK,
+        Inst::CJALR(rs1) => Inst::JALR(Gpr::ra, rs1, 0),
+        Inst::CADD(rd, rs2) => Inst::ADD(rd, rd, rs2),
+        Inst::CFSDSP(rs2, imm) => Inst::FSD(rs2, Gpr::sp, imm as i32),
+        Inst::CSQSP(rs2, imm) => Inst::SQ(rs2, Gpr::sp, imm as i32),
+        Inst::CSWSP(rs2, imm) => Inst::SW(rs2, Gpr::sp, imm as i32),
+        Inst::CFSWSP(rs2, imm) => Inst::FSW(rs2, Gpr::sp, imm as i32),
+        Inst::CSDSP(rs2, imm) => Inst::SD(rs2, Gpr::sp, imm as i32),
+
+        Inst::CLBU(rd, rs1, imm) => Inst::LBU(rd, rs1, imm as i32),
+        Inst::CLHU(rd, rs1, imm) => Inst::LHU(rd, rs1, imm as i32),
+        Inst::CLH(rd, rs1, imm) => Inst::LH(rd, rs1, imm as i32),
+        Inst::CSB(rs2, rs1, imm) => Inst::SB(rs2, rs1, imm as i32),
+        Inst::CSH(rs2, rs1, imm) => Inst::SH(rs2, rs1, imm as i32),
+        Inst::CZEXTB(rd) => Inst::ANDI(rd, rd, 0xff),
+        Inst::CSEXTB(rd) => Inst::SEXTB(rd, rd),
+        Inst::CZEXTH(rd) => Inst::ZEXTH(rd, rd),
+        Inst::CSEXTH(rd) => Inst::SEXTH(rd, rd),
+        Inst::CZEXTW(rd) => Inst::ADDUW(rd, rd, Gpr::zero),
+        Inst::CNOT(rd) => Inst::XORI(rd, rd, -1),
+        Inst::CMUL(rd, rs2) => Inst::MUL(rd, rd, rs2),
+
+        // Zcmp and Zcmt have no single base form
+        _ => return None,
+    };
+    Some(base)
+}
@@ -0,0 +1,1835 @@
+use crate::{Aqrl, Ext, Fence, Fm, Fpr, Gpr, Inst, Isa, Rlist, Rm, Sew, Vm, Vr};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EncodedInst {
+    bytes: [u8; 4],
+    size: u8,
+}
+
+impl EncodedInst {
+    fn half(h: u16) -> Self {
+        let [b0, b1] = h.to_le_bytes();
+        Self {
+            bytes: [b0, b1, 0, 0],
+            size: 2,
+        }
+    }
+
+    fn word(w: u32) -> Self {
+        Self {
+            bytes: w.to_le_bytes(),
+            size: 4,
+        }
+    }
+
+    /// Instruction length in bytes (2 or 4).
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    pub fn bits(&self) -> u32 {
+        u32::from_le_bytes(self.bytes)
+    }
+
+    /// Little-endian bytes, as they appear in memory.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.size as usize]
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum EncodeError {
+    /// Immediate does not fit in the instruction field.
+    ImmOutOfRange(i64),
+    /// Immediate is not a multiple of the field's scale.
+    ImmMisaligned(i64),
+    /// Register number cannot be encoded in this operand slot.
+    BadRegister(u8),
+    /// Instruction has no encoding for this XLEN.
+    BadXlen(u8),
+    /// Instruction belongs to an extension that is not enabled.
+    Disabled(Ext),
+    /// Instruction belongs to a vendor extension that is not enabled.
+    VendorDisabled(&'static str),
+    /// Operand combination is reserved by the specification.
+    Reserved,
+}
+
+impl std::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncodeError::ImmOutOfRange(v) => write!(f, "immediate {} out of range", v),
+            EncodeError::ImmMisaligned(v) => write!(f, "immediate {} misaligned", v),
+            EncodeError::BadRegister(r) => write!(f, "register x{} not encodable here", r),
+            EncodeError::BadXlen(b) => write!(f, "instruction not available on RV{}", b),
+            EncodeError::Disabled(e) => write!(f, "extension {} not enabled", e),
+            EncodeError::VendorDisabled(name) => write!(f, "extension {} not enabled", name),
+            EncodeError::Reserved => write!(f, "reserved operand combination"),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+type Result = std::result::Result<EncodedInst, EncodeError>;
+
+pub fn encode(inst: &Inst, isa: &Isa) -> Result {
+    if let Inst::Custom(c) = inst {
+        if !isa.has_vendor(c.vendor) {
+            return Err(EncodeError::VendorDisabled(c.vendor.name()));
+        }
+        return c.vendor.encode(c, isa).map(EncodedInst::word);
+    }
+    if !inst.enabled(isa) {
+        return Err(EncodeError::Disabled(inst.ext()));
+    }
+    let enc = encode_xlen(inst, isa.xlen())?;
+    if enc.size == 2 && !isa.has(Ext::C) {
+        return Err(EncodeError::Disabled(Ext::C));
+    }
+    Ok(enc)
+}
+
+fn encode_xlen(inst: &Inst, bits: u8) -> Result {
+    match *inst {
+        Inst::ADD(rd, rs1, rs2) => r_type(0b01100, 0b000, 0x00, rd, rs1, rs2),
+        Inst::SUB(rd, rs1, rs2) => r_type(0b01100, 0b000, 0x20, rd, rs1, rs2),
+        Inst::XOR(rd, rs1, rs2) => r_type(0b01100, 0b100, 0x00, rd, rs1, rs2),
+        Inst::OR(rd, rs1, rs2) => r_type(0b01100, 0b110, 0x00, rd, rs1, rs2),
+        Inst::AND(rd, rs1, rs2) => r_type(0b01100, 0b111, 0x00, rd, rs1, rs2),
+
+        Inst::SLL(rd, rs1, rs2) => r_type(0b01100, 0b001, 0x00, rd, rs1, rs2),
+        Inst::SRL(rd, rs1, rs2) => r_type(0b01100, 0b101, 0x00, rd, rs1, rs2),
+        Inst::SRA(rd, rs1, rs2) => r_type(0b01100, 0b101, 0x20, rd, rs1, rs2),
+
+        Inst::SLT(rd, rs1, rs2) => r_type(0b01100, 0b010, 0x00, rd, rs1, rs2),
+        Inst::SLTU(rd, rs1, rs2) => r_type(0b01100, 0b011, 0x00, rd, rs1, rs2),
+
+        Inst::ADDI(rd, rs1, imm) => i_type(0b00100, 0b000, rd, rs1, imm),
+        Inst::XORI(rd, rs1, imm) => i_type(0b00100, 0b100, rd, rs1, imm),
+        Inst::ORI(rd, rs1, imm) => i_type(0b00100, 0b110, rd, rs1, imm),
+        Inst::ANDI(rd, rs1, imm) => i_type(0b00100, 0b111, rd, rs1, imm),
+
+        Inst::SLLI(rd, rs1, shamt) => shift(0b00100, 0b001, 0x00, rd, rs1, shamt, bits),
+        Inst::SRLI(rd, rs1, shamt) => shift(0b00100, 0b101, 0x00, rd, rs1, shamt, bits),
+        Inst::SRAI(rd, rs1, shamt) => shift(0b00100, 0b101, 0x20, rd, rs1, shamt, bits),
+
+        Inst::SLTI(rd, rs1, imm) => i_type(0b00100, 0b010, rd, rs1, imm),
+        Inst::SLTUI(rd, rs1, imm) => i_type(0b00100, 0b011, rd, rs1, imm as i32),
+
+        Inst::LB(rd, rs1, imm) => i_type(0b00000, 0b000, rd, rs1, imm),
+        Inst::LH(rd, rs1, imm) => i_type(0b00000, 0b001, rd, rs1, imm),
+        Inst::LW(rd, rs1, imm) => i_type(0b00000, 0b010, rd, rs1, imm),
+
+        Inst::LBU(rd, rs1, imm) => i_type(0b00000, 0b100, rd, rs1, imm),
+        Inst::LHU(rd, rs1, imm) => i_type(0b00000, 0b101, rd, rs1, imm),
+
+        Inst::SB(rs2, rs1, imm) => s_type(0b01000, 0b000, rs1, rs2, imm),
+        Inst::SH(rs2, rs1, imm) => s_type(0b01000, 0b001, rs1, rs2, imm),
+        Inst::SW(rs2, rs1, imm) => s_type(0b01000, 0b010, rs1, rs2, imm),
+
+        Inst::BEQ(rs1, rs2, imm) => b_type(0b000, rs1, rs2, imm),
+        Inst::BNE(rs1, rs2, imm) => b_type(0b001, rs1, rs2, imm),
+        Inst::BLT(rs1, rs2, imm) => b_type(0b100, rs1, rs2, imm),
+        Inst::BGE(rs1, rs2, imm) => b_type(0b101, rs1, rs2, imm),
+        Inst::BLTU(rs1, rs2, imm) => b_type(0b110, rs1, rs2, imm),
+        Inst::BGEU(rs1, rs2, imm) => b_type(0b111, rs1, rs2, imm),
+
+        Inst::JAL(rd, imm) => {
+            let v = simm(imm, 21, 1)?;
+            let imm = (v >> 20 & 1) << 19 | (v >> 1 & 0x3ff) << 9 | (v >> 11 & 1) << 8 | (v >> 12 & 0xff);
+            Ok(u_word(0b11011, rd, imm))
+        }
+        Inst::JALR(rd, rs1, imm) => i_type(0b11001, 0b000, rd, rs1, imm),
+
+        Inst::LUI(rd, imm) => Ok(u_word(0b01101, rd, uimm(imm, 20, 0)?)),
+        Inst::AUIPC(rd, imm) => Ok(u_word(0b00101, rd, uimm(imm, 20, 0)?)),
+
+        Inst::ECALL => Ok(EncodedInst::word(0x00000073)),
+        Inst::EBREAK => Ok(EncodedInst::word(0x00100073)),
+
+        Inst::FENCE(Fm::Tso, pred, succ) if pred != Fence::RW || succ != Fence::RW => Err(EncodeError::Reserved),
+        Inst::FENCE(fm, pred, succ) => {
+            Ok(word(0b00011, 0b000, 0, 0, (fm as u32) << 8 | (pred.to_u8() as u32) << 4 | succ.to_u8() as u32))
+        }
+        Inst::PAUSE => Ok(EncodedInst::word(0x0100000f)),
+        Inst::FENCEI => Ok(EncodedInst::word(0x0000100f)),
+
+        Inst::CSRRW(rd, rs1, csr) => Ok(word(0b11100, 0b001, rd as u8, rs1 as u8, uimm(csr.to_u16() as u32, 12, 0)?)),
+        Inst::CSRRS(rd, rs1, csr) => Ok(word(0b11100, 0b010, rd as u8, rs1 as u8, uimm(csr.to_u16() as u32, 12, 0)?)),
+        Inst::CSRRC(rd, rs1, csr) => Ok(word(0b11100, 0b011, rd as u8, rs1 as u8, uimm(csr.to_u16() as u32, 12, 0)?)),
+
+        Inst::CSRRWI(rd, imm, csr) => Ok(word(0b11100, 0b101, rd as u8, uimm(imm as u32, 5, 0)? as u8, uimm(csr.to_u16() as u32, 12, 0)?)),
+        Inst::CSRRSI(rd, imm, csr) => Ok(word(0b11100, 0b110, rd as u8, uimm(imm as u32, 5, 0)? as u8, uimm(csr.to_u16() as u32, 12, 0)?)),
+        Inst::CSRRCI(rd, imm, csr) => Ok(word(0b11100, 0b111, rd as u8, uimm(imm as u32, 5, 0)? as u8, uimm(csr.to_u16() as u32, 12, 0)?)),
+
+        Inst::SRET => Ok(EncodedInst::word(0x10200073)),
+        Inst::MRET => Ok(EncodedInst::word(0x30200073)),
+        Inst::WFI => Ok(EncodedInst::word(0x10500073)),
+
+        Inst::LD(rd, rs1, imm) => {
+            xlen(bits != 32, bits)?;
+            i_type(0b00000, 0b011, rd, rs1, imm)
+        }
+        Inst::LWU(rd, rs1, imm) => {
+            xlen(bits != 32, bits)?;
+            i_type(0b00000, 0b110, rd, rs1, imm)
+        }
+        Inst::SD(rs2, rs1, imm) => {
+            xlen(bits != 32, bits)?;
+            s_type(0b01000, 0b011, rs1, rs2, imm)
+        }
+
+        Inst::ADDIW(rd, rs1, imm) => {
+            xlen(bits != 32, bits)?;
+            i_type(0b00110, 0b000, rd, rs1, imm)
+        }
+        Inst::SLLIW(rd, rs1, shamt) => {
+            xlen(bits != 32, bits)?;
+            shift(0b00110, 0b001, 0x00, rd, rs1, shamt, 32)
+        }
+        Inst::SRLIW(rd, rs1, shamt) => {
+            xlen(bits != 32, bits)?;
+            shift(0b00110, 0b101, 0x00, rd, rs1, shamt, 32)
+        }
+        Inst::SRAIW(rd, rs1, shamt) => {
+            xlen(bits != 32, bits)?;
+            shift(0b00110, 0b101, 0x20, rd, rs1, shamt, 32)
+        }
+
+        Inst::ADDW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b000, 0x00, rd, rs1, rs2)
+        }
+        Inst::SUBW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b000, 0x20, rd, rs1, rs2)
+        }
+        Inst::SLLW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b001, 0x00, rd, rs1, rs2)
+        }
+        Inst::SRLW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b101, 0x00, rd, rs1, rs2)
+        }
+        Inst::SRAW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b101, 0x20, rd, rs1, rs2)
+        }
+
+        Inst::LDU(rd, rs1, imm) => {
+            xlen(bits == 128, bits)?;
+            i_type(0b00000, 0b111, rd, rs1, imm)
+        }
+        Inst::LQ(rd, rs1, imm) => {
+            xlen(bits == 128, bits)?;
+            i_type(0b00011, 0b010, rd, rs1, imm)
+        }
+        Inst::SQ(rs2, rs1, imm) => {
+            xlen(bits == 128, bits)?;
+            s_type(0b01000, 0b100, rs1, rs2, imm)
+        }
+
+        Inst::ADDID(rd, rs1, imm) => {
+            xlen(bits == 128, bits)?;
+            i_type(0b10110, 0b000, rd, rs1, imm)
+        }
+        Inst::SLLID(rd, rs1, shamt) => {
+            xlen(bits == 128, bits)?;
+            shift(0b10110, 0b001, 0x00, rd, rs1, shamt, 64)
+        }
+        Inst::SRLID(rd, rs1, shamt) => {
+            xlen(bits == 128, bits)?;
+            shift(0b10110, 0b101, 0x00, rd, rs1, shamt, 64)
+        }
+        Inst::SRAID(rd, rs1, shamt) => {
+            xlen(bits == 128, bits)?;
+            shift(0b10110, 0b101, 0x20, rd, rs1, shamt, 64)
+        }
+
+        Inst::ADDD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b000, 0x00, rd, rs1, rs2)
+        }
+        Inst::SUBD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b000, 0x20, rd, rs1, rs2)
+        }
+        Inst::SLLD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b001, 0x00, rd, rs1, rs2)
+        }
+        Inst::SRLD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b101, 0x00, rd, rs1, rs2)
+        }
+        Inst::SRAD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b101, 0x20, rd, rs1, rs2)
+        }
+
+        Inst::MUL(rd, rs1, rs2) => r_type(0b01100, 0b000, 0x01, rd, rs1, rs2),
+        Inst::MULH(rd, rs1, rs2) => r_type(0b01100, 0b001, 0x01, rd, rs1, rs2),
+        Inst::MULSU(rd, rs1, rs2) => r_type(0b01100, 0b010, 0x01, rd, rs1, rs2),
+        Inst::MULU(rd, rs1, rs2) => r_type(0b01100, 0b011, 0x01, rd, rs1, rs2),
+
+        Inst::MULW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b000, 0x01, rd, rs1, rs2)
+        }
+
+        Inst::MULD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b000, 0x01, rd, rs1, rs2)
+        }
+
+        Inst::DIV(rd, rs1, rs2) => r_type(0b01100, 0b100, 0x01, rd, rs1, rs2),
+        Inst::DIVU(rd, rs1, rs2) => r_type(0b01100, 0b101, 0x01, rd, rs1, rs2),
+        Inst::REM(rd, rs1, rs2) => r_type(0b01100, 0b110, 0x01, rd, rs1, rs2),
+        Inst::REMU(rd, rs1, rs2) => r_type(0b01100, 0b111, 0x01, rd, rs1, rs2),
+
+        Inst::DIVW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b100, 0x01, rd, rs1, rs2)
+        }
+        Inst::DIVUW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b101, 0x01, rd, rs1, rs2)
+        }
+        Inst::REMW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b110, 0x01, rd, rs1, rs2)
+        }
+        Inst::REMUW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b111, 0x01, rd, rs1, rs2)
+        }
+
+        Inst::DIVD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b100, 0x01, rd, rs1, rs2)
+        }
+        Inst::DIVUD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b101, 0x01, rd, rs1, rs2)
+        }
+        Inst::REMD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b110, 0x01, rd, rs1, rs2)
+        }
+        Inst::REMUD(rd, rs1, rs2) => {
+            xlen(bits == 128, bits)?;
+            r_type(0b11110, 0b111, 0x01, rd, rs1, rs2)
+        }
+
+        Inst::LRW(rd, rs1, aqrl) => amo(0b010, 0b00010, aqrl, rd, rs1, Gpr::zero),
+        Inst::SCW(rd, rs1, rs2, aqrl) => amo(0b010, 0b00011, aqrl, rd, rs1, rs2),
+
+        Inst::LRD(rd, rs1, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b00010, aqrl, rd, rs1, Gpr::zero)
+        }
+        Inst::SCD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b00011, aqrl, rd, rs1, rs2)
+        }
+
+        Inst::AMOSWAPW(rd, rs1, rs2, aqrl) => amo(0b010, 0b00001, aqrl, rd, rs1, rs2),
+        Inst::AMOADDW(rd, rs1, rs2, aqrl) => amo(0b010, 0b00000, aqrl, rd, rs1, rs2),
+        Inst::AMOXORW(rd, rs1, rs2, aqrl) => amo(0b010, 0b00100, aqrl, rd, rs1, rs2),
+        Inst::AMOANDW(rd, rs1, rs2, aqrl) => amo(0b010, 0b01100, aqrl, rd, rs1, rs2),
+        Inst::AMOORW(rd, rs1, rs2, aqrl) => amo(0b010, 0b01000, aqrl, rd, rs1, rs2),
+        Inst::AMOMINW(rd, rs1, rs2, aqrl) => amo(0b010, 0b10000, aqrl, rd, rs1, rs2),
+        Inst::AMOMAXW(rd, rs1, rs2, aqrl) => amo(0b010, 0b10100, aqrl, rd, rs1, rs2),
+        Inst::AMOMINUW(rd, rs1, rs2, aqrl) => amo(0b010, 0b11000, aqrl, rd, rs1, rs2),
+        Inst::AMOMAXUW(rd, rs1, rs2, aqrl) => amo(0b010, 0b11100, aqrl, rd, rs1, rs2),
+
+        Inst::AMOSWAPD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b00001, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOADDD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b00000, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOXORD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b00100, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOANDD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b01100, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOORD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b01000, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOMIND(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b10000, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOMAXD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b10100, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOMINUD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b11000, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOMAXUD(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            amo(0b011, 0b11100, aqrl, rd, rs1, rs2)
+        }
+
+        Inst::AMOCASW(rd, rs1, rs2, aqrl) => amo(0b010, 0b00101, aqrl, rd, rs1, rs2),
+        Inst::AMOCASD(rd, rs1, rs2, aqrl) => {
+            if bits == 32 {
+                reg_pair(rd)?;
+                reg_pair(rs2)?;
+            }
+            amo(0b011, 0b00101, aqrl, rd, rs1, rs2)
+        }
+        Inst::AMOCASQ(rd, rs1, rs2, aqrl) => {
+            xlen(bits != 32, bits)?;
+            if bits == 64 {
+                reg_pair(rd)?;
+                reg_pair(rs2)?;
+            }
+            amo(0b100, 0b00101, aqrl, rd, rs1, rs2)
+        }
+
+        Inst::AMOSWAPB(rd, rs1, rs2, aqrl) => amo(0b000, 0b00001, aqrl, rd, rs1, rs2),
+        Inst::AMOADDB(rd, rs1, rs2, aqrl) => amo(0b000, 0b00000, aqrl, rd, rs1, rs2),
+        Inst::AMOXORB(rd, rs1, rs2, aqrl) => amo(0b000, 0b00100, aqrl, rd, rs1, rs2),
+        Inst::AMOANDB(rd, rs1, rs2, aqrl) => amo(0b000, 0b01100, aqrl, rd, rs1, rs2),
+        Inst::AMOORB(rd, rs1, rs2, aqrl) => amo(0b000, 0b01000, aqrl, rd, rs1, rs2),
+        Inst::AMOMINB(rd, rs1, rs2, aqrl) => amo(0b000, 0b10000, aqrl, rd, rs1, rs2),
+        Inst::AMOMAXB(rd, rs1, rs2, aqrl) => amo(0b000, 0b10100, aqrl, rd, rs1, rs2),
+        Inst::AMOMINUB(rd, rs1, rs2, aqrl) => amo(0b000, 0b11000, aqrl, rd, rs1, rs2),
+        Inst::AMOMAXUB(rd, rs1, rs2, aqrl) => amo(0b000, 0b11100, aqrl, rd, rs1, rs2),
+        Inst::AMOCASB(rd, rs1, rs2, aqrl) => amo(0b000, 0b00101, aqrl, rd, rs1, rs2),
+
+        Inst::AMOSWAPH(rd, rs1, rs2, aqrl) => amo(0b001, 0b00001, aqrl, rd, rs1, rs2),
+        Inst::AMOADDH(rd, rs1, rs2, aqrl) => amo(0b001, 0b00000, aqrl, rd, rs1, rs2),
+        Inst::AMOXORH(rd, rs1, rs2, aqrl) => amo(0b001, 0b00100, aqrl, rd, rs1, rs2),
+        Inst::AMOANDH(rd, rs1, rs2, aqrl) => amo(0b001, 0b01100, aqrl, rd, rs1, rs2),
+        Inst::AMOORH(rd, rs1, rs2, aqrl) => amo(0b001, 0b01000, aqrl, rd, rs1, rs2),
+        Inst::AMOMINH(rd, rs1, rs2, aqrl) => amo(0b001, 0b10000, aqrl, rd, rs1, rs2),
+        Inst::AMOMAXH(rd, rs1, rs2, aqrl) => amo(0b001, 0b10100, aqrl, rd, rs1, rs2),
+        Inst::AMOMINUH(rd, rs1, rs2, aqrl) => amo(0b001, 0b11000, aqrl, rd, rs1, rs2),
+        Inst::AMOMAXUH(rd, rs1, rs2, aqrl) => amo(0b001, 0b11100, aqrl, rd, rs1, rs2),
+        Inst::AMOCASH(rd, rs1, rs2, aqrl) => amo(0b001, 0b00101, aqrl, rd, rs1, rs2),
+
+        Inst::FLW(rd, rs1, imm) => i_type(0b00001, 0b010, rd, rs1, imm),
+        Inst::FSW(rs2, rs1, imm) => s_type(0b01001, 0b010, rs1, rs2, imm),
+        Inst::FMADDS(rd, rs1, rs2, rs3, rm) => r4_type(0b10000, 0b00, rm, rd, rs1, rs2, rs3),
+        Inst::FMSUBS(rd, rs1, rs2, rs3, rm) => r4_type(0b10001, 0b00, rm, rd, rs1, rs2, rs3),
+        Inst::FNMSUBS(rd, rs1, rs2, rs3, rm) => r4_type(0b10010, 0b00, rm, rd, rs1, rs2, rs3),
+        Inst::FNMADDS(rd, rs1, rs2, rs3, rm) => r4_type(0b10011, 0b00, rm, rd, rs1, rs2, rs3),
+        Inst::FADDS(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000000, rd, rs1, rs2),
+        Inst::FSUBS(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000100, rd, rs1, rs2),
+        Inst::FMULS(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001000, rd, rs1, rs2),
+        Inst::FDIVS(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001100, rd, rs1, rs2),
+        Inst::FSQRTS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0101100, 0b00000, rd, rs1),
+        Inst::FSGNJS(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010000, rd, rs1, rs2),
+        Inst::FSGNJNS(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010000, rd, rs1, rs2),
+        Inst::FSGNJXS(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010000, rd, rs1, rs2),
+        Inst::FMINS(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010100, rd, rs1, rs2),
+        Inst::FMAXS(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010100, rd, rs1, rs2),
+        Inst::FEQS(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b1010000, rd, rs1, rs2),
+        Inst::FLTS(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b1010000, rd, rs1, rs2),
+        Inst::FLES(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b1010000, rd, rs1, rs2),
+        Inst::FCLASSS(rd, rs1) => r2_type(0b10100, 0b001, 0b1110000, 0b00000, rd, rs1),
+        Inst::FCVTWS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100000, 0b00000, rd, rs1),
+        Inst::FCVTWUS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100000, 0b00001, rd, rs1),
+        Inst::FCVTSW(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101000, 0b00000, rd, rs1),
+        Inst::FCVTSWU(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101000, 0b00001, rd, rs1),
+        Inst::FMVXW(rd, rs1) => r2_type(0b10100, 0b000, 0b1110000, 0b00000, rd, rs1),
+        Inst::FMVWX(rd, rs1) => r2_type(0b10100, 0b000, 0b1111000, 0b00000, rd, rs1),
+
+        Inst::FCVTLS(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100000, 0b00010, rd, rs1)
+        }
+        Inst::FCVTLUS(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100000, 0b00011, rd, rs1)
+        }
+        Inst::FCVTSL(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101000, 0b00010, rd, rs1)
+        }
+        Inst::FCVTSLU(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101000, 0b00011, rd, rs1)
+        }
+
+        Inst::FLD(rd, rs1, imm) => i_type(0b00001, 0b011, rd, rs1, imm),
+        Inst::FSD(rs2, rs1, imm) => s_type(0b01001, 0b011, rs1, rs2, imm),
+        Inst::FMADDD(rd, rs1, rs2, rs3, rm) => r4_type(0b10000, 0b01, rm, rd, rs1, rs2, rs3),
+        Inst::FMSUBD(rd, rs1, rs2, rs3, rm) => r4_type(0b10001, 0b01, rm, rd, rs1, rs2, rs3),
+        Inst::FNMSUBD(rd, rs1, rs2, rs3, rm) => r4_type(0b10010, 0b01, rm, rd, rs1, rs2, rs3),
+        Inst::FNMADDD(rd, rs1, rs2, rs3, rm) => r4_type(0b10011, 0b01, rm, rd, rs1, rs2, rs3),
+        Inst::FADDD(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000001, rd, rs1, rs2),
+        Inst::FSUBD(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000101, rd, rs1, rs2),
+        Inst::FMULD(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001001, rd, rs1, rs2),
+        Inst::FDIVD(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001101, rd, rs1, rs2),
+        Inst::FSQRTD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0101101, 0b00000, rd, rs1),
+        Inst::FSGNJD(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010001, rd, rs1, rs2),
+        Inst::FSGNJND(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010001, rd, rs1, rs2),
+        Inst::FSGNJXD(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010001, rd, rs1, rs2),
+        Inst::FMIND(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010101, rd, rs1, rs2),
+        Inst::FMAXD(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010101, rd, rs1, rs2),
+        Inst::FCVTSD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100000, 0b00001, rd, rs1),
+        Inst::FCVTDS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100001, 0b00000, rd, rs1),
+        Inst::FEQD(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b1010001, rd, rs1, rs2),
+        Inst::FLTD(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b1010001, rd, rs1, rs2),
+        Inst::FLED(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b1010001, rd, rs1, rs2),
+        Inst::FCLASSD(rd, rs1) => r2_type(0b10100, 0b001, 0b1110001, 0b00000, rd, rs1),
+        Inst::FCVTWD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100001, 0b00000, rd, rs1),
+        Inst::FCVTWUD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100001, 0b00001, rd, rs1),
+        Inst::FCVTDW(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101001, 0b00000, rd, rs1),
+        Inst::FCVTDWU(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101001, 0b00001, rd, rs1),
+
+        Inst::FCVTLD(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100001, 0b00010, rd, rs1)
+        }
+        Inst::FCVTLUD(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100001, 0b00011, rd, rs1)
+        }
+        Inst::FCVTDL(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101001, 0b00010, rd, rs1)
+        }
+        Inst::FCVTDLU(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101001, 0b00011, rd, rs1)
+        }
+        Inst::FMVXD(rd, rs1) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, 0b000, 0b1110001, 0b00000, rd, rs1)
+        }
+        Inst::FMVDX(rd, rs1) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, 0b000, 0b1111001, 0b00000, rd, rs1)
+        }
+
+        Inst::FLQ(rd, rs1, imm) => i_type(0b00001, 0b100, rd, rs1, imm),
+        Inst::FSQ(rs2, rs1, imm) => s_type(0b01001, 0b100, rs1, rs2, imm),
+        Inst::FMADDQ(rd, rs1, rs2, rs3, rm) => r4_type(0b10000, 0b11, rm, rd, rs1, rs2, rs3),
+        Inst::FMSUBQ(rd, rs1, rs2, rs3, rm) => r4_type(0b10001, 0b11, rm, rd, rs1, rs2, rs3),
+        Inst::FNMSUBQ(rd, rs1, rs2, rs3, rm) => r4_type(0b10010, 0b11, rm, rd, rs1, rs2, rs3),
+        Inst::FNMADDQ(rd, rs1, rs2, rs3, rm) => r4_type(0b10011, 0b11, rm, rd, rs1, rs2, rs3),
+        Inst::FADDQ(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000011, rd, rs1, rs2),
+        Inst::FSUBQ(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000111, rd, rs1, rs2),
+        Inst::FMULQ(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001011, rd, rs1, rs2),
+        Inst::FDIVQ(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001111, rd, rs1, rs2),
+        Inst::FSQRTQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0101111, 0b00000, rd, rs1),
+        Inst::FSGNJQ(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010011, rd, rs1, rs2),
+        Inst::FSGNJNQ(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010011, rd, rs1, rs2),
+        Inst::FSGNJXQ(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010011, rd, rs1, rs2),
+        Inst::FMINQ(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010111, rd, rs1, rs2),
+        Inst::FMAXQ(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010111, rd, rs1, rs2),
+        Inst::FCVTSQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100000, 0b00011, rd, rs1),
+        Inst::FCVTQS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100011, 0b00000, rd, rs1),
+        Inst::FCVTDQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100001, 0b00011, rd, rs1),
+        Inst::FCVTQD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100011, 0b00001, rd, rs1),
+        Inst::FEQQ(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b1010011, rd, rs1, rs2),
+        Inst::FLTQ(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b1010011, rd, rs1, rs2),
+        Inst::FLEQ(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b1010011, rd, rs1, rs2),
+        Inst::FCLASSQ(rd, rs1) => r2_type(0b10100, 0b001, 0b1110011, 0b00000, rd, rs1),
+        Inst::FCVTWQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100011, 0b00000, rd, rs1),
+        Inst::FCVTWUQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100011, 0b00001, rd, rs1),
+        Inst::FCVTQW(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101011, 0b00000, rd, rs1),
+        Inst::FCVTQWU(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101011, 0b00001, rd, rs1),
+
+        Inst::FCVTLQ(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100011, 0b00010, rd, rs1)
+        }
+        Inst::FCVTLUQ(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100011, 0b00011, rd, rs1)
+        }
+        Inst::FCVTQL(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101011, 0b00010, rd, rs1)
+        }
+        Inst::FCVTQLU(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101011, 0b00011, rd, rs1)
+        }
+
+        Inst::FLH(rd, rs1, imm) => i_type(0b00001, 0b001, rd, rs1, imm),
+        Inst::FSH(rs2, rs1, imm) => s_type(0b01001, 0b001, rs1, rs2, imm),
+        Inst::FMADDH(rd, rs1, rs2, rs3, rm) => r4_type(0b10000, 0b10, rm, rd, rs1, rs2, rs3),
+        Inst::FMSUBH(rd, rs1, rs2, rs3, rm) => r4_type(0b10001, 0b10, rm, rd, rs1, rs2, rs3),
+        Inst::FNMSUBH(rd, rs1, rs2, rs3, rm) => r4_type(0b10010, 0b10, rm, rd, rs1, rs2, rs3),
+        Inst::FNMADDH(rd, rs1, rs2, rs3, rm) => r4_type(0b10011, 0b10, rm, rd, rs1, rs2, rs3),
+        Inst::FADDH(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000010, rd, rs1, rs2),
+        Inst::FSUBH(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0000110, rd, rs1, rs2),
+        Inst::FMULH(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001010, rd, rs1, rs2),
+        Inst::FDIVH(rd, rs1, rs2, rm) => r_type(0b10100, rm as u8, 0b0001110, rd, rs1, rs2),
+        Inst::FSQRTH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0101110, 0b00000, rd, rs1),
+        Inst::FSGNJH(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010010, rd, rs1, rs2),
+        Inst::FSGNJNH(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010010, rd, rs1, rs2),
+        Inst::FSGNJXH(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010010, rd, rs1, rs2),
+        Inst::FMINH(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b0010110, rd, rs1, rs2),
+        Inst::FMAXH(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b0010110, rd, rs1, rs2),
+        Inst::FCVTSH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100000, 0b00010, rd, rs1),
+        Inst::FCVTHS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100010, 0b00000, rd, rs1),
+        Inst::FCVTDH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100001, 0b00010, rd, rs1),
+        Inst::FCVTHD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100010, 0b00001, rd, rs1),
+        Inst::FCVTQH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100011, 0b00010, rd, rs1),
+        Inst::FCVTHQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100010, 0b00011, rd, rs1),
+        Inst::FEQH(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b1010010, rd, rs1, rs2),
+        Inst::FLTH(rd, rs1, rs2) => r_type(0b10100, 0b001, 0b1010010, rd, rs1, rs2),
+        Inst::FLEH(rd, rs1, rs2) => r_type(0b10100, 0b000, 0b1010010, rd, rs1, rs2),
+        Inst::FCLASSH(rd, rs1) => r2_type(0b10100, 0b001, 0b1110010, 0b00000, rd, rs1),
+        Inst::FCVTWH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100010, 0b00000, rd, rs1),
+        Inst::FCVTWUH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1100010, 0b00001, rd, rs1),
+        Inst::FCVTHW(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101010, 0b00000, rd, rs1),
+        Inst::FCVTHWU(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b1101010, 0b00001, rd, rs1),
+        Inst::FMVXH(rd, rs1) => r2_type(0b10100, 0b000, 0b1110010, 0b00000, rd, rs1),
+        Inst::FMVHX(rd, rs1) => r2_type(0b10100, 0b000, 0b1111010, 0b00000, rd, rs1),
+        Inst::FCVTLH(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100010, 0b00010, rd, rs1)
+        }
+        Inst::FCVTLUH(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1100010, 0b00011, rd, rs1)
+        }
+        Inst::FCVTHL(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101010, 0b00010, rd, rs1)
+        }
+        Inst::FCVTHLU(rd, rs1, rm) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b10100, rm as u8, 0b1101010, 0b00011, rd, rs1)
+        }
+
+        Inst::FLIS(rd, fli) => Ok(word(0b10100, 0b000, rd as u8, fli.to_u8(), 0b1111000 << 5 | 0b00001)),
+        Inst::FLID(rd, fli) => Ok(word(0b10100, 0b000, rd as u8, fli.to_u8(), 0b1111001 << 5 | 0b00001)),
+        Inst::FLIH(rd, fli) => Ok(word(0b10100, 0b000, rd as u8, fli.to_u8(), 0b1111010 << 5 | 0b00001)),
+        Inst::FLIQ(rd, fli) => Ok(word(0b10100, 0b000, rd as u8, fli.to_u8(), 0b1111011 << 5 | 0b00001)),
+        Inst::FMINMS(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010100, rd, rs1, rs2),
+        Inst::FMAXMS(rd, rs1, rs2) => r_type(0b10100, 0b011, 0b0010100, rd, rs1, rs2),
+        Inst::FROUNDS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100000, 0b00100, rd, rs1),
+        Inst::FROUNDNXS(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100000, 0b00101, rd, rs1),
+        Inst::FLEQS(rd, rs1, rs2) => r_type(0b10100, 0b100, 0b1010000, rd, rs1, rs2),
+        Inst::FLTQS(rd, rs1, rs2) => r_type(0b10100, 0b101, 0b1010000, rd, rs1, rs2),
+        Inst::FMINMD(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010101, rd, rs1, rs2),
+        Inst::FMAXMD(rd, rs1, rs2) => r_type(0b10100, 0b011, 0b0010101, rd, rs1, rs2),
+        Inst::FROUNDD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100001, 0b00100, rd, rs1),
+        Inst::FROUNDNXD(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100001, 0b00101, rd, rs1),
+        Inst::FLEQD(rd, rs1, rs2) => r_type(0b10100, 0b100, 0b1010001, rd, rs1, rs2),
+        Inst::FLTQD(rd, rs1, rs2) => r_type(0b10100, 0b101, 0b1010001, rd, rs1, rs2),
+        Inst::FMINMH(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010110, rd, rs1, rs2),
+        Inst::FMAXMH(rd, rs1, rs2) => r_type(0b10100, 0b011, 0b0010110, rd, rs1, rs2),
+        Inst::FROUNDH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100010, 0b00100, rd, rs1),
+        Inst::FROUNDNXH(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100010, 0b00101, rd, rs1),
+        Inst::FLEQH(rd, rs1, rs2) => r_type(0b10100, 0b100, 0b1010010, rd, rs1, rs2),
+        Inst::FLTQH(rd, rs1, rs2) => r_type(0b10100, 0b101, 0b1010010, rd, rs1, rs2),
+        Inst::FMINMQ(rd, rs1, rs2) => r_type(0b10100, 0b010, 0b0010111, rd, rs1, rs2),
+        Inst::FMAXMQ(rd, rs1, rs2) => r_type(0b10100, 0b011, 0b0010111, rd, rs1, rs2),
+        Inst::FROUNDQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100011, 0b00100, rd, rs1),
+        Inst::FROUNDNXQ(rd, rs1, rm) => r2_type(0b10100, rm as u8, 0b0100011, 0b00101, rd, rs1),
+        Inst::FLEQQ(rd, rs1, rs2) => r_type(0b10100, 0b100, 0b1010011, rd, rs1, rs2),
+        Inst::FLTQQ(rd, rs1, rs2) => r_type(0b10100, 0b101, 0b1010011, rd, rs1, rs2),
+        Inst::FCVTMODWD(rd, rs1) => r2_type(0b10100, 0b001, 0b1100001, 0b01000, rd, rs1),
+        Inst::FMVHXD(rd, rs1) => {
+            xlen(bits == 32, bits)?;
+            r2_type(0b10100, 0b000, 0b1110001, 0b00001, rd, rs1)
+        }
+        Inst::FMVPDX(rd, rs1, rs2) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b10100, 0b000, 0b1011001, rd, rs1, rs2)
+        }
+        Inst::FMVHXQ(rd, rs1) => {
+            xlen(bits == 64, bits)?;
+            r2_type(0b10100, 0b000, 0b1110011, 0b00001, rd, rs1)
+        }
+        Inst::FMVPQX(rd, rs1, rs2) => {
+            xlen(bits == 64, bits)?;
+            r_type(0b10100, 0b000, 0b1011011, rd, rs1, rs2)
+        }
+
+        Inst::SFENCEVMA(rs1, rs2) => r_type(0b11100, 0b000, 0b0001001, Gpr::zero, rs1, rs2),
+        Inst::SINVALVMA(rs1, rs2) => r_type(0b11100, 0b000, 0b0001011, Gpr::zero, rs1, rs2),
+        Inst::SFENCEWINVAL => Ok(EncodedInst::word(0x18000073)),
+        Inst::SFENCEINVALIR => Ok(EncodedInst::word(0x18100073)),
+
+        Inst::HFENCEVVMA(rs1, rs2) => r_type(0b11100, 0b000, 0b0010001, Gpr::zero, rs1, rs2),
+        Inst::HFENCEGVMA(rs1, rs2) => r_type(0b11100, 0b000, 0b0110001, Gpr::zero, rs1, rs2),
+        Inst::HINVALVVMA(rs1, rs2) => r_type(0b11100, 0b000, 0b0010011, Gpr::zero, rs1, rs2),
+        Inst::HINVALGVMA(rs1, rs2) => r_type(0b11100, 0b000, 0b0110011, Gpr::zero, rs1, rs2),
+
+        Inst::HLVB(rd, rs1) => hlv(0b0110000, 0b00000, rd, rs1),
+        Inst::HLVBU(rd, rs1) => hlv(0b0110000, 0b00001, rd, rs1),
+        Inst::HLVH(rd, rs1) => hlv(0b0110010, 0b00000, rd, rs1),
+        Inst::HLVHU(rd, rs1) => hlv(0b0110010, 0b00001, rd, rs1),
+        Inst::HLVXHU(rd, rs1) => hlv(0b0110010, 0b00011, rd, rs1),
+        Inst::HLVW(rd, rs1) => hlv(0b0110100, 0b00000, rd, rs1),
+        Inst::HLVXWU(rd, rs1) => hlv(0b0110100, 0b00011, rd, rs1),
+        Inst::HSVB(rs1, rs2) => r_type(0b11100, 0b100, 0b0110001, Gpr::zero, rs1, rs2),
+        Inst::HSVH(rs1, rs2) => r_type(0b11100, 0b100, 0b0110011, Gpr::zero, rs1, rs2),
+        Inst::HSVW(rs1, rs2) => r_type(0b11100, 0b100, 0b0110101, Gpr::zero, rs1, rs2),
+
+        Inst::HLVWU(rd, rs1) => {
+            xlen(bits != 32, bits)?;
+            hlv(0b0110100, 0b00001, rd, rs1)
+        }
+        Inst::HLVD(rd, rs1) => {
+            xlen(bits != 32, bits)?;
+            hlv(0b0110110, 0b00000, rd, rs1)
+        }
+        Inst::HSVD(rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b11100, 0b100, 0b0110111, Gpr::zero, rs1, rs2)
+        }
+
+        // quadrant 0
+
+        Inst::CADDI4SPN(rd, imm) => {
+            let v = nonzero(uimm(imm, 10, 2)?, imm as i64)?;
+            Ok(c_word(0b000, place(v, 5, 4, 11) | place(v, 9, 6, 7) | place(v, 2, 2, 6) | place(v, 3, 3, 5) | c_regp(rd)? << 2, 0b00))
+        }
+        Inst::CFLD(rd, rs1, imm) => {
+            xlen(bits != 128, bits)?;
+            c_mem(0b001, c_regp(rd)?, rs1, c_ld_imm(imm)?, 0b00)
+        }
+        Inst::CLQ(rd, rs1, imm) => {
+            xlen(bits == 128, bits)?;
+            c_mem(0b001, c_regp(rd)?, rs1, c_lq_imm(imm)?, 0b00)
+        }
+        Inst::CLW(rd, rs1, imm) => c_mem(0b010, c_regp(rd)?, rs1, c_lw_imm(imm)?, 0b00),
+        Inst::CFLW(rd, rs1, imm) => {
+            xlen(bits == 32, bits)?;
+            c_mem(0b011, c_regp(rd)?, rs1, c_lw_imm(imm)?, 0b00)
+        }
+        Inst::CLD(rd, rs1, imm) => {
+            xlen(bits != 32, bits)?;
+            c_mem(0b011, c_regp(rd)?, rs1, c_ld_imm(imm)?, 0b00)
+        }
+        Inst::CFSD(rs2, rs1, imm) => {
+            xlen(bits != 128, bits)?;
+            c_mem(0b101, c_regp(rs2)?, rs1, c_ld_imm(imm)?, 0b00)
+        }
+        Inst::CSQ(rs2, rs1, imm) => {
+            xlen(bits == 128, bits)?;
+            c_mem(0b101, c_regp(rs2)?, rs1, c_lq_imm(imm)?, 0b00)
+        }
+        Inst::CSW(rs2, rs1, imm) => c_mem(0b110, c_regp(rs2)?, rs1, c_lw_imm(imm)?, 0b00),
+        Inst::CFSW(rs2, rs1, imm) => {
+            xlen(bits == 32, bits)?;
+            c_mem(0b111, c_regp(rs2)?, rs1, c_lw_imm(imm)?, 0b00)
+        }
+        Inst::CSD(rs2, rs1, imm) => {
+            xlen(bits != 32, bits)?;
+            c_mem(0b111, c_regp(rs2)?, rs1, c_ld_imm(imm)?, 0b00)
+        }
+
+        // quadrant 1
+
+        Inst::CNOP => Ok(EncodedInst::half(0x0001)),
+        Inst::CADDI(rd, imm) => {
+            nonzero(imm as u32, imm as i64)?;
+            Ok(c_word(0b000, c_reg_nz(rd)? << 7 | c_i_imm(imm)?, 0b01))
+        }
+        Inst::CJAL(imm) => {
+            xlen(bits == 32, bits)?;
+            Ok(c_word(0b001, c_j_imm(imm)?, 0b01))
+        }
+        Inst::CADDIW(rd, imm) => {
+            xlen(bits != 32, bits)?;
+            Ok(c_word(0b001, c_reg_nz(rd)? << 7 | c_i_imm(imm)?, 0b01))
+        }
+        Inst::CLI(rd, imm) => Ok(c_word(0b010, c_reg_nz(rd)? << 7 | c_i_imm(imm)?, 0b01)),
+        Inst::CADDI16SP(imm) => {
+            let v = nonzero(simm(imm, 10, 4)?, imm as i64)?;
+            Ok(c_word(0b011, place(v, 9, 9, 12) | (Gpr::sp as u16) << 7 | place(v, 4, 4, 6) | place(v, 6, 6, 5) | place(v, 8, 7, 3) | place(v, 5, 5, 2), 0b01))
+        }
+        Inst::CLUI(rd, imm) => {
+            if rd == Gpr::sp {
+                return Err(EncodeError::BadRegister(rd as u8));
+            }
+            let v = match imm {
+                0x00001..=0x0001f | 0xfffe0..=0xfffff => imm,
+                _ => return Err(EncodeError::ImmOutOfRange(imm as i64)),
+            };
+            Ok(c_word(0b011, place(v, 5, 5, 12) | c_reg_nz(rd)? << 7 | place(v, 4, 0, 2), 0b01))
+        }
+        Inst::CSRLI(rd, shamt) => c_shift_b(0b00, rd, shamt, bits),
+        Inst::CSRLI64(rd) => {
+            xlen(bits == 128, bits)?;
+            Ok(c_word(0b100, c_regp(rd)? << 7, 0b01))
+        }
+        Inst::CSRAI(rd, shamt) => c_shift_b(0b01, rd, shamt, bits),
+        Inst::CSRAI64(rd) => {
+            xlen(bits == 128, bits)?;
+            Ok(c_word(0b100, 0b01 << 10 | c_regp(rd)? << 7, 0b01))
+        }
+        Inst::CANDI(rd, imm) => Ok(c_word(0b100, 0b10 << 10 | c_regp(rd)? << 7 | c_i_imm(imm)?, 0b01)),
+        Inst::CSUB(rd, rs2) => c_arith(0b0, 0b00, rd, rs2),
+        Inst::CXOR(rd, rs2) => c_arith(0b0, 0b01, rd, rs2),
+        Inst::COR(rd, rs2) => c_arith(0b0, 0b10, rd, rs2),
+        Inst::CAND(rd, rs2) => c_arith(0b0, 0b11, rd, rs2),
+        Inst::CSUBW(rd, rs2) => {
+            xlen(bits != 32, bits)?;
+            c_arith(0b1, 0b00, rd, rs2)
+        }
+        Inst::CADDW(rd, rs2) => {
+            xlen(bits != 32, bits)?;
+            c_arith(0b1, 0b01, rd, rs2)
+        }
+        Inst::CJ(imm) => Ok(c_word(0b101, c_j_imm(imm)?, 0b01)),
+        Inst::CBEQZ(rs1, imm) => Ok(c_word(0b110, c_regp(rs1)? << 7 | c_b_imm(imm)?, 0b01)),
+        Inst::CBNEZ(rs1, imm) => Ok(c_word(0b111, c_regp(rs1)? << 7 | c_b_imm(imm)?, 0b01)),
+
+        // quadrant 2
+
+        Inst::CSLLI(rd, shamt) => Ok(c_word(0b000, c_reg_nz(rd)? << 7 | c_shamt(shamt, bits)?, 0b10)),
+        Inst::CSLLI64(rd) => {
+            xlen(bits == 128, bits)?;
+            Ok(c_word(0b000, c_reg_nz(rd)? << 7, 0b10))
+        }
+        Inst::CFLDSP(rd, imm) => {
+            xlen(bits != 128, bits)?;
+            Ok(c_word(0b001, (rd as u16) << 7 | c_ldsp_imm(imm)?, 0b10))
+        }
+        Inst::CLQSP(rd, imm) => {
+            xlen(bits == 128, bits)?;
+            Ok(c_word(0b001, c_reg_nz(rd)? << 7 | c_lqsp_imm(imm)?, 0b10))
+        }
+        Inst::CLWSP(rd, imm) => Ok(c_word(0b010, c_reg_nz(rd)? << 7 | c_lwsp_imm(imm)?, 0b10)),
+        Inst::CFLWSP(rd, imm) => {
+            xlen(bits == 32, bits)?;
+            Ok(c_word(0b011, (rd as u16) << 7 | c_lwsp_imm(imm)?, 0b10))
+        }
+        Inst::CLDSP(rd, imm) => {
+            xlen(bits != 32, bits)?;
+            Ok(c_word(0b011, c_reg_nz(rd)? << 7 | c_ldsp_imm(imm)?, 0b10))
+        }
+        Inst::CJR(rs1) => Ok(c_word(0b100, c_reg_nz(rs1)? << 7, 0b10)),
+        Inst::CMV(rd, rs2) => Ok(c_word(0b100, c_reg_nz(rd)? << 7 | c_reg_nz(rs2)? << 2, 0b10)),
+        Inst::CEBREAK => Ok(EncodedInst::half(0x9002)),
+        Inst::CJALR(rs1) => Ok(c_word(0b100, 1 << 12 | c_reg_nz(rs1)? << 7, 0b10)),
+        Inst::CADD(rd, rs2) => Ok(c_word(0b100, 1 << 12 | c_reg_nz(rd)? << 7 | c_reg_nz(rs2)? << 2, 0b10)),
+        Inst::CFSDSP(rs2, imm) => {
+            xlen(bits != 128, bits)?;
+            Ok(c_word(0b101, c_sdsp_imm(imm)? | (rs2 as u16) << 2, 0b10))
+        }
+        Inst::CSQSP(rs2, imm) => {
+            xlen(bits == 128, bits)?;
+            Ok(c_word(0b101, c_sqsp_imm(imm)? | (rs2 as u16) << 2, 0b10))
+        }
+        Inst::CSWSP(rs2, imm) => Ok(c_word(0b110, c_swsp_imm(imm)? | (rs2 as u16) << 2, 0b10)),
+        Inst::CFSWSP(rs2, imm) => {
+            xlen(bits == 32, bits)?;
+            Ok(c_word(0b111, c_swsp_imm(imm)? | (rs2 as u16) << 2, 0b10))
+        }
+        Inst::CSDSP(rs2, imm) => {
+            xlen(bits != 32, bits)?;
+            Ok(c_word(0b111, c_sdsp_imm(imm)? | (rs2 as u16) << 2, 0b10))
+        }
+
+        // Zcb
+
+        Inst::CLBU(rd, rs1, imm) => c_mem(0b100, c_regp(rd)?, rs1, c_lbu_imm(imm)?, 0b00),
+        Inst::CLHU(rd, rs1, imm) => c_mem(0b100, c_regp(rd)?, rs1, 0b001 << 10 | c_lh_imm(imm)?, 0b00),
+        Inst::CLH(rd, rs1, imm) => c_mem(0b100, c_regp(rd)?, rs1, 0b001 << 10 | 1 << 6 | c_lh_imm(imm)?, 0b00),
+        Inst::CSB(rs2, rs1, imm) => c_mem(0b100, c_regp(rs2)?, rs1, 0b010 << 10 | c_lbu_imm(imm)?, 0b00),
+        Inst::CSH(rs2, rs1, imm) => c_mem(0b100, c_regp(rs2)?, rs1, 0b011 << 10 | c_lh_imm(imm)?, 0b00),
+        Inst::CZEXTB(rd) => c_unary(0b11000, rd),
+        Inst::CSEXTB(rd) => c_unary(0b11001, rd),
+        Inst::CZEXTH(rd) => c_unary(0b11010, rd),
+        Inst::CSEXTH(rd) => c_unary(0b11011, rd),
+        Inst::CZEXTW(rd) => {
+            xlen(bits == 64, bits)?;
+            c_unary(0b11100, rd)
+        }
+        Inst::CNOT(rd) => c_unary(0b11101, rd),
+        Inst::CMUL(rd, rs2) => c_arith(0b1, 0b10, rd, rs2),
+
+        // Zcmp, Zcmt
+
+        Inst::CMPUSH(rlist, adj) => c_push_pop(0b11000, rlist, adj, bits),
+        Inst::CMPOP(rlist, adj) => c_push_pop(0b11010, rlist, adj, bits),
+        Inst::CMPOPRETZ(rlist, adj) => c_push_pop(0b11100, rlist, adj, bits),
+        Inst::CMPOPRET(rlist, adj) => c_push_pop(0b11110, rlist, adj, bits),
+        Inst::CMMVSA01(r1s, r2s) => {
+            xlen(bits != 128, bits)?;
+            if r1s == r2s {
+                return Err(EncodeError::Reserved);
+            }
+            Ok(c_word(0b101, 0b011 << 10 | c_sreg(r1s)? << 7 | 0b01 << 5 | c_sreg(r2s)? << 2, 0b10))
+        }
+        Inst::CMMVA01S(r1s, r2s) => {
+            xlen(bits != 128, bits)?;
+            Ok(c_word(0b101, 0b011 << 10 | c_sreg(r1s)? << 7 | 0b11 << 5 | c_sreg(r2s)? << 2, 0b10))
+        }
+        Inst::CMJT(index) => {
+            xlen(bits != 128, bits)?;
+            if index >= 32 {
+                return Err(EncodeError::ImmOutOfRange(index as i64));
+            }
+            Ok(c_word(0b101, (index as u16) << 2, 0b10))
+        }
+        Inst::CMJALT(index) => {
+            xlen(bits != 128, bits)?;
+            if index < 32 {
+                return Err(EncodeError::ImmOutOfRange(index as i64));
+            }
+            Ok(c_word(0b101, (index as u16) << 2, 0b10))
+        }
+
+        Inst::SH1ADD(rd, rs1, rs2) => r_type(0b01100, 0b010, 0x10, rd, rs1, rs2),
+        Inst::SH2ADD(rd, rs1, rs2) => r_type(0b01100, 0b100, 0x10, rd, rs1, rs2),
+        Inst::SH3ADD(rd, rs1, rs2) => r_type(0b01100, 0b110, 0x10, rd, rs1, rs2),
+
+        Inst::ADDUW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b000, 0x04, rd, rs1, rs2)
+        }
+        Inst::SH1ADDUW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b010, 0x10, rd, rs1, rs2)
+        }
+        Inst::SH2ADDUW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b100, 0x10, rd, rs1, rs2)
+        }
+        Inst::SH3ADDUW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b110, 0x10, rd, rs1, rs2)
+        }
+        Inst::SLLIUW(rd, rs1, shamt) => {
+            xlen(bits != 32, bits)?;
+            shift(0b00110, 0b001, 0x04, rd, rs1, shamt, 64)
+        }
+
+        Inst::ANDN(rd, rs1, rs2) => r_type(0b01100, 0b111, 0x20, rd, rs1, rs2),
+        Inst::ORN(rd, rs1, rs2) => r_type(0b01100, 0b110, 0x20, rd, rs1, rs2),
+        Inst::XNOR(rd, rs1, rs2) => r_type(0b01100, 0b100, 0x20, rd, rs1, rs2),
+        Inst::CLZ(rd, rs1) => r2_type(0b00100, 0b001, 0x30, 0b00000, rd, rs1),
+        Inst::CTZ(rd, rs1) => r2_type(0b00100, 0b001, 0x30, 0b00001, rd, rs1),
+        Inst::CPOP(rd, rs1) => r2_type(0b00100, 0b001, 0x30, 0b00010, rd, rs1),
+        Inst::MAX(rd, rs1, rs2) => r_type(0b01100, 0b110, 0x05, rd, rs1, rs2),
+        Inst::MAXU(rd, rs1, rs2) => r_type(0b01100, 0b111, 0x05, rd, rs1, rs2),
+        Inst::MIN(rd, rs1, rs2) => r_type(0b01100, 0b100, 0x05, rd, rs1, rs2),
+        Inst::MINU(rd, rs1, rs2) => r_type(0b01100, 0b101, 0x05, rd, rs1, rs2),
+        Inst::SEXTB(rd, rs1) => r2_type(0b00100, 0b001, 0x30, 0b00100, rd, rs1),
+        Inst::SEXTH(rd, rs1) => r2_type(0b00100, 0b001, 0x30, 0b00101, rd, rs1),
+        Inst::ZEXTH(rd, rs1) if bits == 32 => r2_type(0b01100, 0b100, 0x04, 0b00000, rd, rs1),
+        Inst::ZEXTH(rd, rs1) => r2_type(0b01110, 0b100, 0x04, 0b00000, rd, rs1),
+        Inst::ROL(rd, rs1, rs2) => r_type(0b01100, 0b001, 0x30, rd, rs1, rs2),
+        Inst::ROR(rd, rs1, rs2) => r_type(0b01100, 0b101, 0x30, rd, rs1, rs2),
+        Inst::RORI(rd, rs1, shamt) => {
+            xlen(bits != 128, bits)?;
+            shift(0b00100, 0b101, 0x30, rd, rs1, shamt, bits)
+        }
+        Inst::ORCB(rd, rs1) => r2_type(0b00100, 0b101, 0x14, 0b00111, rd, rs1),
+        Inst::REV8(rd, rs1) if bits == 32 => r2_type(0b00100, 0b101, 0x34, 0b11000, rd, rs1),
+        Inst::REV8(rd, rs1) => {
+            xlen(bits != 128, bits)?;
+            r2_type(0b00100, 0b101, 0x35, 0b11000, rd, rs1)
+        }
+
+        Inst::CLZW(rd, rs1) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b00110, 0b001, 0x30, 0b00000, rd, rs1)
+        }
+        Inst::CTZW(rd, rs1) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b00110, 0b001, 0x30, 0b00001, rd, rs1)
+        }
+        Inst::CPOPW(rd, rs1) => {
+            xlen(bits != 32, bits)?;
+            r2_type(0b00110, 0b001, 0x30, 0b00010, rd, rs1)
+        }
+        Inst::ROLW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b001, 0x30, rd, rs1, rs2)
+        }
+        Inst::RORW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b101, 0x30, rd, rs1, rs2)
+        }
+        Inst::RORIW(rd, rs1, shamt) => {
+            xlen(bits != 32, bits)?;
+            shift(0b00110, 0b101, 0x30, rd, rs1, shamt, 32)
+        }
+
+        Inst::CLMUL(rd, rs1, rs2) => r_type(0b01100, 0b001, 0x05, rd, rs1, rs2),
+        Inst::CLMULH(rd, rs1, rs2) => r_type(0b01100, 0b011, 0x05, rd, rs1, rs2),
+        Inst::CLMULR(rd, rs1, rs2) => r_type(0b01100, 0b010, 0x05, rd, rs1, rs2),
+
+        Inst::BCLR(rd, rs1, rs2) => r_type(0b01100, 0b001, 0x24, rd, rs1, rs2),
+        Inst::BCLRI(rd, rs1, shamt) => {
+            xlen(bits != 128, bits)?;
+            shift(0b00100, 0b001, 0x24, rd, rs1, shamt, bits)
+        }
+        Inst::BEXT(rd, rs1, rs2) => r_type(0b01100, 0b101, 0x24, rd, rs1, rs2),
+        Inst::BEXTI(rd, rs1, shamt) => {
+            xlen(bits != 128, bits)?;
+            shift(0b00100, 0b101, 0x24, rd, rs1, shamt, bits)
+        }
+        Inst::BINV(rd, rs1, rs2) => r_type(0b01100, 0b001, 0x34, rd, rs1, rs2),
+        Inst::BINVI(rd, rs1, shamt) => {
+            xlen(bits != 128, bits)?;
+            shift(0b00100, 0b001, 0x34, rd, rs1, shamt, bits)
+        }
+        Inst::BSET(rd, rs1, rs2) => r_type(0b01100, 0b001, 0x14, rd, rs1, rs2),
+        Inst::BSETI(rd, rs1, shamt) => {
+            xlen(bits != 128, bits)?;
+            shift(0b00100, 0b001, 0x14, rd, rs1, shamt, bits)
+        }
+
+        Inst::PACK(rd, rs1, rs2) => r_type(0b01100, 0b100, 0x04, rd, rs1, rs2),
+        Inst::PACKH(rd, rs1, rs2) => r_type(0b01100, 0b111, 0x04, rd, rs1, rs2),
+        Inst::BREV8(rd, rs1) => r2_type(0b00100, 0b101, 0x34, 0b00111, rd, rs1),
+        Inst::PACKW(rd, rs1, rs2) => {
+            xlen(bits != 32, bits)?;
+            r_type(0b01110, 0b100, 0x04, rd, rs1, rs2)
+        }
+        Inst::ZIP(rd, rs1) => {
+            xlen(bits == 32, bits)?;
+            r2_type(0b00100, 0b001, 0x04, 0b01111, rd, rs1)
+        }
+        Inst::UNZIP(rd, rs1) => {
+            xlen(bits == 32, bits)?;
+            r2_type(0b00100, 0b101, 0x04, 0b01111, rd, rs1)
+        }
+
+        Inst::XPERM4(rd, rs1, rs2) => r_type(0b01100, 0b010, 0x14, rd, rs1, rs2),
+        Inst::XPERM8(rd, rs1, rs2) => r_type(0b01100, 0b100, 0x14, rd, rs1, rs2),
+
+        Inst::AES32DSI(rd, rs1, rs2, bs) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, bsel(bs)? | 0b10101, rd, rs1, rs2)
+        }
+        Inst::AES32DSMI(rd, rs1, rs2, bs) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, bsel(bs)? | 0b10111, rd, rs1, rs2)
+        }
+        Inst::AES32ESI(rd, rs1, rs2, bs) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, bsel(bs)? | 0b10001, rd, rs1, rs2)
+        }
+        Inst::AES32ESMI(rd, rs1, rs2, bs) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, bsel(bs)? | 0b10011, rd, rs1, rs2)
+        }
+        Inst::AES64DS(rd, rs1, rs2) => {
+            xlen(bits == 64, bits)?;
+            r_type(0b01100, 0b000, 0x1d, rd, rs1, rs2)
+        }
+        Inst::AES64DSM(rd, rs1, rs2) => {
+            xlen(bits == 64, bits)?;
+            r_type(0b01100, 0b000, 0x1f, rd, rs1, rs2)
+        }
+        Inst::AES64IM(rd, rs1) => {
+            xlen(bits == 64, bits)?;
+            r2_type(0b00100, 0b001, 0x18, 0b00000, rd, rs1)
+        }
+        Inst::AES64ES(rd, rs1, rs2) => {
+            xlen(bits == 64, bits)?;
+            r_type(0b01100, 0b000, 0x19, rd, rs1, rs2)
+        }
+        Inst::AES64ESM(rd, rs1, rs2) => {
+            xlen(bits == 64, bits)?;
+            r_type(0b01100, 0b000, 0x1b, rd, rs1, rs2)
+        }
+        Inst::AES64KS1I(rd, rs1, rnum) => {
+            xlen(bits == 64, bits)?;
+            if rnum > 0xa {
+                return Err(EncodeError::ImmOutOfRange(rnum as i64));
+            }
+            r2_type(0b00100, 0b001, 0x18, 0b10000 | rnum, rd, rs1)
+        }
+        Inst::AES64KS2(rd, rs1, rs2) => {
+            xlen(bits == 64, bits)?;
+            r_type(0b01100, 0b000, 0x3f, rd, rs1, rs2)
+        }
+
+        Inst::SHA256SIG0(rd, rs1) => r2_type(0b00100, 0b001, 0x08, 0b00010, rd, rs1),
+        Inst::SHA256SIG1(rd, rs1) => r2_type(0b00100, 0b001, 0x08, 0b00011, rd, rs1),
+        Inst::SHA256SUM0(rd, rs1) => r2_type(0b00100, 0b001, 0x08, 0b00000, rd, rs1),
+        Inst::SHA256SUM1(rd, rs1) => r2_type(0b00100, 0b001, 0x08, 0b00001, rd, rs1),
+        Inst::SHA512SIG0H(rd, rs1, rs2) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, 0x2e, rd, rs1, rs2)
+        }
+        Inst::SHA512SIG0L(rd, rs1, rs2) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, 0x2a, rd, rs1, rs2)
+        }
+        Inst::SHA512SIG1H(rd, rs1, rs2) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, 0x2f, rd, rs1, rs2)
+        }
+        Inst::SHA512SIG1L(rd, rs1, rs2) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, 0x2b, rd, rs1, rs2)
+        }
+        Inst::SHA512SUM0R(rd, rs1, rs2) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, 0x28, rd, rs1, rs2)
+        }
+        Inst::SHA512SUM1R(rd, rs1, rs2) => {
+            xlen(bits == 32, bits)?;
+            r_type(0b01100, 0b000, 0x29, rd, rs1, rs2)
+        }
+        Inst::SHA512SIG0(rd, rs1) => {
+            xlen(bits == 64, bits)?;
+            r2_type(0b00100, 0b001, 0x08, 0b00110, rd, rs1)
+        }
+        Inst::SHA512SIG1(rd, rs1) => {
+            xlen(bits == 64, bits)?;
+            r2_type(0b00100, 0b001, 0x08, 0b00111, rd, rs1)
+        }
+        Inst::SHA512SUM0(rd, rs1) => {
+            xlen(bits == 64, bits)?;
+            r2_type(0b00100, 0b001, 0x08, 0b00100, rd, rs1)
+        }
+        Inst::SHA512SUM1(rd, rs1) => {
+            xlen(bits == 64, bits)?;
+            r2_type(0b00100, 0b001, 0x08, 0b00101, rd, rs1)
+        }
+
+        Inst::SM4ED(rd, rs1, rs2, bs) => r_type(0b01100, 0b000, bsel(bs)? | 0b11000, rd, rs1, rs2),
+        Inst::SM4KS(rd, rs1, rs2, bs) => r_type(0b01100, 0b000, bsel(bs)? | 0b11010, rd, rs1, rs2),
+        Inst::SM3P0(rd, rs1) => r2_type(0b00100, 0b001, 0x08, 0b01000, rd, rs1),
+        Inst::SM3P1(rd, rs1) => r2_type(0b00100, 0b001, 0x08, 0b01001, rd, rs1),
+
+        Inst::CZEROEQZ(rd, rs1, rs2) => r_type(0b01100, 0b101, 0x07, rd, rs1, rs2),
+        Inst::CZERONEZ(rd, rs1, rs2) => r_type(0b01100, 0b111, 0x07, rd, rs1, rs2),
+
+        Inst::CBOCLEAN(rs1) => {
+            xlen(bits != 128, bits)?;
+            Ok(word(0b00011, 0b010, 0, rs1 as u8, 0x001))
+        }
+        Inst::CBOFLUSH(rs1) => {
+            xlen(bits != 128, bits)?;
+            Ok(word(0b00011, 0b010, 0, rs1 as u8, 0x002))
+        }
+        Inst::CBOINVAL(rs1) => {
+            xlen(bits != 128, bits)?;
+            Ok(word(0b00011, 0b010, 0, rs1 as u8, 0x000))
+        }
+        Inst::CBOZERO(rs1) => {
+            xlen(bits != 128, bits)?;
+            Ok(word(0b00011, 0b010, 0, rs1 as u8, 0x004))
+        }
+
+        Inst::PREFETCHI(rs1, imm) => Ok(word(0b00100, 0b110, 0, rs1 as u8, simm(imm, 12, 5)?)),
+        Inst::PREFETCHR(rs1, imm) => Ok(word(0b00100, 0b110, 0, rs1 as u8, simm(imm, 12, 5)? | 0b00001)),
+        Inst::PREFETCHW(rs1, imm) => Ok(word(0b00100, 0b110, 0, rs1 as u8, simm(imm, 12, 5)? | 0b00011)),
+
+        Inst::WRSNTO => Ok(EncodedInst::word(0x00d00073)),
+        Inst::WRSSTO => Ok(EncodedInst::word(0x01d00073)),
+
+        Inst::NTLP1 => Ok(EncodedInst::word(0x00200033)),
+        Inst::NTLPALL => Ok(EncodedInst::word(0x00300033)),
+        Inst::NTLS1 => Ok(EncodedInst::word(0x00400033)),
+        Inst::NTLALL => Ok(EncodedInst::word(0x00500033)),
+
+        Inst::VSETVLI(rd, rs1, vtype) => Ok(word(0b10101, 0b111, rd as u8, rs1 as u8, uimm(vtype.to_u16() as u32, 11, 0)?)),
+        Inst::VSETIVLI(rd, avl, vtype) => {
+            Ok(word(0b10101, 0b111, rd as u8, uimm(avl, 5, 0)? as u8, 0b11 << 10 | uimm(vtype.to_u16() as u32, 10, 0)?))
+        }
+        Inst::VSETVL(rd, rs1, rs2) => r_type(0b10101, 0b111, 0b1000000, rd, rs1, rs2),
+
+        Inst::VLE(vd, rs1, eew, nf, vm) => v_mem(0b00001, 0b00, nf, eew, vm, vd, rs1, 0b00000),
+        Inst::VLEFF(vd, rs1, eew, nf, vm) => v_mem(0b00001, 0b00, nf, eew, vm, vd, rs1, 0b10000),
+        Inst::VLM(vd, rs1) => v_mem(0b00001, 0b00, 1, Sew::E8, Vm::Unmasked, vd, rs1, 0b01011),
+        Inst::VLSE(vd, rs1, rs2, eew, nf, vm) => v_mem(0b00001, 0b10, nf, eew, vm, vd, rs1, rs2 as u8),
+        Inst::VLUXEI(vd, rs1, vs2, eew, nf, vm) => v_mem(0b00001, 0b01, nf, eew, vm, vd, rs1, vs2 as u8),
+        Inst::VLOXEI(vd, rs1, vs2, eew, nf, vm) => v_mem(0b00001, 0b11, nf, eew, vm, vd, rs1, vs2 as u8),
+        Inst::VLRE(vd, rs1, eew, nf) => {
+            v_mem(0b00001, 0b00, whole_regs(nf, vd as u8)?, eew, Vm::Unmasked, vd, rs1, 0b01000)
+        }
+        Inst::VSE(vs3, rs1, eew, nf, vm) => v_mem(0b01001, 0b00, nf, eew, vm, vs3, rs1, 0b00000),
+        Inst::VSM(vs3, rs1) => v_mem(0b01001, 0b00, 1, Sew::E8, Vm::Unmasked, vs3, rs1, 0b01011),
+        Inst::VSSE(vs3, rs1, rs2, eew, nf, vm) => v_mem(0b01001, 0b10, nf, eew, vm, vs3, rs1, rs2 as u8),
+        Inst::VSUXEI(vs3, rs1, vs2, eew, nf, vm) => v_mem(0b01001, 0b01, nf, eew, vm, vs3, rs1, vs2 as u8),
+        Inst::VSOXEI(vs3, rs1, vs2, eew, nf, vm) => v_mem(0b01001, 0b11, nf, eew, vm, vs3, rs1, vs2 as u8),
+        Inst::VSR(vs3, rs1, nf) => {
+            v_mem(0b01001, 0b00, whole_regs(nf, vs3 as u8)?, Sew::E8, Vm::Unmasked, vs3, rs1, 0b01000)
+        }
+
+        Inst::VADDVV(vd, vs2, vs1, vm) => v_type(0b000, 0b000000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VADDVX(vd, vs2, rs1, vm) => v_type(0b100, 0b000000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VADDVI(vd, vs2, imm, vm) => v_type(0b011, 0b000000, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VSUBVV(vd, vs2, vs1, vm) => v_type(0b000, 0b000010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSUBVX(vd, vs2, rs1, vm) => v_type(0b100, 0b000010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VRSUBVX(vd, vs2, rs1, vm) => v_type(0b100, 0b000011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VRSUBVI(vd, vs2, imm, vm) => v_type(0b011, 0b000011, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMINUVV(vd, vs2, vs1, vm) => v_type(0b000, 0b000100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMINUVX(vd, vs2, rs1, vm) => v_type(0b100, 0b000100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMINVV(vd, vs2, vs1, vm) => v_type(0b000, 0b000101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMINVX(vd, vs2, rs1, vm) => v_type(0b100, 0b000101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMAXUVV(vd, vs2, vs1, vm) => v_type(0b000, 0b000110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMAXUVX(vd, vs2, rs1, vm) => v_type(0b100, 0b000110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMAXVV(vd, vs2, vs1, vm) => v_type(0b000, 0b000111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMAXVX(vd, vs2, rs1, vm) => v_type(0b100, 0b000111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VANDVV(vd, vs2, vs1, vm) => v_type(0b000, 0b001001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VANDVX(vd, vs2, rs1, vm) => v_type(0b100, 0b001001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VANDVI(vd, vs2, imm, vm) => v_type(0b011, 0b001001, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VORVV(vd, vs2, vs1, vm) => v_type(0b000, 0b001010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VORVX(vd, vs2, rs1, vm) => v_type(0b100, 0b001010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VORVI(vd, vs2, imm, vm) => v_type(0b011, 0b001010, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VXORVV(vd, vs2, vs1, vm) => v_type(0b000, 0b001011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VXORVX(vd, vs2, rs1, vm) => v_type(0b100, 0b001011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VXORVI(vd, vs2, imm, vm) => v_type(0b011, 0b001011, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VRGATHERVV(vd, vs2, vs1, vm) => v_type(0b000, 0b001100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VRGATHERVX(vd, vs2, rs1, vm) => v_type(0b100, 0b001100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VRGATHERVI(vd, vs2, imm, vm) => v_type(0b011, 0b001100, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VRGATHEREI16VV(vd, vs2, vs1, vm) => v_type(0b000, 0b001110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSLIDEUPVX(vd, vs2, rs1, vm) => v_type(0b100, 0b001110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSLIDEUPVI(vd, vs2, imm, vm) => v_type(0b011, 0b001110, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VSLIDEDOWNVX(vd, vs2, rs1, vm) => v_type(0b100, 0b001111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSLIDEDOWNVI(vd, vs2, imm, vm) => v_type(0b011, 0b001111, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VADCVVM(vd, vs2, vs1) => v_type(0b000, 0b010000, Vm::Masked, vd, vs2 as u8, vs1 as u8),
+        Inst::VADCVXM(vd, vs2, rs1) => v_type(0b100, 0b010000, Vm::Masked, vd, vs2 as u8, rs1 as u8),
+        Inst::VADCVIM(vd, vs2, imm) => v_type(0b011, 0b010000, Vm::Masked, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMADCVVM(vd, vs2, vs1) => v_type(0b000, 0b010001, Vm::Masked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMADCVXM(vd, vs2, rs1) => v_type(0b100, 0b010001, Vm::Masked, vd, vs2 as u8, rs1 as u8),
+        Inst::VMADCVIM(vd, vs2, imm) => v_type(0b011, 0b010001, Vm::Masked, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMADCVV(vd, vs2, vs1) => v_type(0b000, 0b010001, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMADCVX(vd, vs2, rs1) => v_type(0b100, 0b010001, Vm::Unmasked, vd, vs2 as u8, rs1 as u8),
+        Inst::VMADCVI(vd, vs2, imm) => v_type(0b011, 0b010001, Vm::Unmasked, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VSBCVVM(vd, vs2, vs1) => v_type(0b000, 0b010010, Vm::Masked, vd, vs2 as u8, vs1 as u8),
+        Inst::VSBCVXM(vd, vs2, rs1) => v_type(0b100, 0b010010, Vm::Masked, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSBCVVM(vd, vs2, vs1) => v_type(0b000, 0b010011, Vm::Masked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSBCVXM(vd, vs2, rs1) => v_type(0b100, 0b010011, Vm::Masked, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSBCVV(vd, vs2, vs1) => v_type(0b000, 0b010011, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSBCVX(vd, vs2, rs1) => v_type(0b100, 0b010011, Vm::Unmasked, vd, vs2 as u8, rs1 as u8),
+        Inst::VMERGEVVM(vd, vs2, vs1) => v_type(0b000, 0b010111, Vm::Masked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMERGEVXM(vd, vs2, rs1) => v_type(0b100, 0b010111, Vm::Masked, vd, vs2 as u8, rs1 as u8),
+        Inst::VMERGEVIM(vd, vs2, imm) => v_type(0b011, 0b010111, Vm::Masked, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMVVV(vd, vs1) => v_type(0b000, 0b010111, Vm::Unmasked, vd, 0, vs1 as u8),
+        Inst::VMVVX(vd, rs1) => v_type(0b100, 0b010111, Vm::Unmasked, vd, 0, rs1 as u8),
+        Inst::VMVVI(vd, imm) => v_type(0b011, 0b010111, Vm::Unmasked, vd, 0, simm(imm, 5, 0)? as u8),
+        Inst::VMSEQVV(vd, vs2, vs1, vm) => v_type(0b000, 0b011000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSEQVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSEQVI(vd, vs2, imm, vm) => v_type(0b011, 0b011000, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMSNEVV(vd, vs2, vs1, vm) => v_type(0b000, 0b011001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSNEVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSNEVI(vd, vs2, imm, vm) => v_type(0b011, 0b011001, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMSLTUVV(vd, vs2, vs1, vm) => v_type(0b000, 0b011010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSLTUVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSLTVV(vd, vs2, vs1, vm) => v_type(0b000, 0b011011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSLTVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSLEUVV(vd, vs2, vs1, vm) => v_type(0b000, 0b011100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSLEUVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSLEUVI(vd, vs2, imm, vm) => v_type(0b011, 0b011100, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMSLEVV(vd, vs2, vs1, vm) => v_type(0b000, 0b011101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMSLEVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSLEVI(vd, vs2, imm, vm) => v_type(0b011, 0b011101, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMSGTUVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSGTUVI(vd, vs2, imm, vm) => v_type(0b011, 0b011110, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VMSGTVX(vd, vs2, rs1, vm) => v_type(0b100, 0b011111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMSGTVI(vd, vs2, imm, vm) => v_type(0b011, 0b011111, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VSADDUVV(vd, vs2, vs1, vm) => v_type(0b000, 0b100000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSADDUVX(vd, vs2, rs1, vm) => v_type(0b100, 0b100000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSADDUVI(vd, vs2, imm, vm) => v_type(0b011, 0b100000, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VSADDVV(vd, vs2, vs1, vm) => v_type(0b000, 0b100001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSADDVX(vd, vs2, rs1, vm) => v_type(0b100, 0b100001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSADDVI(vd, vs2, imm, vm) => v_type(0b011, 0b100001, vm, vd, vs2 as u8, simm(imm, 5, 0)? as u8),
+        Inst::VSSUBUVV(vd, vs2, vs1, vm) => v_type(0b000, 0b100010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSSUBUVX(vd, vs2, rs1, vm) => v_type(0b100, 0b100010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSSUBVV(vd, vs2, vs1, vm) => v_type(0b000, 0b100011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSSUBVX(vd, vs2, rs1, vm) => v_type(0b100, 0b100011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSLLVV(vd, vs2, vs1, vm) => v_type(0b000, 0b100101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSLLVX(vd, vs2, rs1, vm) => v_type(0b100, 0b100101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSLLVI(vd, vs2, imm, vm) => v_type(0b011, 0b100101, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VSMULVV(vd, vs2, vs1, vm) => v_type(0b000, 0b100111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSMULVX(vd, vs2, rs1, vm) => v_type(0b100, 0b100111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMVNRV(vd, vs2, nr) => v_type(0b011, 0b100111, Vm::Unmasked, vd, vs2 as u8, whole_regs(nr, vd as u8 | vs2 as u8)? - 1),
+        Inst::VSRLVV(vd, vs2, vs1, vm) => v_type(0b000, 0b101000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSRLVX(vd, vs2, rs1, vm) => v_type(0b100, 0b101000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSRLVI(vd, vs2, imm, vm) => v_type(0b011, 0b101000, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VSRAVV(vd, vs2, vs1, vm) => v_type(0b000, 0b101001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSRAVX(vd, vs2, rs1, vm) => v_type(0b100, 0b101001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSRAVI(vd, vs2, imm, vm) => v_type(0b011, 0b101001, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VSSRLVV(vd, vs2, vs1, vm) => v_type(0b000, 0b101010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSSRLVX(vd, vs2, rs1, vm) => v_type(0b100, 0b101010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSSRLVI(vd, vs2, imm, vm) => v_type(0b011, 0b101010, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VSSRAVV(vd, vs2, vs1, vm) => v_type(0b000, 0b101011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VSSRAVX(vd, vs2, rs1, vm) => v_type(0b100, 0b101011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSSRAVI(vd, vs2, imm, vm) => v_type(0b011, 0b101011, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VNSRLWV(vd, vs2, vs1, vm) => v_type(0b000, 0b101100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VNSRLWX(vd, vs2, rs1, vm) => v_type(0b100, 0b101100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VNSRLWI(vd, vs2, imm, vm) => v_type(0b011, 0b101100, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VNSRAWV(vd, vs2, vs1, vm) => v_type(0b000, 0b101101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VNSRAWX(vd, vs2, rs1, vm) => v_type(0b100, 0b101101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VNSRAWI(vd, vs2, imm, vm) => v_type(0b011, 0b101101, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VNCLIPUWV(vd, vs2, vs1, vm) => v_type(0b000, 0b101110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VNCLIPUWX(vd, vs2, rs1, vm) => v_type(0b100, 0b101110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VNCLIPUWI(vd, vs2, imm, vm) => v_type(0b011, 0b101110, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VNCLIPWV(vd, vs2, vs1, vm) => v_type(0b000, 0b101111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VNCLIPWX(vd, vs2, rs1, vm) => v_type(0b100, 0b101111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VNCLIPWI(vd, vs2, imm, vm) => v_type(0b011, 0b101111, vm, vd, vs2 as u8, uimm(imm, 5, 0)? as u8),
+        Inst::VWREDSUMUVS(vd, vs2, vs1, vm) => v_type(0b000, 0b110000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWREDSUMVS(vd, vs2, vs1, vm) => v_type(0b000, 0b110001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDSUMVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDANDVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDORVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDXORVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDMINUVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDMINVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDMAXUVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREDMAXVS(vd, vs2, vs1, vm) => v_type(0b010, 0b000111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VAADDUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b001000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VAADDUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b001000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VAADDVV(vd, vs2, vs1, vm) => v_type(0b010, 0b001001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VAADDVX(vd, vs2, rs1, vm) => v_type(0b110, 0b001001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VASUBUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b001010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VASUBUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b001010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VASUBVV(vd, vs2, vs1, vm) => v_type(0b010, 0b001011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VASUBVX(vd, vs2, rs1, vm) => v_type(0b110, 0b001011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSLIDE1UPVX(vd, vs2, rs1, vm) => v_type(0b110, 0b001110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VSLIDE1DOWNVX(vd, vs2, rs1, vm) => v_type(0b110, 0b001111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMVXS(rd, vs2) => v_type(0b010, 0b010000, Vm::Unmasked, rd, vs2 as u8, 0b00000),
+        Inst::VMVSX(vd, rs1) => v_type(0b110, 0b010000, Vm::Unmasked, vd, 0, rs1 as u8),
+        Inst::VCPOPM(rd, vs2, vm) => v_type(0b010, 0b010000, vm, rd, vs2 as u8, 0b10000),
+        Inst::VFIRSTM(rd, vs2, vm) => v_type(0b010, 0b010000, vm, rd, vs2 as u8, 0b10001),
+        Inst::VZEXTVF8(vd, vs2, vm) => v_type(0b010, 0b010010, vm, vd, vs2 as u8, 0b00010),
+        Inst::VSEXTVF8(vd, vs2, vm) => v_type(0b010, 0b010010, vm, vd, vs2 as u8, 0b00011),
+        Inst::VZEXTVF4(vd, vs2, vm) => v_type(0b010, 0b010010, vm, vd, vs2 as u8, 0b00100),
+        Inst::VSEXTVF4(vd, vs2, vm) => v_type(0b010, 0b010010, vm, vd, vs2 as u8, 0b00101),
+        Inst::VZEXTVF2(vd, vs2, vm) => v_type(0b010, 0b010010, vm, vd, vs2 as u8, 0b00110),
+        Inst::VSEXTVF2(vd, vs2, vm) => v_type(0b010, 0b010010, vm, vd, vs2 as u8, 0b00111),
+        Inst::VMSBFM(vd, vs2, vm) => v_type(0b010, 0b010100, vm, vd, vs2 as u8, 0b00001),
+        Inst::VMSOFM(vd, vs2, vm) => v_type(0b010, 0b010100, vm, vd, vs2 as u8, 0b00010),
+        Inst::VMSIFM(vd, vs2, vm) => v_type(0b010, 0b010100, vm, vd, vs2 as u8, 0b00011),
+        Inst::VIOTAM(vd, vs2, vm) => v_type(0b010, 0b010100, vm, vd, vs2 as u8, 0b10000),
+        Inst::VIDV(vd, vm) => v_type(0b010, 0b010100, vm, vd, 0, 0b10001),
+        Inst::VCOMPRESSVM(vd, vs2, vs1) => v_type(0b010, 0b010111, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMANDNMM(vd, vs2, vs1) => v_type(0b010, 0b011000, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMANDMM(vd, vs2, vs1) => v_type(0b010, 0b011001, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMORMM(vd, vs2, vs1) => v_type(0b010, 0b011010, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMXORMM(vd, vs2, vs1) => v_type(0b010, 0b011011, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMORNMM(vd, vs2, vs1) => v_type(0b010, 0b011100, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMNANDMM(vd, vs2, vs1) => v_type(0b010, 0b011101, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMNORMM(vd, vs2, vs1) => v_type(0b010, 0b011110, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VMXNORMM(vd, vs2, vs1) => v_type(0b010, 0b011111, Vm::Unmasked, vd, vs2 as u8, vs1 as u8),
+        Inst::VDIVUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VDIVUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VDIVVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VDIVVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VREMUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREMUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VREMVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VREMVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMULHUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMULHUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMULVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMULVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMULHSUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMULHSUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMULHVV(vd, vs2, vs1, vm) => v_type(0b010, 0b100111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMULHVX(vd, vs2, rs1, vm) => v_type(0b110, 0b100111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMADDVV(vd, vs1, vs2, vm) => v_type(0b010, 0b101001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMADDVX(vd, rs1, vs2, vm) => v_type(0b110, 0b101001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VNMSUBVV(vd, vs1, vs2, vm) => v_type(0b010, 0b101011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VNMSUBVX(vd, rs1, vs2, vm) => v_type(0b110, 0b101011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMACCVV(vd, vs1, vs2, vm) => v_type(0b010, 0b101101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMACCVX(vd, rs1, vs2, vm) => v_type(0b110, 0b101101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VNMSACVV(vd, vs1, vs2, vm) => v_type(0b010, 0b101111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VNMSACVX(vd, rs1, vs2, vm) => v_type(0b110, 0b101111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWADDUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b110000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWADDUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b110000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWADDVV(vd, vs2, vs1, vm) => v_type(0b010, 0b110001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWADDVX(vd, vs2, rs1, vm) => v_type(0b110, 0b110001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWSUBUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b110010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWSUBUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b110010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWSUBVV(vd, vs2, vs1, vm) => v_type(0b010, 0b110011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWSUBVX(vd, vs2, rs1, vm) => v_type(0b110, 0b110011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWADDUWV(vd, vs2, vs1, vm) => v_type(0b010, 0b110100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWADDUWX(vd, vs2, rs1, vm) => v_type(0b110, 0b110100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWADDWV(vd, vs2, vs1, vm) => v_type(0b010, 0b110101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWADDWX(vd, vs2, rs1, vm) => v_type(0b110, 0b110101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWSUBUWV(vd, vs2, vs1, vm) => v_type(0b010, 0b110110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWSUBUWX(vd, vs2, rs1, vm) => v_type(0b110, 0b110110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWSUBWV(vd, vs2, vs1, vm) => v_type(0b010, 0b110111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWSUBWX(vd, vs2, rs1, vm) => v_type(0b110, 0b110111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWMULUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b111000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWMULUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b111000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWMULSUVV(vd, vs2, vs1, vm) => v_type(0b010, 0b111010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWMULSUVX(vd, vs2, rs1, vm) => v_type(0b110, 0b111010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWMULVV(vd, vs2, vs1, vm) => v_type(0b010, 0b111011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWMULVX(vd, vs2, rs1, vm) => v_type(0b110, 0b111011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWMACCUVV(vd, vs1, vs2, vm) => v_type(0b010, 0b111100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWMACCUVX(vd, rs1, vs2, vm) => v_type(0b110, 0b111100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWMACCVV(vd, vs1, vs2, vm) => v_type(0b010, 0b111101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWMACCVX(vd, rs1, vs2, vm) => v_type(0b110, 0b111101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWMACCUSVX(vd, rs1, vs2, vm) => v_type(0b110, 0b111110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VWMACCSUVV(vd, vs1, vs2, vm) => v_type(0b010, 0b111111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VWMACCSUVX(vd, rs1, vs2, vm) => v_type(0b110, 0b111111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFADDVV(vd, vs2, vs1, vm) => v_type(0b001, 0b000000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFADDVF(vd, vs2, rs1, vm) => v_type(0b101, 0b000000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFREDUSUMVS(vd, vs2, vs1, vm) => v_type(0b001, 0b000001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFSUBVV(vd, vs2, vs1, vm) => v_type(0b001, 0b000010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFSUBVF(vd, vs2, rs1, vm) => v_type(0b101, 0b000010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFREDOSUMVS(vd, vs2, vs1, vm) => v_type(0b001, 0b000011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMINVV(vd, vs2, vs1, vm) => v_type(0b001, 0b000100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMINVF(vd, vs2, rs1, vm) => v_type(0b101, 0b000100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFREDMINVS(vd, vs2, vs1, vm) => v_type(0b001, 0b000101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMAXVV(vd, vs2, vs1, vm) => v_type(0b001, 0b000110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMAXVF(vd, vs2, rs1, vm) => v_type(0b101, 0b000110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFREDMAXVS(vd, vs2, vs1, vm) => v_type(0b001, 0b000111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFSGNJVV(vd, vs2, vs1, vm) => v_type(0b001, 0b001000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFSGNJVF(vd, vs2, rs1, vm) => v_type(0b101, 0b001000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFSGNJNVV(vd, vs2, vs1, vm) => v_type(0b001, 0b001001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFSGNJNVF(vd, vs2, rs1, vm) => v_type(0b101, 0b001001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFSGNJXVV(vd, vs2, vs1, vm) => v_type(0b001, 0b001010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFSGNJXVF(vd, vs2, rs1, vm) => v_type(0b101, 0b001010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFSLIDE1UPVF(vd, vs2, rs1, vm) => v_type(0b101, 0b001110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFSLIDE1DOWNVF(vd, vs2, rs1, vm) => v_type(0b101, 0b001111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFMVFS(rd, vs2) => v_type(0b001, 0b010000, Vm::Unmasked, rd, vs2 as u8, 0b00000),
+        Inst::VFMVSF(vd, rs1) => v_type(0b101, 0b010000, Vm::Unmasked, vd, 0, rs1 as u8),
+        Inst::VFCVTXUFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b00000),
+        Inst::VFCVTXFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b00001),
+        Inst::VFCVTFXUV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b00010),
+        Inst::VFCVTFXV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b00011),
+        Inst::VFCVTRTZXUFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b00110),
+        Inst::VFCVTRTZXFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b00111),
+        Inst::VFWCVTXUFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b01000),
+        Inst::VFWCVTXFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b01001),
+        Inst::VFWCVTFXUV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b01010),
+        Inst::VFWCVTFXV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b01011),
+        Inst::VFWCVTFFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b01100),
+        Inst::VFWCVTRTZXUFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b01110),
+        Inst::VFWCVTRTZXFV(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b01111),
+        Inst::VFNCVTXUFW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10000),
+        Inst::VFNCVTXFW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10001),
+        Inst::VFNCVTFXUW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10010),
+        Inst::VFNCVTFXW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10011),
+        Inst::VFNCVTFFW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10100),
+        Inst::VFNCVTRODFFW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10101),
+        Inst::VFNCVTRTZXUFW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10110),
+        Inst::VFNCVTRTZXFW(vd, vs2, vm) => v_type(0b001, 0b010010, vm, vd, vs2 as u8, 0b10111),
+        Inst::VFSQRTV(vd, vs2, vm) => v_type(0b001, 0b010011, vm, vd, vs2 as u8, 0b00000),
+        Inst::VFRSQRT7V(vd, vs2, vm) => v_type(0b001, 0b010011, vm, vd, vs2 as u8, 0b00100),
+        Inst::VFREC7V(vd, vs2, vm) => v_type(0b001, 0b010011, vm, vd, vs2 as u8, 0b00101),
+        Inst::VFCLASSV(vd, vs2, vm) => v_type(0b001, 0b010011, vm, vd, vs2 as u8, 0b10000),
+        Inst::VFMERGEVFM(vd, vs2, rs1) => v_type(0b101, 0b010111, Vm::Masked, vd, vs2 as u8, rs1 as u8),
+        Inst::VFMVVF(vd, rs1) => v_type(0b101, 0b010111, Vm::Unmasked, vd, 0, rs1 as u8),
+        Inst::VMFEQVV(vd, vs2, vs1, vm) => v_type(0b001, 0b011000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMFEQVF(vd, vs2, rs1, vm) => v_type(0b101, 0b011000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMFLEVV(vd, vs2, vs1, vm) => v_type(0b001, 0b011001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMFLEVF(vd, vs2, rs1, vm) => v_type(0b101, 0b011001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMFLTVV(vd, vs2, vs1, vm) => v_type(0b001, 0b011011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMFLTVF(vd, vs2, rs1, vm) => v_type(0b101, 0b011011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMFNEVV(vd, vs2, vs1, vm) => v_type(0b001, 0b011100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VMFNEVF(vd, vs2, rs1, vm) => v_type(0b101, 0b011100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMFGTVF(vd, vs2, rs1, vm) => v_type(0b101, 0b011101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VMFGEVF(vd, vs2, rs1, vm) => v_type(0b101, 0b011111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFDIVVV(vd, vs2, vs1, vm) => v_type(0b001, 0b100000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFDIVVF(vd, vs2, rs1, vm) => v_type(0b101, 0b100000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFRDIVVF(vd, vs2, rs1, vm) => v_type(0b101, 0b100001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFMULVV(vd, vs2, vs1, vm) => v_type(0b001, 0b100100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMULVF(vd, vs2, rs1, vm) => v_type(0b101, 0b100100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFRSUBVF(vd, vs2, rs1, vm) => v_type(0b101, 0b100111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFMADDVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMADDVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFNMADDVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFNMADDVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101001, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFMSUBVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMSUBVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFNMSUBVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFNMSUBVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101011, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFMACCVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMACCVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFNMACCVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFNMACCVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFMSACVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFMSACVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFNMSACVV(vd, vs1, vs2, vm) => v_type(0b001, 0b101111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFNMSACVF(vd, rs1, vs2, vm) => v_type(0b101, 0b101111, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWADDVV(vd, vs2, vs1, vm) => v_type(0b001, 0b110000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWADDVF(vd, vs2, rs1, vm) => v_type(0b101, 0b110000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWREDUSUMVS(vd, vs2, vs1, vm) => v_type(0b001, 0b110001, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWSUBVV(vd, vs2, vs1, vm) => v_type(0b001, 0b110010, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWSUBVF(vd, vs2, rs1, vm) => v_type(0b101, 0b110010, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWREDOSUMVS(vd, vs2, vs1, vm) => v_type(0b001, 0b110011, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWADDWV(vd, vs2, vs1, vm) => v_type(0b001, 0b110100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWADDWF(vd, vs2, rs1, vm) => v_type(0b101, 0b110100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWSUBWV(vd, vs2, vs1, vm) => v_type(0b001, 0b110110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWSUBWF(vd, vs2, rs1, vm) => v_type(0b101, 0b110110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWMULVV(vd, vs2, vs1, vm) => v_type(0b001, 0b111000, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWMULVF(vd, vs2, rs1, vm) => v_type(0b101, 0b111000, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWMACCVV(vd, vs1, vs2, vm) => v_type(0b001, 0b111100, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWMACCVF(vd, rs1, vs2, vm) => v_type(0b101, 0b111100, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWNMACCVV(vd, vs1, vs2, vm) => v_type(0b001, 0b111101, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWNMACCVF(vd, rs1, vs2, vm) => v_type(0b101, 0b111101, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWMSACVV(vd, vs1, vs2, vm) => v_type(0b001, 0b111110, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWMSACVF(vd, rs1, vs2, vm) => v_type(0b101, 0b111110, vm, vd, vs2 as u8, rs1 as u8),
+        Inst::VFWNMSACVV(vd, vs1, vs2, vm) => v_type(0b001, 0b111111, vm, vd, vs2 as u8, vs1 as u8),
+        Inst::VFWNMSACVF(vd, rs1, vs2, vm) => v_type(0b101, 0b111111, vm, vd, vs2 as u8, rs1 as u8),
+
+        // needs the whole `Isa`, see `encode`
+        Inst::Custom(..) => unreachable!(),
+    }
+}
+
+//
+// 32-bit formats
+//
+
+// Register operands of either file are encoded by their number.
+trait Reg: Copy {
+    fn num(self) -> u8;
+}
+
+impl Reg for Gpr {
+    fn num(self) -> u8 {
+        self as u8
+    }
+}
+
+impl Reg for Vr {
+    fn num(self) -> u8 {
+        self as u8
+    }
+}
+
+impl Reg for Fpr {
+    fn num(self) -> u8 {
+        self as u8
+    }
+}
+
+fn word(op: u8, f3: u8, rd: u8, rs1: u8, hi: u32) -> EncodedInst {
+    EncodedInst::word(hi << 20 | (rs1 as u32) << 15 | (f3 as u32) << 12 | (rd as u32) << 7 | (op as u32) << 2 | 0b11)
+}
+
+fn u_word(op: u8, rd: Gpr, imm: u32) -> EncodedInst {
+    EncodedInst::word(imm << 12 | (rd as u32) << 7 | (op as u32) << 2 | 0b11)
+}
+
+fn r_type(op: u8, f3: u8, f7: u8, rd: impl Reg, rs1: impl Reg, rs2: impl Reg) -> Result {
+    Ok(word(op, f3, rd.num(), rs1.num(), (f7 as u32) << 5 | rs2.num() as u32))
+}
+
+// R-type whose rs2 field is an opcode extension rather than a register.
+fn r2_type(op: u8, f3: u8, f7: u8, rs2: u8, rd: impl Reg, rs1: impl Reg) -> Result {
+    Ok(word(op, f3, rd.num(), rs1.num(), (f7 as u32) << 5 | rs2 as u32))
+}
+
+fn r4_type(op: u8, f2: u8, rm: Rm, rd: Fpr, rs1: Fpr, rs2: Fpr, rs3: Fpr) -> Result {
+    Ok(word(op, rm as u8, rd.num(), rs1.num(), (rs3 as u32) << 7 | (f2 as u32) << 5 | rs2 as u32))
+}
+
+fn i_type(op: u8, f3: u8, rd: impl Reg, rs1: Gpr, imm: i32) -> Result {
+    Ok(word(op, f3, rd.num(), rs1 as u8, simm(imm, 12, 0)?))
+}
+
+fn s_type(op: u8, f3: u8, rs1: Gpr, rs2: impl Reg, imm: i32) -> Result {
+    let v = simm(imm, 12, 0)?;
+    Ok(word(op, f3, (v & 0x1f) as u8, rs1 as u8, (v >> 5) << 5 | rs2.num() as u32))
+}
+
+fn b_type(f3: u8, rs1: Gpr, rs2: Gpr, imm: i32) -> Result {
+    let v = simm(imm, 13, 1)?;
+    let lo = (v >> 1 & 0xf) << 1 | (v >> 11 & 1);
+    let hi = (v >> 12 & 1) << 6 | (v >> 5 & 0x3f);
+    Ok(word(0b11000, f3, lo as u8, rs1 as u8, hi << 5 | rs2 as u32))
+}
+
+// shamt is 5, 6 or 7 bits wide depending on XLEN; f7 keeps only the bits above it.
+fn shift(op: u8, f3: u8, f7: u8, rd: Gpr, rs1: Gpr, shamt: u8, bits: u8) -> Result {
+    if shamt as u32 >= bits as u32 {
+        return Err(EncodeError::ImmOutOfRange(shamt as i64));
+    }
+    Ok(word(op, f3, rd as u8, rs1 as u8, (f7 as u32) << 5 | shamt as u32))
+}
+
+// First register of an even-odd pair.
+fn reg_pair(r: Gpr) -> std::result::Result<(), EncodeError> {
+    if r as u8 & 1 != 0 {
+        return Err(EncodeError::BadRegister(r as u8));
+    }
+    Ok(())
+}
+
+fn amo(f3: u8, f5: u8, aqrl: Aqrl, rd: Gpr, rs1: Gpr, rs2: Gpr) -> Result {
+    r_type(0b01011, f3, f5 << 2 | aqrl as u8, rd, rs1, rs2)
+}
+
+fn hlv(f7: u8, f5: u8, rd: Gpr, rs1: Gpr) -> Result {
+    r2_type(0b11100, 0b100, f7, f5, rd, rs1)
+}
+
+// OP-V: funct6 vm vs2 vs1 funct3 vd. The vs2 and vs1 slots also hold
+// scalar registers, immediates and opcode extensions, hence plain numbers.
+fn v_type(f3: u8, f6: u8, vm: Vm, vd: impl Reg, vs2: u8, vs1: u8) -> Result {
+    Ok(word(0b10101, f3, vd.num(), vs1, (f6 as u32) << 6 | (vm as u32) << 5 | vs2 as u32))
+}
+
+// Vector loads and stores: nf mew mop vm lumop/rs2/vs2 rs1 width vd/vs3.
+#[allow(clippy::too_many_arguments)]
+fn v_mem(op: u8, mop: u8, nf: u8, eew: Sew, vm: Vm, vd: Vr, rs1: Gpr, rs2: u8) -> Result {
+    if !(1..=8).contains(&nf) {
+        return Err(EncodeError::ImmOutOfRange(nf as i64));
+    }
+    let width = match eew {
+        Sew::E8 => 0b000,
+        _ => 0b100 | eew as u8,
+    };
+    Ok(word(op, width, vd as u8, rs1 as u8, ((nf - 1) as u32) << 9 | (mop as u32) << 6 | (vm as u32) << 5 | rs2 as u32))
+}
+
+// Whole-register moves, loads and stores: 1, 2, 4 or 8 registers, aligned to that count.
+fn whole_regs(n: u8, regs: u8) -> std::result::Result<u8, EncodeError> {
+    if !n.is_power_of_two() || n > 8 || !regs.is_multiple_of(n) {
+        return Err(EncodeError::Reserved);
+    }
+    Ok(n)
+}
+
+//
+// Immediate checks
+//
+
+fn simm(v: i32, width: u8, align: u8) -> std::result::Result<u32, EncodeError> {
+    if v & ((1 << align) - 1) != 0 {
+        return Err(EncodeError::ImmMisaligned(v as i64));
+    }
+    let lim = 1i64 << (width - 1);
+    if (v as i64) < -lim || (v as i64) >= lim {
+        return Err(EncodeError::ImmOutOfRange(v as i64));
+    }
+    Ok(v as u32 & ((1u64 << width) - 1) as u32)
+}
+
+fn uimm(v: u32, width: u8, align: u8) -> std::result::Result<u32, EncodeError> {
+    if v & ((1 << align) - 1) != 0 {
+        return Err(EncodeError::ImmMisaligned(v as i64));
+    }
+    if v as u64 >= 1u64 << width {
+        return Err(EncodeError::ImmOutOfRange(v as i64));
+    }
+    Ok(v)
+}
+
+fn nonzero(v: u32, imm: i64) -> std::result::Result<u32, EncodeError> {
+    if v == 0 {
+        return Err(EncodeError::ImmOutOfRange(imm));
+    }
+    Ok(v)
+}
+
+// Byte select of the AES32 and SM4 instructions, in funct7[6:5].
+fn bsel(bs: u8) -> std::result::Result<u8, EncodeError> {
+    Ok((uimm(bs as u32, 2, 0)? as u8) << 5)
+}
+
+fn xlen(ok: bool, bits: u8) -> std::result::Result<(), EncodeError> {
+    if !ok {
+        return Err(EncodeError::BadXlen(bits));
+    }
+    Ok(())
+}
+
+//
+// RVC formats
+//
+
+fn c_word(f3: u8, body: u16, op: u8) -> EncodedInst {
+    EncodedInst::half((f3 as u16) << 13 | body | op as u16)
+}
+
+// Moves v[hi:lo] to bit `to` of a 16-bit word.
+fn place(v: u32, hi: u8, lo: u8, to: u8) -> u16 {
+    ((v >> lo & ((1 << (hi - lo + 1)) - 1)) << to) as u16
+}
+
+fn c_regp(r: impl Reg) -> std::result::Result<u16, EncodeError> {
+    match r.num() {
+        8..=15 => Ok(r.num() as u16 - 8),
+        n => Err(EncodeError::BadRegister(n)),
+    }
+}
+
+fn c_reg_nz(r: Gpr) -> std::result::Result<u16, EncodeError> {
+    match r {
+        Gpr::zero => Err(EncodeError::BadRegister(0)),
+        r => Ok(r as u16),
+    }
+}
+
+fn c_mem(f3: u8, r: u16, rs1: Gpr, imm: u16, op: u8) -> Result {
+    Ok(c_word(f3, c_regp(rs1)? << 7 | imm | r << 2, op))
+}
+
+// Zcb C.ZEXT.B and friends: funct5 in bits 6:2.
+fn c_unary(f5: u16, rd: Gpr) -> Result {
+    Ok(c_word(0b100, 0b111 << 10 | c_regp(rd)? << 7 | f5 << 2, 0b01))
+}
+
+// Zcmp: stack_adj must lie within 48 bytes above the space the registers need.
+fn c_push_pop(f5: u16, rlist: Rlist, adj: u32, bits: u8) -> Result {
+    xlen(bits != 128, bits)?;
+    let spimm = adj.checked_sub(rlist.stack_adj_base(bits)).ok_or(EncodeError::ImmOutOfRange(adj as i64))?;
+    let v = uimm(spimm, 6, 4).map_err(|_| EncodeError::ImmOutOfRange(adj as i64))?;
+    Ok(c_word(0b101, f5 << 8 | (rlist.to_u8() as u16) << 4 | place(v, 5, 4, 2), 0b10))
+}
+
+// Zcmp r1s′/r2s′: s0-s7 only.
+fn c_sreg(r: Gpr) -> std::result::Result<u16, EncodeError> {
+    match r as u8 {
+        8 | 9 => Ok(r as u16 - 8),
+        18..=23 => Ok(r as u16 - 16),
+        n => Err(EncodeError::BadRegister(n)),
+    }
+}
+
+fn c_arith(b12: u16, f2: u16, rd: Gpr, rs2: Gpr) -> Result {
+    Ok(c_word(0b100, b12 << 12 | 0b11 << 10 | c_regp(rd)? << 7 | f2 << 5 | c_regp(rs2)? << 2, 0b01))
+}
+
+fn c_shift_b(f2: u16, rd: Gpr, shamt: u8, bits: u8) -> Result {
+    Ok(c_word(0b100, f2 << 10 | c_regp(rd)? << 7 | c_shamt(shamt, bits)?, 0b01))
+}
+
+// C.SLLI/C.SRLI/C.SRAI: shamt=0 is reserved for the RV128 *64 forms, and
+// RV128 sign-extends the field, reaching 1-31 and 96-127.
+fn c_shamt(shamt: u8, bits: u8) -> std::result::Result<u16, EncodeError> {
+    let ok = match bits {
+        32 => (1..32).contains(&shamt),
+        64 => (1..64).contains(&shamt),
+        _ => (1..32).contains(&shamt) || (96..128).contains(&shamt),
+    };
+    if !ok {
+        return Err(EncodeError::ImmOutOfRange(shamt as i64));
+    }
+    let v = shamt as u32 & 0x3f;
+    Ok(place(v, 5, 5, 12) | place(v, 4, 0, 2))
+}
+
+fn c_i_imm(imm: i32) -> std::result::Result<u16, EncodeError> {
+    let v = simm(imm, 6, 0)?;
+    Ok(place(v, 5, 5, 12) | place(v, 4, 0, 2))
+}
+
+fn c_lw_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 7, 2)?;
+    Ok(place(v, 5, 3, 10) | place(v, 2, 2, 6) | place(v, 6, 6, 5))
+}
+
+fn c_ld_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 8, 3)?;
+    Ok(place(v, 5, 3, 10) | place(v, 7, 6, 5))
+}
+
+fn c_lq_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 9, 4)?;
+    Ok(place(v, 5, 4, 11) | place(v, 8, 8, 10) | place(v, 7, 6, 5))
+}
+
+fn c_lbu_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 2, 0)?;
+    Ok(place(v, 0, 0, 6) | place(v, 1, 1, 5))
+}
+
+fn c_lh_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 2, 1)?;
+    Ok(place(v, 1, 1, 5))
+}
+
+fn c_lwsp_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 8, 2)?;
+    Ok(place(v, 5, 5, 12) | place(v, 4, 2, 4) | place(v, 7, 6, 2))
+}
+
+fn c_ldsp_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 9, 3)?;
+    Ok(place(v, 5, 5, 12) | place(v, 4, 3, 5) | place(v, 8, 6, 2))
+}
+
+fn c_lqsp_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 10, 4)?;
+    Ok(place(v, 5, 5, 12) | place(v, 4, 4, 6) | place(v, 9, 6, 2))
+}
+
+fn c_swsp_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 8, 2)?;
+    Ok(place(v, 5, 2, 9) | place(v, 7, 6, 7))
+}
+
+fn c_sdsp_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 9, 3)?;
+    Ok(place(v, 5, 3, 10) | place(v, 8, 6, 7))
+}
+
+fn c_sqsp_imm(imm: u32) -> std::result::Result<u16, EncodeError> {
+    let v = uimm(imm, 10, 4)?;
+    Ok(place(v, 5, 4, 11) | place(v, 9, 6, 7))
+}
+
+fn c_j_imm(imm: i32) -> std::result::Result<u16, EncodeError> {
+    let v = simm(imm, 12, 1)?;
+    Ok(place(v, 11, 11, 12)
+        | place(v, 4, 4, 11)
+        | place(v, 9, 8, 9)
+        | place(v, 10, 10, 8)
+        | place(v, 6, 6, 7)
+        | place(v, 7, 7, 6)
+        | place(v, 3, 1, 3)
+        | place(v, 5, 5, 2))
+}
+
+fn c_b_imm(imm: i32) -> std::result::Result<u16, EncodeError> {
+    let v = simm(imm, 9, 1)?;
+    Ok(place(v, 8, 8, 12) | place(v, 4, 3, 10) | place(v, 7, 6, 5) | place(v, 2, 1, 3) | place(v, 5, 5, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode, ByteSlice, Inst, Isa};
+
+    fn isa(bits: u8) -> Isa {
+        format!("rv{}gqcvh_zicbom_zicbop_zicboz_zicond_zihintntl_zihintpause_zabha_zacas_zawrs_zfa_zfh_zcb_zba_zbb_zbc_zbs_zbkb_zbkc_zbkx_zknd_zkne_zknh_zksed_zksh", bits)
+            .parse()
+            .unwrap()
+    }
+
+    // Whatever `encode` accepts for an XLEN must decode back to the same
+    // instruction on that XLEN.
+    #[test]
+    fn roundtrip() {
+        let isas = [isa(32), isa(64), isa(128)];
+        // every 16-bit word, and every opcode, funct3, rs2 and funct7 of
+        // the 32-bit ones with fixed rd and rs1
+        let words = (0..0x10000).chain((0..0x100000).map(|i: u32| (i & 0x1f) << 2 | 3 | 10 << 7 | (i >> 5 & 7) << 12 | 11 << 15 | (i >> 8) << 20));
+        let mut insts = Vec::new();
+        for isa in &isas {
+            for w in words.clone() {
+                if let Ok(d) = decode(&mut ByteSlice::from(&w.to_le_bytes()[..]), isa) {
+                    insts.push(d.inst);
+                }
+            }
+        }
+        let mut bad = Vec::new();
+        for inst in &insts {
+            for isa in &isas {
+                let Ok(enc) = encode(inst, isa) else {
+                    continue;
+                };
+                let back = decode(&mut ByteSlice::from(enc.as_bytes()), isa).map(|d| d.inst);
+                // pack with rs2 = zero is zext.h on RV32
+                if back.as_ref() != Ok(inst) && !matches!((inst, &back), (Inst::PACK(..), Ok(Inst::ZEXTH(..)))) {
+                    bad.push(format!("RV{}: {:?} -> {:?}", isa.xlen(), inst, back));
+                }
+            }
+        }
+        assert!(bad.is_empty(), "{}", bad.join("\n"));
+    }
+}
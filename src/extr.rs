@@ -34,9 +34,13 @@ pub fn f7_bits(w: u32) -> u8 {
     bits(w, 25, 7) as u8
 }
 
-// pub fn f2_bits(w: u32) -> u8 {
-//     bits(w, 25, 2) as u8
-// }
+pub fn f2_bits(w: u32) -> u8 {
+    bits(w, 25, 2) as u8
+}
+
+pub fn f12_bits(w: u32) -> u16 {
+    bits(w, 20, 12) as u16
+}
 
 // R-type
 
@@ -52,9 +56,9 @@ pub fn rs2_bits(w: u32) -> u8 {
     bits(w, 20, 5) as u8
 }
 
-// pub fn rs3_bits(w: u32) -> u8 {
-//     bits(w, 27, 5) as u8
-// }
+pub fn rs3_bits(w: u32) -> u8 {
+    bits(w, 27, 5) as u8
+}
 
 // I-type (immediate)
 
@@ -62,6 +66,26 @@ pub fn i_imm_bits(w: u32) -> i32 {
     (bits(w, 20, 12) | sign(w) << 12) as i32
 }
 
+// CSR
+
+pub fn csr_bits(w: u32) -> u16 {
+    bits(w, 20, 12) as u16
+}
+
+// FENCE
+
+pub fn fm_bits(w: u32) -> u8 {
+    bits(w, 28, 4) as u8
+}
+
+pub fn pred_bits(w: u32) -> u8 {
+    bits(w, 24, 4) as u8
+}
+
+pub fn succ_bits(w: u32) -> u8 {
+    bits(w, 20, 4) as u8
+}
+
 // SHAMT
 
 pub fn shamt32_imm_bits(w: u32) -> u8 {
@@ -72,6 +96,10 @@ pub fn shamt64_imm_bits(w: u32) -> u8 {
     bits(w, 20, 6) as u8
 }
 
+pub fn shamt128_imm_bits(w: u32) -> u8 {
+    bits(w, 20, 7) as u8
+}
+
 // S-type (store)
 
 pub fn s_imm_bits(w: u32) -> i32 {
@@ -81,7 +109,7 @@ pub fn s_imm_bits(w: u32) -> i32 {
 // U-type: (LUI, AUIPC)
 
 pub fn u_imm_bits(w: u32) -> u32 {
-    bits(w, 12, 20) as u32
+    bits(w, 12, 20)
 }
 
 // J-type (JAL/R)
@@ -96,6 +124,50 @@ pub fn b_imm_bits(w: u32) -> i32 {
     (bits(w, 8, 4) << 1 | bits(w, 25, 6) << 5 | bits(w, 7, 1) << 11 | sign(w) << 12) as i32
 }
 
+// Scalar crypto: byte select (AES32, SM4), round number (AES64KS1I)
+
+pub fn bs_bits(w: u32) -> u8 {
+    bits(w, 30, 2) as u8
+}
+
+pub fn rnum_bits(w: u32) -> u8 {
+    bits(w, 20, 4) as u8
+}
+
+// V-type: funct6 vm vs2 vs1/rs1/simm5 funct3 vd (OP-V)
+
+pub fn vm_bits(w: u32) -> u8 {
+    bits(w, 25, 1) as u8
+}
+
+pub fn v_simm5_bits(w: u32) -> i32 {
+    ((bits(w, 15, 5) << 27) as i32) >> 27
+}
+
+// vsetvli: zimm[10:0], vsetivli: zimm[9:0]
+
+pub fn vtype_bits(w: u32) -> u16 {
+    bits(w, 20, 11) as u16
+}
+
+pub fn vtypei_bits(w: u32) -> u16 {
+    bits(w, 20, 10) as u16
+}
+
+// Vector load/store: nf mew mop vm lumop/rs2/vs2 rs1 width vd/vs3
+
+pub fn nf_bits(w: u32) -> u8 {
+    bits(w, 29, 3) as u8
+}
+
+pub fn mew_bits(w: u32) -> u8 {
+    bits(w, 28, 1) as u8
+}
+
+pub fn mop_bits(w: u32) -> u8 {
+    bits(w, 26, 2) as u8
+}
+
 //
 // RVC instruction format:
 //
@@ -120,3 +192,226 @@ pub fn c_f3_bits(w: u16) -> u8 {
 // Integer          Register ABI Name   s0  s1  a0  a1  a2  a3  a4  a5
 // Floating-Point   Register Number     f8  f9  f10 f11 f12 f13 f14 f15
 // Floating-Point   Register ABI Name   fs0 fs1 fa0 fa1 fa2 fa3 fa4 fa5
+
+fn c_bits(w: u16, shr: u8, msh: u8) -> u32 {
+    bits(w as u32, shr, msh)
+}
+
+fn c_sext(v: u32, width: u8) -> i32 {
+    let shl = 32 - width as u32;
+    ((v << shl) as i32) >> shl
+}
+
+// CR / CI / CSS: full 5-bit register fields
+
+pub fn c_rd_bits(w: u16) -> u8 {
+    c_bits(w, 7, 5) as u8
+}
+
+pub fn c_rs2_bits(w: u16) -> u8 {
+    c_bits(w, 2, 5) as u8
+}
+
+// CR: funct4
+
+pub fn c_f4_bits(w: u16) -> u8 {
+    c_bits(w, 12, 4) as u8
+}
+
+// CIW / CL / CS / CA / CB: 3-bit register fields (x8-x15)
+
+pub fn c_rdp_bits(w: u16) -> u8 {
+    c_bits(w, 2, 3) as u8 + 8
+}
+
+pub fn c_rs1p_bits(w: u16) -> u8 {
+    c_bits(w, 7, 3) as u8 + 8
+}
+
+pub fn c_rs2p_bits(w: u16) -> u8 {
+    c_bits(w, 2, 3) as u8 + 8
+}
+
+// CA: funct6 (bits 15:10)
+
+pub fn c_f6_bits(w: u16) -> u8 {
+    c_bits(w, 10, 6) as u8
+}
+
+// CA: funct2 (bits 6:5)
+
+pub fn c_ca_f2_bits(w: u16) -> u8 {
+    c_bits(w, 5, 2) as u8
+}
+
+// CB: funct2 (bits 11:10)
+
+pub fn c_cb_f2_bits(w: u16) -> u8 {
+    c_bits(w, 10, 2) as u8
+}
+
+// CI: imm[5] imm[4:0] (C.ADDI, C.LI, C.ADDIW, C.ANDI)
+
+pub fn c_i_imm_bits(w: u16) -> i32 {
+    c_sext(c_bits(w, 2, 5) | c_bits(w, 12, 1) << 5, 6)
+}
+
+// CI: shamt[5] shamt[4:0] (C.SLLI, C.SRLI, C.SRAI)
+
+pub fn c_shamt_bits(w: u16) -> u8 {
+    (c_bits(w, 2, 5) | c_bits(w, 12, 1) << 5) as u8
+}
+
+// CI: nzimm[17] nzimm[16:12] (C.LUI), as the 20-bit U-type field
+
+pub fn c_lui_imm_bits(w: u16) -> u32 {
+    (c_i_imm_bits(w) as u32) & 0xfffff
+}
+
+// CI: nzimm[9] nzimm[4|6|8:7|5] (C.ADDI16SP)
+
+pub fn c_addi16sp_imm_bits(w: u16) -> i32 {
+    c_sext(
+        c_bits(w, 6, 1) << 4
+            | c_bits(w, 2, 1) << 5
+            | c_bits(w, 5, 1) << 6
+            | c_bits(w, 3, 2) << 7
+            | c_bits(w, 12, 1) << 9,
+        10,
+    )
+}
+
+// CIW: nzuimm[5:4|9:6|2|3] (C.ADDI4SPN)
+
+pub fn c_addi4spn_imm_bits(w: u16) -> u32 {
+    c_bits(w, 6, 1) << 2 | c_bits(w, 5, 1) << 3 | c_bits(w, 11, 2) << 4 | c_bits(w, 7, 4) << 6
+}
+
+// CL / CS: uimm[5:3] uimm[2|6] (C.LW, C.SW, C.FLW, C.FSW)
+
+pub fn c_lw_imm_bits(w: u16) -> u32 {
+    c_bits(w, 6, 1) << 2 | c_bits(w, 10, 3) << 3 | c_bits(w, 5, 1) << 6
+}
+
+// CL / CS: uimm[5:3] uimm[7:6] (C.LD, C.SD, C.FLD, C.FSD)
+
+pub fn c_ld_imm_bits(w: u16) -> u32 {
+    c_bits(w, 10, 3) << 3 | c_bits(w, 5, 2) << 6
+}
+
+// CL / CS: uimm[5:4|8] uimm[7:6] (C.LQ, C.SQ)
+
+pub fn c_lq_imm_bits(w: u16) -> u32 {
+    c_bits(w, 11, 2) << 4 | c_bits(w, 5, 2) << 6 | c_bits(w, 10, 1) << 8
+}
+
+// CI: uimm[5] uimm[4:2|7:6] (C.LWSP, C.FLWSP)
+
+pub fn c_lwsp_imm_bits(w: u16) -> u32 {
+    c_bits(w, 4, 3) << 2 | c_bits(w, 12, 1) << 5 | c_bits(w, 2, 2) << 6
+}
+
+// CI: uimm[5] uimm[4:3|8:6] (C.LDSP, C.FLDSP)
+
+pub fn c_ldsp_imm_bits(w: u16) -> u32 {
+    c_bits(w, 5, 2) << 3 | c_bits(w, 12, 1) << 5 | c_bits(w, 2, 3) << 6
+}
+
+// CI: uimm[5] uimm[4|9:6] (C.LQSP)
+
+pub fn c_lqsp_imm_bits(w: u16) -> u32 {
+    c_bits(w, 6, 1) << 4 | c_bits(w, 12, 1) << 5 | c_bits(w, 2, 4) << 6
+}
+
+// CSS: uimm[5:2|7:6] (C.SWSP, C.FSWSP)
+
+pub fn c_swsp_imm_bits(w: u16) -> u32 {
+    c_bits(w, 9, 4) << 2 | c_bits(w, 7, 2) << 6
+}
+
+// CSS: uimm[5:3|8:6] (C.SDSP, C.FSDSP)
+
+pub fn c_sdsp_imm_bits(w: u16) -> u32 {
+    c_bits(w, 10, 3) << 3 | c_bits(w, 7, 3) << 6
+}
+
+// CSS: uimm[5:4|9:6] (C.SQSP)
+
+pub fn c_sqsp_imm_bits(w: u16) -> u32 {
+    c_bits(w, 11, 2) << 4 | c_bits(w, 7, 4) << 6
+}
+
+// CL / CS: uimm[0|1] (C.LBU, C.SB), uimm[1] (C.LHU, C.LH, C.SH)
+
+pub fn c_lbu_imm_bits(w: u16) -> u32 {
+    c_bits(w, 6, 1) | c_bits(w, 5, 1) << 1
+}
+
+pub fn c_lh_imm_bits(w: u16) -> u32 {
+    c_bits(w, 5, 1) << 1
+}
+
+// CU: funct5 (bits 6:2)
+
+pub fn c_cu_f5_bits(w: u16) -> u8 {
+    c_bits(w, 2, 5) as u8
+}
+
+// CMPP: rlist spimm[5:4] (CM.PUSH, CM.POP, CM.POPRET, CM.POPRETZ)
+
+pub fn c_cmpp_f5_bits(w: u16) -> u8 {
+    c_bits(w, 8, 5) as u8
+}
+
+pub fn c_rlist_bits(w: u16) -> u8 {
+    c_bits(w, 4, 4) as u8
+}
+
+pub fn c_spimm_bits(w: u16) -> u32 {
+    c_bits(w, 2, 2) << 4
+}
+
+// CMMV: r1s′ r2s′, numbers of s0-s7 (CM.MVSA01, CM.MVA01S)
+
+pub fn c_r1s_bits(w: u16) -> u8 {
+    c_bits(w, 7, 3) as u8
+}
+
+pub fn c_r2s_bits(w: u16) -> u8 {
+    c_bits(w, 2, 3) as u8
+}
+
+// CMJT: index[7:0] (CM.JT, CM.JALT)
+
+pub fn c_index_bits(w: u16) -> u8 {
+    c_bits(w, 2, 8) as u8
+}
+
+// CJ: imm[11|4|9:8|10|6|7|3:1|5] (C.J, C.JAL)
+
+pub fn c_j_imm_bits(w: u16) -> i32 {
+    c_sext(
+        c_bits(w, 3, 3) << 1
+            | c_bits(w, 11, 1) << 4
+            | c_bits(w, 2, 1) << 5
+            | c_bits(w, 7, 1) << 6
+            | c_bits(w, 6, 1) << 7
+            | c_bits(w, 9, 2) << 8
+            | c_bits(w, 8, 1) << 10
+            | c_bits(w, 12, 1) << 11,
+        12,
+    )
+}
+
+// CB: imm[8|4:3] imm[7:6|2:1|5] (C.BEQZ, C.BNEZ)
+
+pub fn c_b_imm_bits(w: u16) -> i32 {
+    c_sext(
+        c_bits(w, 3, 2) << 1
+            | c_bits(w, 10, 2) << 3
+            | c_bits(w, 2, 1) << 5
+            | c_bits(w, 5, 2) << 6
+            | c_bits(w, 12, 1) << 8,
+        9,
+    )
+}
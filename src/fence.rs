@@ -0,0 +1,71 @@
+// FENCE operands: the fence mode and the predecessor/successor sets
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Fm {
+    None = 0b0000, // ordinary fence
+    Tso = 0b1000,  // fence.tso, only with pred = succ = rw
+}
+
+impl Fm {
+    pub fn from_u8(r: u8) -> Option<Fm> {
+        match r {
+            0b0000 => Some(Fm::None),
+            0b1000 => Some(Fm::Tso),
+            _ => None, // other fence modes are reserved
+        }
+    }
+}
+
+// Set of I (device input), O (device output), R (memory read) and W (memory write).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Fence(u8);
+
+impl Fence {
+    pub const NONE: Fence = Fence(0);
+    pub const I: Fence = Fence(0b1000);
+    pub const O: Fence = Fence(0b0100);
+    pub const R: Fence = Fence(0b0010);
+    pub const W: Fence = Fence(0b0001);
+    pub const RW: Fence = Fence(0b0011);
+    pub const IORW: Fence = Fence(0b1111);
+
+    pub fn from_u8(r: u8) -> Option<Fence> {
+        if r < 16 {
+            Some(Fence(r))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    pub fn contains(self, other: Fence) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for Fence {
+    type Output = Fence;
+
+    fn bitor(self, rhs: Fence) -> Fence {
+        Fence(self.0 | rhs.0)
+    }
+}
+
+impl std::fmt::Display for Fence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+        for (set, c) in [(Fence::I, 'i'), (Fence::O, 'o'), (Fence::R, 'r'), (Fence::W, 'w')] {
+            if self.contains(set) {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// Constant operand of the Zfa `fli` instructions: an index into a fixed table
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Fli(u8);
+
+// Entries 1 (`min`), 30 (`inf`) and 31 (`nan`) have no fixed value.
+const VALUES: [f64; 32] = [
+    -1.0, 0.0, 1.52587890625e-05, 3.0517578125e-05, 0.00390625, 0.0078125, 0.0625, 0.125,
+    0.25, 0.3125, 0.375, 0.4375, 0.5, 0.625, 0.75, 0.875,
+    1.0, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0, 4.0,
+    8.0, 16.0, 128.0, 256.0, 32768.0, 65536.0, f64::INFINITY, f64::NAN,
+];
+
+impl Fli {
+    pub fn from_u8(r: u8) -> Option<Fli> {
+        if r < 32 {
+            Some(Fli(r))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Index of `value` in the table, if it is one of the constants.
+    pub fn from_f64(value: f64) -> Option<Fli> {
+        if value.is_nan() {
+            return Some(Fli(31));
+        }
+        (0..31).filter(|&i| i != 1).find(|&i| VALUES[i as usize] == value).map(Fli)
+    }
+
+    /// Entry 1, the smallest positive normal number of the destination format.
+    pub fn is_min(self) -> bool {
+        self.0 == 1
+    }
+
+    /// The constant, or `None` for `min`, whose value depends on the format.
+    /// `nan` is the canonical quiet NaN.
+    pub fn value(self) -> Option<f64> {
+        if self.is_min() {
+            None
+        } else {
+            Some(VALUES[self.0 as usize])
+        }
+    }
+}
+
+// `0.5`, `1.0`, `min`, `inf`, `nan`.
+impl std::fmt::Display for Fli {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            1 => write!(f, "min"),
+            30 => write!(f, "inf"),
+            31 => write!(f, "nan"),
+            i => write!(f, "{:?}", VALUES[i as usize]),
+        }
+    }
+}
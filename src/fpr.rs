@@ -0,0 +1,84 @@
+#[allow(dead_code)]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Fpr {
+    ft0 = 0,
+    ft1,
+    ft2,
+    ft3,
+    ft4,
+    ft5,
+    ft6,
+    ft7,
+    fs0,
+    fs1,
+    fa0,
+    fa1,
+    fa2,
+    fa3,
+    fa4,
+    fa5,
+    fa6,
+    fa7,
+    fs2,
+    fs3,
+    fs4,
+    fs5,
+    fs6,
+    fs7,
+    fs8,
+    fs9,
+    fs10,
+    fs11,
+    ft8,
+    ft9,
+    ft10,
+    ft11,
+}
+
+impl Fpr {
+    pub fn from_u8(r: u8) -> Option<Fpr> {
+        match r {
+        0 => Some(Fpr::ft0),
+        1 => Some(Fpr::ft1),
+        2 => Some(Fpr::ft2),
+        3 => Some(Fpr::ft3),
+        4 => Some(Fpr::ft4),
+        5 => Some(Fpr::ft5),
+        6 => Some(Fpr::ft6),
+        7 => Some(Fpr::ft7),
+        8 => Some(Fpr::fs0),
+        9 => Some(Fpr::fs1),
+        10 => Some(Fpr::fa0),
+        11 => Some(Fpr::fa1),
+        12 => Some(Fpr::fa2),
+        13 => Some(Fpr::fa3),
+        14 => Some(Fpr::fa4),
+        15 => Some(Fpr::fa5),
+        16 => Some(Fpr::fa6),
+        17 => Some(Fpr::fa7),
+        18 => Some(Fpr::fs2),
+        19 => Some(Fpr::fs3),
+        20 => Some(Fpr::fs4),
+        21 => Some(Fpr::fs5),
+        22 => Some(Fpr::fs6),
+        23 => Some(Fpr::fs7),
+        24 => Some(Fpr::fs8),
+        25 => Some(Fpr::fs9),
+        26 => Some(Fpr::fs10),
+        27 => Some(Fpr::fs11),
+        28 => Some(Fpr::ft8),
+        29 => Some(Fpr::ft9),
+        30 => Some(Fpr::ft10),
+        31 => Some(Fpr::ft11),
+        _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Fpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -79,6 +79,9 @@ impl Gpr {
 
 impl std::fmt::Display for Gpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Gpr::fp => write!(f, "s0"), // binutils prints x8 as s0
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
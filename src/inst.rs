@@ -1,11 +1,7 @@
-use crate::{Gpr, Csr};
+use crate::{Gpr, Fpr, Vr, Vm, Csr, Rm, Aqrl, Fence, Fm, Sew, Vtype, Fli, Rlist, Custom, Ext, Isa};
 
-#[derive(Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum Inst {
-    ERROR,
-
-    UNDEF(u32),
-
     ADD(Gpr, Gpr, Gpr),
     SUB(Gpr, Gpr, Gpr),
     XOR(Gpr, Gpr, Gpr),
@@ -38,7 +34,7 @@ pub enum Inst {
     LBU(Gpr, Gpr, i32),
     LHU(Gpr, Gpr, i32),
 
-    SB(Gpr, Gpr, i32),
+    SB(Gpr, Gpr, i32),          // rs2, offset(rs1)
     SH(Gpr, Gpr, i32),
     SW(Gpr, Gpr, i32),
 
@@ -58,10 +54,12 @@ pub enum Inst {
     ECALL,
     EBREAK,
 
+    FENCE(Fm, Fence, Fence),    // fm pred succ 00000 000 00000 0001111
+    PAUSE,                      // 0000 0001 0000 00000 000 00000 0001111  (Zihintpause)
+
     // RV32/RV64 Zifencei extensions
 
-    FENCE(Gpr, Gpr, i32),
-    FENCEI(Gpr, Gpr, i32),
+    FENCEI,                     // 000000000000 00000 001 00000 0001111
     
     // RV32/RV64 Zicsr extensions
     
@@ -96,6 +94,23 @@ pub enum Inst {
     SRLW(Gpr, Gpr, Gpr),
     SRAW(Gpr, Gpr, Gpr),
 
+    // 128 bits extensions
+
+    LDU(Gpr, Gpr, i32),
+    LQ(Gpr, Gpr, i32),
+    SQ(Gpr, Gpr, i32),
+
+    ADDID(Gpr, Gpr, i32),
+    SLLID(Gpr, Gpr, u8),
+    SRLID(Gpr, Gpr, u8),
+    SRAID(Gpr, Gpr, u8),
+
+    ADDD(Gpr, Gpr, Gpr),
+    SUBD(Gpr, Gpr, Gpr),
+    SLLD(Gpr, Gpr, Gpr),
+    SRLD(Gpr, Gpr, Gpr),
+    SRAD(Gpr, Gpr, Gpr),
+
     // Multiply Extension
 
     MUL(Gpr, Gpr, Gpr),
@@ -107,6 +122,10 @@ pub enum Inst {
 
     MULW(Gpr, Gpr, Gpr),
 
+    // Multiply Extension (128 bits)
+
+    MULD(Gpr, Gpr, Gpr),
+
     // Divide/Remaining Extension
 
     DIV(Gpr, Gpr, Gpr),
@@ -121,178 +140,273 @@ pub enum Inst {
     REMW(Gpr, Gpr, Gpr),
     REMUW(Gpr, Gpr, Gpr),
 
+    // Divide/Remaining Extension (128 bits)
+
+    DIVD(Gpr, Gpr, Gpr),
+    DIVUD(Gpr, Gpr, Gpr),
+    REMD(Gpr, Gpr, Gpr),
+    REMUD(Gpr, Gpr, Gpr),
+
     // Load eXclusive / Store Conditional Extension
 
-    LRW(Gpr, Gpr),
-    SCW(Gpr, Gpr, Gpr),
+    LRW(Gpr, Gpr, Aqrl),            // 00010 aq rl 00000 rs1 010 rd 0101111
+    SCW(Gpr, Gpr, Gpr, Aqrl),       // 00011 aq rl rs2 rs1 010 rd 0101111
 
     // Load eXclusive / Store Conditional Extension (64 bits)
 
-    LRD(Gpr, Gpr),
-    SCD(Gpr, Gpr, Gpr),
+    LRD(Gpr, Gpr, Aqrl),            // 00010 aq rl 00000 rs1 011 rd 0101111
+    SCD(Gpr, Gpr, Gpr, Aqrl),       // 00011 aq rl rs2 rs1 011 rd 0101111
 
     // Atomic Extension
 
-    AMOSWAPW(Gpr, Gpr, Gpr),    // 00001 aq rl rs2 rs1 010 rd 0101111 
-    AMOADDW(Gpr, Gpr, Gpr),     // 00000 aq rl rs2 rs1 010 rd 0101111 
-    AMOXORW(Gpr, Gpr, Gpr),     // 00100 aq rl rs2 rs1 010 rd 0101111 
-    AMOANDW(Gpr, Gpr, Gpr),     // 01100 aq rl rs2 rs1 010 rd 0101111 
-    AMOORW(Gpr, Gpr, Gpr),      // 01000 aq rl rs2 rs1 010 rd 0101111 
-    AMOMINW(Gpr, Gpr, Gpr),     // 10000 aq rl rs2 rs1 010 rd 0101111 
-    AMOMAXW(Gpr, Gpr, Gpr),     // 10100 aq rl rs2 rs1 010 rd 0101111 
-    AMOMINUW(Gpr, Gpr, Gpr),    // 11000 aq rl rs2 rs1 010 rd 0101111 
-    AMOMAXUW(Gpr, Gpr, Gpr),    // 11100 aq rl rs2 rs1 010 rd 0101111 
+    AMOSWAPW(Gpr, Gpr, Gpr, Aqrl),  // 00001 aq rl rs2 rs1 010 rd 0101111
+    AMOADDW(Gpr, Gpr, Gpr, Aqrl),   // 00000 aq rl rs2 rs1 010 rd 0101111
+    AMOXORW(Gpr, Gpr, Gpr, Aqrl),   // 00100 aq rl rs2 rs1 010 rd 0101111
+    AMOANDW(Gpr, Gpr, Gpr, Aqrl),   // 01100 aq rl rs2 rs1 010 rd 0101111
+    AMOORW(Gpr, Gpr, Gpr, Aqrl),    // 01000 aq rl rs2 rs1 010 rd 0101111
+    AMOMINW(Gpr, Gpr, Gpr, Aqrl),   // 10000 aq rl rs2 rs1 010 rd 0101111
+    AMOMAXW(Gpr, Gpr, Gpr, Aqrl),   // 10100 aq rl rs2 rs1 010 rd 0101111
+    AMOMINUW(Gpr, Gpr, Gpr, Aqrl),  // 11000 aq rl rs2 rs1 010 rd 0101111
+    AMOMAXUW(Gpr, Gpr, Gpr, Aqrl),  // 11100 aq rl rs2 rs1 010 rd 0101111
     
     // Atomic Extension (64 bits)
 
-    AMOSWAPD(Gpr, Gpr, Gpr),    // 00001 aq rl rs2 rs1 011 rd 0101111 
-    AMOADDD(Gpr, Gpr, Gpr),     // 00000 aq rl rs2 rs1 011 rd 0101111 
-    AMOXORD(Gpr, Gpr, Gpr),     // 00100 aq rl rs2 rs1 011 rd 0101111 
-    AMOANDD(Gpr, Gpr, Gpr),     // 01100 aq rl rs2 rs1 011 rd 0101111 
-    AMOORD(Gpr, Gpr, Gpr),      // 01000 aq rl rs2 rs1 011 rd 0101111 
-    AMOMIND(Gpr, Gpr, Gpr),     // 10000 aq rl rs2 rs1 011 rd 0101111 
-    AMOMAXD(Gpr, Gpr, Gpr),     // 10100 aq rl rs2 rs1 011 rd 0101111 
-    AMOMINUD(Gpr, Gpr, Gpr),    // 11000 aq rl rs2 rs1 011 rd 0101111 
-    AMOMAXUD(Gpr, Gpr, Gpr),    // 11100 aq rl rs2 rs1 011 rd 0101111 
-
-/*
-    // Floating Point Extension
-
-    FLW(Gpr, Gpr, u64),
-    FSW(Gpr, Gpr, u64),
-
-    FMADDS(Gpr, Gpr, Gpr, Gpr, u8),
-    FMSUBS(Gpr, Gpr, Gpr, Gpr, u8),
-    FNMSUBS(Gpr, Gpr,Gpr, Gpr, u8),
-    FNMADDS(Gpr, Gpr,Gpr, Gpr, u8),
+    AMOSWAPD(Gpr, Gpr, Gpr, Aqrl),  // 00001 aq rl rs2 rs1 011 rd 0101111
+    AMOADDD(Gpr, Gpr, Gpr, Aqrl),   // 00000 aq rl rs2 rs1 011 rd 0101111
+    AMOXORD(Gpr, Gpr, Gpr, Aqrl),   // 00100 aq rl rs2 rs1 011 rd 0101111
+    AMOANDD(Gpr, Gpr, Gpr, Aqrl),   // 01100 aq rl rs2 rs1 011 rd 0101111
+    AMOORD(Gpr, Gpr, Gpr, Aqrl),    // 01000 aq rl rs2 rs1 011 rd 0101111
+    AMOMIND(Gpr, Gpr, Gpr, Aqrl),   // 10000 aq rl rs2 rs1 011 rd 0101111
+    AMOMAXD(Gpr, Gpr, Gpr, Aqrl),   // 10100 aq rl rs2 rs1 011 rd 0101111
+    AMOMINUD(Gpr, Gpr, Gpr, Aqrl),  // 11000 aq rl rs2 rs1 011 rd 0101111
+    AMOMAXUD(Gpr, Gpr, Gpr, Aqrl),  // 11100 aq rl rs2 rs1 011 rd 0101111
+
+    // Atomic Compare-and-Swap Extension (Zacas): rd and rs2 name even-odd
+    // register pairs for amocas.d on RV32 and amocas.q on RV64
+
+    AMOCASW(Gpr, Gpr, Gpr, Aqrl),   // 00101 aq rl rs2 rs1 010 rd 0101111
+    AMOCASD(Gpr, Gpr, Gpr, Aqrl),   // 00101 aq rl rs2 rs1 011 rd 0101111
+    AMOCASQ(Gpr, Gpr, Gpr, Aqrl),   // 00101 aq rl rs2 rs1 100 rd 0101111  (RV64/128)
+
+    // Byte and Halfword Atomic Memory Operations (Zabha)
+
+    AMOSWAPB(Gpr, Gpr, Gpr, Aqrl),  // 00001 aq rl rs2 rs1 000 rd 0101111
+    AMOADDB(Gpr, Gpr, Gpr, Aqrl),   // 00000 aq rl rs2 rs1 000 rd 0101111
+    AMOXORB(Gpr, Gpr, Gpr, Aqrl),   // 00100 aq rl rs2 rs1 000 rd 0101111
+    AMOANDB(Gpr, Gpr, Gpr, Aqrl),   // 01100 aq rl rs2 rs1 000 rd 0101111
+    AMOORB(Gpr, Gpr, Gpr, Aqrl),    // 01000 aq rl rs2 rs1 000 rd 0101111
+    AMOMINB(Gpr, Gpr, Gpr, Aqrl),   // 10000 aq rl rs2 rs1 000 rd 0101111
+    AMOMAXB(Gpr, Gpr, Gpr, Aqrl),   // 10100 aq rl rs2 rs1 000 rd 0101111
+    AMOMINUB(Gpr, Gpr, Gpr, Aqrl),  // 11000 aq rl rs2 rs1 000 rd 0101111
+    AMOMAXUB(Gpr, Gpr, Gpr, Aqrl),  // 11100 aq rl rs2 rs1 000 rd 0101111
+    AMOCASB(Gpr, Gpr, Gpr, Aqrl),   // 00101 aq rl rs2 rs1 000 rd 0101111  (Zacas)
+
+    AMOSWAPH(Gpr, Gpr, Gpr, Aqrl),  // 00001 aq rl rs2 rs1 001 rd 0101111
+    AMOADDH(Gpr, Gpr, Gpr, Aqrl),   // 00000 aq rl rs2 rs1 001 rd 0101111
+    AMOXORH(Gpr, Gpr, Gpr, Aqrl),   // 00100 aq rl rs2 rs1 001 rd 0101111
+    AMOANDH(Gpr, Gpr, Gpr, Aqrl),   // 01100 aq rl rs2 rs1 001 rd 0101111
+    AMOORH(Gpr, Gpr, Gpr, Aqrl),    // 01000 aq rl rs2 rs1 001 rd 0101111
+    AMOMINH(Gpr, Gpr, Gpr, Aqrl),   // 10000 aq rl rs2 rs1 001 rd 0101111
+    AMOMAXH(Gpr, Gpr, Gpr, Aqrl),   // 10100 aq rl rs2 rs1 001 rd 0101111
+    AMOMINUH(Gpr, Gpr, Gpr, Aqrl),  // 11000 aq rl rs2 rs1 001 rd 0101111
+    AMOMAXUH(Gpr, Gpr, Gpr, Aqrl),  // 11100 aq rl rs2 rs1 001 rd 0101111
+    AMOCASH(Gpr, Gpr, Gpr, Aqrl),   // 00101 aq rl rs2 rs1 001 rd 0101111  (Zacas)
 
-    FADDS(Gpr, Gpr, Gpr, u8),
-    FSUBS(Gpr, Gpr, Gpr, u8),
-    FMULS(Gpr, Gpr, Gpr, u8),
-    FDIVS(Gpr, Gpr, Gpr, u8),
-    FSQRTS(Gpr, Gpr, Gpr, u8),
-
-    FSGNJS(Gpr, Gpr, Gpr, u8),
-    FSGNJNS(Gpr, Gpr, Gpr, u8),
-    FSGNJXS(Gpr, Gpr, Gpr, u8),
-
-    FMINS(Gpr, Gpr, Gpr, u8),
-    FMAXS(Gpr, Gpr, Gpr, u8),
-
-    FCVTWS(Gpr, Gpr, Gpr, u8),
-    FCVTWUS(Gpr, Gpr, Gpr, u8),
-
-    FMVXW(Gpr, Gpr, Gpr, u8),
-
-    FEQS(Gpr, Gpr, Gpr, u8),
-    FLTS(Gpr, Gpr, Gpr, u8),
-    FLES(Gpr, Gpr, Gpr, u8),
-
-    FCLASSS(Gpr, Gpr, Gpr, u8),
+    // Floating Point Extension
 
-    FCVTSW(Gpr, Gpr, Gpr, u8),
-    FCVTSWU(Gpr, Gpr, Gpr, u8),
-    FMVWX(Gpr, Gpr, Gpr, u8),
+    FLW(Fpr, Gpr, i32),                 // imm[11:0] rs1 010 rd 0000111
+    FSW(Fpr, Gpr, i32),                 // imm[11:5] rs2 rs1 010 imm[4:0] 0100111
+    FMADDS(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 00 rs2 rs1 rm rd 1000011
+    FMSUBS(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 00 rs2 rs1 rm rd 1000111
+    FNMSUBS(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 00 rs2 rs1 rm rd 1001011
+    FNMADDS(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 00 rs2 rs1 rm rd 1001111
+    FADDS(Fpr, Fpr, Fpr, Rm),           // 0000000 rs2 rs1 rm rd 1010011
+    FSUBS(Fpr, Fpr, Fpr, Rm),           // 0000100 rs2 rs1 rm rd 1010011
+    FMULS(Fpr, Fpr, Fpr, Rm),           // 0001000 rs2 rs1 rm rd 1010011
+    FDIVS(Fpr, Fpr, Fpr, Rm),           // 0001100 rs2 rs1 rm rd 1010011
+    FSQRTS(Fpr, Fpr, Rm),               // 0101100 00000 rs1 rm rd 1010011
+    FSGNJS(Fpr, Fpr, Fpr),              // 0010000 rs2 rs1 000 rd 1010011
+    FSGNJNS(Fpr, Fpr, Fpr),             // 0010000 rs2 rs1 001 rd 1010011
+    FSGNJXS(Fpr, Fpr, Fpr),             // 0010000 rs2 rs1 010 rd 1010011
+    FMINS(Fpr, Fpr, Fpr),               // 0010100 rs2 rs1 000 rd 1010011
+    FMAXS(Fpr, Fpr, Fpr),               // 0010100 rs2 rs1 001 rd 1010011
+    FEQS(Gpr, Fpr, Fpr),                // 1010000 rs2 rs1 010 rd 1010011
+    FLTS(Gpr, Fpr, Fpr),                // 1010000 rs2 rs1 001 rd 1010011
+    FLES(Gpr, Fpr, Fpr),                // 1010000 rs2 rs1 000 rd 1010011
+    FCLASSS(Gpr, Fpr),                  // 1110000 00000 rs1 001 rd 1010011
+    FCVTWS(Gpr, Fpr, Rm),               // 1100000 00000 rs1 rm rd 1010011
+    FCVTWUS(Gpr, Fpr, Rm),              // 1100000 00001 rs1 rm rd 1010011
+    FCVTSW(Fpr, Gpr, Rm),               // 1101000 00000 rs1 rm rd 1010011
+    FCVTSWU(Fpr, Gpr, Rm),              // 1101000 00001 rs1 rm rd 1010011
+    FMVXW(Gpr, Fpr),                    // 1110000 00000 rs1 000 rd 1010011
+    FMVWX(Fpr, Gpr),                    // 1111000 00000 rs1 000 rd 1010011
 
     // Floating Point Extension (64 bits)
 
-    FCVTLS(Gpr, Gpr, Gpr, u8),
-    FCVTLUS(Gpr, Gpr, Gpr, u8),
-    FCVTSL(Gpr, Gpr, Gpr, u8),
-    FCVTSLU(Gpr, Gpr, Gpr, u8),
+    FCVTLS(Gpr, Fpr, Rm),               // 1100000 00010 rs1 rm rd 1010011
+    FCVTLUS(Gpr, Fpr, Rm),              // 1100000 00011 rs1 rm rd 1010011
+    FCVTSL(Fpr, Gpr, Rm),               // 1101000 00010 rs1 rm rd 1010011
+    FCVTSLU(Fpr, Gpr, Rm),              // 1101000 00011 rs1 rm rd 1010011
 
     // Floating Point D Extension
 
-    FLD(Gpr),
-    FSD(Gpr),
-
-    FMADDD(Gpr),
-    FMSUBD(Gpr),
-    FNMSUBD(Gpr),
-    FNMADDD(Gpr),
-
-    FADDD(Gpr),
-    FSUBD(Gpr),
-    FMULD(Gpr),
-    FDIVD(Gpr),
-    FSQRTD(Gpr),
-
-    FSGNJD(Gpr),
-    FSGNJND(Gpr),
-    FSGNJXD(Gpr),
-
-    FMIND(Gpr),
-    FMAXD(Gpr),
-
-    FCVTSD(Gpr),
-    FCVTDS(Gpr),
-
-    FEQD(Gpr),
-    FLTD(Gpr),
-    FLED(Gpr),
-
-    FCLASSD(Gpr),
-
-    FCVTWD(Gpr),
-    FCVTWUD(Gpr),
-    FCVTDW(Gpr),
-    FCVTDWU(Gpr),
+    FLD(Fpr, Gpr, i32),                 // imm[11:0] rs1 011 rd 0000111
+    FSD(Fpr, Gpr, i32),                 // imm[11:5] rs2 rs1 011 imm[4:0] 0100111
+    FMADDD(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 01 rs2 rs1 rm rd 1000011
+    FMSUBD(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 01 rs2 rs1 rm rd 1000111
+    FNMSUBD(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 01 rs2 rs1 rm rd 1001011
+    FNMADDD(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 01 rs2 rs1 rm rd 1001111
+    FADDD(Fpr, Fpr, Fpr, Rm),           // 0000001 rs2 rs1 rm rd 1010011
+    FSUBD(Fpr, Fpr, Fpr, Rm),           // 0000101 rs2 rs1 rm rd 1010011
+    FMULD(Fpr, Fpr, Fpr, Rm),           // 0001001 rs2 rs1 rm rd 1010011
+    FDIVD(Fpr, Fpr, Fpr, Rm),           // 0001101 rs2 rs1 rm rd 1010011
+    FSQRTD(Fpr, Fpr, Rm),               // 0101101 00000 rs1 rm rd 1010011
+    FSGNJD(Fpr, Fpr, Fpr),              // 0010001 rs2 rs1 000 rd 1010011
+    FSGNJND(Fpr, Fpr, Fpr),             // 0010001 rs2 rs1 001 rd 1010011
+    FSGNJXD(Fpr, Fpr, Fpr),             // 0010001 rs2 rs1 010 rd 1010011
+    FMIND(Fpr, Fpr, Fpr),               // 0010101 rs2 rs1 000 rd 1010011
+    FMAXD(Fpr, Fpr, Fpr),               // 0010101 rs2 rs1 001 rd 1010011
+    FCVTSD(Fpr, Fpr, Rm),               // 0100000 00001 rs1 rm rd 1010011
+    FCVTDS(Fpr, Fpr, Rm),               // 0100001 00000 rs1 rm rd 1010011
+    FEQD(Gpr, Fpr, Fpr),                // 1010001 rs2 rs1 010 rd 1010011
+    FLTD(Gpr, Fpr, Fpr),                // 1010001 rs2 rs1 001 rd 1010011
+    FLED(Gpr, Fpr, Fpr),                // 1010001 rs2 rs1 000 rd 1010011
+    FCLASSD(Gpr, Fpr),                  // 1110001 00000 rs1 001 rd 1010011
+    FCVTWD(Gpr, Fpr, Rm),               // 1100001 00000 rs1 rm rd 1010011
+    FCVTWUD(Gpr, Fpr, Rm),              // 1100001 00001 rs1 rm rd 1010011
+    FCVTDW(Fpr, Gpr, Rm),               // 1101001 00000 rs1 rm rd 1010011
+    FCVTDWU(Fpr, Gpr, Rm),              // 1101001 00001 rs1 rm rd 1010011
 
     // Floating point D extensions (64 bits)
 
-    FCVTLD(Gpr),
-    FCVTLUD(Gpr),
-    FMVXD(Gpr),
-    FCVTDL(Gpr),
-    FCVTDLU(Gpr),
-    FMVDX(Gpr),
+    FCVTLD(Gpr, Fpr, Rm),               // 1100001 00010 rs1 rm rd 1010011
+    FCVTLUD(Gpr, Fpr, Rm),              // 1100001 00011 rs1 rm rd 1010011
+    FCVTDL(Fpr, Gpr, Rm),               // 1101001 00010 rs1 rm rd 1010011
+    FCVTDLU(Fpr, Gpr, Rm),              // 1101001 00011 rs1 rm rd 1010011
+    FMVXD(Gpr, Fpr),                    // 1110001 00000 rs1 000 rd 1010011
+    FMVDX(Fpr, Gpr),                    // 1111001 00000 rs1 000 rd 1010011
 
     // Floating point Q extensions
 
-    FLQ(Gpr),
-    FSQ(Gpr),
-
-    FMADDQ(Gpr),
-    FMSUBQ(Gpr),
-    FNMSUBQ(Gpr),
-    FNMADDQ(Gpr),
-
-    FADDQ(Gpr),
-    FSUBQ(Gpr),
-    FMULQ(Gpr),
-    FDIVQ(Gpr),
-    FSQRTQ(Gpr),
-
-    FSGNJQ(Gpr),
-    FSGNJNQ(Gpr),
-    FSGNJXQ(Gpr),
-
-    FMINQ(Gpr),
-    FMAXQ(Gpr),
-
-    FCVTSQ(Gpr),
-    FCVTQS(Gpr),
-    FCVTDQ(Gpr),
-    FCVTQD(Gpr),
-
-    FEQQ(Gpr),
-    FLTQ(Gpr),
-    FLEQ(Gpr),
-
-    FCLASSQ(Gpr),
-
-    FCVTWQ(Gpr),
-    FCVTWUQ(Gpr),
-    FCVTQW(Gpr),
-    FCVTQWU(Gpr),
+    FLQ(Fpr, Gpr, i32),                 // imm[11:0] rs1 100 rd 0000111
+    FSQ(Fpr, Gpr, i32),                 // imm[11:5] rs2 rs1 100 imm[4:0] 0100111
+    FMADDQ(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 11 rs2 rs1 rm rd 1000011
+    FMSUBQ(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 11 rs2 rs1 rm rd 1000111
+    FNMSUBQ(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 11 rs2 rs1 rm rd 1001011
+    FNMADDQ(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 11 rs2 rs1 rm rd 1001111
+    FADDQ(Fpr, Fpr, Fpr, Rm),           // 0000011 rs2 rs1 rm rd 1010011
+    FSUBQ(Fpr, Fpr, Fpr, Rm),           // 0000111 rs2 rs1 rm rd 1010011
+    FMULQ(Fpr, Fpr, Fpr, Rm),           // 0001011 rs2 rs1 rm rd 1010011
+    FDIVQ(Fpr, Fpr, Fpr, Rm),           // 0001111 rs2 rs1 rm rd 1010011
+    FSQRTQ(Fpr, Fpr, Rm),               // 0101111 00000 rs1 rm rd 1010011
+    FSGNJQ(Fpr, Fpr, Fpr),              // 0010011 rs2 rs1 000 rd 1010011
+    FSGNJNQ(Fpr, Fpr, Fpr),             // 0010011 rs2 rs1 001 rd 1010011
+    FSGNJXQ(Fpr, Fpr, Fpr),             // 0010011 rs2 rs1 010 rd 1010011
+    FMINQ(Fpr, Fpr, Fpr),               // 0010111 rs2 rs1 000 rd 1010011
+    FMAXQ(Fpr, Fpr, Fpr),               // 0010111 rs2 rs1 001 rd 1010011
+    FCVTSQ(Fpr, Fpr, Rm),               // 0100000 00011 rs1 rm rd 1010011
+    FCVTQS(Fpr, Fpr, Rm),               // 0100011 00000 rs1 rm rd 1010011
+    FCVTDQ(Fpr, Fpr, Rm),               // 0100001 00011 rs1 rm rd 1010011
+    FCVTQD(Fpr, Fpr, Rm),               // 0100011 00001 rs1 rm rd 1010011
+    FEQQ(Gpr, Fpr, Fpr),                // 1010011 rs2 rs1 010 rd 1010011
+    FLTQ(Gpr, Fpr, Fpr),                // 1010011 rs2 rs1 001 rd 1010011
+    FLEQ(Gpr, Fpr, Fpr),                // 1010011 rs2 rs1 000 rd 1010011
+    FCLASSQ(Gpr, Fpr),                  // 1110011 00000 rs1 001 rd 1010011
+    FCVTWQ(Gpr, Fpr, Rm),               // 1100011 00000 rs1 rm rd 1010011
+    FCVTWUQ(Gpr, Fpr, Rm),              // 1100011 00001 rs1 rm rd 1010011
+    FCVTQW(Fpr, Gpr, Rm),               // 1101011 00000 rs1 rm rd 1010011
+    FCVTQWU(Fpr, Gpr, Rm),              // 1101011 00001 rs1 rm rd 1010011
 
     // Floating point Q extensions (64 bits)
 
-    FCVTLQ(Gpr),
-    FCVTLUQ(Gpr),
-    FCVTQL(Gpr),
-    FCVTQLU(Gpr),
-*/
+    FCVTLQ(Gpr, Fpr, Rm),               // 1100011 00010 rs1 rm rd 1010011
+    FCVTLUQ(Gpr, Fpr, Rm),              // 1100011 00011 rs1 rm rd 1010011
+    FCVTQL(Fpr, Gpr, Rm),               // 1101011 00010 rs1 rm rd 1010011
+    FCVTQLU(Fpr, Gpr, Rm),              // 1101011 00011 rs1 rm rd 1010011
+
+    // Half-precision floating point (Zfh, Zfhmin)
+
+    FLH(Fpr, Gpr, i32),                 // imm[11:0] rs1 001 rd 0000111
+    FSH(Fpr, Gpr, i32),                 // imm[11:5] rs2 rs1 001 imm[4:0] 0100111
+    FMADDH(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 10 rs2 rs1 rm rd 1000011
+    FMSUBH(Fpr, Fpr, Fpr, Fpr, Rm),     // rs3 10 rs2 rs1 rm rd 1000111
+    FNMSUBH(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 10 rs2 rs1 rm rd 1001011
+    FNMADDH(Fpr, Fpr, Fpr, Fpr, Rm),    // rs3 10 rs2 rs1 rm rd 1001111
+    FADDH(Fpr, Fpr, Fpr, Rm),           // 0000010 rs2 rs1 rm rd 1010011
+    FSUBH(Fpr, Fpr, Fpr, Rm),           // 0000110 rs2 rs1 rm rd 1010011
+    FMULH(Fpr, Fpr, Fpr, Rm),           // 0001010 rs2 rs1 rm rd 1010011
+    FDIVH(Fpr, Fpr, Fpr, Rm),           // 0001110 rs2 rs1 rm rd 1010011
+    FSQRTH(Fpr, Fpr, Rm),               // 0101110 00000 rs1 rm rd 1010011
+    FSGNJH(Fpr, Fpr, Fpr),              // 0010010 rs2 rs1 000 rd 1010011
+    FSGNJNH(Fpr, Fpr, Fpr),             // 0010010 rs2 rs1 001 rd 1010011
+    FSGNJXH(Fpr, Fpr, Fpr),             // 0010010 rs2 rs1 010 rd 1010011
+    FMINH(Fpr, Fpr, Fpr),               // 0010110 rs2 rs1 000 rd 1010011
+    FMAXH(Fpr, Fpr, Fpr),               // 0010110 rs2 rs1 001 rd 1010011
+    FCVTSH(Fpr, Fpr, Rm),               // 0100000 00010 rs1 rm rd 1010011
+    FCVTHS(Fpr, Fpr, Rm),               // 0100010 00000 rs1 rm rd 1010011
+    FCVTDH(Fpr, Fpr, Rm),               // 0100001 00010 rs1 rm rd 1010011
+    FCVTHD(Fpr, Fpr, Rm),               // 0100010 00001 rs1 rm rd 1010011
+    FCVTQH(Fpr, Fpr, Rm),               // 0100011 00010 rs1 rm rd 1010011
+    FCVTHQ(Fpr, Fpr, Rm),               // 0100010 00011 rs1 rm rd 1010011
+    FEQH(Gpr, Fpr, Fpr),                // 1010010 rs2 rs1 010 rd 1010011
+    FLTH(Gpr, Fpr, Fpr),                // 1010010 rs2 rs1 001 rd 1010011
+    FLEH(Gpr, Fpr, Fpr),                // 1010010 rs2 rs1 000 rd 1010011
+    FCLASSH(Gpr, Fpr),                  // 1110010 00000 rs1 001 rd 1010011
+    FCVTWH(Gpr, Fpr, Rm),               // 1100010 00000 rs1 rm rd 1010011
+    FCVTWUH(Gpr, Fpr, Rm),              // 1100010 00001 rs1 rm rd 1010011
+    FCVTHW(Fpr, Gpr, Rm),               // 1101010 00000 rs1 rm rd 1010011
+    FCVTHWU(Fpr, Gpr, Rm),              // 1101010 00001 rs1 rm rd 1010011
+    FMVXH(Gpr, Fpr),                    // 1110010 00000 rs1 000 rd 1010011
+    FMVHX(Fpr, Gpr),                    // 1111010 00000 rs1 000 rd 1010011
+
+    // Half-precision floating point (Zfh), RV64 only
+
+    FCVTLH(Gpr, Fpr, Rm),               // 1100010 00010 rs1 rm rd 1010011
+    FCVTLUH(Gpr, Fpr, Rm),              // 1100010 00011 rs1 rm rd 1010011
+    FCVTHL(Fpr, Gpr, Rm),               // 1101010 00010 rs1 rm rd 1010011
+    FCVTHLU(Fpr, Gpr, Rm),              // 1101010 00011 rs1 rm rd 1010011
+
+    // Additional floating point (Zfa)
+
+    FLIS(Fpr, Fli),                     // 1111000 00001 imm 000 rd 1010011
+    FLID(Fpr, Fli),                     // 1111001 00001 imm 000 rd 1010011
+    FLIH(Fpr, Fli),                     // 1111010 00001 imm 000 rd 1010011
+    FLIQ(Fpr, Fli),                     // 1111011 00001 imm 000 rd 1010011
+    FMINMS(Fpr, Fpr, Fpr),              // 0010100 rs2 rs1 010 rd 1010011
+    FMAXMS(Fpr, Fpr, Fpr),              // 0010100 rs2 rs1 011 rd 1010011
+    FROUNDS(Fpr, Fpr, Rm),              // 0100000 00100 rs1 rm rd 1010011
+    FROUNDNXS(Fpr, Fpr, Rm),            // 0100000 00101 rs1 rm rd 1010011
+    FLEQS(Gpr, Fpr, Fpr),               // 1010000 rs2 rs1 100 rd 1010011
+    FLTQS(Gpr, Fpr, Fpr),               // 1010000 rs2 rs1 101 rd 1010011
+    FMINMD(Fpr, Fpr, Fpr),              // 0010101 rs2 rs1 010 rd 1010011
+    FMAXMD(Fpr, Fpr, Fpr),              // 0010101 rs2 rs1 011 rd 1010011
+    FROUNDD(Fpr, Fpr, Rm),              // 0100001 00100 rs1 rm rd 1010011
+    FROUNDNXD(Fpr, Fpr, Rm),            // 0100001 00101 rs1 rm rd 1010011
+    FLEQD(Gpr, Fpr, Fpr),               // 1010001 rs2 rs1 100 rd 1010011
+    FLTQD(Gpr, Fpr, Fpr),               // 1010001 rs2 rs1 101 rd 1010011
+    FMINMH(Fpr, Fpr, Fpr),              // 0010110 rs2 rs1 010 rd 1010011
+    FMAXMH(Fpr, Fpr, Fpr),              // 0010110 rs2 rs1 011 rd 1010011
+    FROUNDH(Fpr, Fpr, Rm),              // 0100010 00100 rs1 rm rd 1010011
+    FROUNDNXH(Fpr, Fpr, Rm),            // 0100010 00101 rs1 rm rd 1010011
+    FLEQH(Gpr, Fpr, Fpr),               // 1010010 rs2 rs1 100 rd 1010011
+    FLTQH(Gpr, Fpr, Fpr),               // 1010010 rs2 rs1 101 rd 1010011
+    FMINMQ(Fpr, Fpr, Fpr),              // 0010111 rs2 rs1 010 rd 1010011
+    FMAXMQ(Fpr, Fpr, Fpr),              // 0010111 rs2 rs1 011 rd 1010011
+    FROUNDQ(Fpr, Fpr, Rm),              // 0100011 00100 rs1 rm rd 1010011
+    FROUNDNXQ(Fpr, Fpr, Rm),            // 0100011 00101 rs1 rm rd 1010011
+    FLEQQ(Gpr, Fpr, Fpr),               // 1010011 rs2 rs1 100 rd 1010011
+    FLTQQ(Gpr, Fpr, Fpr),               // 1010011 rs2 rs1 101 rd 1010011
+    FCVTMODWD(Gpr, Fpr),                // 1100001 01000 rs1 001 rd 1010011
+
+    // Additional floating point (Zfa), RV32 only
+
+    FMVHXD(Gpr, Fpr),                   // 1110001 00001 rs1 000 rd 1010011
+    FMVPDX(Fpr, Gpr, Gpr),              // 1011001 rs2 rs1 000 rd 1010011
+
+    // Additional floating point (Zfa), RV64 only
+
+    FMVHXQ(Gpr, Fpr),                   // 1110011 00001 rs1 000 rd 1010011
+    FMVPQX(Fpr, Gpr, Gpr),              // 1011011 rs2 rs1 000 rd 1010011
 
     // Supervisor Memory-Management Instructions
 
@@ -327,64 +441,1474 @@ pub enum Inst {
     HLVD(Gpr, Gpr),         // 0110110 00000 rs1 100 rd 1110011     
     HSVD(Gpr, Gpr),         // 0110111 rs2 rs1 100 00000 1110011    
 
-    CUNDEF,
-
-    CILLEGAL,
-    CADDI4SPN,  
-    CFLD,
-    CLQ,
-    CLW,
-    CFLW,
-    CLD,
-    CFSD,
-    CSQ,
-    CSW,
-    CFSW,
-    CSD,
-
-    CNOP,
-    CADDI,
-    CJAL,
-    CADDIW,
-    CLI,
-    CADDI16SP,
-    CLUI,
-    CSRLI,
-    CSRLI64,
-    CSRAI,
-    CSRAI64,
-    CANDI,
-    CSUB,
-    CXOR,
-    COR,
-    CAND,
-    CSUBW,
-    CADDW,
-    CJ,
-    CBEQZ,
-    CBNEZ,
-
-    CSLLI,
-    CSLLI64,
-    CFLDSP,
-    CLQSP,
-    CLWSP,
-    CFLWSP,
-    CLDSP,
-    CJR,
-    CMV,
-    CEBREAK,
-    CJALR,
-    CADD,
-    CFSDSP,
-    CSQSP,
-    CSWSP,
-    CFSWSP,
-    CSDSP,
-
-    // TODO: bit manipulation
-    // TODO: vector (with separate floating point H extension)
+    // Compressed Extension, quadrant 0
+
+    CADDI4SPN(Gpr, u32),        // 000 nzuimm[5:4|9:6|2|3] rd′ 00
+    CFLD(Fpr, Gpr, u32),        // 001 uimm[5:3] rs1′ uimm[7:6] rd′ 00  (RV32/64)
+    CLQ(Gpr, Gpr, u32),         // 001 uimm[5:4|8] rs1′ uimm[7:6] rd′ 00  (RV128)
+    CLW(Gpr, Gpr, u32),         // 010 uimm[5:3] rs1′ uimm[2|6] rd′ 00
+    CFLW(Fpr, Gpr, u32),        // 011 uimm[5:3] rs1′ uimm[2|6] rd′ 00  (RV32)
+    CLD(Gpr, Gpr, u32),         // 011 uimm[5:3] rs1′ uimm[7:6] rd′ 00  (RV64/128)
+    CFSD(Fpr, Gpr, u32),        // 101 uimm[5:3] rs1′ uimm[7:6] rs2′ 00  (RV32/64)
+    CSQ(Gpr, Gpr, u32),         // 101 uimm[5:4|8] rs1′ uimm[7:6] rs2′ 00  (RV128)
+    CSW(Gpr, Gpr, u32),         // 110 uimm[5:3] rs1′ uimm[2|6] rs2′ 00
+    CFSW(Fpr, Gpr, u32),        // 111 uimm[5:3] rs1′ uimm[2|6] rs2′ 00  (RV32)
+    CSD(Gpr, Gpr, u32),         // 111 uimm[5:3] rs1′ uimm[7:6] rs2′ 00  (RV64/128)
+
+    // Compressed Extension, quadrant 1
+
+    CNOP,                       // 000 0 0 0 01
+    CADDI(Gpr, i32),            // 000 nzimm[5] rs1/rd nzimm[4:0] 01
+    CJAL(i32),                  // 001 imm[11|4|9:8|10|6|7|3:1|5] 01  (RV32)
+    CADDIW(Gpr, i32),           // 001 imm[5] rs1/rd imm[4:0] 01  (RV64/128)
+    CLI(Gpr, i32),              // 010 imm[5] rd imm[4:0] 01
+    CADDI16SP(i32),             // 011 nzimm[9] 2 nzimm[4|6|8:7|5] 01
+    CLUI(Gpr, u32),             // 011 nzimm[17] rd nzimm[16:12] 01
+    CSRLI(Gpr, u8),             // 100 nzuimm[5] 00 rs1′/rd′ nzuimm[4:0] 01
+    CSRLI64(Gpr),               // 100 0 00 rs1′/rd′ 0 01  (RV128)
+    CSRAI(Gpr, u8),             // 100 nzuimm[5] 01 rs1′/rd′ nzuimm[4:0] 01
+    CSRAI64(Gpr),               // 100 0 01 rs1′/rd′ 0 01  (RV128)
+    CANDI(Gpr, i32),            // 100 imm[5] 10 rs1′/rd′ imm[4:0] 01
+    CSUB(Gpr, Gpr),             // 100 0 11 rs1′/rd′ 00 rs2′ 01
+    CXOR(Gpr, Gpr),             // 100 0 11 rs1′/rd′ 01 rs2′ 01
+    COR(Gpr, Gpr),              // 100 0 11 rs1′/rd′ 10 rs2′ 01
+    CAND(Gpr, Gpr),             // 100 0 11 rs1′/rd′ 11 rs2′ 01
+    CSUBW(Gpr, Gpr),            // 100 1 11 rs1′/rd′ 00 rs2′ 01  (RV64/128)
+    CADDW(Gpr, Gpr),            // 100 1 11 rs1′/rd′ 01 rs2′ 01  (RV64/128)
+    CJ(i32),                    // 101 imm[11|4|9:8|10|6|7|3:1|5] 01
+    CBEQZ(Gpr, i32),            // 110 imm[8|4:3] rs1′ imm[7:6|2:1|5] 01
+    CBNEZ(Gpr, i32),            // 111 imm[8|4:3] rs1′ imm[7:6|2:1|5] 01
+
+    // Compressed Extension, quadrant 2
+
+    CSLLI(Gpr, u8),             // 000 nzuimm[5] rs1/rd nzuimm[4:0] 10
+    CSLLI64(Gpr),               // 000 0 rs1/rd 0 10  (RV128)
+    CFLDSP(Fpr, u32),           // 001 uimm[5] rd uimm[4:3|8:6] 10  (RV32/64)
+    CLQSP(Gpr, u32),            // 001 uimm[5] rd uimm[4|9:6] 10  (RV128)
+    CLWSP(Gpr, u32),            // 010 uimm[5] rd uimm[4:2|7:6] 10
+    CFLWSP(Fpr, u32),           // 011 uimm[5] rd uimm[4:2|7:6] 10  (RV32)
+    CLDSP(Gpr, u32),            // 011 uimm[5] rd uimm[4:3|8:6] 10  (RV64/128)
+    CJR(Gpr),                   // 100 0 rs1 0 10
+    CMV(Gpr, Gpr),              // 100 0 rd rs2 10
+    CEBREAK,                    // 100 1 0 0 10
+    CJALR(Gpr),                 // 100 1 rs1 0 10
+    CADD(Gpr, Gpr),             // 100 1 rs1/rd rs2 10
+    CFSDSP(Fpr, u32),           // 101 uimm[5:3|8:6] rs2 10  (RV32/64)
+    CSQSP(Gpr, u32),            // 101 uimm[5:4|9:6] rs2 10  (RV128)
+    CSWSP(Gpr, u32),            // 110 uimm[5:2|7:6] rs2 10
+    CFSWSP(Fpr, u32),           // 111 uimm[5:2|7:6] rs2 10  (RV32)
+    CSDSP(Gpr, u32),            // 111 uimm[5:3|8:6] rs2 10  (RV64/128)
+
+    // Additional compressed instructions (Zcb)
+
+    CLBU(Gpr, Gpr, u32),        // 100 000 rs1′ uimm[0|1] rd′ 00
+    CLHU(Gpr, Gpr, u32),        // 100 001 rs1′ 0 uimm[1] rd′ 00
+    CLH(Gpr, Gpr, u32),         // 100 001 rs1′ 1 uimm[1] rd′ 00
+    CSB(Gpr, Gpr, u32),         // 100 010 rs1′ uimm[0|1] rs2′ 00
+    CSH(Gpr, Gpr, u32),         // 100 011 rs1′ 0 uimm[1] rs2′ 00
+    CZEXTB(Gpr),                // 100 111 rd′ 11 000 01
+    CSEXTB(Gpr),                // 100 111 rd′ 11 001 01  (Zbb)
+    CZEXTH(Gpr),                // 100 111 rd′ 11 010 01  (Zbb)
+    CSEXTH(Gpr),                // 100 111 rd′ 11 011 01  (Zbb)
+    CZEXTW(Gpr),                // 100 111 rd′ 11 100 01  (RV64, Zba)
+    CNOT(Gpr),                  // 100 111 rd′ 11 101 01
+    CMUL(Gpr, Gpr),             // 100 111 rd′ 10 rs2′ 01  (M)
+
+    // Push/pop and register moves (Zcmp): rlist, stack_adj (bytes)
+
+    CMPUSH(Rlist, u32),         // 101 11000 rlist spimm[5:4] 10
+    CMPOP(Rlist, u32),          // 101 11010 rlist spimm[5:4] 10
+    CMPOPRETZ(Rlist, u32),      // 101 11100 rlist spimm[5:4] 10
+    CMPOPRET(Rlist, u32),       // 101 11110 rlist spimm[5:4] 10
+    CMMVSA01(Gpr, Gpr),         // 101 011 r1s′ 01 r2s′ 10
+    CMMVA01S(Gpr, Gpr),         // 101 011 r1s′ 11 r2s′ 10
+
+    // Table jumps (Zcmt)
+
+    CMJT(u8),                   // 101 000 index[7:0] 10  (index < 32)
+    CMJALT(u8),                 // 101 000 index[7:0] 10  (index >= 32)
+
+    // Address generation (Zba)
+
+    SH1ADD(Gpr, Gpr, Gpr),      // 0010000 rs2 rs1 010 rd 0110011
+    SH2ADD(Gpr, Gpr, Gpr),      // 0010000 rs2 rs1 100 rd 0110011
+    SH3ADD(Gpr, Gpr, Gpr),      // 0010000 rs2 rs1 110 rd 0110011
+
+    // Address generation (Zba), RV64 only
+
+    ADDUW(Gpr, Gpr, Gpr),       // 0000100 rs2 rs1 000 rd 0111011
+    SH1ADDUW(Gpr, Gpr, Gpr),    // 0010000 rs2 rs1 010 rd 0111011
+    SH2ADDUW(Gpr, Gpr, Gpr),    // 0010000 rs2 rs1 100 rd 0111011
+    SH3ADDUW(Gpr, Gpr, Gpr),    // 0010000 rs2 rs1 110 rd 0111011
+    SLLIUW(Gpr, Gpr, u8),       // 000010 shamt rs1 001 rd 0011011
+
+    // Basic bit manipulation (Zbb)
+
+    ANDN(Gpr, Gpr, Gpr),        // 0100000 rs2 rs1 111 rd 0110011
+    ORN(Gpr, Gpr, Gpr),         // 0100000 rs2 rs1 110 rd 0110011
+    XNOR(Gpr, Gpr, Gpr),        // 0100000 rs2 rs1 100 rd 0110011
+    CLZ(Gpr, Gpr),              // 0110000 00000 rs1 001 rd 0010011
+    CTZ(Gpr, Gpr),              // 0110000 00001 rs1 001 rd 0010011
+    CPOP(Gpr, Gpr),             // 0110000 00010 rs1 001 rd 0010011
+    MAX(Gpr, Gpr, Gpr),         // 0000101 rs2 rs1 110 rd 0110011
+    MAXU(Gpr, Gpr, Gpr),        // 0000101 rs2 rs1 111 rd 0110011
+    MIN(Gpr, Gpr, Gpr),         // 0000101 rs2 rs1 100 rd 0110011
+    MINU(Gpr, Gpr, Gpr),        // 0000101 rs2 rs1 101 rd 0110011
+    SEXTB(Gpr, Gpr),            // 0110000 00100 rs1 001 rd 0010011
+    SEXTH(Gpr, Gpr),            // 0110000 00101 rs1 001 rd 0010011
+    ZEXTH(Gpr, Gpr),            // 0000100 00000 rs1 100 rd 0110011  (RV32), 0111011 (RV64)
+    ROL(Gpr, Gpr, Gpr),         // 0110000 rs2 rs1 001 rd 0110011
+    ROR(Gpr, Gpr, Gpr),         // 0110000 rs2 rs1 101 rd 0110011
+    RORI(Gpr, Gpr, u8),         // 011000 shamt rs1 101 rd 0010011
+    ORCB(Gpr, Gpr),             // 001010000111 rs1 101 rd 0010011
+    REV8(Gpr, Gpr),             // 011010011000 rs1 101 rd 0010011  (RV32), 011010111000 (RV64)
+
+    // Basic bit manipulation (Zbb), RV64 only
+
+    CLZW(Gpr, Gpr),             // 0110000 00000 rs1 001 rd 0011011
+    CTZW(Gpr, Gpr),             // 0110000 00001 rs1 001 rd 0011011
+    CPOPW(Gpr, Gpr),            // 0110000 00010 rs1 001 rd 0011011
+    ROLW(Gpr, Gpr, Gpr),        // 0110000 rs2 rs1 001 rd 0111011
+    RORW(Gpr, Gpr, Gpr),        // 0110000 rs2 rs1 101 rd 0111011
+    RORIW(Gpr, Gpr, u8),        // 0110000 shamt rs1 101 rd 0011011
+
+    // Carry-less multiplication (Zbc)
+
+    CLMUL(Gpr, Gpr, Gpr),       // 0000101 rs2 rs1 001 rd 0110011
+    CLMULH(Gpr, Gpr, Gpr),      // 0000101 rs2 rs1 011 rd 0110011
+    CLMULR(Gpr, Gpr, Gpr),      // 0000101 rs2 rs1 010 rd 0110011
+
+    // Single-bit instructions (Zbs)
+
+    BCLR(Gpr, Gpr, Gpr),        // 0100100 rs2 rs1 001 rd 0110011
+    BCLRI(Gpr, Gpr, u8),        // 010010 shamt rs1 001 rd 0010011
+    BEXT(Gpr, Gpr, Gpr),        // 0100100 rs2 rs1 101 rd 0110011
+    BEXTI(Gpr, Gpr, u8),        // 010010 shamt rs1 101 rd 0010011
+    BINV(Gpr, Gpr, Gpr),        // 0110100 rs2 rs1 001 rd 0110011
+    BINVI(Gpr, Gpr, u8),        // 011010 shamt rs1 001 rd 0010011
+    BSET(Gpr, Gpr, Gpr),        // 0010100 rs2 rs1 001 rd 0110011
+    BSETI(Gpr, Gpr, u8),        // 001010 shamt rs1 001 rd 0010011
+
+    // Bit manipulation for cryptography (Zbkb)
+
+    PACK(Gpr, Gpr, Gpr),        // 0000100 rs2 rs1 100 rd 0110011
+    PACKH(Gpr, Gpr, Gpr),       // 0000100 rs2 rs1 111 rd 0110011
+    BREV8(Gpr, Gpr),            // 011010000111 rs1 101 rd 0010011
+
+    // Bit manipulation for cryptography (Zbkb), RV64 only
+
+    PACKW(Gpr, Gpr, Gpr),       // 0000100 rs2 rs1 100 rd 0111011
+
+    // Bit manipulation for cryptography (Zbkb), RV32 only
+
+    ZIP(Gpr, Gpr),              // 000010001111 rs1 001 rd 0010011
+    UNZIP(Gpr, Gpr),            // 000010001111 rs1 101 rd 0010011
+
+    // Crossbar permutations (Zbkx)
+
+    XPERM4(Gpr, Gpr, Gpr),      // 0010100 rs2 rs1 010 rd 0110011
+    XPERM8(Gpr, Gpr, Gpr),      // 0010100 rs2 rs1 100 rd 0110011
+
+    // AES (Zknd, Zkne), RV32 only; the last operand is the byte select
+
+    AES32DSI(Gpr, Gpr, Gpr, u8),    // bs 10101 rs2 rs1 000 rd 0110011
+    AES32DSMI(Gpr, Gpr, Gpr, u8),   // bs 10111 rs2 rs1 000 rd 0110011
+    AES32ESI(Gpr, Gpr, Gpr, u8),    // bs 10001 rs2 rs1 000 rd 0110011
+    AES32ESMI(Gpr, Gpr, Gpr, u8),   // bs 10011 rs2 rs1 000 rd 0110011
+
+    // AES (Zknd, Zkne), RV64 only
+
+    AES64DS(Gpr, Gpr, Gpr),     // 0011101 rs2 rs1 000 rd 0110011
+    AES64DSM(Gpr, Gpr, Gpr),    // 0011111 rs2 rs1 000 rd 0110011
+    AES64IM(Gpr, Gpr),          // 001100000000 rs1 001 rd 0010011
+    AES64ES(Gpr, Gpr, Gpr),     // 0011001 rs2 rs1 000 rd 0110011
+    AES64ESM(Gpr, Gpr, Gpr),    // 0011011 rs2 rs1 000 rd 0110011
+    AES64KS1I(Gpr, Gpr, u8),    // 00110001 rnum rs1 001 rd 0010011
+    AES64KS2(Gpr, Gpr, Gpr),    // 0111111 rs2 rs1 000 rd 0110011
+
+    // SHA-2 (Zknh)
+
+    SHA256SIG0(Gpr, Gpr),       // 000100000010 rs1 001 rd 0010011
+    SHA256SIG1(Gpr, Gpr),       // 000100000011 rs1 001 rd 0010011
+    SHA256SUM0(Gpr, Gpr),       // 000100000000 rs1 001 rd 0010011
+    SHA256SUM1(Gpr, Gpr),       // 000100000001 rs1 001 rd 0010011
+
+    // SHA-2 (Zknh), RV32 only
+
+    SHA512SIG0H(Gpr, Gpr, Gpr), // 0101110 rs2 rs1 000 rd 0110011
+    SHA512SIG0L(Gpr, Gpr, Gpr), // 0101010 rs2 rs1 000 rd 0110011
+    SHA512SIG1H(Gpr, Gpr, Gpr), // 0101111 rs2 rs1 000 rd 0110011
+    SHA512SIG1L(Gpr, Gpr, Gpr), // 0101011 rs2 rs1 000 rd 0110011
+    SHA512SUM0R(Gpr, Gpr, Gpr), // 0101000 rs2 rs1 000 rd 0110011
+    SHA512SUM1R(Gpr, Gpr, Gpr), // 0101001 rs2 rs1 000 rd 0110011
+
+    // SHA-2 (Zknh), RV64 only
+
+    SHA512SIG0(Gpr, Gpr),       // 000100000110 rs1 001 rd 0010011
+    SHA512SIG1(Gpr, Gpr),       // 000100000111 rs1 001 rd 0010011
+    SHA512SUM0(Gpr, Gpr),       // 000100000100 rs1 001 rd 0010011
+    SHA512SUM1(Gpr, Gpr),       // 000100000101 rs1 001 rd 0010011
+
+    // SM4 (Zksed); the last operand is the byte select
+
+    SM4ED(Gpr, Gpr, Gpr, u8),   // bs 11000 rs2 rs1 000 rd 0110011
+    SM4KS(Gpr, Gpr, Gpr, u8),   // bs 11010 rs2 rs1 000 rd 0110011
+
+    // SM3 (Zksh)
+
+    SM3P0(Gpr, Gpr),            // 000100001000 rs1 001 rd 0010011
+    SM3P1(Gpr, Gpr),            // 000100001001 rs1 001 rd 0010011
+
+    // Integer conditional operations (Zicond)
+
+    CZEROEQZ(Gpr, Gpr, Gpr),    // 0000111 rs2 rs1 101 rd 0110011
+    CZERONEZ(Gpr, Gpr, Gpr),    // 0000111 rs2 rs1 111 rd 0110011
+
+    // Cache-block management (Zicbom, Zicboz)
+
+    CBOCLEAN(Gpr),              // 000000000001 rs1 010 00000 0001111
+    CBOFLUSH(Gpr),              // 000000000010 rs1 010 00000 0001111
+    CBOINVAL(Gpr),              // 000000000000 rs1 010 00000 0001111
+    CBOZERO(Gpr),               // 000000000100 rs1 010 00000 0001111  (Zicboz)
+
+    // Cache-block prefetch hints (Zicbop): rs1, offset (a multiple of 32)
+
+    PREFETCHI(Gpr, i32),        // imm[11:5] 00000 rs1 110 00000 0010011
+    PREFETCHR(Gpr, i32),        // imm[11:5] 00001 rs1 110 00000 0010011
+    PREFETCHW(Gpr, i32),        // imm[11:5] 00011 rs1 110 00000 0010011
+
+    // Wait-on-reservation-set (Zawrs)
+
+    WRSNTO,                     // 000000001101 00000 000 00000 1110011
+    WRSSTO,                     // 000000011101 00000 000 00000 1110011
+
+    // Non-temporal locality hints (Zihintntl)
+
+    NTLP1,                      // 0000000 00010 00000 000 00000 0110011
+    NTLPALL,                    // 0000000 00011 00000 000 00000 0110011
+    NTLS1,                      // 0000000 00100 00000 000 00000 0110011
+    NTLALL,                     // 0000000 00101 00000 000 00000 0110011
+
+    // Vector configuration
+
+    VSETVLI(Gpr, Gpr, Vtype),                   // 0 zimm[10:0] rs1 111 rd 1010111
+    VSETIVLI(Gpr, u32, Vtype),                  // 11 zimm[9:0] uimm[4:0] 111 rd 1010111
+    VSETVL(Gpr, Gpr, Gpr),                      // 1000000 rs2 rs1 111 rd 1010111
+
+    // Vector loads and stores: the EEW is part of the mnemonic, nf (1-8) is the
+    // number of fields of a segment access (vlseg<nf>e<eew>.v when above 1)
+
+    VLE(Vr, Gpr, Sew, u8, Vm),                  // nf-1 0 00 vm 00000 rs1 width vd 0000111
+    VLEFF(Vr, Gpr, Sew, u8, Vm),                // nf-1 0 00 vm 10000 rs1 width vd 0000111
+    VLM(Vr, Gpr),                               // 000 0 00 1 01011 rs1 000 vd 0000111
+    VLSE(Vr, Gpr, Gpr, Sew, u8, Vm),            // nf-1 0 10 vm rs2 rs1 width vd 0000111
+    VLUXEI(Vr, Gpr, Vr, Sew, u8, Vm),           // nf-1 0 01 vm vs2 rs1 width vd 0000111
+    VLOXEI(Vr, Gpr, Vr, Sew, u8, Vm),           // nf-1 0 11 vm vs2 rs1 width vd 0000111
+    VLRE(Vr, Gpr, Sew, u8),                     // nf-1 0 00 1 01000 rs1 width vd 0000111  (nf = 1, 2, 4, 8)
+    VSE(Vr, Gpr, Sew, u8, Vm),                  // nf-1 0 00 vm 00000 rs1 width vs3 0100111
+    VSM(Vr, Gpr),                               // 000 0 00 1 01011 rs1 000 vs3 0100111
+    VSSE(Vr, Gpr, Gpr, Sew, u8, Vm),            // nf-1 0 10 vm rs2 rs1 width vs3 0100111
+    VSUXEI(Vr, Gpr, Vr, Sew, u8, Vm),           // nf-1 0 01 vm vs2 rs1 width vs3 0100111
+    VSOXEI(Vr, Gpr, Vr, Sew, u8, Vm),           // nf-1 0 11 vm vs2 rs1 width vs3 0100111
+    VSR(Vr, Gpr, u8),                           // nf-1 0 00 1 01000 rs1 000 vs3 0100111  (nf = 1, 2, 4, 8)
+
+    // Vector integer arithmetic (OPIVV, OPIVX, OPIVI)
+
+    VADDVV(Vr, Vr, Vr, Vm),                     // 000000 vm vs2 vs1 000 vd 1010111
+    VADDVX(Vr, Vr, Gpr, Vm),                    // 000000 vm vs2 rs1 100 vd 1010111
+    VADDVI(Vr, Vr, i32, Vm),                    // 000000 vm vs2 simm5 011 vd 1010111
+    VSUBVV(Vr, Vr, Vr, Vm),                     // 000010 vm vs2 vs1 000 vd 1010111
+    VSUBVX(Vr, Vr, Gpr, Vm),                    // 000010 vm vs2 rs1 100 vd 1010111
+    VRSUBVX(Vr, Vr, Gpr, Vm),                   // 000011 vm vs2 rs1 100 vd 1010111
+    VRSUBVI(Vr, Vr, i32, Vm),                   // 000011 vm vs2 simm5 011 vd 1010111
+    VMINUVV(Vr, Vr, Vr, Vm),                    // 000100 vm vs2 vs1 000 vd 1010111
+    VMINUVX(Vr, Vr, Gpr, Vm),                   // 000100 vm vs2 rs1 100 vd 1010111
+    VMINVV(Vr, Vr, Vr, Vm),                     // 000101 vm vs2 vs1 000 vd 1010111
+    VMINVX(Vr, Vr, Gpr, Vm),                    // 000101 vm vs2 rs1 100 vd 1010111
+    VMAXUVV(Vr, Vr, Vr, Vm),                    // 000110 vm vs2 vs1 000 vd 1010111
+    VMAXUVX(Vr, Vr, Gpr, Vm),                   // 000110 vm vs2 rs1 100 vd 1010111
+    VMAXVV(Vr, Vr, Vr, Vm),                     // 000111 vm vs2 vs1 000 vd 1010111
+    VMAXVX(Vr, Vr, Gpr, Vm),                    // 000111 vm vs2 rs1 100 vd 1010111
+    VANDVV(Vr, Vr, Vr, Vm),                     // 001001 vm vs2 vs1 000 vd 1010111
+    VANDVX(Vr, Vr, Gpr, Vm),                    // 001001 vm vs2 rs1 100 vd 1010111
+    VANDVI(Vr, Vr, i32, Vm),                    // 001001 vm vs2 simm5 011 vd 1010111
+    VORVV(Vr, Vr, Vr, Vm),                      // 001010 vm vs2 vs1 000 vd 1010111
+    VORVX(Vr, Vr, Gpr, Vm),                     // 001010 vm vs2 rs1 100 vd 1010111
+    VORVI(Vr, Vr, i32, Vm),                     // 001010 vm vs2 simm5 011 vd 1010111
+    VXORVV(Vr, Vr, Vr, Vm),                     // 001011 vm vs2 vs1 000 vd 1010111
+    VXORVX(Vr, Vr, Gpr, Vm),                    // 001011 vm vs2 rs1 100 vd 1010111
+    VXORVI(Vr, Vr, i32, Vm),                    // 001011 vm vs2 simm5 011 vd 1010111
+    VRGATHERVV(Vr, Vr, Vr, Vm),                 // 001100 vm vs2 vs1 000 vd 1010111
+    VRGATHERVX(Vr, Vr, Gpr, Vm),                // 001100 vm vs2 rs1 100 vd 1010111
+    VRGATHERVI(Vr, Vr, u32, Vm),                // 001100 vm vs2 uimm5 011 vd 1010111
+    VRGATHEREI16VV(Vr, Vr, Vr, Vm),             // 001110 vm vs2 vs1 000 vd 1010111
+    VSLIDEUPVX(Vr, Vr, Gpr, Vm),                // 001110 vm vs2 rs1 100 vd 1010111
+    VSLIDEUPVI(Vr, Vr, u32, Vm),                // 001110 vm vs2 uimm5 011 vd 1010111
+    VSLIDEDOWNVX(Vr, Vr, Gpr, Vm),              // 001111 vm vs2 rs1 100 vd 1010111
+    VSLIDEDOWNVI(Vr, Vr, u32, Vm),              // 001111 vm vs2 uimm5 011 vd 1010111
+    VADCVVM(Vr, Vr, Vr),                        // 010000 0 vs2 vs1 000 vd 1010111
+    VADCVXM(Vr, Vr, Gpr),                       // 010000 0 vs2 rs1 100 vd 1010111
+    VADCVIM(Vr, Vr, i32),                       // 010000 0 vs2 simm5 011 vd 1010111
+    VMADCVVM(Vr, Vr, Vr),                       // 010001 0 vs2 vs1 000 vd 1010111
+    VMADCVXM(Vr, Vr, Gpr),                      // 010001 0 vs2 rs1 100 vd 1010111
+    VMADCVIM(Vr, Vr, i32),                      // 010001 0 vs2 simm5 011 vd 1010111
+    VMADCVV(Vr, Vr, Vr),                        // 010001 1 vs2 vs1 000 vd 1010111
+    VMADCVX(Vr, Vr, Gpr),                       // 010001 1 vs2 rs1 100 vd 1010111
+    VMADCVI(Vr, Vr, i32),                       // 010001 1 vs2 simm5 011 vd 1010111
+    VSBCVVM(Vr, Vr, Vr),                        // 010010 0 vs2 vs1 000 vd 1010111
+    VSBCVXM(Vr, Vr, Gpr),                       // 010010 0 vs2 rs1 100 vd 1010111
+    VMSBCVVM(Vr, Vr, Vr),                       // 010011 0 vs2 vs1 000 vd 1010111
+    VMSBCVXM(Vr, Vr, Gpr),                      // 010011 0 vs2 rs1 100 vd 1010111
+    VMSBCVV(Vr, Vr, Vr),                        // 010011 1 vs2 vs1 000 vd 1010111
+    VMSBCVX(Vr, Vr, Gpr),                       // 010011 1 vs2 rs1 100 vd 1010111
+    VMERGEVVM(Vr, Vr, Vr),                      // 010111 0 vs2 vs1 000 vd 1010111
+    VMERGEVXM(Vr, Vr, Gpr),                     // 010111 0 vs2 rs1 100 vd 1010111
+    VMERGEVIM(Vr, Vr, i32),                     // 010111 0 vs2 simm5 011 vd 1010111
+    VMVVV(Vr, Vr),                              // 010111 1 00000 vs1 000 vd 1010111
+    VMVVX(Vr, Gpr),                             // 010111 1 00000 rs1 100 vd 1010111
+    VMVVI(Vr, i32),                             // 010111 1 00000 simm5 011 vd 1010111
+    VMSEQVV(Vr, Vr, Vr, Vm),                    // 011000 vm vs2 vs1 000 vd 1010111
+    VMSEQVX(Vr, Vr, Gpr, Vm),                   // 011000 vm vs2 rs1 100 vd 1010111
+    VMSEQVI(Vr, Vr, i32, Vm),                   // 011000 vm vs2 simm5 011 vd 1010111
+    VMSNEVV(Vr, Vr, Vr, Vm),                    // 011001 vm vs2 vs1 000 vd 1010111
+    VMSNEVX(Vr, Vr, Gpr, Vm),                   // 011001 vm vs2 rs1 100 vd 1010111
+    VMSNEVI(Vr, Vr, i32, Vm),                   // 011001 vm vs2 simm5 011 vd 1010111
+    VMSLTUVV(Vr, Vr, Vr, Vm),                   // 011010 vm vs2 vs1 000 vd 1010111
+    VMSLTUVX(Vr, Vr, Gpr, Vm),                  // 011010 vm vs2 rs1 100 vd 1010111
+    VMSLTVV(Vr, Vr, Vr, Vm),                    // 011011 vm vs2 vs1 000 vd 1010111
+    VMSLTVX(Vr, Vr, Gpr, Vm),                   // 011011 vm vs2 rs1 100 vd 1010111
+    VMSLEUVV(Vr, Vr, Vr, Vm),                   // 011100 vm vs2 vs1 000 vd 1010111
+    VMSLEUVX(Vr, Vr, Gpr, Vm),                  // 011100 vm vs2 rs1 100 vd 1010111
+    VMSLEUVI(Vr, Vr, i32, Vm),                  // 011100 vm vs2 simm5 011 vd 1010111
+    VMSLEVV(Vr, Vr, Vr, Vm),                    // 011101 vm vs2 vs1 000 vd 1010111
+    VMSLEVX(Vr, Vr, Gpr, Vm),                   // 011101 vm vs2 rs1 100 vd 1010111
+    VMSLEVI(Vr, Vr, i32, Vm),                   // 011101 vm vs2 simm5 011 vd 1010111
+    VMSGTUVX(Vr, Vr, Gpr, Vm),                  // 011110 vm vs2 rs1 100 vd 1010111
+    VMSGTUVI(Vr, Vr, i32, Vm),                  // 011110 vm vs2 simm5 011 vd 1010111
+    VMSGTVX(Vr, Vr, Gpr, Vm),                   // 011111 vm vs2 rs1 100 vd 1010111
+    VMSGTVI(Vr, Vr, i32, Vm),                   // 011111 vm vs2 simm5 011 vd 1010111
+    VSADDUVV(Vr, Vr, Vr, Vm),                   // 100000 vm vs2 vs1 000 vd 1010111
+    VSADDUVX(Vr, Vr, Gpr, Vm),                  // 100000 vm vs2 rs1 100 vd 1010111
+    VSADDUVI(Vr, Vr, i32, Vm),                  // 100000 vm vs2 simm5 011 vd 1010111
+    VSADDVV(Vr, Vr, Vr, Vm),                    // 100001 vm vs2 vs1 000 vd 1010111
+    VSADDVX(Vr, Vr, Gpr, Vm),                   // 100001 vm vs2 rs1 100 vd 1010111
+    VSADDVI(Vr, Vr, i32, Vm),                   // 100001 vm vs2 simm5 011 vd 1010111
+    VSSUBUVV(Vr, Vr, Vr, Vm),                   // 100010 vm vs2 vs1 000 vd 1010111
+    VSSUBUVX(Vr, Vr, Gpr, Vm),                  // 100010 vm vs2 rs1 100 vd 1010111
+    VSSUBVV(Vr, Vr, Vr, Vm),                    // 100011 vm vs2 vs1 000 vd 1010111
+    VSSUBVX(Vr, Vr, Gpr, Vm),                   // 100011 vm vs2 rs1 100 vd 1010111
+    VSLLVV(Vr, Vr, Vr, Vm),                     // 100101 vm vs2 vs1 000 vd 1010111
+    VSLLVX(Vr, Vr, Gpr, Vm),                    // 100101 vm vs2 rs1 100 vd 1010111
+    VSLLVI(Vr, Vr, u32, Vm),                    // 100101 vm vs2 uimm5 011 vd 1010111
+    VSMULVV(Vr, Vr, Vr, Vm),                    // 100111 vm vs2 vs1 000 vd 1010111
+    VSMULVX(Vr, Vr, Gpr, Vm),                   // 100111 vm vs2 rs1 100 vd 1010111
+    VMVNRV(Vr, Vr, u8),                         // 100111 1 vs2 nr-1 011 vd 1010111
+    VSRLVV(Vr, Vr, Vr, Vm),                     // 101000 vm vs2 vs1 000 vd 1010111
+    VSRLVX(Vr, Vr, Gpr, Vm),                    // 101000 vm vs2 rs1 100 vd 1010111
+    VSRLVI(Vr, Vr, u32, Vm),                    // 101000 vm vs2 uimm5 011 vd 1010111
+    VSRAVV(Vr, Vr, Vr, Vm),                     // 101001 vm vs2 vs1 000 vd 1010111
+    VSRAVX(Vr, Vr, Gpr, Vm),                    // 101001 vm vs2 rs1 100 vd 1010111
+    VSRAVI(Vr, Vr, u32, Vm),                    // 101001 vm vs2 uimm5 011 vd 1010111
+    VSSRLVV(Vr, Vr, Vr, Vm),                    // 101010 vm vs2 vs1 000 vd 1010111
+    VSSRLVX(Vr, Vr, Gpr, Vm),                   // 101010 vm vs2 rs1 100 vd 1010111
+    VSSRLVI(Vr, Vr, u32, Vm),                   // 101010 vm vs2 uimm5 011 vd 1010111
+    VSSRAVV(Vr, Vr, Vr, Vm),                    // 101011 vm vs2 vs1 000 vd 1010111
+    VSSRAVX(Vr, Vr, Gpr, Vm),                   // 101011 vm vs2 rs1 100 vd 1010111
+    VSSRAVI(Vr, Vr, u32, Vm),                   // 101011 vm vs2 uimm5 011 vd 1010111
+    VNSRLWV(Vr, Vr, Vr, Vm),                    // 101100 vm vs2 vs1 000 vd 1010111
+    VNSRLWX(Vr, Vr, Gpr, Vm),                   // 101100 vm vs2 rs1 100 vd 1010111
+    VNSRLWI(Vr, Vr, u32, Vm),                   // 101100 vm vs2 uimm5 011 vd 1010111
+    VNSRAWV(Vr, Vr, Vr, Vm),                    // 101101 vm vs2 vs1 000 vd 1010111
+    VNSRAWX(Vr, Vr, Gpr, Vm),                   // 101101 vm vs2 rs1 100 vd 1010111
+    VNSRAWI(Vr, Vr, u32, Vm),                   // 101101 vm vs2 uimm5 011 vd 1010111
+    VNCLIPUWV(Vr, Vr, Vr, Vm),                  // 101110 vm vs2 vs1 000 vd 1010111
+    VNCLIPUWX(Vr, Vr, Gpr, Vm),                 // 101110 vm vs2 rs1 100 vd 1010111
+    VNCLIPUWI(Vr, Vr, u32, Vm),                 // 101110 vm vs2 uimm5 011 vd 1010111
+    VNCLIPWV(Vr, Vr, Vr, Vm),                   // 101111 vm vs2 vs1 000 vd 1010111
+    VNCLIPWX(Vr, Vr, Gpr, Vm),                  // 101111 vm vs2 rs1 100 vd 1010111
+    VNCLIPWI(Vr, Vr, u32, Vm),                  // 101111 vm vs2 uimm5 011 vd 1010111
+    VWREDSUMUVS(Vr, Vr, Vr, Vm),                // 110000 vm vs2 vs1 000 vd 1010111
+    VWREDSUMVS(Vr, Vr, Vr, Vm),                 // 110001 vm vs2 vs1 000 vd 1010111
+
+    // Vector integer arithmetic (OPMVV, OPMVX)
+
+    VREDSUMVS(Vr, Vr, Vr, Vm),                  // 000000 vm vs2 vs1 010 vd 1010111
+    VREDANDVS(Vr, Vr, Vr, Vm),                  // 000001 vm vs2 vs1 010 vd 1010111
+    VREDORVS(Vr, Vr, Vr, Vm),                   // 000010 vm vs2 vs1 010 vd 1010111
+    VREDXORVS(Vr, Vr, Vr, Vm),                  // 000011 vm vs2 vs1 010 vd 1010111
+    VREDMINUVS(Vr, Vr, Vr, Vm),                 // 000100 vm vs2 vs1 010 vd 1010111
+    VREDMINVS(Vr, Vr, Vr, Vm),                  // 000101 vm vs2 vs1 010 vd 1010111
+    VREDMAXUVS(Vr, Vr, Vr, Vm),                 // 000110 vm vs2 vs1 010 vd 1010111
+    VREDMAXVS(Vr, Vr, Vr, Vm),                  // 000111 vm vs2 vs1 010 vd 1010111
+    VAADDUVV(Vr, Vr, Vr, Vm),                   // 001000 vm vs2 vs1 010 vd 1010111
+    VAADDUVX(Vr, Vr, Gpr, Vm),                  // 001000 vm vs2 rs1 110 vd 1010111
+    VAADDVV(Vr, Vr, Vr, Vm),                    // 001001 vm vs2 vs1 010 vd 1010111
+    VAADDVX(Vr, Vr, Gpr, Vm),                   // 001001 vm vs2 rs1 110 vd 1010111
+    VASUBUVV(Vr, Vr, Vr, Vm),                   // 001010 vm vs2 vs1 010 vd 1010111
+    VASUBUVX(Vr, Vr, Gpr, Vm),                  // 001010 vm vs2 rs1 110 vd 1010111
+    VASUBVV(Vr, Vr, Vr, Vm),                    // 001011 vm vs2 vs1 010 vd 1010111
+    VASUBVX(Vr, Vr, Gpr, Vm),                   // 001011 vm vs2 rs1 110 vd 1010111
+    VSLIDE1UPVX(Vr, Vr, Gpr, Vm),               // 001110 vm vs2 rs1 110 vd 1010111
+    VSLIDE1DOWNVX(Vr, Vr, Gpr, Vm),             // 001111 vm vs2 rs1 110 vd 1010111
+    VMVXS(Gpr, Vr),                             // 010000 1 vs2 00000 010 rd 1010111
+    VMVSX(Vr, Gpr),                             // 010000 1 00000 rs1 110 vd 1010111
+    VCPOPM(Gpr, Vr, Vm),                        // 010000 vm vs2 10000 010 rd 1010111
+    VFIRSTM(Gpr, Vr, Vm),                       // 010000 vm vs2 10001 010 rd 1010111
+    VZEXTVF8(Vr, Vr, Vm),                       // 010010 vm vs2 00010 010 vd 1010111
+    VSEXTVF8(Vr, Vr, Vm),                       // 010010 vm vs2 00011 010 vd 1010111
+    VZEXTVF4(Vr, Vr, Vm),                       // 010010 vm vs2 00100 010 vd 1010111
+    VSEXTVF4(Vr, Vr, Vm),                       // 010010 vm vs2 00101 010 vd 1010111
+    VZEXTVF2(Vr, Vr, Vm),                       // 010010 vm vs2 00110 010 vd 1010111
+    VSEXTVF2(Vr, Vr, Vm),                       // 010010 vm vs2 00111 010 vd 1010111
+    VMSBFM(Vr, Vr, Vm),                         // 010100 vm vs2 00001 010 vd 1010111
+    VMSOFM(Vr, Vr, Vm),                         // 010100 vm vs2 00010 010 vd 1010111
+    VMSIFM(Vr, Vr, Vm),                         // 010100 vm vs2 00011 010 vd 1010111
+    VIOTAM(Vr, Vr, Vm),                         // 010100 vm vs2 10000 010 vd 1010111
+    VIDV(Vr, Vm),                               // 010100 vm 00000 10001 010 vd 1010111
+    VCOMPRESSVM(Vr, Vr, Vr),                    // 010111 1 vs2 vs1 010 vd 1010111
+    VMANDNMM(Vr, Vr, Vr),                       // 011000 1 vs2 vs1 010 vd 1010111
+    VMANDMM(Vr, Vr, Vr),                        // 011001 1 vs2 vs1 010 vd 1010111
+    VMORMM(Vr, Vr, Vr),                         // 011010 1 vs2 vs1 010 vd 1010111
+    VMXORMM(Vr, Vr, Vr),                        // 011011 1 vs2 vs1 010 vd 1010111
+    VMORNMM(Vr, Vr, Vr),                        // 011100 1 vs2 vs1 010 vd 1010111
+    VMNANDMM(Vr, Vr, Vr),                       // 011101 1 vs2 vs1 010 vd 1010111
+    VMNORMM(Vr, Vr, Vr),                        // 011110 1 vs2 vs1 010 vd 1010111
+    VMXNORMM(Vr, Vr, Vr),                       // 011111 1 vs2 vs1 010 vd 1010111
+    VDIVUVV(Vr, Vr, Vr, Vm),                    // 100000 vm vs2 vs1 010 vd 1010111
+    VDIVUVX(Vr, Vr, Gpr, Vm),                   // 100000 vm vs2 rs1 110 vd 1010111
+    VDIVVV(Vr, Vr, Vr, Vm),                     // 100001 vm vs2 vs1 010 vd 1010111
+    VDIVVX(Vr, Vr, Gpr, Vm),                    // 100001 vm vs2 rs1 110 vd 1010111
+    VREMUVV(Vr, Vr, Vr, Vm),                    // 100010 vm vs2 vs1 010 vd 1010111
+    VREMUVX(Vr, Vr, Gpr, Vm),                   // 100010 vm vs2 rs1 110 vd 1010111
+    VREMVV(Vr, Vr, Vr, Vm),                     // 100011 vm vs2 vs1 010 vd 1010111
+    VREMVX(Vr, Vr, Gpr, Vm),                    // 100011 vm vs2 rs1 110 vd 1010111
+    VMULHUVV(Vr, Vr, Vr, Vm),                   // 100100 vm vs2 vs1 010 vd 1010111
+    VMULHUVX(Vr, Vr, Gpr, Vm),                  // 100100 vm vs2 rs1 110 vd 1010111
+    VMULVV(Vr, Vr, Vr, Vm),                     // 100101 vm vs2 vs1 010 vd 1010111
+    VMULVX(Vr, Vr, Gpr, Vm),                    // 100101 vm vs2 rs1 110 vd 1010111
+    VMULHSUVV(Vr, Vr, Vr, Vm),                  // 100110 vm vs2 vs1 010 vd 1010111
+    VMULHSUVX(Vr, Vr, Gpr, Vm),                 // 100110 vm vs2 rs1 110 vd 1010111
+    VMULHVV(Vr, Vr, Vr, Vm),                    // 100111 vm vs2 vs1 010 vd 1010111
+    VMULHVX(Vr, Vr, Gpr, Vm),                   // 100111 vm vs2 rs1 110 vd 1010111
+    VMADDVV(Vr, Vr, Vr, Vm),                    // 101001 vm vs2 vs1 010 vd 1010111
+    VMADDVX(Vr, Gpr, Vr, Vm),                   // 101001 vm vs2 rs1 110 vd 1010111
+    VNMSUBVV(Vr, Vr, Vr, Vm),                   // 101011 vm vs2 vs1 010 vd 1010111
+    VNMSUBVX(Vr, Gpr, Vr, Vm),                  // 101011 vm vs2 rs1 110 vd 1010111
+    VMACCVV(Vr, Vr, Vr, Vm),                    // 101101 vm vs2 vs1 010 vd 1010111
+    VMACCVX(Vr, Gpr, Vr, Vm),                   // 101101 vm vs2 rs1 110 vd 1010111
+    VNMSACVV(Vr, Vr, Vr, Vm),                   // 101111 vm vs2 vs1 010 vd 1010111
+    VNMSACVX(Vr, Gpr, Vr, Vm),                  // 101111 vm vs2 rs1 110 vd 1010111
+    VWADDUVV(Vr, Vr, Vr, Vm),                   // 110000 vm vs2 vs1 010 vd 1010111
+    VWADDUVX(Vr, Vr, Gpr, Vm),                  // 110000 vm vs2 rs1 110 vd 1010111
+    VWADDVV(Vr, Vr, Vr, Vm),                    // 110001 vm vs2 vs1 010 vd 1010111
+    VWADDVX(Vr, Vr, Gpr, Vm),                   // 110001 vm vs2 rs1 110 vd 1010111
+    VWSUBUVV(Vr, Vr, Vr, Vm),                   // 110010 vm vs2 vs1 010 vd 1010111
+    VWSUBUVX(Vr, Vr, Gpr, Vm),                  // 110010 vm vs2 rs1 110 vd 1010111
+    VWSUBVV(Vr, Vr, Vr, Vm),                    // 110011 vm vs2 vs1 010 vd 1010111
+    VWSUBVX(Vr, Vr, Gpr, Vm),                   // 110011 vm vs2 rs1 110 vd 1010111
+    VWADDUWV(Vr, Vr, Vr, Vm),                   // 110100 vm vs2 vs1 010 vd 1010111
+    VWADDUWX(Vr, Vr, Gpr, Vm),                  // 110100 vm vs2 rs1 110 vd 1010111
+    VWADDWV(Vr, Vr, Vr, Vm),                    // 110101 vm vs2 vs1 010 vd 1010111
+    VWADDWX(Vr, Vr, Gpr, Vm),                   // 110101 vm vs2 rs1 110 vd 1010111
+    VWSUBUWV(Vr, Vr, Vr, Vm),                   // 110110 vm vs2 vs1 010 vd 1010111
+    VWSUBUWX(Vr, Vr, Gpr, Vm),                  // 110110 vm vs2 rs1 110 vd 1010111
+    VWSUBWV(Vr, Vr, Vr, Vm),                    // 110111 vm vs2 vs1 010 vd 1010111
+    VWSUBWX(Vr, Vr, Gpr, Vm),                   // 110111 vm vs2 rs1 110 vd 1010111
+    VWMULUVV(Vr, Vr, Vr, Vm),                   // 111000 vm vs2 vs1 010 vd 1010111
+    VWMULUVX(Vr, Vr, Gpr, Vm),                  // 111000 vm vs2 rs1 110 vd 1010111
+    VWMULSUVV(Vr, Vr, Vr, Vm),                  // 111010 vm vs2 vs1 010 vd 1010111
+    VWMULSUVX(Vr, Vr, Gpr, Vm),                 // 111010 vm vs2 rs1 110 vd 1010111
+    VWMULVV(Vr, Vr, Vr, Vm),                    // 111011 vm vs2 vs1 010 vd 1010111
+    VWMULVX(Vr, Vr, Gpr, Vm),                   // 111011 vm vs2 rs1 110 vd 1010111
+    VWMACCUVV(Vr, Vr, Vr, Vm),                  // 111100 vm vs2 vs1 010 vd 1010111
+    VWMACCUVX(Vr, Gpr, Vr, Vm),                 // 111100 vm vs2 rs1 110 vd 1010111
+    VWMACCVV(Vr, Vr, Vr, Vm),                   // 111101 vm vs2 vs1 010 vd 1010111
+    VWMACCVX(Vr, Gpr, Vr, Vm),                  // 111101 vm vs2 rs1 110 vd 1010111
+    VWMACCUSVX(Vr, Gpr, Vr, Vm),                // 111110 vm vs2 rs1 110 vd 1010111
+    VWMACCSUVV(Vr, Vr, Vr, Vm),                 // 111111 vm vs2 vs1 010 vd 1010111
+    VWMACCSUVX(Vr, Gpr, Vr, Vm),                // 111111 vm vs2 rs1 110 vd 1010111
+
+    // Vector floating-point arithmetic (OPFVV, OPFVF)
+
+    VFADDVV(Vr, Vr, Vr, Vm),                    // 000000 vm vs2 vs1 001 vd 1010111
+    VFADDVF(Vr, Vr, Fpr, Vm),                   // 000000 vm vs2 rs1 101 vd 1010111
+    VFREDUSUMVS(Vr, Vr, Vr, Vm),                // 000001 vm vs2 vs1 001 vd 1010111
+    VFSUBVV(Vr, Vr, Vr, Vm),                    // 000010 vm vs2 vs1 001 vd 1010111
+    VFSUBVF(Vr, Vr, Fpr, Vm),                   // 000010 vm vs2 rs1 101 vd 1010111
+    VFREDOSUMVS(Vr, Vr, Vr, Vm),                // 000011 vm vs2 vs1 001 vd 1010111
+    VFMINVV(Vr, Vr, Vr, Vm),                    // 000100 vm vs2 vs1 001 vd 1010111
+    VFMINVF(Vr, Vr, Fpr, Vm),                   // 000100 vm vs2 rs1 101 vd 1010111
+    VFREDMINVS(Vr, Vr, Vr, Vm),                 // 000101 vm vs2 vs1 001 vd 1010111
+    VFMAXVV(Vr, Vr, Vr, Vm),                    // 000110 vm vs2 vs1 001 vd 1010111
+    VFMAXVF(Vr, Vr, Fpr, Vm),                   // 000110 vm vs2 rs1 101 vd 1010111
+    VFREDMAXVS(Vr, Vr, Vr, Vm),                 // 000111 vm vs2 vs1 001 vd 1010111
+    VFSGNJVV(Vr, Vr, Vr, Vm),                   // 001000 vm vs2 vs1 001 vd 1010111
+    VFSGNJVF(Vr, Vr, Fpr, Vm),                  // 001000 vm vs2 rs1 101 vd 1010111
+    VFSGNJNVV(Vr, Vr, Vr, Vm),                  // 001001 vm vs2 vs1 001 vd 1010111
+    VFSGNJNVF(Vr, Vr, Fpr, Vm),                 // 001001 vm vs2 rs1 101 vd 1010111
+    VFSGNJXVV(Vr, Vr, Vr, Vm),                  // 001010 vm vs2 vs1 001 vd 1010111
+    VFSGNJXVF(Vr, Vr, Fpr, Vm),                 // 001010 vm vs2 rs1 101 vd 1010111
+    VFSLIDE1UPVF(Vr, Vr, Fpr, Vm),              // 001110 vm vs2 rs1 101 vd 1010111
+    VFSLIDE1DOWNVF(Vr, Vr, Fpr, Vm),            // 001111 vm vs2 rs1 101 vd 1010111
+    VFMVFS(Fpr, Vr),                            // 010000 1 vs2 00000 001 rd 1010111
+    VFMVSF(Vr, Fpr),                            // 010000 1 00000 rs1 101 vd 1010111
+    VFCVTXUFV(Vr, Vr, Vm),                      // 010010 vm vs2 00000 001 vd 1010111
+    VFCVTXFV(Vr, Vr, Vm),                       // 010010 vm vs2 00001 001 vd 1010111
+    VFCVTFXUV(Vr, Vr, Vm),                      // 010010 vm vs2 00010 001 vd 1010111
+    VFCVTFXV(Vr, Vr, Vm),                       // 010010 vm vs2 00011 001 vd 1010111
+    VFCVTRTZXUFV(Vr, Vr, Vm),                   // 010010 vm vs2 00110 001 vd 1010111
+    VFCVTRTZXFV(Vr, Vr, Vm),                    // 010010 vm vs2 00111 001 vd 1010111
+    VFWCVTXUFV(Vr, Vr, Vm),                     // 010010 vm vs2 01000 001 vd 1010111
+    VFWCVTXFV(Vr, Vr, Vm),                      // 010010 vm vs2 01001 001 vd 1010111
+    VFWCVTFXUV(Vr, Vr, Vm),                     // 010010 vm vs2 01010 001 vd 1010111
+    VFWCVTFXV(Vr, Vr, Vm),                      // 010010 vm vs2 01011 001 vd 1010111
+    VFWCVTFFV(Vr, Vr, Vm),                      // 010010 vm vs2 01100 001 vd 1010111
+    VFWCVTRTZXUFV(Vr, Vr, Vm),                  // 010010 vm vs2 01110 001 vd 1010111
+    VFWCVTRTZXFV(Vr, Vr, Vm),                   // 010010 vm vs2 01111 001 vd 1010111
+    VFNCVTXUFW(Vr, Vr, Vm),                     // 010010 vm vs2 10000 001 vd 1010111
+    VFNCVTXFW(Vr, Vr, Vm),                      // 010010 vm vs2 10001 001 vd 1010111
+    VFNCVTFXUW(Vr, Vr, Vm),                     // 010010 vm vs2 10010 001 vd 1010111
+    VFNCVTFXW(Vr, Vr, Vm),                      // 010010 vm vs2 10011 001 vd 1010111
+    VFNCVTFFW(Vr, Vr, Vm),                      // 010010 vm vs2 10100 001 vd 1010111
+    VFNCVTRODFFW(Vr, Vr, Vm),                   // 010010 vm vs2 10101 001 vd 1010111
+    VFNCVTRTZXUFW(Vr, Vr, Vm),                  // 010010 vm vs2 10110 001 vd 1010111
+    VFNCVTRTZXFW(Vr, Vr, Vm),                   // 010010 vm vs2 10111 001 vd 1010111
+    VFSQRTV(Vr, Vr, Vm),                        // 010011 vm vs2 00000 001 vd 1010111
+    VFRSQRT7V(Vr, Vr, Vm),                      // 010011 vm vs2 00100 001 vd 1010111
+    VFREC7V(Vr, Vr, Vm),                        // 010011 vm vs2 00101 001 vd 1010111
+    VFCLASSV(Vr, Vr, Vm),                       // 010011 vm vs2 10000 001 vd 1010111
+    VFMERGEVFM(Vr, Vr, Fpr),                    // 010111 0 vs2 rs1 101 vd 1010111
+    VFMVVF(Vr, Fpr),                            // 010111 1 00000 rs1 101 vd 1010111
+    VMFEQVV(Vr, Vr, Vr, Vm),                    // 011000 vm vs2 vs1 001 vd 1010111
+    VMFEQVF(Vr, Vr, Fpr, Vm),                   // 011000 vm vs2 rs1 101 vd 1010111
+    VMFLEVV(Vr, Vr, Vr, Vm),                    // 011001 vm vs2 vs1 001 vd 1010111
+    VMFLEVF(Vr, Vr, Fpr, Vm),                   // 011001 vm vs2 rs1 101 vd 1010111
+    VMFLTVV(Vr, Vr, Vr, Vm),                    // 011011 vm vs2 vs1 001 vd 1010111
+    VMFLTVF(Vr, Vr, Fpr, Vm),                   // 011011 vm vs2 rs1 101 vd 1010111
+    VMFNEVV(Vr, Vr, Vr, Vm),                    // 011100 vm vs2 vs1 001 vd 1010111
+    VMFNEVF(Vr, Vr, Fpr, Vm),                   // 011100 vm vs2 rs1 101 vd 1010111
+    VMFGTVF(Vr, Vr, Fpr, Vm),                   // 011101 vm vs2 rs1 101 vd 1010111
+    VMFGEVF(Vr, Vr, Fpr, Vm),                   // 011111 vm vs2 rs1 101 vd 1010111
+    VFDIVVV(Vr, Vr, Vr, Vm),                    // 100000 vm vs2 vs1 001 vd 1010111
+    VFDIVVF(Vr, Vr, Fpr, Vm),                   // 100000 vm vs2 rs1 101 vd 1010111
+    VFRDIVVF(Vr, Vr, Fpr, Vm),                  // 100001 vm vs2 rs1 101 vd 1010111
+    VFMULVV(Vr, Vr, Vr, Vm),                    // 100100 vm vs2 vs1 001 vd 1010111
+    VFMULVF(Vr, Vr, Fpr, Vm),                   // 100100 vm vs2 rs1 101 vd 1010111
+    VFRSUBVF(Vr, Vr, Fpr, Vm),                  // 100111 vm vs2 rs1 101 vd 1010111
+    VFMADDVV(Vr, Vr, Vr, Vm),                   // 101000 vm vs2 vs1 001 vd 1010111
+    VFMADDVF(Vr, Fpr, Vr, Vm),                  // 101000 vm vs2 rs1 101 vd 1010111
+    VFNMADDVV(Vr, Vr, Vr, Vm),                  // 101001 vm vs2 vs1 001 vd 1010111
+    VFNMADDVF(Vr, Fpr, Vr, Vm),                 // 101001 vm vs2 rs1 101 vd 1010111
+    VFMSUBVV(Vr, Vr, Vr, Vm),                   // 101010 vm vs2 vs1 001 vd 1010111
+    VFMSUBVF(Vr, Fpr, Vr, Vm),                  // 101010 vm vs2 rs1 101 vd 1010111
+    VFNMSUBVV(Vr, Vr, Vr, Vm),                  // 101011 vm vs2 vs1 001 vd 1010111
+    VFNMSUBVF(Vr, Fpr, Vr, Vm),                 // 101011 vm vs2 rs1 101 vd 1010111
+    VFMACCVV(Vr, Vr, Vr, Vm),                   // 101100 vm vs2 vs1 001 vd 1010111
+    VFMACCVF(Vr, Fpr, Vr, Vm),                  // 101100 vm vs2 rs1 101 vd 1010111
+    VFNMACCVV(Vr, Vr, Vr, Vm),                  // 101101 vm vs2 vs1 001 vd 1010111
+    VFNMACCVF(Vr, Fpr, Vr, Vm),                 // 101101 vm vs2 rs1 101 vd 1010111
+    VFMSACVV(Vr, Vr, Vr, Vm),                   // 101110 vm vs2 vs1 001 vd 1010111
+    VFMSACVF(Vr, Fpr, Vr, Vm),                  // 101110 vm vs2 rs1 101 vd 1010111
+    VFNMSACVV(Vr, Vr, Vr, Vm),                  // 101111 vm vs2 vs1 001 vd 1010111
+    VFNMSACVF(Vr, Fpr, Vr, Vm),                 // 101111 vm vs2 rs1 101 vd 1010111
+    VFWADDVV(Vr, Vr, Vr, Vm),                   // 110000 vm vs2 vs1 001 vd 1010111
+    VFWADDVF(Vr, Vr, Fpr, Vm),                  // 110000 vm vs2 rs1 101 vd 1010111
+    VFWREDUSUMVS(Vr, Vr, Vr, Vm),               // 110001 vm vs2 vs1 001 vd 1010111
+    VFWSUBVV(Vr, Vr, Vr, Vm),                   // 110010 vm vs2 vs1 001 vd 1010111
+    VFWSUBVF(Vr, Vr, Fpr, Vm),                  // 110010 vm vs2 rs1 101 vd 1010111
+    VFWREDOSUMVS(Vr, Vr, Vr, Vm),               // 110011 vm vs2 vs1 001 vd 1010111
+    VFWADDWV(Vr, Vr, Vr, Vm),                   // 110100 vm vs2 vs1 001 vd 1010111
+    VFWADDWF(Vr, Vr, Fpr, Vm),                  // 110100 vm vs2 rs1 101 vd 1010111
+    VFWSUBWV(Vr, Vr, Vr, Vm),                   // 110110 vm vs2 vs1 001 vd 1010111
+    VFWSUBWF(Vr, Vr, Fpr, Vm),                  // 110110 vm vs2 rs1 101 vd 1010111
+    VFWMULVV(Vr, Vr, Vr, Vm),                   // 111000 vm vs2 vs1 001 vd 1010111
+    VFWMULVF(Vr, Vr, Fpr, Vm),                  // 111000 vm vs2 rs1 101 vd 1010111
+    VFWMACCVV(Vr, Vr, Vr, Vm),                  // 111100 vm vs2 vs1 001 vd 1010111
+    VFWMACCVF(Vr, Fpr, Vr, Vm),                 // 111100 vm vs2 rs1 101 vd 1010111
+    VFWNMACCVV(Vr, Vr, Vr, Vm),                 // 111101 vm vs2 vs1 001 vd 1010111
+    VFWNMACCVF(Vr, Fpr, Vr, Vm),                // 111101 vm vs2 rs1 101 vd 1010111
+    VFWMSACVV(Vr, Vr, Vr, Vm),                  // 111110 vm vs2 vs1 001 vd 1010111
+    VFWMSACVF(Vr, Fpr, Vr, Vm),                 // 111110 vm vs2 rs1 101 vd 1010111
+    VFWNMSACVV(Vr, Vr, Vr, Vm),                 // 111111 vm vs2 vs1 001 vd 1010111
+    VFWNMSACVF(Vr, Fpr, Vr, Vm),                // 111111 vm vs2 rs1 101 vd 1010111
+
+    // Vendor extensions, mostly in custom-0..3 (0001011, 0101011, 1011011, 1111011)
+    Custom(Custom),
+
     // TODO: decimal
     // TODO: packed-simd
     // TODO: compressed instruction
 }
+
+impl Inst {
+    /// Extension that defines the instruction. Compressed floating-point loads
+    /// and stores report F or D; every 16-bit form also needs C. Vendor
+    /// instructions report I; their extension is `Custom::vendor`.
+    pub fn ext(&self) -> Ext {
+        match self {
+            Inst::MUL(..)
+            | Inst::MULH(..)
+            | Inst::MULSU(..)
+            | Inst::MULU(..)
+            | Inst::MULW(..)
+            | Inst::DIV(..)
+            | Inst::DIVU(..)
+            | Inst::REM(..)
+            | Inst::REMU(..)
+            | Inst::DIVW(..)
+            | Inst::DIVUW(..)
+            | Inst::REMW(..)
+            | Inst::REMUW(..)
+            | Inst::MULD(..)
+            | Inst::DIVD(..)
+            | Inst::DIVUD(..)
+            | Inst::REMD(..)
+            | Inst::REMUD(..) => Ext::M,
+            Inst::LRW(..)
+            | Inst::SCW(..)
+            | Inst::LRD(..)
+            | Inst::SCD(..)
+            | Inst::AMOSWAPW(..)
+            | Inst::AMOADDW(..)
+            | Inst::AMOXORW(..)
+            | Inst::AMOANDW(..)
+            | Inst::AMOORW(..)
+            | Inst::AMOMINW(..)
+            | Inst::AMOMAXW(..)
+            | Inst::AMOMINUW(..)
+            | Inst::AMOMAXUW(..)
+            | Inst::AMOSWAPD(..)
+            | Inst::AMOADDD(..)
+            | Inst::AMOXORD(..)
+            | Inst::AMOANDD(..)
+            | Inst::AMOORD(..)
+            | Inst::AMOMIND(..)
+            | Inst::AMOMAXD(..)
+            | Inst::AMOMINUD(..)
+            | Inst::AMOMAXUD(..) => Ext::A,
+            Inst::FLW(..)
+            | Inst::FSW(..)
+            | Inst::FMADDS(..)
+            | Inst::FMSUBS(..)
+            | Inst::FNMSUBS(..)
+            | Inst::FNMADDS(..)
+            | Inst::FADDS(..)
+            | Inst::FSUBS(..)
+            | Inst::FMULS(..)
+            | Inst::FDIVS(..)
+            | Inst::FSQRTS(..)
+            | Inst::FSGNJS(..)
+            | Inst::FSGNJNS(..)
+            | Inst::FSGNJXS(..)
+            | Inst::FMINS(..)
+            | Inst::FMAXS(..)
+            | Inst::FEQS(..)
+            | Inst::FLTS(..)
+            | Inst::FLES(..)
+            | Inst::FCLASSS(..)
+            | Inst::FCVTWS(..)
+            | Inst::FCVTWUS(..)
+            | Inst::FCVTSW(..)
+            | Inst::FCVTSWU(..)
+            | Inst::FMVXW(..)
+            | Inst::FMVWX(..)
+            | Inst::FCVTLS(..)
+            | Inst::FCVTLUS(..)
+            | Inst::FCVTSL(..)
+            | Inst::FCVTSLU(..)
+            | Inst::CFLW(..)
+            | Inst::CFSW(..)
+            | Inst::CFLWSP(..)
+            | Inst::CFSWSP(..) => Ext::F,
+            Inst::FLD(..)
+            | Inst::FSD(..)
+            | Inst::FMADDD(..)
+            | Inst::FMSUBD(..)
+            | Inst::FNMSUBD(..)
+            | Inst::FNMADDD(..)
+            | Inst::FADDD(..)
+            | Inst::FSUBD(..)
+            | Inst::FMULD(..)
+            | Inst::FDIVD(..)
+            | Inst::FSQRTD(..)
+            | Inst::FSGNJD(..)
+            | Inst::FSGNJND(..)
+            | Inst::FSGNJXD(..)
+            | Inst::FMIND(..)
+            | Inst::FMAXD(..)
+            | Inst::FCVTSD(..)
+            | Inst::FCVTDS(..)
+            | Inst::FEQD(..)
+            | Inst::FLTD(..)
+            | Inst::FLED(..)
+            | Inst::FCLASSD(..)
+            | Inst::FCVTWD(..)
+            | Inst::FCVTWUD(..)
+            | Inst::FCVTDW(..)
+            | Inst::FCVTDWU(..)
+            | Inst::FCVTLD(..)
+            | Inst::FCVTLUD(..)
+            | Inst::FCVTDL(..)
+            | Inst::FCVTDLU(..)
+            | Inst::FMVXD(..)
+            | Inst::FMVDX(..)
+            | Inst::CFLD(..)
+            | Inst::CFSD(..)
+            | Inst::CFLDSP(..)
+            | Inst::CFSDSP(..) => Ext::D,
+            Inst::FLQ(..)
+            | Inst::FSQ(..)
+            | Inst::FMADDQ(..)
+            | Inst::FMSUBQ(..)
+            | Inst::FNMSUBQ(..)
+            | Inst::FNMADDQ(..)
+            | Inst::FADDQ(..)
+            | Inst::FSUBQ(..)
+            | Inst::FMULQ(..)
+            | Inst::FDIVQ(..)
+            | Inst::FSQRTQ(..)
+            | Inst::FSGNJQ(..)
+            | Inst::FSGNJNQ(..)
+            | Inst::FSGNJXQ(..)
+            | Inst::FMINQ(..)
+            | Inst::FMAXQ(..)
+            | Inst::FCVTSQ(..)
+            | Inst::FCVTQS(..)
+            | Inst::FCVTDQ(..)
+            | Inst::FCVTQD(..)
+            | Inst::FEQQ(..)
+            | Inst::FLTQ(..)
+            | Inst::FLEQ(..)
+            | Inst::FCLASSQ(..)
+            | Inst::FCVTWQ(..)
+            | Inst::FCVTWUQ(..)
+            | Inst::FCVTQW(..)
+            | Inst::FCVTQWU(..)
+            | Inst::FCVTLQ(..)
+            | Inst::FCVTLUQ(..)
+            | Inst::FCVTQL(..)
+            | Inst::FCVTQLU(..) => Ext::Q,
+            Inst::FLH(..)
+            | Inst::FSH(..)
+            | Inst::FMVXH(..)
+            | Inst::FMVHX(..)
+            | Inst::FCVTSH(..)
+            | Inst::FCVTHS(..)
+            | Inst::FCVTDH(..)
+            | Inst::FCVTHD(..)
+            | Inst::FCVTQH(..)
+            | Inst::FCVTHQ(..) => Ext::Zfhmin,
+            Inst::FMADDH(..)
+            | Inst::FMSUBH(..)
+            | Inst::FNMSUBH(..)
+            | Inst::FNMADDH(..)
+            | Inst::FADDH(..)
+            | Inst::FSUBH(..)
+            | Inst::FMULH(..)
+            | Inst::FDIVH(..)
+            | Inst::FSQRTH(..)
+            | Inst::FSGNJH(..)
+            | Inst::FSGNJNH(..)
+            | Inst::FSGNJXH(..)
+            | Inst::FMINH(..)
+            | Inst::FMAXH(..)
+            | Inst::FEQH(..)
+            | Inst::FLTH(..)
+            | Inst::FLEH(..)
+            | Inst::FCLASSH(..)
+            | Inst::FCVTWH(..)
+            | Inst::FCVTWUH(..)
+            | Inst::FCVTHW(..)
+            | Inst::FCVTHWU(..)
+            | Inst::FCVTLH(..)
+            | Inst::FCVTLUH(..)
+            | Inst::FCVTHL(..)
+            | Inst::FCVTHLU(..) => Ext::Zfh,
+            Inst::FLIS(..)
+            | Inst::FLID(..)
+            | Inst::FLIH(..)
+            | Inst::FLIQ(..)
+            | Inst::FMINMS(..)
+            | Inst::FMAXMS(..)
+            | Inst::FROUNDS(..)
+            | Inst::FROUNDNXS(..)
+            | Inst::FLEQS(..)
+            | Inst::FLTQS(..)
+            | Inst::FMINMD(..)
+            | Inst::FMAXMD(..)
+            | Inst::FROUNDD(..)
+            | Inst::FROUNDNXD(..)
+            | Inst::FLEQD(..)
+            | Inst::FLTQD(..)
+            | Inst::FMINMH(..)
+            | Inst::FMAXMH(..)
+            | Inst::FROUNDH(..)
+            | Inst::FROUNDNXH(..)
+            | Inst::FLEQH(..)
+            | Inst::FLTQH(..)
+            | Inst::FMINMQ(..)
+            | Inst::FMAXMQ(..)
+            | Inst::FROUNDQ(..)
+            | Inst::FROUNDNXQ(..)
+            | Inst::FLEQQ(..)
+            | Inst::FLTQQ(..)
+            | Inst::FCVTMODWD(..)
+            | Inst::FMVHXD(..)
+            | Inst::FMVPDX(..)
+            | Inst::FMVHXQ(..)
+            | Inst::FMVPQX(..) => Ext::Zfa,
+            Inst::CADDI4SPN(..)
+            | Inst::CLQ(..)
+            | Inst::CLW(..)
+            | Inst::CLD(..)
+            | Inst::CSQ(..)
+            | Inst::CSW(..)
+            | Inst::CSD(..)
+            | Inst::CNOP
+            | Inst::CADDI(..)
+            | Inst::CJAL(..)
+            | Inst::CADDIW(..)
+            | Inst::CLI(..)
+            | Inst::CADDI16SP(..)
+            | Inst::CLUI(..)
+            | Inst::CSRLI(..)
+            | Inst::CSRLI64(..)
+            | Inst::CSRAI(..)
+            | Inst::CSRAI64(..)
+            | Inst::CANDI(..)
+            | Inst::CSUB(..)
+            | Inst::CXOR(..)
+            | Inst::COR(..)
+            | Inst::CAND(..)
+            | Inst::CSUBW(..)
+            | Inst::CADDW(..)
+            | Inst::CJ(..)
+            | Inst::CBEQZ(..)
+            | Inst::CBNEZ(..)
+            | Inst::CSLLI(..)
+            | Inst::CSLLI64(..)
+            | Inst::CLQSP(..)
+            | Inst::CLWSP(..)
+            | Inst::CLDSP(..)
+            | Inst::CJR(..)
+            | Inst::CMV(..)
+            | Inst::CEBREAK
+            | Inst::CJALR(..)
+            | Inst::CADD(..)
+            | Inst::CSQSP(..)
+            | Inst::CSWSP(..)
+            | Inst::CSDSP(..) => Ext::C,
+            Inst::HFENCEVVMA(..)
+            | Inst::HFENCEGVMA(..)
+            | Inst::HINVALVVMA(..)
+            | Inst::HINVALGVMA(..)
+            | Inst::HLVB(..)
+            | Inst::HLVBU(..)
+            | Inst::HLVH(..)
+            | Inst::HLVHU(..)
+            | Inst::HLVXHU(..)
+            | Inst::HLVW(..)
+            | Inst::HLVXWU(..)
+            | Inst::HSVB(..)
+            | Inst::HSVH(..)
+            | Inst::HSVW(..)
+            | Inst::HLVWU(..)
+            | Inst::HLVD(..)
+            | Inst::HSVD(..) => Ext::H,
+            Inst::CSRRW(..)
+            | Inst::CSRRS(..)
+            | Inst::CSRRC(..)
+            | Inst::CSRRWI(..)
+            | Inst::CSRRSI(..)
+            | Inst::CSRRCI(..) => Ext::Zicsr,
+            Inst::FENCEI => Ext::Zifencei,
+            Inst::PAUSE => Ext::Zihintpause,
+            Inst::SH1ADD(..)
+            | Inst::SH2ADD(..)
+            | Inst::SH3ADD(..)
+            | Inst::ADDUW(..)
+            | Inst::SH1ADDUW(..)
+            | Inst::SH2ADDUW(..)
+            | Inst::SH3ADDUW(..)
+            | Inst::SLLIUW(..) => Ext::Zba,
+            Inst::ANDN(..)
+            | Inst::ORN(..)
+            | Inst::XNOR(..)
+            | Inst::MAX(..)
+            | Inst::MAXU(..)
+            | Inst::MIN(..)
+            | Inst::MINU(..)
+            | Inst::SEXTB(..)
+            | Inst::SEXTH(..)
+            | Inst::ZEXTH(..)
+            | Inst::ROL(..)
+            | Inst::ROR(..)
+            | Inst::RORI(..)
+            | Inst::ORCB(..)
+            | Inst::REV8(..)
+            | Inst::ROLW(..)
+            | Inst::RORW(..)
+            | Inst::RORIW(..) => Ext::Zbb,
+            Inst::CLMUL(..) | Inst::CLMULH(..) | Inst::CLMULR(..) => Ext::Zbc,
+            Inst::BCLR(..)
+            | Inst::BCLRI(..)
+            | Inst::BEXT(..)
+            | Inst::BEXTI(..)
+            | Inst::BINV(..)
+            | Inst::BINVI(..)
+            | Inst::BSET(..)
+            | Inst::BSETI(..) => Ext::Zbs,
+            Inst::PACK(..)
+            | Inst::PACKH(..)
+            | Inst::BREV8(..)
+            | Inst::PACKW(..)
+            | Inst::ZIP(..)
+            | Inst::UNZIP(..) => Ext::Zbkb,
+            Inst::XPERM4(..) | Inst::XPERM8(..) => Ext::Zbkx,
+            Inst::AES32DSI(..)
+            | Inst::AES32DSMI(..)
+            | Inst::AES64DS(..)
+            | Inst::AES64DSM(..)
+            | Inst::AES64IM(..)
+            | Inst::AES64KS1I(..)
+            | Inst::AES64KS2(..) => Ext::Zknd,
+            Inst::AES32ESI(..) | Inst::AES32ESMI(..) | Inst::AES64ES(..) | Inst::AES64ESM(..) => Ext::Zkne,
+            Inst::SHA256SIG0(..)
+            | Inst::SHA256SIG1(..)
+            | Inst::SHA256SUM0(..)
+            | Inst::SHA256SUM1(..)
+            | Inst::SHA512SIG0H(..)
+            | Inst::SHA512SIG0L(..)
+            | Inst::SHA512SIG1H(..)
+            | Inst::SHA512SIG1L(..)
+            | Inst::SHA512SUM0R(..)
+            | Inst::SHA512SUM1R(..)
+            | Inst::SHA512SIG0(..)
+            | Inst::SHA512SIG1(..)
+            | Inst::SHA512SUM0(..)
+            | Inst::SHA512SUM1(..) => Ext::Zknh,
+            Inst::SM4ED(..) | Inst::SM4KS(..) => Ext::Zksed,
+            Inst::SM3P0(..) | Inst::SM3P1(..) => Ext::Zksh,
+            Inst::CZEROEQZ(..) | Inst::CZERONEZ(..) => Ext::Zicond,
+            Inst::AMOCASW(..) | Inst::AMOCASD(..) | Inst::AMOCASQ(..) => Ext::Zacas,
+            Inst::AMOSWAPB(..)
+            | Inst::AMOADDB(..)
+            | Inst::AMOXORB(..)
+            | Inst::AMOANDB(..)
+            | Inst::AMOORB(..)
+            | Inst::AMOMINB(..)
+            | Inst::AMOMAXB(..)
+            | Inst::AMOMINUB(..)
+            | Inst::AMOMAXUB(..)
+            | Inst::AMOCASB(..)
+            | Inst::AMOSWAPH(..)
+            | Inst::AMOADDH(..)
+            | Inst::AMOXORH(..)
+            | Inst::AMOANDH(..)
+            | Inst::AMOORH(..)
+            | Inst::AMOMINH(..)
+            | Inst::AMOMAXH(..)
+            | Inst::AMOMINUH(..)
+            | Inst::AMOMAXUH(..)
+            | Inst::AMOCASH(..) => Ext::Zabha,
+            Inst::CLBU(..)
+            | Inst::CLHU(..)
+            | Inst::CLH(..)
+            | Inst::CSB(..)
+            | Inst::CSH(..)
+            | Inst::CZEXTB(..)
+            | Inst::CSEXTB(..)
+            | Inst::CZEXTH(..)
+            | Inst::CSEXTH(..)
+            | Inst::CZEXTW(..)
+            | Inst::CNOT(..)
+            | Inst::CMUL(..) => Ext::Zcb,
+            Inst::CMPUSH(..)
+            | Inst::CMPOP(..)
+            | Inst::CMPOPRETZ(..)
+            | Inst::CMPOPRET(..)
+            | Inst::CMMVSA01(..)
+            | Inst::CMMVA01S(..) => Ext::Zcmp,
+            Inst::CMJT(..) | Inst::CMJALT(..) => Ext::Zcmt,
+            Inst::CBOCLEAN(..) | Inst::CBOFLUSH(..) | Inst::CBOINVAL(..) => Ext::Zicbom,
+            Inst::CBOZERO(..) => Ext::Zicboz,
+            Inst::PREFETCHI(..) | Inst::PREFETCHR(..) | Inst::PREFETCHW(..) => Ext::Zicbop,
+            Inst::WRSNTO | Inst::WRSSTO => Ext::Zawrs,
+            Inst::NTLP1 | Inst::NTLPALL | Inst::NTLS1 | Inst::NTLALL => Ext::Zihintntl,
+            Inst::VSETVLI(..)
+            | Inst::VSETIVLI(..)
+            | Inst::VSETVL(..)
+            | Inst::VLE(..)
+            | Inst::VLEFF(..)
+            | Inst::VLM(..)
+            | Inst::VLSE(..)
+            | Inst::VLUXEI(..)
+            | Inst::VLOXEI(..)
+            | Inst::VLRE(..)
+            | Inst::VSE(..)
+            | Inst::VSM(..)
+            | Inst::VSSE(..)
+            | Inst::VSUXEI(..)
+            | Inst::VSOXEI(..)
+            | Inst::VSR(..)
+            | Inst::VADDVV(..)
+            | Inst::VADDVX(..)
+            | Inst::VADDVI(..)
+            | Inst::VSUBVV(..)
+            | Inst::VSUBVX(..)
+            | Inst::VRSUBVX(..)
+            | Inst::VRSUBVI(..)
+            | Inst::VMINUVV(..)
+            | Inst::VMINUVX(..)
+            | Inst::VMINVV(..)
+            | Inst::VMINVX(..)
+            | Inst::VMAXUVV(..)
+            | Inst::VMAXUVX(..)
+            | Inst::VMAXVV(..)
+            | Inst::VMAXVX(..)
+            | Inst::VANDVV(..)
+            | Inst::VANDVX(..)
+            | Inst::VANDVI(..)
+            | Inst::VORVV(..)
+            | Inst::VORVX(..)
+            | Inst::VORVI(..)
+            | Inst::VXORVV(..)
+            | Inst::VXORVX(..)
+            | Inst::VXORVI(..)
+            | Inst::VRGATHERVV(..)
+            | Inst::VRGATHERVX(..)
+            | Inst::VRGATHERVI(..)
+            | Inst::VRGATHEREI16VV(..)
+            | Inst::VSLIDEUPVX(..)
+            | Inst::VSLIDEUPVI(..)
+            | Inst::VSLIDEDOWNVX(..)
+            | Inst::VSLIDEDOWNVI(..)
+            | Inst::VADCVVM(..)
+            | Inst::VADCVXM(..)
+            | Inst::VADCVIM(..)
+            | Inst::VMADCVVM(..)
+            | Inst::VMADCVXM(..)
+            | Inst::VMADCVIM(..)
+            | Inst::VMADCVV(..)
+            | Inst::VMADCVX(..)
+            | Inst::VMADCVI(..)
+            | Inst::VSBCVVM(..)
+            | Inst::VSBCVXM(..)
+            | Inst::VMSBCVVM(..)
+            | Inst::VMSBCVXM(..)
+            | Inst::VMSBCVV(..)
+            | Inst::VMSBCVX(..)
+            | Inst::VMERGEVVM(..)
+            | Inst::VMERGEVXM(..)
+            | Inst::VMERGEVIM(..)
+            | Inst::VMVVV(..)
+            | Inst::VMVVX(..)
+            | Inst::VMVVI(..)
+            | Inst::VMSEQVV(..)
+            | Inst::VMSEQVX(..)
+            | Inst::VMSEQVI(..)
+            | Inst::VMSNEVV(..)
+            | Inst::VMSNEVX(..)
+            | Inst::VMSNEVI(..)
+            | Inst::VMSLTUVV(..)
+            | Inst::VMSLTUVX(..)
+            | Inst::VMSLTVV(..)
+            | Inst::VMSLTVX(..)
+            | Inst::VMSLEUVV(..)
+            | Inst::VMSLEUVX(..)
+            | Inst::VMSLEUVI(..)
+            | Inst::VMSLEVV(..)
+            | Inst::VMSLEVX(..)
+            | Inst::VMSLEVI(..)
+            | Inst::VMSGTUVX(..)
+            | Inst::VMSGTUVI(..)
+            | Inst::VMSGTVX(..)
+            | Inst::VMSGTVI(..)
+            | Inst::VSADDUVV(..)
+            | Inst::VSADDUVX(..)
+            | Inst::VSADDUVI(..)
+            | Inst::VSADDVV(..)
+            | Inst::VSADDVX(..)
+            | Inst::VSADDVI(..)
+            | Inst::VSSUBUVV(..)
+            | Inst::VSSUBUVX(..)
+            | Inst::VSSUBVV(..)
+            | Inst::VSSUBVX(..)
+            | Inst::VSLLVV(..)
+            | Inst::VSLLVX(..)
+            | Inst::VSLLVI(..)
+            | Inst::VSMULVV(..)
+            | Inst::VSMULVX(..)
+            | Inst::VMVNRV(..)
+            | Inst::VSRLVV(..)
+            | Inst::VSRLVX(..)
+            | Inst::VSRLVI(..)
+            | Inst::VSRAVV(..)
+            | Inst::VSRAVX(..)
+            | Inst::VSRAVI(..)
+            | Inst::VSSRLVV(..)
+            | Inst::VSSRLVX(..)
+            | Inst::VSSRLVI(..)
+            | Inst::VSSRAVV(..)
+            | Inst::VSSRAVX(..)
+            | Inst::VSSRAVI(..)
+            | Inst::VNSRLWV(..)
+            | Inst::VNSRLWX(..)
+            | Inst::VNSRLWI(..)
+            | Inst::VNSRAWV(..)
+            | Inst::VNSRAWX(..)
+            | Inst::VNSRAWI(..)
+            | Inst::VNCLIPUWV(..)
+            | Inst::VNCLIPUWX(..)
+            | Inst::VNCLIPUWI(..)
+            | Inst::VNCLIPWV(..)
+            | Inst::VNCLIPWX(..)
+            | Inst::VNCLIPWI(..)
+            | Inst::VWREDSUMUVS(..)
+            | Inst::VWREDSUMVS(..)
+            | Inst::VREDSUMVS(..)
+            | Inst::VREDANDVS(..)
+            | Inst::VREDORVS(..)
+            | Inst::VREDXORVS(..)
+            | Inst::VREDMINUVS(..)
+            | Inst::VREDMINVS(..)
+            | Inst::VREDMAXUVS(..)
+            | Inst::VREDMAXVS(..)
+            | Inst::VAADDUVV(..)
+            | Inst::VAADDUVX(..)
+            | Inst::VAADDVV(..)
+            | Inst::VAADDVX(..)
+            | Inst::VASUBUVV(..)
+            | Inst::VASUBUVX(..)
+            | Inst::VASUBVV(..)
+            | Inst::VASUBVX(..)
+            | Inst::VSLIDE1UPVX(..)
+            | Inst::VSLIDE1DOWNVX(..)
+            | Inst::VMVXS(..)
+            | Inst::VMVSX(..)
+            | Inst::VCPOPM(..)
+            | Inst::VFIRSTM(..)
+            | Inst::VZEXTVF8(..)
+            | Inst::VSEXTVF8(..)
+            | Inst::VZEXTVF4(..)
+            | Inst::VSEXTVF4(..)
+            | Inst::VZEXTVF2(..)
+            | Inst::VSEXTVF2(..)
+            | Inst::VMSBFM(..)
+            | Inst::VMSOFM(..)
+            | Inst::VMSIFM(..)
+            | Inst::VIOTAM(..)
+            | Inst::VIDV(..)
+            | Inst::VCOMPRESSVM(..)
+            | Inst::VMANDNMM(..)
+            | Inst::VMANDMM(..)
+            | Inst::VMORMM(..)
+            | Inst::VMXORMM(..)
+            | Inst::VMORNMM(..)
+            | Inst::VMNANDMM(..)
+            | Inst::VMNORMM(..)
+            | Inst::VMXNORMM(..)
+            | Inst::VDIVUVV(..)
+            | Inst::VDIVUVX(..)
+            | Inst::VDIVVV(..)
+            | Inst::VDIVVX(..)
+            | Inst::VREMUVV(..)
+            | Inst::VREMUVX(..)
+            | Inst::VREMVV(..)
+            | Inst::VREMVX(..)
+            | Inst::VMULHUVV(..)
+            | Inst::VMULHUVX(..)
+            | Inst::VMULVV(..)
+            | Inst::VMULVX(..)
+            | Inst::VMULHSUVV(..)
+            | Inst::VMULHSUVX(..)
+            | Inst::VMULHVV(..)
+            | Inst::VMULHVX(..)
+            | Inst::VMADDVV(..)
+            | Inst::VMADDVX(..)
+            | Inst::VNMSUBVV(..)
+            | Inst::VNMSUBVX(..)
+            | Inst::VMACCVV(..)
+            | Inst::VMACCVX(..)
+            | Inst::VNMSACVV(..)
+            | Inst::VNMSACVX(..)
+            | Inst::VWADDUVV(..)
+            | Inst::VWADDUVX(..)
+            | Inst::VWADDVV(..)
+            | Inst::VWADDVX(..)
+            | Inst::VWSUBUVV(..)
+            | Inst::VWSUBUVX(..)
+            | Inst::VWSUBVV(..)
+            | Inst::VWSUBVX(..)
+            | Inst::VWADDUWV(..)
+            | Inst::VWADDUWX(..)
+            | Inst::VWADDWV(..)
+            | Inst::VWADDWX(..)
+            | Inst::VWSUBUWV(..)
+            | Inst::VWSUBUWX(..)
+            | Inst::VWSUBWV(..)
+            | Inst::VWSUBWX(..)
+            | Inst::VWMULUVV(..)
+            | Inst::VWMULUVX(..)
+            | Inst::VWMULSUVV(..)
+            | Inst::VWMULSUVX(..)
+            | Inst::VWMULVV(..)
+            | Inst::VWMULVX(..)
+            | Inst::VWMACCUVV(..)
+            | Inst::VWMACCUVX(..)
+            | Inst::VWMACCVV(..)
+            | Inst::VWMACCVX(..)
+            | Inst::VWMACCUSVX(..)
+            | Inst::VWMACCSUVV(..)
+            | Inst::VWMACCSUVX(..)
+            | Inst::VFADDVV(..)
+            | Inst::VFADDVF(..)
+            | Inst::VFREDUSUMVS(..)
+            | Inst::VFSUBVV(..)
+            | Inst::VFSUBVF(..)
+            | Inst::VFREDOSUMVS(..)
+            | Inst::VFMINVV(..)
+            | Inst::VFMINVF(..)
+            | Inst::VFREDMINVS(..)
+            | Inst::VFMAXVV(..)
+            | Inst::VFMAXVF(..)
+            | Inst::VFREDMAXVS(..)
+            | Inst::VFSGNJVV(..)
+            | Inst::VFSGNJVF(..)
+            | Inst::VFSGNJNVV(..)
+            | Inst::VFSGNJNVF(..)
+            | Inst::VFSGNJXVV(..)
+            | Inst::VFSGNJXVF(..)
+            | Inst::VFSLIDE1UPVF(..)
+            | Inst::VFSLIDE1DOWNVF(..)
+            | Inst::VFMVFS(..)
+            | Inst::VFMVSF(..)
+            | Inst::VFCVTXUFV(..)
+            | Inst::VFCVTXFV(..)
+            | Inst::VFCVTFXUV(..)
+            | Inst::VFCVTFXV(..)
+            | Inst::VFCVTRTZXUFV(..)
+            | Inst::VFCVTRTZXFV(..)
+            | Inst::VFWCVTXUFV(..)
+            | Inst::VFWCVTXFV(..)
+            | Inst::VFWCVTFXUV(..)
+            | Inst::VFWCVTFXV(..)
+            | Inst::VFWCVTFFV(..)
+            | Inst::VFWCVTRTZXUFV(..)
+            | Inst::VFWCVTRTZXFV(..)
+            | Inst::VFNCVTXUFW(..)
+            | Inst::VFNCVTXFW(..)
+            | Inst::VFNCVTFXUW(..)
+            | Inst::VFNCVTFXW(..)
+            | Inst::VFNCVTFFW(..)
+            | Inst::VFNCVTRODFFW(..)
+            | Inst::VFNCVTRTZXUFW(..)
+            | Inst::VFNCVTRTZXFW(..)
+            | Inst::VFSQRTV(..)
+            | Inst::VFRSQRT7V(..)
+            | Inst::VFREC7V(..)
+            | Inst::VFCLASSV(..)
+            | Inst::VFMERGEVFM(..)
+            | Inst::VFMVVF(..)
+            | Inst::VMFEQVV(..)
+            | Inst::VMFEQVF(..)
+            | Inst::VMFLEVV(..)
+            | Inst::VMFLEVF(..)
+            | Inst::VMFLTVV(..)
+            | Inst::VMFLTVF(..)
+            | Inst::VMFNEVV(..)
+            | Inst::VMFNEVF(..)
+            | Inst::VMFGTVF(..)
+            | Inst::VMFGEVF(..)
+            | Inst::VFDIVVV(..)
+            | Inst::VFDIVVF(..)
+            | Inst::VFRDIVVF(..)
+            | Inst::VFMULVV(..)
+            | Inst::VFMULVF(..)
+            | Inst::VFRSUBVF(..)
+            | Inst::VFMADDVV(..)
+            | Inst::VFMADDVF(..)
+            | Inst::VFNMADDVV(..)
+            | Inst::VFNMADDVF(..)
+            | Inst::VFMSUBVV(..)
+            | Inst::VFMSUBVF(..)
+            | Inst::VFNMSUBVV(..)
+            | Inst::VFNMSUBVF(..)
+            | Inst::VFMACCVV(..)
+            | Inst::VFMACCVF(..)
+            | Inst::VFNMACCVV(..)
+            | Inst::VFNMACCVF(..)
+            | Inst::VFMSACVV(..)
+            | Inst::VFMSACVF(..)
+            | Inst::VFNMSACVV(..)
+            | Inst::VFNMSACVF(..)
+            | Inst::VFWADDVV(..)
+            | Inst::VFWADDVF(..)
+            | Inst::VFWREDUSUMVS(..)
+            | Inst::VFWSUBVV(..)
+            | Inst::VFWSUBVF(..)
+            | Inst::VFWREDOSUMVS(..)
+            | Inst::VFWADDWV(..)
+            | Inst::VFWADDWF(..)
+            | Inst::VFWSUBWV(..)
+            | Inst::VFWSUBWF(..)
+            | Inst::VFWMULVV(..)
+            | Inst::VFWMULVF(..)
+            | Inst::VFWMACCVV(..)
+            | Inst::VFWMACCVF(..)
+            | Inst::VFWNMACCVV(..)
+            | Inst::VFWNMACCVF(..)
+            | Inst::VFWMSACVV(..)
+            | Inst::VFWMSACVF(..)
+            | Inst::VFWNMSACVV(..)
+            | Inst::VFWNMSACVF(..) => Ext::V,
+            _ => Ext::I,
+        }
+    }
+
+    /// Length in bytes: 2 for the 16-bit forms, 4 otherwise.
+    pub fn size(&self) -> u8 {
+        match self {
+            Inst::CADDI4SPN(..)
+            | Inst::CFLD(..)
+            | Inst::CLQ(..)
+            | Inst::CLW(..)
+            | Inst::CFLW(..)
+            | Inst::CLD(..)
+            | Inst::CFSD(..)
+            | Inst::CSQ(..)
+            | Inst::CSW(..)
+            | Inst::CFSW(..)
+            | Inst::CSD(..)
+            | Inst::CNOP
+            | Inst::CADDI(..)
+            | Inst::CJAL(..)
+            | Inst::CADDIW(..)
+            | Inst::CLI(..)
+            | Inst::CADDI16SP(..)
+            | Inst::CLUI(..)
+            | Inst::CSRLI(..)
+            | Inst::CSRLI64(..)
+            | Inst::CSRAI(..)
+            | Inst::CSRAI64(..)
+            | Inst::CANDI(..)
+            | Inst::CSUB(..)
+            | Inst::CXOR(..)
+            | Inst::COR(..)
+            | Inst::CAND(..)
+            | Inst::CSUBW(..)
+            | Inst::CADDW(..)
+            | Inst::CJ(..)
+            | Inst::CBEQZ(..)
+            | Inst::CBNEZ(..)
+            | Inst::CSLLI(..)
+            | Inst::CSLLI64(..)
+            | Inst::CFLDSP(..)
+            | Inst::CLQSP(..)
+            | Inst::CLWSP(..)
+            | Inst::CFLWSP(..)
+            | Inst::CLDSP(..)
+            | Inst::CJR(..)
+            | Inst::CMV(..)
+            | Inst::CEBREAK
+            | Inst::CJALR(..)
+            | Inst::CADD(..)
+            | Inst::CFSDSP(..)
+            | Inst::CSQSP(..)
+            | Inst::CSWSP(..)
+            | Inst::CFSWSP(..)
+            | Inst::CSDSP(..)
+            | Inst::CLBU(..)
+            | Inst::CLHU(..)
+            | Inst::CLH(..)
+            | Inst::CSB(..)
+            | Inst::CSH(..)
+            | Inst::CZEXTB(..)
+            | Inst::CSEXTB(..)
+            | Inst::CZEXTH(..)
+            | Inst::CSEXTH(..)
+            | Inst::CZEXTW(..)
+            | Inst::CNOT(..)
+            | Inst::CMUL(..)
+            | Inst::CMPUSH(..)
+            | Inst::CMPOP(..)
+            | Inst::CMPOPRETZ(..)
+            | Inst::CMPOPRET(..)
+            | Inst::CMMVSA01(..)
+            | Inst::CMMVA01S(..)
+            | Inst::CMJT(..)
+            | Inst::CMJALT(..) => 2,
+            _ => 4,
+        }
+    }
+
+    /// Whether `isa` provides the instruction. Some instructions are shared by
+    /// several extensions, e.g. `ror` by Zbb and Zbkb; `ext` names only the first.
+    pub fn enabled(&self, isa: &Isa) -> bool {
+        let also = match self {
+            Inst::ANDN(..)
+            | Inst::ORN(..)
+            | Inst::XNOR(..)
+            | Inst::ROL(..)
+            | Inst::ROR(..)
+            | Inst::RORI(..)
+            | Inst::REV8(..)
+            | Inst::ROLW(..)
+            | Inst::RORW(..)
+            | Inst::RORIW(..) => Some(Ext::Zbkb),
+            Inst::CLMUL(..) | Inst::CLMULH(..) => Some(Ext::Zbkc),
+            Inst::AES64KS1I(..) | Inst::AES64KS2(..) => Some(Ext::Zkne),
+            _ => None,
+        };
+        // Conversions and Zfa operations on a format also need that format;
+        // some Zcb instructions compress Zbb, Zba or M ones, and amocas.b/h
+        // belong to both Zabha and Zacas.
+        let needs = match self {
+            Inst::FCVTDH(..)
+            | Inst::FCVTHD(..)
+            | Inst::FLID(..)
+            | Inst::FMINMD(..)
+            | Inst::FMAXMD(..)
+            | Inst::FROUNDD(..)
+            | Inst::FROUNDNXD(..)
+            | Inst::FLEQD(..)
+            | Inst::FLTQD(..)
+            | Inst::FCVTMODWD(..)
+            | Inst::FMVHXD(..)
+            | Inst::FMVPDX(..) => Some(Ext::D),
+            Inst::FCVTQH(..)
+            | Inst::FCVTHQ(..)
+            | Inst::FLIQ(..)
+            | Inst::FMINMQ(..)
+            | Inst::FMAXMQ(..)
+            | Inst::FROUNDQ(..)
+            | Inst::FROUNDNXQ(..)
+            | Inst::FLEQQ(..)
+            | Inst::FLTQQ(..)
+            | Inst::FMVHXQ(..)
+            | Inst::FMVPQX(..) => Some(Ext::Q),
+            Inst::FLIH(..)
+            | Inst::FMINMH(..)
+            | Inst::FMAXMH(..)
+            | Inst::FROUNDH(..)
+            | Inst::FROUNDNXH(..)
+            | Inst::FLEQH(..)
+            | Inst::FLTQH(..) => Some(Ext::Zfh),
+            Inst::CSEXTB(..) | Inst::CZEXTH(..) | Inst::CSEXTH(..) => Some(Ext::Zbb),
+            Inst::CZEXTW(..) => Some(Ext::Zba),
+            Inst::CMUL(..) => Some(Ext::M),
+            Inst::AMOCASB(..) | Inst::AMOCASH(..) => Some(Ext::Zacas),
+            _ => None,
+        };
+        if let Inst::Custom(c) = self {
+            return isa.has_vendor(c.vendor);
+        }
+        // Zcmp and Zcmt take over the C.D encodings.
+        if let Inst::CFLD(..) | Inst::CFSD(..) | Inst::CFLDSP(..) | Inst::CFSDSP(..) = self {
+            if isa.has(Ext::Zcmp) || isa.has(Ext::Zcmt) {
+                return false;
+            }
+        }
+        (isa.has(self.ext()) || also.is_some_and(|e| isa.has(e))) && needs.is_none_or(|e| isa.has(e))
+    }
+}
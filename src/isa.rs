@@ -0,0 +1,388 @@
+// ISA configuration: XLEN plus the set of enabled extensions
+
+use crate::vendor::{self, Vendor};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(u8)]
+pub enum Ext {
+    I = 0,
+    M,
+    A,
+    F,
+    D,
+    Q,
+    C,
+    V,
+    H,
+
+    Zicbom,
+    Zicbop,
+    Zicboz,
+    Zicond,
+    Zicsr,
+    Zifencei,
+    Zihintntl,
+    Zihintpause,
+    Zabha,
+    Zacas,
+    Zawrs,
+
+    Zfa,
+    Zfh,
+    Zfhmin,
+
+    Zcb,
+    Zcmp,
+    Zcmt,
+
+    Zba,
+    Zbb,
+    Zbc,
+    Zbs,
+    Zbkb,
+    Zbkc,
+    Zbkx,
+    Zknd,
+    Zkne,
+    Zknh,
+    Zksed,
+    Zksh,
+}
+
+impl Ext {
+    // canonical order, single-letter extensions first
+    const ALL: [Ext; 38] = [
+        Ext::I,
+        Ext::M,
+        Ext::A,
+        Ext::F,
+        Ext::D,
+        Ext::Q,
+        Ext::C,
+        Ext::V,
+        Ext::H,
+        Ext::Zicbom,
+        Ext::Zicbop,
+        Ext::Zicboz,
+        Ext::Zicond,
+        Ext::Zicsr,
+        Ext::Zifencei,
+        Ext::Zihintntl,
+        Ext::Zihintpause,
+        Ext::Zabha,
+        Ext::Zacas,
+        Ext::Zawrs,
+        Ext::Zfa,
+        Ext::Zfh,
+        Ext::Zfhmin,
+        Ext::Zcb,
+        Ext::Zcmp,
+        Ext::Zcmt,
+        Ext::Zba,
+        Ext::Zbb,
+        Ext::Zbc,
+        Ext::Zbs,
+        Ext::Zbkb,
+        Ext::Zbkc,
+        Ext::Zbkx,
+        Ext::Zknd,
+        Ext::Zkne,
+        Ext::Zknh,
+        Ext::Zksed,
+        Ext::Zksh,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Ext::I => "i",
+            Ext::M => "m",
+            Ext::A => "a",
+            Ext::F => "f",
+            Ext::D => "d",
+            Ext::Q => "q",
+            Ext::C => "c",
+            Ext::V => "v",
+            Ext::H => "h",
+            Ext::Zicbom => "zicbom",
+            Ext::Zicbop => "zicbop",
+            Ext::Zicboz => "zicboz",
+            Ext::Zicond => "zicond",
+            Ext::Zicsr => "zicsr",
+            Ext::Zifencei => "zifencei",
+            Ext::Zihintntl => "zihintntl",
+            Ext::Zihintpause => "zihintpause",
+            Ext::Zabha => "zabha",
+            Ext::Zacas => "zacas",
+            Ext::Zawrs => "zawrs",
+            Ext::Zfa => "zfa",
+            Ext::Zfh => "zfh",
+            Ext::Zfhmin => "zfhmin",
+            Ext::Zcb => "zcb",
+            Ext::Zcmp => "zcmp",
+            Ext::Zcmt => "zcmt",
+            Ext::Zba => "zba",
+            Ext::Zbb => "zbb",
+            Ext::Zbc => "zbc",
+            Ext::Zbs => "zbs",
+            Ext::Zbkb => "zbkb",
+            Ext::Zbkc => "zbkc",
+            Ext::Zbkx => "zbkx",
+            Ext::Zknd => "zknd",
+            Ext::Zkne => "zkne",
+            Ext::Zknh => "zknh",
+            Ext::Zksed => "zksed",
+            Ext::Zksh => "zksh",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Ext> {
+        Ext::ALL.iter().copied().find(|e| e.name() == name)
+    }
+
+    fn bit(self) -> u128 {
+        1 << self as u8
+    }
+
+    // Extensions that must be present for this one to make sense.
+    fn implied(self) -> &'static [Ext] {
+        match self {
+            Ext::F => &[Ext::Zicsr],
+            Ext::D => &[Ext::F],
+            Ext::Q => &[Ext::D],
+            Ext::V => &[Ext::D],
+            Ext::Zabha | Ext::Zacas => &[Ext::A],
+            Ext::Zfa => &[Ext::F],
+            Ext::Zfh => &[Ext::Zfhmin],
+            Ext::Zfhmin => &[Ext::F],
+            Ext::Zcb | Ext::Zcmp => &[Ext::C],
+            Ext::Zcmt => &[Ext::C, Ext::Zicsr],
+            _ => &[],
+        }
+    }
+}
+
+impl std::fmt::Display for Ext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Isa {
+    xlen: u8,
+    exts: u128,
+    // one bit per slot of the vendor registry
+    vendors: u64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum IsaError {
+    /// The string does not start with `rv32`, `rv64` or `rv128`, or misa has no MXL.
+    BadXlen,
+    /// Base ISA other than `i` or `g` (RV32E/RV64E are not supported).
+    BadBase(char),
+    /// Extension name this crate does not know about.
+    UnknownExt(String),
+}
+
+impl std::fmt::Display for IsaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsaError::BadXlen => write!(f, "missing or invalid XLEN"),
+            IsaError::BadBase(c) => write!(f, "unsupported base ISA '{}'", c),
+            IsaError::UnknownExt(e) => write!(f, "unknown extension '{}'", e),
+        }
+    }
+}
+
+impl std::error::Error for IsaError {}
+
+impl Isa {
+    /// Base integer ISA only; `xlen` must be 32, 64 or 128.
+    pub fn new(xlen: u8) -> Result<Isa, IsaError> {
+        match xlen {
+            32 | 64 | 128 => Ok(Isa { xlen, exts: Ext::I.bit(), vendors: 0 }),
+            _ => Err(IsaError::BadXlen),
+        }
+    }
+
+    /// Decodes the MXL and Extensions fields of a `misa` CSR value.
+    /// RV32/RV64 values are passed zero-extended.
+    pub fn from_misa(misa: u128) -> Result<Isa, IsaError> {
+        let mxl = [127, 63, 31]
+            .iter()
+            .map(|&hi| (misa >> (hi - 1)) & 0b11)
+            .find(|&mxl| mxl != 0);
+        let mut isa = match mxl {
+            Some(1) => Isa::new(32)?,
+            Some(2) => Isa::new(64)?,
+            Some(3) => Isa::new(128)?,
+            _ => return Err(IsaError::BadXlen),
+        };
+
+        let letter = |c: u8| misa & (1 << (c - b'a')) != 0;
+        if !letter(b'i') {
+            return Err(IsaError::BadBase(if letter(b'e') { 'e' } else { '?' }));
+        }
+        for e in Ext::ALL {
+            let name = e.name().as_bytes();
+            if name.len() == 1 && letter(name[0]) {
+                isa = isa.with(e);
+            }
+        }
+        // misa itself is a CSR
+        Ok(isa.with(Ext::Zicsr))
+    }
+
+    pub fn xlen(&self) -> u8 {
+        self.xlen
+    }
+
+    pub fn has(&self, ext: Ext) -> bool {
+        self.exts & ext.bit() != 0
+    }
+
+    /// Enables `ext` and the extensions it depends on (D implies F, and so on).
+    pub fn with(mut self, ext: Ext) -> Isa {
+        self.exts |= ext.bit();
+        for &e in ext.implied() {
+            self = self.with(e);
+        }
+        self
+    }
+
+    /// Disables `ext` and the extensions that depend on it.
+    pub fn without(mut self, ext: Ext) -> Isa {
+        if ext == Ext::I {
+            return self;
+        }
+        self.exts &= !ext.bit();
+        for e in Ext::ALL {
+            if e.implied().contains(&ext) {
+                self = self.without(e);
+            }
+        }
+        self
+    }
+
+    pub fn has_vendor(&self, vendor: &dyn Vendor) -> bool {
+        vendor::index(vendor.name()).is_some_and(|i| self.vendors & 1 << i != 0)
+    }
+
+    /// Enables a vendor extension, registering it if its name is new.
+    ///
+    /// # Panics
+    ///
+    /// If the name is new and 64 vendor extensions are already known.
+    pub fn with_vendor(mut self, vendor: &'static dyn Vendor) -> Isa {
+        let i = vendor::index_or_register(vendor).expect("too many vendor extensions");
+        self.vendors |= 1 << i;
+        self
+    }
+
+    /// Enabled vendor extensions, built-in ones first.
+    pub fn vendors(&self) -> impl Iterator<Item = &'static dyn Vendor> + '_ {
+        (0..64).filter(|i| self.vendors & 1 << i != 0).map(vendor::get)
+    }
+}
+
+impl std::str::FromStr for Isa {
+    type Err = IsaError;
+
+    /// Parses ISA strings such as `rv64imafdc_zicsr_zifencei_zba_zbb` or
+    /// `rv32i2p1_m2p0`. Version numbers are accepted and ignored; `x` names
+    /// must be built-in or registered vendor extensions.
+    fn from_str(s: &str) -> Result<Isa, IsaError> {
+        let s = s.to_ascii_lowercase();
+        let rest = s.strip_prefix("rv").ok_or(IsaError::BadXlen)?;
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let mut isa = match &rest[..digits] {
+            "32" => Isa::new(32)?,
+            "64" => Isa::new(64)?,
+            "128" => Isa::new(128)?,
+            _ => return Err(IsaError::BadXlen),
+        };
+        let mut rest = &rest[digits..];
+
+        match rest.chars().next() {
+            Some('i') => {}
+            Some('g') => {
+                for e in [Ext::M, Ext::A, Ext::F, Ext::D, Ext::Zicsr, Ext::Zifencei] {
+                    isa = isa.with(e);
+                }
+            }
+            Some(c) => return Err(IsaError::BadBase(c)),
+            None => return Err(IsaError::BadBase(' ')),
+        }
+        rest = skip_version(&rest[1..]);
+
+        while let Some(c) = rest.chars().next() {
+            let name = match c {
+                '_' => {
+                    rest = &rest[1..];
+                    continue;
+                }
+                // multi-letter extensions run up to the next underscore
+                'z' | 's' | 'x' => {
+                    let end = rest.find('_').unwrap_or(rest.len());
+                    let name = strip_version(&rest[..end]);
+                    rest = &rest[end..];
+                    name
+                }
+                _ => {
+                    let name = &rest[..c.len_utf8()];
+                    rest = skip_version(&rest[c.len_utf8()..]);
+                    name
+                }
+            };
+            match (Ext::from_name(name), vendor::index(name)) {
+                (Some(e), _) => isa = isa.with(e),
+                (None, Some(i)) => isa.vendors |= 1 << i,
+                (None, None) => return Err(IsaError::UnknownExt(name.to_string())),
+            }
+        }
+
+        Ok(isa)
+    }
+}
+
+// Skips a leading `<major>[p<minor>]` version.
+fn skip_version(s: &str) -> &str {
+    let s = s.trim_start_matches(|c: char| c.is_ascii_digit());
+    match s.strip_prefix('p') {
+        Some(minor) if minor.starts_with(|c: char| c.is_ascii_digit()) => {
+            minor.trim_start_matches(|c: char| c.is_ascii_digit())
+        }
+        _ => s,
+    }
+}
+
+// Removes a trailing `<major>[p<minor>]` version from a multi-letter name.
+fn strip_version(name: &str) -> &str {
+    let s = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    if s.len() == name.len() {
+        return name;
+    }
+    match s.strip_suffix('p') {
+        Some(major) if major.ends_with(|c: char| c.is_ascii_digit()) => {
+            major.trim_end_matches(|c: char| c.is_ascii_digit())
+        }
+        _ => s,
+    }
+}
+
+impl std::fmt::Display for Isa {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rv{}", self.xlen)?;
+        for e in Ext::ALL.iter().filter(|e| self.has(**e) && e.name().len() == 1) {
+            write!(f, "{}", e)?;
+        }
+        for e in Ext::ALL.iter().filter(|e| self.has(**e) && e.name().len() > 1) {
+            write!(f, "_{}", e)?;
+        }
+        for v in self.vendors() {
+            write!(f, "_{}", v.name())?;
+        }
+        Ok(())
+    }
+}
@@ -1,25 +1,67 @@
-#![feature(exclusive_range_pattern)]
-#![feature(arbitrary_enum_discriminant)]
-
 mod extr;
 
 mod gpr;
 pub use gpr::Gpr;
 
-// mod fpr;
-// pub use fpr::Fpr;
+mod fpr;
+pub use fpr::Fpr;
+
+mod vr;
+pub use vr::{Vm, Vr};
+
+mod rm;
+pub use rm::Rm;
+
+mod aqrl;
+pub use aqrl::Aqrl;
+
+mod fli;
+pub use fli::Fli;
+
+mod fence;
+pub use fence::{Fence, Fm};
+
+mod rlist;
+pub use rlist::Rlist;
+
+mod vtype;
+pub use vtype::{Lmul, Sew, Vtype};
 
 mod csr;
 pub use csr::Csr;
 
+mod isa;
+pub use isa::{Ext, Isa, IsaError};
+
+mod vendor;
+pub use vendor::{register_vendor, Arg, Custom, Vendor};
+
+mod thead;
+pub use thead::XThead;
+
 mod inst;
 pub use inst::Inst;
 
+mod display;
+
+mod disasm;
+pub use disasm::{Disassembler, Line, Symbols};
+
 mod bytes;
 pub use bytes::{ByteSlice, ByteReader};
 
 mod dec;
-pub use dec::decode;
+pub use dec::{decode, decode_expanded, DecodeError, Decoded};
+
+mod enc;
+pub use enc::{encode, EncodeError, EncodedInst};
+
+mod compress;
+pub use compress::{compress, compress_all, expand};
+
+mod parse;
+mod pseudo;
+pub use parse::{parse, ParseError, ParseErrorKind};
 
-// mod enc;
-// pub use enc::encode;
+mod asm;
+pub use asm::{Assembler, Object, Reloc, RelocKind, Section, Symbol};
@@ -0,0 +1,1415 @@
+// GNU assembler syntax: one instruction or pseudo-instruction per line,
+// operands written the way `Display` prints them, except that branch and jump
+// targets are offsets
+
+use crate::pseudo::pseudo;
+use crate::{encode, Aqrl, Csr, EncodeError, Fence, Fli, Fm, Fpr, Gpr, Inst, Isa, Lmul, Rlist, Rm, Sew, Vm, Vr, Vtype};
+
+use std::cell::Cell;
+
+/// Where and why a line failed to parse; `line` and `col` count from 1.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum ParseErrorKind {
+    /// Mnemonic of no instruction this crate knows.
+    UnknownMnemonic(String),
+    /// Something else was found where this was expected.
+    Expected(&'static str),
+    /// Immediate that does not fit the operand's type; whether it fits the
+    /// instruction field is for `encode` to check.
+    OutOfRange(i64),
+    /// Assembler directive this crate does not support.
+    UnknownDirective(String),
+    /// Symbol used but defined nowhere in the source.
+    Undefined(String),
+    /// Label defined twice.
+    Redefined(String),
+    /// The instruction does not encode, e.g. a branch whose target is out of range.
+    Encode(EncodeError),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.col)?;
+        match &self.kind {
+            ParseErrorKind::UnknownMnemonic(m) => write!(f, "unknown instruction '{}'", m),
+            ParseErrorKind::Expected(what) if what.starts_with(char::is_alphabetic) => write!(f, "expected {}", what),
+            ParseErrorKind::Expected(what) => write!(f, "expected '{}'", what),
+            ParseErrorKind::OutOfRange(imm) => write!(f, "immediate {} out of range", imm),
+            ParseErrorKind::UnknownDirective(d) => write!(f, "unknown directive '{}'", d),
+            ParseErrorKind::Undefined(name) => write!(f, "undefined symbol '{}'", name),
+            ParseErrorKind::Redefined(name) => write!(f, "symbol '{}' already defined", name),
+            ParseErrorKind::Encode(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a whole listing. Blank lines and `#` comments are skipped, and
+/// pseudo-instructions such as `li` and `call` expand into the instructions
+/// they stand for on `isa`'s XLEN. Every instruction must encode on `isa`.
+pub fn parse(src: &str, isa: &Isa) -> Result<Vec<Inst>, ParseError> {
+    let mut insts = Vec::new();
+    for (i, text) in src.lines().enumerate() {
+        let imm_col = Cell::new(None);
+        let eval = |tok: &Tok| {
+            imm_col.set(imm_col.get().or(Some(tok.col)));
+            literal(tok)
+        };
+        for inst in parse_line(text, i + 1, isa.xlen(), &eval)? {
+            if let Err(e) = encode(&inst, isa) {
+                // immediates are reported where the first one is written
+                let col = match (e, imm_col.get()) {
+                    (EncodeError::ImmOutOfRange(_) | EncodeError::ImmMisaligned(_), Some(col)) => col,
+                    _ => text.len() - text.trim_start().len() + 1,
+                };
+                return Err(ParseError { line: i + 1, col, kind: ParseErrorKind::Encode(e) });
+            }
+            insts.push(inst);
+        }
+    }
+    Ok(insts)
+}
+
+impl std::str::FromStr for Inst {
+    type Err = ParseError;
+
+    /// Parses a single instruction such as `sd ra,8(sp)`, or a
+    /// pseudo-instruction that stands for one, taking `li` as on RV64.
+    fn from_str(s: &str) -> Result<Inst, ParseError> {
+        match &parse_line(s, 1, 64, &literal)?[..] {
+            [inst] => Ok(inst.clone()),
+            _ => Err(ParseError { line: 1, col: 1, kind: ParseErrorKind::Expected("instruction") }),
+        }
+    }
+}
+
+// The instructions on `text`, none if it is blank or a comment, several for
+// some pseudo-instructions. Immediates go through `eval`.
+pub(crate) fn parse_line(text: &str, line: usize, xlen: u8, eval: Eval) -> Result<Vec<Inst>, ParseError> {
+    let text = match text.find('#') {
+        Some(i) => &text[..i],
+        None => text,
+    };
+    if text.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    let len = text[start..end].find(char::is_whitespace).unwrap_or(end - start);
+    let ops = Operands { text: &text[..end], start: start + len, line, eval };
+    let mnemonic = text[start..start + len].to_ascii_lowercase();
+
+    let (m, aqrl) = match mnemonic.rsplit_once('.') {
+        Some((m, suffix)) if m.starts_with("lr.") || m.starts_with("sc.") || m.starts_with("amo") => match suffix {
+            "aq" => (m, Aqrl::Aq),
+            "rl" => (m, Aqrl::Rl),
+            "aqrl" => (m, Aqrl::AqRl),
+            _ => (&mnemonic[..], Aqrl::None),
+        },
+        _ => (&mnemonic[..], Aqrl::None),
+    };
+    if let Some(insts) = pseudo(m, &ops, xlen)? {
+        return Ok(insts);
+    }
+    match inst(m, aqrl, &ops)? {
+        Some(inst) => Ok(vec![inst]),
+        None => match vector_memory(m, &ops)? {
+            Some(inst) => Ok(vec![inst]),
+            None => Err(ops.error(start, ParseErrorKind::UnknownMnemonic(mnemonic))),
+        },
+    }
+}
+
+// Operand text of one line, matched against templates such as `{},{}({})`:
+// `{}` is an operand, `[...]` an optional tail, anything else must appear as
+// written, whitespace aside.
+pub(crate) struct Operands<'a> {
+    text: &'a str,
+    start: usize,
+    line: usize,
+    eval: Eval<'a>,
+}
+
+// One operand, trimmed, with its position for error messages.
+#[derive(Copy, Clone)]
+pub(crate) struct Tok<'a> {
+    pub text: &'a str,
+    pub col: usize,
+    pub line: usize,
+    eval: Eval<'a>,
+}
+
+// Value of an immediate operand; the assembler's also knows symbols.
+pub(crate) type Eval<'a> = &'a dyn Fn(&Tok) -> Result<i64, ParseError>;
+
+fn literal(tok: &Tok) -> Result<i64, ParseError> {
+    tok.int()
+}
+
+impl<'a> Tok<'a> {
+    // A register name or similar outside any line.
+    fn word(text: &'a str) -> Tok<'a> {
+        Tok::at(text, 0, 0)
+    }
+
+    pub fn at(text: &'a str, col: usize, line: usize) -> Tok<'a> {
+        Tok { text, col, line, eval: &literal }
+    }
+
+    pub fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError { line: self.line, col: self.col, kind }
+    }
+
+    pub fn expected(&self, what: &'static str) -> ParseError {
+        self.error(ParseErrorKind::Expected(what))
+    }
+
+    pub fn int(&self) -> Result<i64, ParseError> {
+        parse_int(self.text).ok_or_else(|| self.expected("integer"))
+    }
+
+    pub fn imm<T: TryFrom<i64>>(&self) -> Result<T, ParseError> {
+        let v = (self.eval)(self)?;
+        T::try_from(v).map_err(|_| self.error(ParseErrorKind::OutOfRange(v)))
+    }
+}
+
+impl<'a> Operands<'a> {
+    fn error(&self, pos: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { line: self.line, col: self.text[..pos].chars().count() + 1, kind }
+    }
+
+    fn skip_space(&self, pos: usize) -> usize {
+        pos + self.text[pos..].len() - self.text[pos..].trim_start().len()
+    }
+
+    fn tok(&self, from: usize, to: usize) -> Tok<'a> {
+        let from = self.skip_space(from).min(to);
+        Tok {
+            text: self.text[from..to].trim_end(),
+            col: self.text[..from].chars().count() + 1,
+            line: self.line,
+            eval: self.eval,
+        }
+    }
+
+    // Splits the operands according to `tmpl`; omitted optional operands are
+    // empty tokens.
+    pub fn split(&self, tmpl: &'static str) -> Result<Vec<Tok<'a>>, ParseError> {
+        let mut toks = Vec::new();
+        let mut pos = self.start;
+        let mut i = 0;
+        while i < tmpl.len() {
+            let rest = &tmpl[i..];
+            if rest.starts_with('[') {
+                if self.skip_space(pos) == self.text.len() {
+                    let end = self.tok(pos, pos);
+                    toks.extend(rest.matches("{}").map(|_| Tok { ..end }));
+                    return Ok(toks);
+                }
+                i += 1;
+            } else if rest.starts_with(']') {
+                i += 1;
+            } else if rest.starts_with("{}") {
+                i += 2;
+                // the operand runs up to the next literal outside any brackets;
+                // before `(`, to the last one, as in `%lo(x)(a0)`
+                let next = tmpl[i..].bytes().find(|&c| c != b'[' && c != b']');
+                let mut depth = 0;
+                let mut end = None;
+                for (k, c) in self.text[pos..].bytes().enumerate() {
+                    if depth == 0 && Some(c) == next {
+                        end = Some(k);
+                        if c != b'(' {
+                            break;
+                        }
+                    }
+                    match c {
+                        b'(' | b'{' => depth += 1,
+                        b')' | b'}' => depth -= 1,
+                        b',' if depth == 0 && next == Some(b'(') => break,
+                        _ => {}
+                    }
+                }
+                let len = end
+                    .or_else(|| {
+                        // missing separator: stop after the first word so the error points past it
+                        let from = self.skip_space(pos) - pos;
+                        next.and(self.text[pos + from..].find(char::is_whitespace).map(|n| from + n))
+                    })
+                    .unwrap_or(self.text.len() - pos);
+                let tok = self.tok(pos, pos + len);
+                if tok.text.is_empty() && next == Some(b'(') {
+                    toks.push(Tok { text: "0", ..tok }); // `ld a0,(a1)`
+                } else {
+                    toks.push(tok);
+                }
+                pos += len;
+            } else {
+                let len = match rest.find(|c: char| !c.is_ascii_alphanumeric()) {
+                    Some(0) => 1,
+                    Some(n) => n,
+                    None => rest.len(),
+                };
+                let want = &rest[..len];
+                pos = self.skip_space(pos);
+                let got_len = if len == 1 && !want.as_bytes()[0].is_ascii_alphanumeric() {
+                    self.text[pos..].starts_with(want) as usize
+                } else {
+                    self.text[pos..].find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(self.text.len() - pos)
+                };
+                let got = &self.text[pos..pos + got_len];
+                if got_len == 0 || !(got.eq_ignore_ascii_case(want) || same_gpr(got, want)) {
+                    return Err(self.error(pos, ParseErrorKind::Expected(want)));
+                }
+                pos += got_len;
+                i += len;
+            }
+        }
+        if self.skip_space(pos) != self.text.len() {
+            return Err(self.error(self.skip_space(pos), ParseErrorKind::Expected("end of line")));
+        }
+        Ok(toks)
+    }
+
+    // Text after the mnemonic.
+    pub fn rest(&self) -> &'a str {
+        self.text[self.start..].trim()
+    }
+
+    // Operands matched against `tmpl` and handed to `f`.
+    pub fn get<T: FromToks, R>(&self, tmpl: &'static str, f: impl FnOnce(T) -> R) -> Result<R, ParseError> {
+        let toks = self.split(tmpl)?;
+        Ok(f(T::from_toks(&toks)?))
+    }
+}
+
+// `sp` in a template also matches `x2`.
+fn same_gpr(got: &str, want: &str) -> bool {
+    matches!((Gpr::parse(&Tok::word(got)), Gpr::parse(&Tok::word(want))), (Ok(a), Ok(b)) if a == b)
+}
+
+// Decimal, `0x` hexadecimal or `0b` binary, optionally signed.
+pub(crate) fn parse_int(s: &str) -> Option<i64> {
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let v = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16).ok()?
+    } else if let Some(bin) = s.strip_prefix("0b").or_else(|| s.strip_prefix("0B")) {
+        u64::from_str_radix(bin, 2).ok()?
+    } else if s.starts_with(|c: char| c.is_ascii_digit()) {
+        s.parse::<u64>().ok()?
+    } else {
+        return None;
+    };
+    // 64-bit wraparound, as in `li a0,0xffffffffffffffff`
+    Some(if neg { (v as i64).wrapping_neg() } else { v as i64 })
+}
+
+pub(crate) trait Operand: Sized {
+    fn parse(tok: &Tok) -> Result<Self, ParseError>;
+}
+
+impl Operand for Gpr {
+    fn parse(tok: &Tok) -> Result<Gpr, ParseError> {
+        let s = tok.text.to_ascii_lowercase();
+        (0..32)
+            .filter_map(Gpr::from_u8)
+            .find(|&r| r.to_string() == s || format!("{:?}", r) == s || format!("x{}", r as u8) == s)
+            .ok_or_else(|| tok.expected("integer register"))
+    }
+}
+
+impl Operand for Fpr {
+    fn parse(tok: &Tok) -> Result<Fpr, ParseError> {
+        let s = tok.text.to_ascii_lowercase();
+        (0..32)
+            .filter_map(Fpr::from_u8)
+            .find(|&r| r.to_string() == s || format!("f{}", r as u8) == s)
+            .ok_or_else(|| tok.expected("floating-point register"))
+    }
+}
+
+impl Operand for Vr {
+    fn parse(tok: &Tok) -> Result<Vr, ParseError> {
+        let s = tok.text.to_ascii_lowercase();
+        (0..32)
+            .filter_map(Vr::from_u8)
+            .find(|&r| r.to_string() == s)
+            .ok_or_else(|| tok.expected("vector register"))
+    }
+}
+
+impl Operand for Vm {
+    fn parse(tok: &Tok) -> Result<Vm, ParseError> {
+        match &tok.text.to_ascii_lowercase()[..] {
+            "" => Ok(Vm::Unmasked),
+            "v0.t" => Ok(Vm::Masked),
+            _ => Err(tok.expected("v0.t")),
+        }
+    }
+}
+
+// Rounding mode; `None` when omitted, for the instruction's default.
+impl Operand for Option<Rm> {
+    fn parse(tok: &Tok) -> Result<Option<Rm>, ParseError> {
+        if tok.text.is_empty() {
+            return Ok(None);
+        }
+        let s = tok.text.to_ascii_lowercase();
+        (0..8)
+            .filter_map(Rm::from_u8)
+            .find(|rm| rm.to_string() == s)
+            .map(Some)
+            .ok_or_else(|| tok.expected("rounding mode"))
+    }
+}
+
+impl Operand for Csr {
+    fn parse(tok: &Tok) -> Result<Csr, ParseError> {
+        if let Some(v) = parse_int(tok.text) {
+            return match u16::try_from(v) {
+                Ok(r) if r < 0x1000 => Ok(Csr::from_u16(r)),
+                _ => Err(tok.error(ParseErrorKind::OutOfRange(v))),
+            };
+        }
+        let s = tok.text.to_ascii_lowercase();
+        (0..0x1000)
+            .map(Csr::from_u16)
+            .find(|csr| csr.to_string() == s)
+            .ok_or_else(|| tok.expected("CSR"))
+    }
+}
+
+impl Operand for i32 {
+    fn parse(tok: &Tok) -> Result<i32, ParseError> {
+        tok.imm()
+    }
+}
+
+impl Operand for u32 {
+    fn parse(tok: &Tok) -> Result<u32, ParseError> {
+        tok.imm()
+    }
+}
+
+impl Operand for u8 {
+    fn parse(tok: &Tok) -> Result<u8, ParseError> {
+        tok.imm()
+    }
+}
+
+// `iorw` letters in any order, or `0`.
+impl Operand for Fence {
+    fn parse(tok: &Tok) -> Result<Fence, ParseError> {
+        if tok.text == "0" {
+            return Ok(Fence::NONE);
+        }
+        let mut bits = 0;
+        for c in tok.text.to_ascii_lowercase().chars() {
+            let bit = match c {
+                'i' => 0b1000,
+                'o' => 0b0100,
+                'r' => 0b0010,
+                'w' => 0b0001,
+                _ => 0,
+            };
+            if bit == 0 || bits & bit != 0 {
+                return Err(tok.expected("fence set"));
+            }
+            bits |= bit;
+        }
+        Fence::from_u8(bits).filter(|_| bits != 0).ok_or_else(|| tok.expected("fence set"))
+    }
+}
+
+// `min`, `inf`, `nan` or one of the constants, such as `0.625`.
+impl Operand for Fli {
+    fn parse(tok: &Tok) -> Result<Fli, ParseError> {
+        match &tok.text.to_ascii_lowercase()[..] {
+            "min" => Fli::from_u8(1),
+            "inf" => Fli::from_u8(30),
+            "nan" => Fli::from_u8(31),
+            s => s.parse().ok().and_then(Fli::from_f64),
+        }
+        .ok_or_else(|| tok.expected("fli constant"))
+    }
+}
+
+// `{ra}`, `{ra,s0}` or `{ra,s0-sN}`, also with x names.
+impl Operand for Rlist {
+    fn parse(tok: &Tok) -> Result<Rlist, ParseError> {
+        let s: String = tok.text.chars().filter(|c| !c.is_whitespace()).collect();
+        let reg = |text| Gpr::parse(&Tok::word(text)).ok();
+        let inner = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')).unwrap_or("");
+        let count = match inner.split_once(',') {
+            None if reg(inner) == Some(Gpr::ra) => Some(1),
+            Some((ra, regs)) if reg(ra) == Some(Gpr::ra) => match regs.split_once('-') {
+                None if reg(regs) == Some(Gpr::fp) => Some(2),
+                Some((s0, sn)) if reg(s0) == Some(Gpr::fp) => match reg(sn) {
+                    Some(Gpr::s1) => Some(3),
+                    Some(r) if (Gpr::s2 as u8..=Gpr::s9 as u8).contains(&(r as u8)) => Some(r as u8 - Gpr::s2 as u8 + 4),
+                    Some(Gpr::s11) => Some(13), // s10 and s11 always go together
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+        match count {
+            Some(13) => Ok(Rlist::from_u8(15).unwrap()),
+            Some(n) => Ok(Rlist::from_u8(n + 3).unwrap()),
+            None => Err(tok.expected("register list")),
+        }
+    }
+}
+
+// `e32,m1,ta,ma` with LMUL and the policies optional, or a plain number.
+impl Operand for Vtype {
+    fn parse(tok: &Tok) -> Result<Vtype, ParseError> {
+        if let Some(v) = parse_int(tok.text) {
+            return u16::try_from(v)
+                .ok()
+                .and_then(Vtype::from_u16)
+                .ok_or_else(|| tok.error(ParseErrorKind::OutOfRange(v)));
+        }
+        let s = tok.text.to_ascii_lowercase();
+        let mut parts = s.split(',').map(str::trim).peekable();
+        let sew = parts.next().and_then(|e| (0..4).filter_map(Sew::from_u8).find(|sew| sew.to_string() == e));
+        let sew = sew.ok_or_else(|| tok.expected("element width"))?;
+        let lmul = match parts.peek().and_then(|&m| (0..8).filter_map(Lmul::from_u8).find(|l| l.to_string() == m)) {
+            Some(lmul) => {
+                parts.next();
+                lmul
+            }
+            None => Lmul::M1,
+        };
+        let ta = parts.next_if(|&p| p == "ta" || p == "tu") == Some("ta");
+        let ma = parts.next_if(|&p| p == "ma" || p == "mu") == Some("ma");
+        match parts.next() {
+            None => Ok(Vtype::new(sew, lmul, ta, ma)),
+            Some(_) => Err(tok.expected("vtype")),
+        }
+    }
+}
+
+// Rounding mode written out, or the instruction's default.
+fn rm_or(rm: Option<Rm>, default: Rm) -> Rm {
+    rm.unwrap_or(default)
+}
+
+pub(crate) trait FromToks: Sized {
+    fn from_toks(toks: &[Tok]) -> Result<Self, ParseError>;
+}
+
+macro_rules! from_toks {
+    ($($t:ident $i:tt),*) => {
+        impl<$($t: Operand),*> FromToks for ($($t,)*) {
+            #[allow(unused_variables)]
+            fn from_toks(toks: &[Tok]) -> Result<Self, ParseError> {
+                Ok(($($t::parse(&toks[$i])?,)*))
+            }
+        }
+    };
+}
+
+from_toks!();
+from_toks!(A 0);
+from_toks!(A 0, B 1);
+from_toks!(A 0, B 1, C 2);
+from_toks!(A 0, B 1, C 2, D 3);
+from_toks!(A 0, B 1, C 2, D 3, E 4);
+
+// Vector loads and stores, which carry the element width and segment count
+// in the mnemonic: `vlseg2e32.v`.
+fn vector_memory(m: &str, ops: &Operands) -> Result<Option<Inst>, ParseError> {
+    let mut nums = Vec::new();
+    let mut shape = String::new();
+    for (i, c) in m.char_indices() {
+        if !c.is_ascii_digit() {
+            shape.push(c);
+        } else if !m[..i].ends_with(|c: char| c.is_ascii_digit()) {
+            shape.push('#');
+            let len = m[i..].find(|c: char| !c.is_ascii_digit()).unwrap_or(m.len() - i);
+            nums.push(m[i..i + len].parse::<u32>().unwrap_or(0));
+        }
+    }
+    let sew = |bits| (0..4).filter_map(Sew::from_u8).find(|s| s.bits() == bits);
+    let (nf, eew) = match (&shape[..], &nums[..]) {
+        ("vle#.v" | "vle#ff.v" | "vlse#.v" | "vluxei#.v" | "vloxei#.v", &[eew]) => (1, sew(eew)),
+        ("vse#.v" | "vsse#.v" | "vsuxei#.v" | "vsoxei#.v", &[eew]) => (1, sew(eew)),
+        (_, &[nf, eew]) if (2..=8).contains(&nf) && !shape.starts_with("vl#") => (nf as u8, sew(eew)),
+        ("vl#re#.v", &[nf, eew]) if nf.is_power_of_two() && nf <= 8 => (nf as u8, sew(eew)),
+        ("vs#r.v" | "vmv#r.v", &[nf]) if nf.is_power_of_two() && nf <= 8 => (nf as u8, Some(Sew::E8)),
+        _ => return Ok(None),
+    };
+    let Some(eew) = eew else {
+        return Ok(None);
+    };
+    let inst = match &shape[..] {
+        "vle#.v" | "vlseg#e#.v" => ops.get("{},({})[,{}]", |(vd, rs1, vm)| Inst::VLE(vd, rs1, eew, nf, vm))?,
+        "vle#ff.v" | "vlseg#e#ff.v" => ops.get("{},({})[,{}]", |(vd, rs1, vm)| Inst::VLEFF(vd, rs1, eew, nf, vm))?,
+        "vlse#.v" | "vlsseg#e#.v" => ops.get("{},({}),{}[,{}]", |(vd, rs1, rs2, vm)| Inst::VLSE(vd, rs1, rs2, eew, nf, vm))?,
+        "vluxei#.v" | "vluxseg#ei#.v" => ops.get("{},({}),{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VLUXEI(vd, rs1, vs2, eew, nf, vm))?,
+        "vloxei#.v" | "vloxseg#ei#.v" => ops.get("{},({}),{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VLOXEI(vd, rs1, vs2, eew, nf, vm))?,
+        "vl#re#.v" => ops.get("{},({})", |(vd, rs1)| Inst::VLRE(vd, rs1, eew, nf))?,
+        "vse#.v" | "vsseg#e#.v" => ops.get("{},({})[,{}]", |(vs3, rs1, vm)| Inst::VSE(vs3, rs1, eew, nf, vm))?,
+        "vsse#.v" | "vssseg#e#.v" => ops.get("{},({}),{}[,{}]", |(vs3, rs1, rs2, vm)| Inst::VSSE(vs3, rs1, rs2, eew, nf, vm))?,
+        "vsuxei#.v" | "vsuxseg#ei#.v" => ops.get("{},({}),{}[,{}]", |(vs3, rs1, vs2, vm)| Inst::VSUXEI(vs3, rs1, vs2, eew, nf, vm))?,
+        "vsoxei#.v" | "vsoxseg#ei#.v" => ops.get("{},({}),{}[,{}]", |(vs3, rs1, vs2, vm)| Inst::VSOXEI(vs3, rs1, vs2, eew, nf, vm))?,
+        "vs#r.v" => ops.get("{},({})", |(vs3, rs1)| Inst::VSR(vs3, rs1, nf))?,
+        "vmv#r.v" => ops.get("{},{}", |(vd, vs2)| Inst::VMVNRV(vd, vs2, nf))?,
+        _ => return Ok(None),
+    };
+    Ok(Some(inst))
+}
+
+fn inst(m: &str, aqrl: Aqrl, ops: &Operands) -> Result<Option<Inst>, ParseError> {
+    let inst = match m {
+        "unimp" => ops.get("", |()| Inst::CSRRW(Gpr::zero, Gpr::zero, Csr::cycle))?,
+        "fence" => ops.get("{},{}", |(pred, succ)| Inst::FENCE(Fm::None, pred, succ))?,
+        "fence.tso" => ops.get("", |()| Inst::FENCE(Fm::Tso, Fence::RW, Fence::RW))?,
+        "add" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ADD(rd, rs1, rs2))?,
+        "sub" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SUB(rd, rs1, rs2))?,
+        "xor" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::XOR(rd, rs1, rs2))?,
+        "or" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::OR(rd, rs1, rs2))?,
+        "and" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::AND(rd, rs1, rs2))?,
+        "sll" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SLL(rd, rs1, rs2))?,
+        "srl" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SRL(rd, rs1, rs2))?,
+        "sra" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SRA(rd, rs1, rs2))?,
+        "slt" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SLT(rd, rs1, rs2))?,
+        "sltu" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SLTU(rd, rs1, rs2))?,
+        "addi" => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::ADDI(rd, rs1, imm))?,
+        "xori" => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::XORI(rd, rs1, imm))?,
+        "ori" => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::ORI(rd, rs1, imm))?,
+        "andi" => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::ANDI(rd, rs1, imm))?,
+        "slli" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SLLI(rd, rs1, shamt))?,
+        "srli" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SRLI(rd, rs1, shamt))?,
+        "srai" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SRAI(rd, rs1, shamt))?,
+        "slti" => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::SLTI(rd, rs1, imm))?,
+        "sltiu" => ops.get("{},{},{}", |(rd, rs1, imm): (_, _, i32)| Inst::SLTUI(rd, rs1, imm as u32))?,
+        "lb" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LB(rd, rs1, imm))?,
+        "lh" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LH(rd, rs1, imm))?,
+        "lw" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LW(rd, rs1, imm))?,
+        "lbu" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LBU(rd, rs1, imm))?,
+        "lhu" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LHU(rd, rs1, imm))?,
+        "sb" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::SB(rs2, rs1, imm))?,
+        "sh" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::SH(rs2, rs1, imm))?,
+        "sw" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::SW(rs2, rs1, imm))?,
+        "beq" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BEQ(rs1, rs2, imm))?,
+        "bne" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BNE(rs1, rs2, imm))?,
+        "blt" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BLT(rs1, rs2, imm))?,
+        "bge" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BGE(rs1, rs2, imm))?,
+        "bltu" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BLTU(rs1, rs2, imm))?,
+        "bgeu" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BGEU(rs1, rs2, imm))?,
+        "jal" => ops.get("{},{}", |(rd, imm)| Inst::JAL(rd, imm))?,
+        "jalr" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::JALR(rd, rs1, imm))?,
+        "lui" => ops.get("{},{}", |(rd, imm)| Inst::LUI(rd, imm))?,
+        "auipc" => ops.get("{},{}", |(rd, imm)| Inst::AUIPC(rd, imm))?,
+        "ecall" => ops.get("", |()| Inst::ECALL)?,
+        "ebreak" => ops.get("", |()| Inst::EBREAK)?,
+        "pause" => ops.get("", |()| Inst::PAUSE)?,
+        "fence.i" => ops.get("", |()| Inst::FENCEI)?,
+        "csrrw" => ops.get("{},{},{}", |(rd, csr, rs1)| Inst::CSRRW(rd, rs1, csr))?,
+        "csrrs" => ops.get("{},{},{}", |(rd, csr, rs1)| Inst::CSRRS(rd, rs1, csr))?,
+        "csrrc" => ops.get("{},{},{}", |(rd, csr, rs1)| Inst::CSRRC(rd, rs1, csr))?,
+        "csrrwi" => ops.get("{},{},{}", |(rd, csr, imm)| Inst::CSRRWI(rd, imm, csr))?,
+        "csrrsi" => ops.get("{},{},{}", |(rd, csr, imm)| Inst::CSRRSI(rd, imm, csr))?,
+        "csrrci" => ops.get("{},{},{}", |(rd, csr, imm)| Inst::CSRRCI(rd, imm, csr))?,
+        "sret" => ops.get("", |()| Inst::SRET)?,
+        "mret" => ops.get("", |()| Inst::MRET)?,
+        "wfi" => ops.get("", |()| Inst::WFI)?,
+        "ld" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LD(rd, rs1, imm))?,
+        "lwu" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LWU(rd, rs1, imm))?,
+        "ldu" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LDU(rd, rs1, imm))?,
+        "lq" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::LQ(rd, rs1, imm))?,
+        "sq" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::SQ(rs2, rs1, imm))?,
+        "addid" => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::ADDID(rd, rs1, imm))?,
+        "sllid" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SLLID(rd, rs1, shamt))?,
+        "srlid" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SRLID(rd, rs1, shamt))?,
+        "sraid" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SRAID(rd, rs1, shamt))?,
+        "addd" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ADDD(rd, rs1, rs2))?,
+        "subd" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SUBD(rd, rs1, rs2))?,
+        "slld" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SLLD(rd, rs1, rs2))?,
+        "srld" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SRLD(rd, rs1, rs2))?,
+        "srad" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SRAD(rd, rs1, rs2))?,
+        "muld" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MULD(rd, rs1, rs2))?,
+        "divd" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::DIVD(rd, rs1, rs2))?,
+        "divud" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::DIVUD(rd, rs1, rs2))?,
+        "remd" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::REMD(rd, rs1, rs2))?,
+        "remud" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::REMUD(rd, rs1, rs2))?,
+        "sd" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::SD(rs2, rs1, imm))?,
+        "addiw" => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::ADDIW(rd, rs1, imm))?,
+        "slliw" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SLLIW(rd, rs1, shamt))?,
+        "srliw" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SRLIW(rd, rs1, shamt))?,
+        "sraiw" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SRAIW(rd, rs1, shamt))?,
+        "addw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ADDW(rd, rs1, rs2))?,
+        "subw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SUBW(rd, rs1, rs2))?,
+        "sllw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SLLW(rd, rs1, rs2))?,
+        "srlw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SRLW(rd, rs1, rs2))?,
+        "sraw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SRAW(rd, rs1, rs2))?,
+        "mul" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MUL(rd, rs1, rs2))?,
+        "mulh" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MULH(rd, rs1, rs2))?,
+        "mulhsu" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MULSU(rd, rs1, rs2))?,
+        "mulhu" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MULU(rd, rs1, rs2))?,
+        "mulw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MULW(rd, rs1, rs2))?,
+        "div" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::DIV(rd, rs1, rs2))?,
+        "divu" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::DIVU(rd, rs1, rs2))?,
+        "rem" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::REM(rd, rs1, rs2))?,
+        "remu" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::REMU(rd, rs1, rs2))?,
+        "divw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::DIVW(rd, rs1, rs2))?,
+        "divuw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::DIVUW(rd, rs1, rs2))?,
+        "remw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::REMW(rd, rs1, rs2))?,
+        "remuw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::REMUW(rd, rs1, rs2))?,
+        "lr.w" => ops.get("{},({})", |(rd, rs1)| Inst::LRW(rd, rs1, aqrl))?,
+        "sc.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::SCW(rd, rs1, rs2, aqrl))?,
+        "lr.d" => ops.get("{},({})", |(rd, rs1)| Inst::LRD(rd, rs1, aqrl))?,
+        "sc.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::SCD(rd, rs1, rs2, aqrl))?,
+        "amoswap.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOSWAPW(rd, rs1, rs2, aqrl))?,
+        "amoadd.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOADDW(rd, rs1, rs2, aqrl))?,
+        "amoxor.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOXORW(rd, rs1, rs2, aqrl))?,
+        "amoand.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOANDW(rd, rs1, rs2, aqrl))?,
+        "amoor.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOORW(rd, rs1, rs2, aqrl))?,
+        "amomin.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMINW(rd, rs1, rs2, aqrl))?,
+        "amomax.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXW(rd, rs1, rs2, aqrl))?,
+        "amominu.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMINUW(rd, rs1, rs2, aqrl))?,
+        "amomaxu.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXUW(rd, rs1, rs2, aqrl))?,
+        "amoswap.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOSWAPD(rd, rs1, rs2, aqrl))?,
+        "amoadd.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOADDD(rd, rs1, rs2, aqrl))?,
+        "amoxor.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOXORD(rd, rs1, rs2, aqrl))?,
+        "amoand.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOANDD(rd, rs1, rs2, aqrl))?,
+        "amoor.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOORD(rd, rs1, rs2, aqrl))?,
+        "amomin.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMIND(rd, rs1, rs2, aqrl))?,
+        "amomax.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXD(rd, rs1, rs2, aqrl))?,
+        "amominu.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMINUD(rd, rs1, rs2, aqrl))?,
+        "amomaxu.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXUD(rd, rs1, rs2, aqrl))?,
+        "amocas.w" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOCASW(rd, rs1, rs2, aqrl))?,
+        "amocas.d" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOCASD(rd, rs1, rs2, aqrl))?,
+        "amocas.q" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOCASQ(rd, rs1, rs2, aqrl))?,
+        "amoswap.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOSWAPB(rd, rs1, rs2, aqrl))?,
+        "amoadd.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOADDB(rd, rs1, rs2, aqrl))?,
+        "amoxor.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOXORB(rd, rs1, rs2, aqrl))?,
+        "amoand.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOANDB(rd, rs1, rs2, aqrl))?,
+        "amoor.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOORB(rd, rs1, rs2, aqrl))?,
+        "amomin.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMINB(rd, rs1, rs2, aqrl))?,
+        "amomax.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXB(rd, rs1, rs2, aqrl))?,
+        "amominu.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMINUB(rd, rs1, rs2, aqrl))?,
+        "amomaxu.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXUB(rd, rs1, rs2, aqrl))?,
+        "amocas.b" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOCASB(rd, rs1, rs2, aqrl))?,
+        "amoswap.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOSWAPH(rd, rs1, rs2, aqrl))?,
+        "amoadd.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOADDH(rd, rs1, rs2, aqrl))?,
+        "amoxor.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOXORH(rd, rs1, rs2, aqrl))?,
+        "amoand.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOANDH(rd, rs1, rs2, aqrl))?,
+        "amoor.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOORH(rd, rs1, rs2, aqrl))?,
+        "amomin.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMINH(rd, rs1, rs2, aqrl))?,
+        "amomax.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXH(rd, rs1, rs2, aqrl))?,
+        "amominu.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMINUH(rd, rs1, rs2, aqrl))?,
+        "amomaxu.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOMAXUH(rd, rs1, rs2, aqrl))?,
+        "amocas.h" => ops.get("{},{},({})", |(rd, rs2, rs1)| Inst::AMOCASH(rd, rs1, rs2, aqrl))?,
+        "flw" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::FLW(rd, rs1, imm))?,
+        "fsw" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::FSW(rs2, rs1, imm))?,
+        "fmadd.s" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMADDS(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fmsub.s" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMSUBS(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmsub.s" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMSUBS(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmadd.s" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMADDS(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fadd.s" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FADDS(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsub.s" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FSUBS(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fmul.s" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FMULS(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fdiv.s" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FDIVS(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsqrt.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FSQRTS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fsgnj.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJS(rd, rs1, rs2))?,
+        "fsgnjn.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJNS(rd, rs1, rs2))?,
+        "fsgnjx.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJXS(rd, rs1, rs2))?,
+        "fmin.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMINS(rd, rs1, rs2))?,
+        "fmax.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXS(rd, rs1, rs2))?,
+        "feq.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FEQS(rd, rs1, rs2))?,
+        "flt.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTS(rd, rs1, rs2))?,
+        "fle.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLES(rd, rs1, rs2))?,
+        "fclass.s" => ops.get("{},{}", |(rd, rs1)| Inst::FCLASSS(rd, rs1))?,
+        "fcvt.w.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.wu.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWUS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.s.w" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTSW(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.s.wu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTSWU(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fmv.x.w" => ops.get("{},{}", |(rd, rs1)| Inst::FMVXW(rd, rs1))?,
+        "fmv.w.x" => ops.get("{},{}", |(rd, rs1)| Inst::FMVWX(rd, rs1))?,
+        "fcvt.l.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.lu.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLUS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.s.l" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTSL(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.s.lu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTSLU(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fld" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::FLD(rd, rs1, imm))?,
+        "fsd" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::FSD(rs2, rs1, imm))?,
+        "fmadd.d" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMADDD(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fmsub.d" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMSUBD(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmsub.d" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMSUBD(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmadd.d" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMADDD(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fadd.d" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FADDD(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsub.d" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FSUBD(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fmul.d" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FMULD(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fdiv.d" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FDIVD(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsqrt.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FSQRTD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fsgnj.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJD(rd, rs1, rs2))?,
+        "fsgnjn.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJND(rd, rs1, rs2))?,
+        "fsgnjx.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJXD(rd, rs1, rs2))?,
+        "fmin.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMIND(rd, rs1, rs2))?,
+        "fmax.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXD(rd, rs1, rs2))?,
+        "fcvt.s.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTSD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.d.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTDS(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "feq.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FEQD(rd, rs1, rs2))?,
+        "flt.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTD(rd, rs1, rs2))?,
+        "fle.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLED(rd, rs1, rs2))?,
+        "fclass.d" => ops.get("{},{}", |(rd, rs1)| Inst::FCLASSD(rd, rs1))?,
+        "fcvt.w.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.wu.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWUD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.d.w" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTDW(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.d.wu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTDWU(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.l.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.lu.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLUD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.d.l" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTDL(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.d.lu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTDLU(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fmv.x.d" => ops.get("{},{}", |(rd, rs1)| Inst::FMVXD(rd, rs1))?,
+        "fmv.d.x" => ops.get("{},{}", |(rd, rs1)| Inst::FMVDX(rd, rs1))?,
+        "flq" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::FLQ(rd, rs1, imm))?,
+        "fsq" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::FSQ(rs2, rs1, imm))?,
+        "fmadd.q" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMADDQ(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fmsub.q" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMSUBQ(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmsub.q" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMSUBQ(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmadd.q" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMADDQ(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fadd.q" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FADDQ(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsub.q" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FSUBQ(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fmul.q" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FMULQ(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fdiv.q" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FDIVQ(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsqrt.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FSQRTQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fsgnj.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJQ(rd, rs1, rs2))?,
+        "fsgnjn.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJNQ(rd, rs1, rs2))?,
+        "fsgnjx.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJXQ(rd, rs1, rs2))?,
+        "fmin.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMINQ(rd, rs1, rs2))?,
+        "fmax.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXQ(rd, rs1, rs2))?,
+        "fcvt.s.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTSQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.q.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTQS(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.d.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTDQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.q.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTQD(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "feq.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FEQQ(rd, rs1, rs2))?,
+        "flt.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTQ(rd, rs1, rs2))?,
+        "fle.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLEQ(rd, rs1, rs2))?,
+        "fclass.q" => ops.get("{},{}", |(rd, rs1)| Inst::FCLASSQ(rd, rs1))?,
+        "fcvt.w.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.wu.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWUQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.q.w" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTQW(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.q.wu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTQWU(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.l.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.lu.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLUQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.q.l" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTQL(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.q.lu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTQLU(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "flh" => ops.get("{},{}({})", |(rd, imm, rs1)| Inst::FLH(rd, rs1, imm))?,
+        "fsh" => ops.get("{},{}({})", |(rs2, imm, rs1)| Inst::FSH(rs2, rs1, imm))?,
+        "fmadd.h" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMADDH(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fmsub.h" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FMSUBH(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmsub.h" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMSUBH(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fnmadd.h" => ops.get("{},{},{},{}[,{}]", |(rd, rs1, rs2, rs3, rm)| Inst::FNMADDH(rd, rs1, rs2, rs3, rm_or(rm, Rm::Dyn)))?,
+        "fadd.h" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FADDH(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsub.h" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FSUBH(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fmul.h" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FMULH(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fdiv.h" => ops.get("{},{},{}[,{}]", |(rd, rs1, rs2, rm)| Inst::FDIVH(rd, rs1, rs2, rm_or(rm, Rm::Dyn)))?,
+        "fsqrt.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FSQRTH(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fsgnj.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJH(rd, rs1, rs2))?,
+        "fsgnjn.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJNH(rd, rs1, rs2))?,
+        "fsgnjx.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FSGNJXH(rd, rs1, rs2))?,
+        "fmin.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMINH(rd, rs1, rs2))?,
+        "fmax.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXH(rd, rs1, rs2))?,
+        "fcvt.s.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTSH(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.h.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTHS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.d.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTDH(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.h.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTHD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.q.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTQH(rd, rs1, rm_or(rm, Rm::Rne)))?,
+        "fcvt.h.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTHQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "feq.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FEQH(rd, rs1, rs2))?,
+        "flt.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTH(rd, rs1, rs2))?,
+        "fle.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLEH(rd, rs1, rs2))?,
+        "fclass.h" => ops.get("{},{}", |(rd, rs1)| Inst::FCLASSH(rd, rs1))?,
+        "fcvt.w.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWH(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.wu.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTWUH(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.h.w" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTHW(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.h.wu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTHWU(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fmv.x.h" => ops.get("{},{}", |(rd, rs1)| Inst::FMVXH(rd, rs1))?,
+        "fmv.h.x" => ops.get("{},{}", |(rd, rs1)| Inst::FMVHX(rd, rs1))?,
+        "fcvt.l.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLH(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.lu.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTLUH(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.h.l" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTHL(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fcvt.h.lu" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FCVTHLU(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fli.s" => ops.get("{},{}", |(rd, fli)| Inst::FLIS(rd, fli))?,
+        "fli.d" => ops.get("{},{}", |(rd, fli)| Inst::FLID(rd, fli))?,
+        "fli.h" => ops.get("{},{}", |(rd, fli)| Inst::FLIH(rd, fli))?,
+        "fli.q" => ops.get("{},{}", |(rd, fli)| Inst::FLIQ(rd, fli))?,
+        "fminm.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMINMS(rd, rs1, rs2))?,
+        "fmaxm.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXMS(rd, rs1, rs2))?,
+        "fround.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "froundnx.s" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDNXS(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fleq.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLEQS(rd, rs1, rs2))?,
+        "fltq.s" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTQS(rd, rs1, rs2))?,
+        "fminm.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMINMD(rd, rs1, rs2))?,
+        "fmaxm.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXMD(rd, rs1, rs2))?,
+        "fround.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "froundnx.d" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDNXD(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fleq.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLEQD(rd, rs1, rs2))?,
+        "fltq.d" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTQD(rd, rs1, rs2))?,
+        "fminm.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMINMH(rd, rs1, rs2))?,
+        "fmaxm.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXMH(rd, rs1, rs2))?,
+        "fround.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDH(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "froundnx.h" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDNXH(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fleq.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLEQH(rd, rs1, rs2))?,
+        "fltq.h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTQH(rd, rs1, rs2))?,
+        "fminm.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMINMQ(rd, rs1, rs2))?,
+        "fmaxm.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMAXMQ(rd, rs1, rs2))?,
+        "fround.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "froundnx.q" => ops.get("{},{}[,{}]", |(rd, rs1, rm)| Inst::FROUNDNXQ(rd, rs1, rm_or(rm, Rm::Dyn)))?,
+        "fleq.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLEQQ(rd, rs1, rs2))?,
+        "fltq.q" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FLTQQ(rd, rs1, rs2))?,
+        "fcvtmod.w.d" => ops.get("{},{},rtz", |(rd, rs1)| Inst::FCVTMODWD(rd, rs1))?,
+        "fmvh.x.d" => ops.get("{},{}", |(rd, rs1)| Inst::FMVHXD(rd, rs1))?,
+        "fmvp.d.x" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMVPDX(rd, rs1, rs2))?,
+        "fmvh.x.q" => ops.get("{},{}", |(rd, rs1)| Inst::FMVHXQ(rd, rs1))?,
+        "fmvp.q.x" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::FMVPQX(rd, rs1, rs2))?,
+        "sfence.vma" => ops.get("{},{}", |(rs1, rs2)| Inst::SFENCEVMA(rs1, rs2))?,
+        "sinval.vma" => ops.get("{},{}", |(rs1, rs2)| Inst::SINVALVMA(rs1, rs2))?,
+        "sfence.w.inval" => ops.get("", |()| Inst::SFENCEWINVAL)?,
+        "sfence.inval.ir" => ops.get("", |()| Inst::SFENCEINVALIR)?,
+        "hfence.vvma" => ops.get("{},{}", |(rs1, rs2)| Inst::HFENCEVVMA(rs1, rs2))?,
+        "hfence.gvma" => ops.get("{},{}", |(rs1, rs2)| Inst::HFENCEGVMA(rs1, rs2))?,
+        "hinval.vvma" => ops.get("{},{}", |(rs1, rs2)| Inst::HINVALVVMA(rs1, rs2))?,
+        "hinval.gvma" => ops.get("{},{}", |(rs1, rs2)| Inst::HINVALGVMA(rs1, rs2))?,
+        "hlv.b" => ops.get("{},({})", |(rd, rs1)| Inst::HLVB(rd, rs1))?,
+        "hlv.bu" => ops.get("{},({})", |(rd, rs1)| Inst::HLVBU(rd, rs1))?,
+        "hlv.h" => ops.get("{},({})", |(rd, rs1)| Inst::HLVH(rd, rs1))?,
+        "hlv.hu" => ops.get("{},({})", |(rd, rs1)| Inst::HLVHU(rd, rs1))?,
+        "hlvx.hu" => ops.get("{},({})", |(rd, rs1)| Inst::HLVXHU(rd, rs1))?,
+        "hlv.w" => ops.get("{},({})", |(rd, rs1)| Inst::HLVW(rd, rs1))?,
+        "hlvx.wu" => ops.get("{},({})", |(rd, rs1)| Inst::HLVXWU(rd, rs1))?,
+        "hsv.b" => ops.get("{},({})", |(rs2, rs1)| Inst::HSVB(rs1, rs2))?,
+        "hsv.h" => ops.get("{},({})", |(rs2, rs1)| Inst::HSVH(rs1, rs2))?,
+        "hsv.w" => ops.get("{},({})", |(rs2, rs1)| Inst::HSVW(rs1, rs2))?,
+        "hlv.wu" => ops.get("{},({})", |(rd, rs1)| Inst::HLVWU(rd, rs1))?,
+        "hlv.d" => ops.get("{},({})", |(rd, rs1)| Inst::HLVD(rd, rs1))?,
+        "hsv.d" => ops.get("{},({})", |(rs2, rs1)| Inst::HSVD(rs1, rs2))?,
+        "c.addi4spn" => ops.get("{},sp,{}", |(rd, imm)| Inst::CADDI4SPN(rd, imm))?,
+        "c.fld" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CFLD(r, rs1, imm))?,
+        "c.lq" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CLQ(r, rs1, imm))?,
+        "c.lw" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CLW(r, rs1, imm))?,
+        "c.flw" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CFLW(r, rs1, imm))?,
+        "c.ld" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CLD(r, rs1, imm))?,
+        "c.fsd" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CFSD(r, rs1, imm))?,
+        "c.sq" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CSQ(r, rs1, imm))?,
+        "c.sw" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CSW(r, rs1, imm))?,
+        "c.fsw" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CFSW(r, rs1, imm))?,
+        "c.sd" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CSD(r, rs1, imm))?,
+        "c.nop" => ops.get("", |()| Inst::CNOP)?,
+        "c.addi" => ops.get("{},{}", |(rd, imm)| Inst::CADDI(rd, imm))?,
+        "c.jal" => ops.get("{}", |(imm,)| Inst::CJAL(imm))?,
+        "c.addiw" => ops.get("{},{}", |(rd, imm)| Inst::CADDIW(rd, imm))?,
+        "c.li" => ops.get("{},{}", |(rd, imm)| Inst::CLI(rd, imm))?,
+        "c.addi16sp" => ops.get("sp,{}", |(imm,)| Inst::CADDI16SP(imm))?,
+        "c.lui" => ops.get("{},{}", |(rd, imm)| Inst::CLUI(rd, imm))?,
+        "c.srli" => ops.get("{},{}", |(rd, shamt)| Inst::CSRLI(rd, shamt))?,
+        "c.srli64" => ops.get("{}", |(rd,)| Inst::CSRLI64(rd))?,
+        "c.srai" => ops.get("{},{}", |(rd, shamt)| Inst::CSRAI(rd, shamt))?,
+        "c.srai64" => ops.get("{}", |(rd,)| Inst::CSRAI64(rd))?,
+        "c.andi" => ops.get("{},{}", |(rd, imm)| Inst::CANDI(rd, imm))?,
+        "c.sub" => ops.get("{},{}", |(rd, rs2)| Inst::CSUB(rd, rs2))?,
+        "c.xor" => ops.get("{},{}", |(rd, rs2)| Inst::CXOR(rd, rs2))?,
+        "c.or" => ops.get("{},{}", |(rd, rs2)| Inst::COR(rd, rs2))?,
+        "c.and" => ops.get("{},{}", |(rd, rs2)| Inst::CAND(rd, rs2))?,
+        "c.subw" => ops.get("{},{}", |(rd, rs2)| Inst::CSUBW(rd, rs2))?,
+        "c.addw" => ops.get("{},{}", |(rd, rs2)| Inst::CADDW(rd, rs2))?,
+        "c.j" => ops.get("{}", |(imm,)| Inst::CJ(imm))?,
+        "c.beqz" => ops.get("{},{}", |(rs1, imm)| Inst::CBEQZ(rs1, imm))?,
+        "c.bnez" => ops.get("{},{}", |(rs1, imm)| Inst::CBNEZ(rs1, imm))?,
+        "c.slli" => ops.get("{},{}", |(rd, shamt)| Inst::CSLLI(rd, shamt))?,
+        "c.slli64" => ops.get("{}", |(rd,)| Inst::CSLLI64(rd))?,
+        "c.fldsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CFLDSP(r, imm))?,
+        "c.lqsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CLQSP(r, imm))?,
+        "c.lwsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CLWSP(r, imm))?,
+        "c.flwsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CFLWSP(r, imm))?,
+        "c.ldsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CLDSP(r, imm))?,
+        "c.jr" => ops.get("{}", |(rd,)| Inst::CJR(rd))?,
+        "c.mv" => ops.get("{},{}", |(rd, rs2)| Inst::CMV(rd, rs2))?,
+        "c.ebreak" => ops.get("", |()| Inst::CEBREAK)?,
+        "c.jalr" => ops.get("{}", |(rd,)| Inst::CJALR(rd))?,
+        "c.add" => ops.get("{},{}", |(rd, rs2)| Inst::CADD(rd, rs2))?,
+        "c.fsdsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CFSDSP(r, imm))?,
+        "c.sqsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CSQSP(r, imm))?,
+        "c.swsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CSWSP(r, imm))?,
+        "c.fswsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CFSWSP(r, imm))?,
+        "c.sdsp" => ops.get("{},{}(sp)", |(r, imm)| Inst::CSDSP(r, imm))?,
+        "c.lbu" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CLBU(r, rs1, imm))?,
+        "c.lhu" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CLHU(r, rs1, imm))?,
+        "c.lh" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CLH(r, rs1, imm))?,
+        "c.sb" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CSB(r, rs1, imm))?,
+        "c.sh" => ops.get("{},{}({})", |(r, imm, rs1)| Inst::CSH(r, rs1, imm))?,
+        "c.zext.b" => ops.get("{}", |(rd,)| Inst::CZEXTB(rd))?,
+        "c.sext.b" => ops.get("{}", |(rd,)| Inst::CSEXTB(rd))?,
+        "c.zext.h" => ops.get("{}", |(rd,)| Inst::CZEXTH(rd))?,
+        "c.sext.h" => ops.get("{}", |(rd,)| Inst::CSEXTH(rd))?,
+        "c.zext.w" => ops.get("{}", |(rd,)| Inst::CZEXTW(rd))?,
+        "c.not" => ops.get("{}", |(rd,)| Inst::CNOT(rd))?,
+        "c.mul" => ops.get("{},{}", |(rd, rs2)| Inst::CMUL(rd, rs2))?,
+        "cm.push" => ops.get("{},-{}", |(rlist, adj)| Inst::CMPUSH(rlist, adj))?,
+        "cm.pop" => ops.get("{},{}", |(rlist, adj)| Inst::CMPOP(rlist, adj))?,
+        "cm.popretz" => ops.get("{},{}", |(rlist, adj)| Inst::CMPOPRETZ(rlist, adj))?,
+        "cm.popret" => ops.get("{},{}", |(rlist, adj)| Inst::CMPOPRET(rlist, adj))?,
+        "cm.mvsa01" => ops.get("{},{}", |(r1s, r2s)| Inst::CMMVSA01(r1s, r2s))?,
+        "cm.mva01s" => ops.get("{},{}", |(r1s, r2s)| Inst::CMMVA01S(r1s, r2s))?,
+        "cm.jt" => ops.get("{}", |(index,)| Inst::CMJT(index))?,
+        "cm.jalt" => ops.get("{}", |(index,)| Inst::CMJALT(index))?,
+        "sh1add" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SH1ADD(rd, rs1, rs2))?,
+        "sh2add" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SH2ADD(rd, rs1, rs2))?,
+        "sh3add" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SH3ADD(rd, rs1, rs2))?,
+        "add.uw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ADDUW(rd, rs1, rs2))?,
+        "sh1add.uw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SH1ADDUW(rd, rs1, rs2))?,
+        "sh2add.uw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SH2ADDUW(rd, rs1, rs2))?,
+        "sh3add.uw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SH3ADDUW(rd, rs1, rs2))?,
+        "slli.uw" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::SLLIUW(rd, rs1, shamt))?,
+        "andn" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ANDN(rd, rs1, rs2))?,
+        "orn" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ORN(rd, rs1, rs2))?,
+        "xnor" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::XNOR(rd, rs1, rs2))?,
+        "clz" => ops.get("{},{}", |(rd, rs1)| Inst::CLZ(rd, rs1))?,
+        "ctz" => ops.get("{},{}", |(rd, rs1)| Inst::CTZ(rd, rs1))?,
+        "cpop" => ops.get("{},{}", |(rd, rs1)| Inst::CPOP(rd, rs1))?,
+        "max" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MAX(rd, rs1, rs2))?,
+        "maxu" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MAXU(rd, rs1, rs2))?,
+        "min" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MIN(rd, rs1, rs2))?,
+        "minu" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::MINU(rd, rs1, rs2))?,
+        "sext.b" => ops.get("{},{}", |(rd, rs1)| Inst::SEXTB(rd, rs1))?,
+        "sext.h" => ops.get("{},{}", |(rd, rs1)| Inst::SEXTH(rd, rs1))?,
+        "zext.h" => ops.get("{},{}", |(rd, rs1)| Inst::ZEXTH(rd, rs1))?,
+        "rol" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ROL(rd, rs1, rs2))?,
+        "ror" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ROR(rd, rs1, rs2))?,
+        "rori" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::RORI(rd, rs1, shamt))?,
+        "orc.b" => ops.get("{},{}", |(rd, rs1)| Inst::ORCB(rd, rs1))?,
+        "rev8" => ops.get("{},{}", |(rd, rs1)| Inst::REV8(rd, rs1))?,
+        "clzw" => ops.get("{},{}", |(rd, rs1)| Inst::CLZW(rd, rs1))?,
+        "ctzw" => ops.get("{},{}", |(rd, rs1)| Inst::CTZW(rd, rs1))?,
+        "cpopw" => ops.get("{},{}", |(rd, rs1)| Inst::CPOPW(rd, rs1))?,
+        "rolw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::ROLW(rd, rs1, rs2))?,
+        "rorw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::RORW(rd, rs1, rs2))?,
+        "roriw" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::RORIW(rd, rs1, shamt))?,
+        "clmul" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::CLMUL(rd, rs1, rs2))?,
+        "clmulh" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::CLMULH(rd, rs1, rs2))?,
+        "clmulr" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::CLMULR(rd, rs1, rs2))?,
+        "bclr" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::BCLR(rd, rs1, rs2))?,
+        "bclri" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::BCLRI(rd, rs1, shamt))?,
+        "bext" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::BEXT(rd, rs1, rs2))?,
+        "bexti" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::BEXTI(rd, rs1, shamt))?,
+        "binv" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::BINV(rd, rs1, rs2))?,
+        "binvi" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::BINVI(rd, rs1, shamt))?,
+        "bset" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::BSET(rd, rs1, rs2))?,
+        "bseti" => ops.get("{},{},{}", |(rd, rs1, shamt)| Inst::BSETI(rd, rs1, shamt))?,
+        "pack" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::PACK(rd, rs1, rs2))?,
+        "packh" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::PACKH(rd, rs1, rs2))?,
+        "brev8" => ops.get("{},{}", |(rd, rs1)| Inst::BREV8(rd, rs1))?,
+        "packw" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::PACKW(rd, rs1, rs2))?,
+        "zip" => ops.get("{},{}", |(rd, rs1)| Inst::ZIP(rd, rs1))?,
+        "unzip" => ops.get("{},{}", |(rd, rs1)| Inst::UNZIP(rd, rs1))?,
+        "xperm4" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::XPERM4(rd, rs1, rs2))?,
+        "xperm8" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::XPERM8(rd, rs1, rs2))?,
+        "aes32dsi" => ops.get("{},{},{},{}", |(rd, rs1, rs2, bs)| Inst::AES32DSI(rd, rs1, rs2, bs))?,
+        "aes32dsmi" => ops.get("{},{},{},{}", |(rd, rs1, rs2, bs)| Inst::AES32DSMI(rd, rs1, rs2, bs))?,
+        "aes32esi" => ops.get("{},{},{},{}", |(rd, rs1, rs2, bs)| Inst::AES32ESI(rd, rs1, rs2, bs))?,
+        "aes32esmi" => ops.get("{},{},{},{}", |(rd, rs1, rs2, bs)| Inst::AES32ESMI(rd, rs1, rs2, bs))?,
+        "aes64ds" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::AES64DS(rd, rs1, rs2))?,
+        "aes64dsm" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::AES64DSM(rd, rs1, rs2))?,
+        "aes64im" => ops.get("{},{}", |(rd, rs1)| Inst::AES64IM(rd, rs1))?,
+        "aes64es" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::AES64ES(rd, rs1, rs2))?,
+        "aes64esm" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::AES64ESM(rd, rs1, rs2))?,
+        "aes64ks1i" => ops.get("{},{},{}", |(rd, rs1, rnum)| Inst::AES64KS1I(rd, rs1, rnum))?,
+        "aes64ks2" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::AES64KS2(rd, rs1, rs2))?,
+        "sha256sig0" => ops.get("{},{}", |(rd, rs1)| Inst::SHA256SIG0(rd, rs1))?,
+        "sha256sig1" => ops.get("{},{}", |(rd, rs1)| Inst::SHA256SIG1(rd, rs1))?,
+        "sha256sum0" => ops.get("{},{}", |(rd, rs1)| Inst::SHA256SUM0(rd, rs1))?,
+        "sha256sum1" => ops.get("{},{}", |(rd, rs1)| Inst::SHA256SUM1(rd, rs1))?,
+        "sha512sig0h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SHA512SIG0H(rd, rs1, rs2))?,
+        "sha512sig0l" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SHA512SIG0L(rd, rs1, rs2))?,
+        "sha512sig1h" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SHA512SIG1H(rd, rs1, rs2))?,
+        "sha512sig1l" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SHA512SIG1L(rd, rs1, rs2))?,
+        "sha512sum0r" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SHA512SUM0R(rd, rs1, rs2))?,
+        "sha512sum1r" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::SHA512SUM1R(rd, rs1, rs2))?,
+        "sha512sig0" => ops.get("{},{}", |(rd, rs1)| Inst::SHA512SIG0(rd, rs1))?,
+        "sha512sig1" => ops.get("{},{}", |(rd, rs1)| Inst::SHA512SIG1(rd, rs1))?,
+        "sha512sum0" => ops.get("{},{}", |(rd, rs1)| Inst::SHA512SUM0(rd, rs1))?,
+        "sha512sum1" => ops.get("{},{}", |(rd, rs1)| Inst::SHA512SUM1(rd, rs1))?,
+        "sm4ed" => ops.get("{},{},{},{}", |(rd, rs1, rs2, bs)| Inst::SM4ED(rd, rs1, rs2, bs))?,
+        "sm4ks" => ops.get("{},{},{},{}", |(rd, rs1, rs2, bs)| Inst::SM4KS(rd, rs1, rs2, bs))?,
+        "sm3p0" => ops.get("{},{}", |(rd, rs1)| Inst::SM3P0(rd, rs1))?,
+        "sm3p1" => ops.get("{},{}", |(rd, rs1)| Inst::SM3P1(rd, rs1))?,
+        "czero.eqz" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::CZEROEQZ(rd, rs1, rs2))?,
+        "czero.nez" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::CZERONEZ(rd, rs1, rs2))?,
+        "cbo.clean" => ops.get("0({})", |(rs1,)| Inst::CBOCLEAN(rs1))?,
+        "cbo.flush" => ops.get("0({})", |(rs1,)| Inst::CBOFLUSH(rs1))?,
+        "cbo.inval" => ops.get("0({})", |(rs1,)| Inst::CBOINVAL(rs1))?,
+        "cbo.zero" => ops.get("0({})", |(rs1,)| Inst::CBOZERO(rs1))?,
+        "prefetch.i" => ops.get("{}({})", |(imm, rs1)| Inst::PREFETCHI(rs1, imm))?,
+        "prefetch.r" => ops.get("{}({})", |(imm, rs1)| Inst::PREFETCHR(rs1, imm))?,
+        "prefetch.w" => ops.get("{}({})", |(imm, rs1)| Inst::PREFETCHW(rs1, imm))?,
+        "wrs.nto" => ops.get("", |()| Inst::WRSNTO)?,
+        "wrs.sto" => ops.get("", |()| Inst::WRSSTO)?,
+        "ntl.p1" => ops.get("", |()| Inst::NTLP1)?,
+        "ntl.pall" => ops.get("", |()| Inst::NTLPALL)?,
+        "ntl.s1" => ops.get("", |()| Inst::NTLS1)?,
+        "ntl.all" => ops.get("", |()| Inst::NTLALL)?,
+        "vsetvli" => ops.get("{},{},{}", |(rd, rs1, vtype)| Inst::VSETVLI(rd, rs1, vtype))?,
+        "vsetivli" => ops.get("{},{},{}", |(rd, avl, vtype)| Inst::VSETIVLI(rd, avl, vtype))?,
+        "vsetvl" => ops.get("{},{},{}", |(rd, rs1, rs2)| Inst::VSETVL(rd, rs1, rs2))?,
+        "vlm.v" => ops.get("{},({})", |(vd, rs1)| Inst::VLM(vd, rs1))?,
+        "vsm.v" => ops.get("{},({})", |(vs3, rs1)| Inst::VSM(vs3, rs1))?,
+        "vadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VADDVV(vd, vs2, vs1, vm))?,
+        "vadd.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VADDVX(vd, vs2, rs1, vm))?,
+        "vadd.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VADDVI(vd, vs2, imm, vm))?,
+        "vsub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSUBVV(vd, vs2, vs1, vm))?,
+        "vsub.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSUBVX(vd, vs2, rs1, vm))?,
+        "vrsub.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VRSUBVX(vd, vs2, rs1, vm))?,
+        "vrsub.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VRSUBVI(vd, vs2, imm, vm))?,
+        "vminu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMINUVV(vd, vs2, vs1, vm))?,
+        "vminu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMINUVX(vd, vs2, rs1, vm))?,
+        "vmin.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMINVV(vd, vs2, vs1, vm))?,
+        "vmin.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMINVX(vd, vs2, rs1, vm))?,
+        "vmaxu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMAXUVV(vd, vs2, vs1, vm))?,
+        "vmaxu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMAXUVX(vd, vs2, rs1, vm))?,
+        "vmax.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMAXVV(vd, vs2, vs1, vm))?,
+        "vmax.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMAXVX(vd, vs2, rs1, vm))?,
+        "vand.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VANDVV(vd, vs2, vs1, vm))?,
+        "vand.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VANDVX(vd, vs2, rs1, vm))?,
+        "vand.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VANDVI(vd, vs2, imm, vm))?,
+        "vor.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VORVV(vd, vs2, vs1, vm))?,
+        "vor.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VORVX(vd, vs2, rs1, vm))?,
+        "vor.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VORVI(vd, vs2, imm, vm))?,
+        "vxor.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VXORVV(vd, vs2, vs1, vm))?,
+        "vxor.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VXORVX(vd, vs2, rs1, vm))?,
+        "vxor.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VXORVI(vd, vs2, imm, vm))?,
+        "vrgather.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VRGATHERVV(vd, vs2, vs1, vm))?,
+        "vrgather.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VRGATHERVX(vd, vs2, rs1, vm))?,
+        "vrgather.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VRGATHERVI(vd, vs2, imm, vm))?,
+        "vrgatherei16.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VRGATHEREI16VV(vd, vs2, vs1, vm))?,
+        "vslideup.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSLIDEUPVX(vd, vs2, rs1, vm))?,
+        "vslideup.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSLIDEUPVI(vd, vs2, imm, vm))?,
+        "vslidedown.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSLIDEDOWNVX(vd, vs2, rs1, vm))?,
+        "vslidedown.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSLIDEDOWNVI(vd, vs2, imm, vm))?,
+        "vadc.vvm" => ops.get("{},{},{},v0", |(vd, vs2, vs1)| Inst::VADCVVM(vd, vs2, vs1))?,
+        "vadc.vxm" => ops.get("{},{},{},v0", |(vd, vs2, rs1)| Inst::VADCVXM(vd, vs2, rs1))?,
+        "vadc.vim" => ops.get("{},{},{},v0", |(vd, vs2, imm)| Inst::VADCVIM(vd, vs2, imm))?,
+        "vmadc.vvm" => ops.get("{},{},{},v0", |(vd, vs2, vs1)| Inst::VMADCVVM(vd, vs2, vs1))?,
+        "vmadc.vxm" => ops.get("{},{},{},v0", |(vd, vs2, rs1)| Inst::VMADCVXM(vd, vs2, rs1))?,
+        "vmadc.vim" => ops.get("{},{},{},v0", |(vd, vs2, imm)| Inst::VMADCVIM(vd, vs2, imm))?,
+        "vmadc.vv" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMADCVV(vd, vs2, vs1))?,
+        "vmadc.vx" => ops.get("{},{},{}", |(vd, vs2, rs1)| Inst::VMADCVX(vd, vs2, rs1))?,
+        "vmadc.vi" => ops.get("{},{},{}", |(vd, vs2, imm)| Inst::VMADCVI(vd, vs2, imm))?,
+        "vsbc.vvm" => ops.get("{},{},{},v0", |(vd, vs2, vs1)| Inst::VSBCVVM(vd, vs2, vs1))?,
+        "vsbc.vxm" => ops.get("{},{},{},v0", |(vd, vs2, rs1)| Inst::VSBCVXM(vd, vs2, rs1))?,
+        "vmsbc.vvm" => ops.get("{},{},{},v0", |(vd, vs2, vs1)| Inst::VMSBCVVM(vd, vs2, vs1))?,
+        "vmsbc.vxm" => ops.get("{},{},{},v0", |(vd, vs2, rs1)| Inst::VMSBCVXM(vd, vs2, rs1))?,
+        "vmsbc.vv" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMSBCVV(vd, vs2, vs1))?,
+        "vmsbc.vx" => ops.get("{},{},{}", |(vd, vs2, rs1)| Inst::VMSBCVX(vd, vs2, rs1))?,
+        "vmerge.vvm" => ops.get("{},{},{},v0", |(vd, vs2, vs1)| Inst::VMERGEVVM(vd, vs2, vs1))?,
+        "vmerge.vxm" => ops.get("{},{},{},v0", |(vd, vs2, rs1)| Inst::VMERGEVXM(vd, vs2, rs1))?,
+        "vmerge.vim" => ops.get("{},{},{},v0", |(vd, vs2, imm)| Inst::VMERGEVIM(vd, vs2, imm))?,
+        "vmv.v.v" => ops.get("{},{}", |(vd, vs1)| Inst::VMVVV(vd, vs1))?,
+        "vmv.v.x" => ops.get("{},{}", |(vd, rs1)| Inst::VMVVX(vd, rs1))?,
+        "vmv.v.i" => ops.get("{},{}", |(vd, imm)| Inst::VMVVI(vd, imm))?,
+        "vmseq.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMSEQVV(vd, vs2, vs1, vm))?,
+        "vmseq.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSEQVX(vd, vs2, rs1, vm))?,
+        "vmseq.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VMSEQVI(vd, vs2, imm, vm))?,
+        "vmsne.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMSNEVV(vd, vs2, vs1, vm))?,
+        "vmsne.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSNEVX(vd, vs2, rs1, vm))?,
+        "vmsne.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VMSNEVI(vd, vs2, imm, vm))?,
+        "vmsltu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMSLTUVV(vd, vs2, vs1, vm))?,
+        "vmsltu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSLTUVX(vd, vs2, rs1, vm))?,
+        "vmslt.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMSLTVV(vd, vs2, vs1, vm))?,
+        "vmslt.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSLTVX(vd, vs2, rs1, vm))?,
+        "vmsleu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMSLEUVV(vd, vs2, vs1, vm))?,
+        "vmsleu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSLEUVX(vd, vs2, rs1, vm))?,
+        "vmsleu.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VMSLEUVI(vd, vs2, imm, vm))?,
+        "vmsle.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMSLEVV(vd, vs2, vs1, vm))?,
+        "vmsle.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSLEVX(vd, vs2, rs1, vm))?,
+        "vmsle.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VMSLEVI(vd, vs2, imm, vm))?,
+        "vmsgtu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSGTUVX(vd, vs2, rs1, vm))?,
+        "vmsgtu.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VMSGTUVI(vd, vs2, imm, vm))?,
+        "vmsgt.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMSGTVX(vd, vs2, rs1, vm))?,
+        "vmsgt.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VMSGTVI(vd, vs2, imm, vm))?,
+        "vsaddu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSADDUVV(vd, vs2, vs1, vm))?,
+        "vsaddu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSADDUVX(vd, vs2, rs1, vm))?,
+        "vsaddu.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSADDUVI(vd, vs2, imm, vm))?,
+        "vsadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSADDVV(vd, vs2, vs1, vm))?,
+        "vsadd.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSADDVX(vd, vs2, rs1, vm))?,
+        "vsadd.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSADDVI(vd, vs2, imm, vm))?,
+        "vssubu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSSUBUVV(vd, vs2, vs1, vm))?,
+        "vssubu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSSUBUVX(vd, vs2, rs1, vm))?,
+        "vssub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSSUBVV(vd, vs2, vs1, vm))?,
+        "vssub.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSSUBVX(vd, vs2, rs1, vm))?,
+        "vsll.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSLLVV(vd, vs2, vs1, vm))?,
+        "vsll.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSLLVX(vd, vs2, rs1, vm))?,
+        "vsll.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSLLVI(vd, vs2, imm, vm))?,
+        "vsmul.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSMULVV(vd, vs2, vs1, vm))?,
+        "vsmul.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSMULVX(vd, vs2, rs1, vm))?,
+        "vsrl.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSRLVV(vd, vs2, vs1, vm))?,
+        "vsrl.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSRLVX(vd, vs2, rs1, vm))?,
+        "vsrl.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSRLVI(vd, vs2, imm, vm))?,
+        "vsra.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSRAVV(vd, vs2, vs1, vm))?,
+        "vsra.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSRAVX(vd, vs2, rs1, vm))?,
+        "vsra.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSRAVI(vd, vs2, imm, vm))?,
+        "vssrl.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSSRLVV(vd, vs2, vs1, vm))?,
+        "vssrl.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSSRLVX(vd, vs2, rs1, vm))?,
+        "vssrl.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSSRLVI(vd, vs2, imm, vm))?,
+        "vssra.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VSSRAVV(vd, vs2, vs1, vm))?,
+        "vssra.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSSRAVX(vd, vs2, rs1, vm))?,
+        "vssra.vi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VSSRAVI(vd, vs2, imm, vm))?,
+        "vnsrl.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VNSRLWV(vd, vs2, vs1, vm))?,
+        "vnsrl.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VNSRLWX(vd, vs2, rs1, vm))?,
+        "vnsrl.wi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VNSRLWI(vd, vs2, imm, vm))?,
+        "vnsra.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VNSRAWV(vd, vs2, vs1, vm))?,
+        "vnsra.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VNSRAWX(vd, vs2, rs1, vm))?,
+        "vnsra.wi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VNSRAWI(vd, vs2, imm, vm))?,
+        "vnclipu.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VNCLIPUWV(vd, vs2, vs1, vm))?,
+        "vnclipu.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VNCLIPUWX(vd, vs2, rs1, vm))?,
+        "vnclipu.wi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VNCLIPUWI(vd, vs2, imm, vm))?,
+        "vnclip.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VNCLIPWV(vd, vs2, vs1, vm))?,
+        "vnclip.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VNCLIPWX(vd, vs2, rs1, vm))?,
+        "vnclip.wi" => ops.get("{},{},{}[,{}]", |(vd, vs2, imm, vm)| Inst::VNCLIPWI(vd, vs2, imm, vm))?,
+        "vwredsumu.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWREDSUMUVS(vd, vs2, vs1, vm))?,
+        "vwredsum.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWREDSUMVS(vd, vs2, vs1, vm))?,
+        "vredsum.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDSUMVS(vd, vs2, vs1, vm))?,
+        "vredand.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDANDVS(vd, vs2, vs1, vm))?,
+        "vredor.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDORVS(vd, vs2, vs1, vm))?,
+        "vredxor.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDXORVS(vd, vs2, vs1, vm))?,
+        "vredminu.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDMINUVS(vd, vs2, vs1, vm))?,
+        "vredmin.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDMINVS(vd, vs2, vs1, vm))?,
+        "vredmaxu.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDMAXUVS(vd, vs2, vs1, vm))?,
+        "vredmax.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREDMAXVS(vd, vs2, vs1, vm))?,
+        "vaaddu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VAADDUVV(vd, vs2, vs1, vm))?,
+        "vaaddu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VAADDUVX(vd, vs2, rs1, vm))?,
+        "vaadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VAADDVV(vd, vs2, vs1, vm))?,
+        "vaadd.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VAADDVX(vd, vs2, rs1, vm))?,
+        "vasubu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VASUBUVV(vd, vs2, vs1, vm))?,
+        "vasubu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VASUBUVX(vd, vs2, rs1, vm))?,
+        "vasub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VASUBVV(vd, vs2, vs1, vm))?,
+        "vasub.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VASUBVX(vd, vs2, rs1, vm))?,
+        "vslide1up.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSLIDE1UPVX(vd, vs2, rs1, vm))?,
+        "vslide1down.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VSLIDE1DOWNVX(vd, vs2, rs1, vm))?,
+        "vmv.x.s" => ops.get("{},{}", |(rd, vs2)| Inst::VMVXS(rd, vs2))?,
+        "vmv.s.x" => ops.get("{},{}", |(vd, rs1)| Inst::VMVSX(vd, rs1))?,
+        "vcpop.m" => ops.get("{},{}[,{}]", |(rd, vs2, vm)| Inst::VCPOPM(rd, vs2, vm))?,
+        "vfirst.m" => ops.get("{},{}[,{}]", |(rd, vs2, vm)| Inst::VFIRSTM(rd, vs2, vm))?,
+        "vzext.vf8" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VZEXTVF8(vd, vs2, vm))?,
+        "vsext.vf8" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VSEXTVF8(vd, vs2, vm))?,
+        "vzext.vf4" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VZEXTVF4(vd, vs2, vm))?,
+        "vsext.vf4" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VSEXTVF4(vd, vs2, vm))?,
+        "vzext.vf2" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VZEXTVF2(vd, vs2, vm))?,
+        "vsext.vf2" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VSEXTVF2(vd, vs2, vm))?,
+        "vmsbf.m" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VMSBFM(vd, vs2, vm))?,
+        "vmsof.m" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VMSOFM(vd, vs2, vm))?,
+        "vmsif.m" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VMSIFM(vd, vs2, vm))?,
+        "viota.m" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VIOTAM(vd, vs2, vm))?,
+        "vid.v" => ops.get("{}[,{}]", |(vd, vm)| Inst::VIDV(vd, vm))?,
+        "vcompress.vm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VCOMPRESSVM(vd, vs2, vs1))?,
+        "vmandn.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMANDNMM(vd, vs2, vs1))?,
+        "vmand.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMANDMM(vd, vs2, vs1))?,
+        "vmor.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMORMM(vd, vs2, vs1))?,
+        "vmxor.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMXORMM(vd, vs2, vs1))?,
+        "vmorn.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMORNMM(vd, vs2, vs1))?,
+        "vmnand.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMNANDMM(vd, vs2, vs1))?,
+        "vmnor.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMNORMM(vd, vs2, vs1))?,
+        "vmxnor.mm" => ops.get("{},{},{}", |(vd, vs2, vs1)| Inst::VMXNORMM(vd, vs2, vs1))?,
+        "vdivu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VDIVUVV(vd, vs2, vs1, vm))?,
+        "vdivu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VDIVUVX(vd, vs2, rs1, vm))?,
+        "vdiv.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VDIVVV(vd, vs2, vs1, vm))?,
+        "vdiv.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VDIVVX(vd, vs2, rs1, vm))?,
+        "vremu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREMUVV(vd, vs2, vs1, vm))?,
+        "vremu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VREMUVX(vd, vs2, rs1, vm))?,
+        "vrem.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VREMVV(vd, vs2, vs1, vm))?,
+        "vrem.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VREMVX(vd, vs2, rs1, vm))?,
+        "vmulhu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMULHUVV(vd, vs2, vs1, vm))?,
+        "vmulhu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMULHUVX(vd, vs2, rs1, vm))?,
+        "vmul.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMULVV(vd, vs2, vs1, vm))?,
+        "vmul.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMULVX(vd, vs2, rs1, vm))?,
+        "vmulhsu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMULHSUVV(vd, vs2, vs1, vm))?,
+        "vmulhsu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMULHSUVX(vd, vs2, rs1, vm))?,
+        "vmulh.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMULHVV(vd, vs2, vs1, vm))?,
+        "vmulh.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMULHVX(vd, vs2, rs1, vm))?,
+        "vmadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VMADDVV(vd, vs1, vs2, vm))?,
+        "vmadd.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VMADDVX(vd, rs1, vs2, vm))?,
+        "vnmsub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VNMSUBVV(vd, vs1, vs2, vm))?,
+        "vnmsub.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VNMSUBVX(vd, rs1, vs2, vm))?,
+        "vmacc.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VMACCVV(vd, vs1, vs2, vm))?,
+        "vmacc.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VMACCVX(vd, rs1, vs2, vm))?,
+        "vnmsac.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VNMSACVV(vd, vs1, vs2, vm))?,
+        "vnmsac.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VNMSACVX(vd, rs1, vs2, vm))?,
+        "vwaddu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWADDUVV(vd, vs2, vs1, vm))?,
+        "vwaddu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWADDUVX(vd, vs2, rs1, vm))?,
+        "vwadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWADDVV(vd, vs2, vs1, vm))?,
+        "vwadd.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWADDVX(vd, vs2, rs1, vm))?,
+        "vwsubu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWSUBUVV(vd, vs2, vs1, vm))?,
+        "vwsubu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWSUBUVX(vd, vs2, rs1, vm))?,
+        "vwsub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWSUBVV(vd, vs2, vs1, vm))?,
+        "vwsub.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWSUBVX(vd, vs2, rs1, vm))?,
+        "vwaddu.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWADDUWV(vd, vs2, vs1, vm))?,
+        "vwaddu.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWADDUWX(vd, vs2, rs1, vm))?,
+        "vwadd.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWADDWV(vd, vs2, vs1, vm))?,
+        "vwadd.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWADDWX(vd, vs2, rs1, vm))?,
+        "vwsubu.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWSUBUWV(vd, vs2, vs1, vm))?,
+        "vwsubu.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWSUBUWX(vd, vs2, rs1, vm))?,
+        "vwsub.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWSUBWV(vd, vs2, vs1, vm))?,
+        "vwsub.wx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWSUBWX(vd, vs2, rs1, vm))?,
+        "vwmulu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWMULUVV(vd, vs2, vs1, vm))?,
+        "vwmulu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWMULUVX(vd, vs2, rs1, vm))?,
+        "vwmulsu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWMULSUVV(vd, vs2, vs1, vm))?,
+        "vwmulsu.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWMULSUVX(vd, vs2, rs1, vm))?,
+        "vwmul.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VWMULVV(vd, vs2, vs1, vm))?,
+        "vwmul.vx" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VWMULVX(vd, vs2, rs1, vm))?,
+        "vwmaccu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VWMACCUVV(vd, vs1, vs2, vm))?,
+        "vwmaccu.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VWMACCUVX(vd, rs1, vs2, vm))?,
+        "vwmacc.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VWMACCVV(vd, vs1, vs2, vm))?,
+        "vwmacc.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VWMACCVX(vd, rs1, vs2, vm))?,
+        "vwmaccus.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VWMACCUSVX(vd, rs1, vs2, vm))?,
+        "vwmaccsu.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VWMACCSUVV(vd, vs1, vs2, vm))?,
+        "vwmaccsu.vx" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VWMACCSUVX(vd, rs1, vs2, vm))?,
+        "vfadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFADDVV(vd, vs2, vs1, vm))?,
+        "vfadd.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFADDVF(vd, vs2, rs1, vm))?,
+        "vfredusum.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFREDUSUMVS(vd, vs2, vs1, vm))?,
+        "vfsub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFSUBVV(vd, vs2, vs1, vm))?,
+        "vfsub.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFSUBVF(vd, vs2, rs1, vm))?,
+        "vfredosum.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFREDOSUMVS(vd, vs2, vs1, vm))?,
+        "vfmin.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFMINVV(vd, vs2, vs1, vm))?,
+        "vfmin.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFMINVF(vd, vs2, rs1, vm))?,
+        "vfredmin.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFREDMINVS(vd, vs2, vs1, vm))?,
+        "vfmax.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFMAXVV(vd, vs2, vs1, vm))?,
+        "vfmax.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFMAXVF(vd, vs2, rs1, vm))?,
+        "vfredmax.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFREDMAXVS(vd, vs2, vs1, vm))?,
+        "vfsgnj.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFSGNJVV(vd, vs2, vs1, vm))?,
+        "vfsgnj.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFSGNJVF(vd, vs2, rs1, vm))?,
+        "vfsgnjn.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFSGNJNVV(vd, vs2, vs1, vm))?,
+        "vfsgnjn.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFSGNJNVF(vd, vs2, rs1, vm))?,
+        "vfsgnjx.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFSGNJXVV(vd, vs2, vs1, vm))?,
+        "vfsgnjx.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFSGNJXVF(vd, vs2, rs1, vm))?,
+        "vfslide1up.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFSLIDE1UPVF(vd, vs2, rs1, vm))?,
+        "vfslide1down.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFSLIDE1DOWNVF(vd, vs2, rs1, vm))?,
+        "vfmv.f.s" => ops.get("{},{}", |(rd, vs2)| Inst::VFMVFS(rd, vs2))?,
+        "vfmv.s.f" => ops.get("{},{}", |(vd, rs1)| Inst::VFMVSF(vd, rs1))?,
+        "vfcvt.xu.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFCVTXUFV(vd, vs2, vm))?,
+        "vfcvt.x.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFCVTXFV(vd, vs2, vm))?,
+        "vfcvt.f.xu.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFCVTFXUV(vd, vs2, vm))?,
+        "vfcvt.f.x.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFCVTFXV(vd, vs2, vm))?,
+        "vfcvt.rtz.xu.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFCVTRTZXUFV(vd, vs2, vm))?,
+        "vfcvt.rtz.x.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFCVTRTZXFV(vd, vs2, vm))?,
+        "vfwcvt.xu.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFWCVTXUFV(vd, vs2, vm))?,
+        "vfwcvt.x.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFWCVTXFV(vd, vs2, vm))?,
+        "vfwcvt.f.xu.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFWCVTFXUV(vd, vs2, vm))?,
+        "vfwcvt.f.x.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFWCVTFXV(vd, vs2, vm))?,
+        "vfwcvt.f.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFWCVTFFV(vd, vs2, vm))?,
+        "vfwcvt.rtz.xu.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFWCVTRTZXUFV(vd, vs2, vm))?,
+        "vfwcvt.rtz.x.f.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFWCVTRTZXFV(vd, vs2, vm))?,
+        "vfncvt.xu.f.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTXUFW(vd, vs2, vm))?,
+        "vfncvt.x.f.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTXFW(vd, vs2, vm))?,
+        "vfncvt.f.xu.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTFXUW(vd, vs2, vm))?,
+        "vfncvt.f.x.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTFXW(vd, vs2, vm))?,
+        "vfncvt.f.f.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTFFW(vd, vs2, vm))?,
+        "vfncvt.rod.f.f.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTRODFFW(vd, vs2, vm))?,
+        "vfncvt.rtz.xu.f.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTRTZXUFW(vd, vs2, vm))?,
+        "vfncvt.rtz.x.f.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFNCVTRTZXFW(vd, vs2, vm))?,
+        "vfsqrt.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFSQRTV(vd, vs2, vm))?,
+        "vfrsqrt7.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFRSQRT7V(vd, vs2, vm))?,
+        "vfrec7.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFREC7V(vd, vs2, vm))?,
+        "vfclass.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFCLASSV(vd, vs2, vm))?,
+        "vfmerge.vfm" => ops.get("{},{},{},v0", |(vd, vs2, rs1)| Inst::VFMERGEVFM(vd, vs2, rs1))?,
+        "vfmv.v.f" => ops.get("{},{}", |(vd, rs1)| Inst::VFMVVF(vd, rs1))?,
+        "vmfeq.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMFEQVV(vd, vs2, vs1, vm))?,
+        "vmfeq.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMFEQVF(vd, vs2, rs1, vm))?,
+        "vmfle.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMFLEVV(vd, vs2, vs1, vm))?,
+        "vmfle.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMFLEVF(vd, vs2, rs1, vm))?,
+        "vmflt.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMFLTVV(vd, vs2, vs1, vm))?,
+        "vmflt.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMFLTVF(vd, vs2, rs1, vm))?,
+        "vmfne.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VMFNEVV(vd, vs2, vs1, vm))?,
+        "vmfne.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMFNEVF(vd, vs2, rs1, vm))?,
+        "vmfgt.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMFGTVF(vd, vs2, rs1, vm))?,
+        "vmfge.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VMFGEVF(vd, vs2, rs1, vm))?,
+        "vfdiv.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFDIVVV(vd, vs2, vs1, vm))?,
+        "vfdiv.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFDIVVF(vd, vs2, rs1, vm))?,
+        "vfrdiv.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFRDIVVF(vd, vs2, rs1, vm))?,
+        "vfmul.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFMULVV(vd, vs2, vs1, vm))?,
+        "vfmul.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFMULVF(vd, vs2, rs1, vm))?,
+        "vfrsub.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFRSUBVF(vd, vs2, rs1, vm))?,
+        "vfmadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFMADDVV(vd, vs1, vs2, vm))?,
+        "vfmadd.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFMADDVF(vd, rs1, vs2, vm))?,
+        "vfnmadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFNMADDVV(vd, vs1, vs2, vm))?,
+        "vfnmadd.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFNMADDVF(vd, rs1, vs2, vm))?,
+        "vfmsub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFMSUBVV(vd, vs1, vs2, vm))?,
+        "vfmsub.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFMSUBVF(vd, rs1, vs2, vm))?,
+        "vfnmsub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFNMSUBVV(vd, vs1, vs2, vm))?,
+        "vfnmsub.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFNMSUBVF(vd, rs1, vs2, vm))?,
+        "vfmacc.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFMACCVV(vd, vs1, vs2, vm))?,
+        "vfmacc.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFMACCVF(vd, rs1, vs2, vm))?,
+        "vfnmacc.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFNMACCVV(vd, vs1, vs2, vm))?,
+        "vfnmacc.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFNMACCVF(vd, rs1, vs2, vm))?,
+        "vfmsac.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFMSACVV(vd, vs1, vs2, vm))?,
+        "vfmsac.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFMSACVF(vd, rs1, vs2, vm))?,
+        "vfnmsac.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFNMSACVV(vd, vs1, vs2, vm))?,
+        "vfnmsac.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFNMSACVF(vd, rs1, vs2, vm))?,
+        "vfwadd.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFWADDVV(vd, vs2, vs1, vm))?,
+        "vfwadd.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFWADDVF(vd, vs2, rs1, vm))?,
+        "vfwredusum.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFWREDUSUMVS(vd, vs2, vs1, vm))?,
+        "vfwsub.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFWSUBVV(vd, vs2, vs1, vm))?,
+        "vfwsub.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFWSUBVF(vd, vs2, rs1, vm))?,
+        "vfwredosum.vs" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFWREDOSUMVS(vd, vs2, vs1, vm))?,
+        "vfwadd.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFWADDWV(vd, vs2, vs1, vm))?,
+        "vfwadd.wf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFWADDWF(vd, vs2, rs1, vm))?,
+        "vfwsub.wv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFWSUBWV(vd, vs2, vs1, vm))?,
+        "vfwsub.wf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFWSUBWF(vd, vs2, rs1, vm))?,
+        "vfwmul.vv" => ops.get("{},{},{}[,{}]", |(vd, vs2, vs1, vm)| Inst::VFWMULVV(vd, vs2, vs1, vm))?,
+        "vfwmul.vf" => ops.get("{},{},{}[,{}]", |(vd, vs2, rs1, vm)| Inst::VFWMULVF(vd, vs2, rs1, vm))?,
+        "vfwmacc.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFWMACCVV(vd, vs1, vs2, vm))?,
+        "vfwmacc.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFWMACCVF(vd, rs1, vs2, vm))?,
+        "vfwnmacc.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFWNMACCVV(vd, vs1, vs2, vm))?,
+        "vfwnmacc.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFWNMACCVF(vd, rs1, vs2, vm))?,
+        "vfwmsac.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFWMSACVV(vd, vs1, vs2, vm))?,
+        "vfwmsac.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFWMSACVF(vd, rs1, vs2, vm))?,
+        "vfwnmsac.vv" => ops.get("{},{},{}[,{}]", |(vd, vs1, vs2, vm)| Inst::VFWNMSACVV(vd, vs1, vs2, vm))?,
+        "vfwnmsac.vf" => ops.get("{},{},{}[,{}]", |(vd, rs1, vs2, vm)| Inst::VFWNMSACVF(vd, rs1, vs2, vm))?,
+        _ => return Ok(None),
+    };
+    Ok(Some(inst))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, EncodeError, Ext, Gpr, Inst, Isa, ParseError, ParseErrorKind};
+
+    #[test]
+    fn blank_lines() {
+        let isa: Isa = "rv64gc".parse().unwrap();
+        let nop = Inst::ADDI(Gpr::zero, Gpr::zero, 0);
+        let src = "nop\n    # save registers\n\t\n    \nnop  # done\n  \t# end\n";
+        assert_eq!(parse(src, &isa), Ok(vec![nop.clone(), nop]));
+        assert!("  ".parse::<Inst>().is_err());
+        assert!("\t# nothing".parse::<Inst>().is_err());
+    }
+
+    #[test]
+    fn checked_against_isa() {
+        let rv32: Isa = "rv32gc".parse().unwrap();
+        let rv64: Isa = "rv64gc".parse().unwrap();
+        let err = |src: &str, isa: &Isa| parse(src, isa).unwrap_err();
+        let at = |line, col, e| ParseError { line, col, kind: ParseErrorKind::Encode(e) };
+        assert_eq!(err("nop\n  sd ra,8(sp)", &rv32), at(2, 3, EncodeError::BadXlen(32)));
+        assert_eq!(err("sext.w a0,a1", &rv32), at(1, 1, EncodeError::BadXlen(32)));
+        assert_eq!(err("addiw a0,a1,1", &rv32), at(1, 1, EncodeError::BadXlen(32)));
+        assert_eq!(err("pause", &rv64), at(1, 1, EncodeError::Disabled(Ext::Zihintpause)));
+        assert_eq!(err("addi a0,a0,2048", &rv64), at(1, 12, EncodeError::ImmOutOfRange(2048)));
+        assert_eq!(err("slli a0,a0,64", &rv64), at(1, 12, EncodeError::ImmOutOfRange(64)));
+        assert_eq!(parse("sd ra,8(sp)\naddi a0,a0,2047\nslli a0,a0,63", &rv64).map(|v| v.len()), Ok(3));
+    }
+}
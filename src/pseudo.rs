@@ -0,0 +1,252 @@
+// Pseudo-instructions of the GNU assembler, expanded into the instructions
+// they stand for; they include every alias `display` prints
+
+use crate::parse::{Operand, Operands, ParseError, ParseErrorKind, Tok};
+use crate::{Csr, Fence, Fm, Gpr, Inst, Sew};
+
+// Expansion of `m`, `None` if it is no pseudo-instruction or the operands are
+// those of the real instruction of the same name.
+pub(crate) fn pseudo(m: &str, ops: &Operands, xlen: u8) -> Result<Option<Vec<Inst>>, ParseError> {
+    let rest = ops.rest();
+    let n = if rest.is_empty() { 0 } else { rest.matches(',').count() + 1 };
+    let inst = match m {
+        "nop" => ops.get("", |()| Inst::ADDI(Gpr::zero, Gpr::zero, 0))?,
+        "li" => {
+            let toks = ops.split("{},{}")?;
+            let rd = Gpr::parse(&toks[0])?;
+            let imm: i64 = toks[1].imm()?;
+            let imm = match xlen {
+                // 0xffffffff is -1 to a 32-bit register
+                32 => i32::try_from(imm)
+                    .or_else(|_| u32::try_from(imm).map(|v| v as i32))
+                    .map_err(|_| toks[1].error(ParseErrorKind::OutOfRange(imm)))? as i64,
+                _ => imm,
+            };
+            return Ok(Some(li(rd, imm, xlen)));
+        }
+        "la" | "lla" => {
+            let toks = ops.split("{},{}")?;
+            let rd = Gpr::parse(&toks[0])?;
+            let (hi, lo) = pcrel(&toks[1])?;
+            return Ok(Some(vec![Inst::AUIPC(rd, hi), Inst::ADDI(rd, rd, lo)]));
+        }
+        "call" | "tail" => {
+            let toks = ops.split(if m == "call" && n == 2 { "{},{}" } else { "{}" })?;
+            let (rd, tmp, target) = match toks[..] {
+                [ref rd, ref target] => {
+                    let rd = Gpr::parse(rd)?;
+                    (rd, rd, target)
+                }
+                _ if m == "call" => (Gpr::ra, Gpr::ra, &toks[0]),
+                _ => (Gpr::zero, Gpr::t1, &toks[0]),
+            };
+            let (hi, lo) = pcrel(target)?;
+            return Ok(Some(vec![Inst::AUIPC(tmp, hi), Inst::JALR(rd, tmp, lo)]));
+        }
+        "mv" => ops.get("{},{}", |(rd, rs1)| Inst::ADDI(rd, rs1, 0))?,
+        "not" => ops.get("{},{}", |(rd, rs1)| Inst::XORI(rd, rs1, -1))?,
+        "neg" => ops.get("{},{}", |(rd, rs2)| Inst::SUB(rd, Gpr::zero, rs2))?,
+        "negw" => ops.get("{},{}", |(rd, rs2)| Inst::SUBW(rd, Gpr::zero, rs2))?,
+        "sext.w" => ops.get("{},{}", |(rd, rs1)| Inst::ADDIW(rd, rs1, 0))?,
+        "zext.b" => ops.get("{},{}", |(rd, rs1)| Inst::ANDI(rd, rs1, 255))?,
+        "zext.w" => ops.get("{},{}", |(rd, rs1)| Inst::ADDUW(rd, rs1, Gpr::zero))?,
+        "seqz" => ops.get("{},{}", |(rd, rs1)| Inst::SLTUI(rd, rs1, 1))?,
+        "snez" => ops.get("{},{}", |(rd, rs2)| Inst::SLTU(rd, Gpr::zero, rs2))?,
+        "sltz" => ops.get("{},{}", |(rd, rs1)| Inst::SLT(rd, rs1, Gpr::zero))?,
+        "sgtz" => ops.get("{},{}", |(rd, rs2)| Inst::SLT(rd, Gpr::zero, rs2))?,
+
+        "beqz" => ops.get("{},{}", |(rs1, imm)| Inst::BEQ(rs1, Gpr::zero, imm))?,
+        "bnez" => ops.get("{},{}", |(rs1, imm)| Inst::BNE(rs1, Gpr::zero, imm))?,
+        "blez" => ops.get("{},{}", |(rs2, imm)| Inst::BGE(Gpr::zero, rs2, imm))?,
+        "bgez" => ops.get("{},{}", |(rs1, imm)| Inst::BGE(rs1, Gpr::zero, imm))?,
+        "bltz" => ops.get("{},{}", |(rs1, imm)| Inst::BLT(rs1, Gpr::zero, imm))?,
+        "bgtz" => ops.get("{},{}", |(rs2, imm)| Inst::BLT(Gpr::zero, rs2, imm))?,
+        "bgt" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BLT(rs2, rs1, imm))?,
+        "ble" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BGE(rs2, rs1, imm))?,
+        "bgtu" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BLTU(rs2, rs1, imm))?,
+        "bleu" => ops.get("{},{},{}", |(rs1, rs2, imm)| Inst::BGEU(rs2, rs1, imm))?,
+        "j" => ops.get("{}", |(imm,)| Inst::JAL(Gpr::zero, imm))?,
+        "jal" if n == 1 => ops.get("{}", |(imm,)| Inst::JAL(Gpr::ra, imm))?,
+        "ret" => ops.get("", |()| Inst::JALR(Gpr::zero, Gpr::ra, 0))?,
+        "jr" if rest.contains('(') => ops.get("{}({})", |(imm, rs1)| Inst::JALR(Gpr::zero, rs1, imm))?,
+        "jr" => ops.get("{}", |(rs1,)| Inst::JALR(Gpr::zero, rs1, 0))?,
+        "jalr" if n == 1 && rest.contains('(') => ops.get("{}({})", |(imm, rs1)| Inst::JALR(Gpr::ra, rs1, imm))?,
+        "jalr" if n == 1 => ops.get("{}", |(rs1,)| Inst::JALR(Gpr::ra, rs1, 0))?,
+        "jalr" if n == 2 && !rest.contains('(') => ops.get("{},{}", |(rd, rs1)| Inst::JALR(rd, rs1, 0))?,
+        "jalr" if n == 3 => ops.get("{},{},{}", |(rd, rs1, imm)| Inst::JALR(rd, rs1, imm))?,
+
+        "fence" if n == 0 => Inst::FENCE(Fm::None, Fence::IORW, Fence::IORW),
+        "sfence.vma" if n == 0 => Inst::SFENCEVMA(Gpr::zero, Gpr::zero),
+        "sfence.vma" if n == 1 => ops.get("{}", |(rs1,)| Inst::SFENCEVMA(rs1, Gpr::zero))?,
+        "hfence.vvma" if n == 0 => Inst::HFENCEVVMA(Gpr::zero, Gpr::zero),
+        "hfence.vvma" if n == 1 => ops.get("{}", |(rs1,)| Inst::HFENCEVVMA(rs1, Gpr::zero))?,
+        "hfence.gvma" if n == 0 => Inst::HFENCEGVMA(Gpr::zero, Gpr::zero),
+        "hfence.gvma" if n == 1 => ops.get("{}", |(rs1,)| Inst::HFENCEGVMA(rs1, Gpr::zero))?,
+
+        "rdinstret" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::instret))?,
+        "rdinstreth" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::instreth))?,
+        "rdcycle" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::cycle))?,
+        "rdcycleh" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::cycleh))?,
+        "rdtime" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::time))?,
+        "rdtimeh" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::timeh))?,
+        "frcsr" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::fcsr))?,
+        "frrm" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::frm))?,
+        "frflags" => ops.get("{}", |(rd,)| Inst::CSRRS(rd, Gpr::zero, Csr::fflags))?,
+        "fscsr" | "fsrm" | "fsflags" | "fsrmi" | "fsflagsi" => {
+            let csr = match m.trim_end_matches('i') {
+                "fscsr" => Csr::fcsr,
+                "fsrm" => Csr::frm,
+                _ => Csr::fflags,
+            };
+            let toks = ops.split(if n == 2 { "{},{}" } else { "{}" })?;
+            let (rd, src) = match toks[..] {
+                [ref rd, ref src] => (Gpr::parse(rd)?, src),
+                _ => (Gpr::zero, &toks[0]),
+            };
+            match m.ends_with('i') {
+                true => Inst::CSRRWI(rd, u8::parse(src)?, csr),
+                false => Inst::CSRRW(rd, Gpr::parse(src)?, csr),
+            }
+        }
+        "csrr" => ops.get("{},{}", |(rd, csr)| Inst::CSRRS(rd, Gpr::zero, csr))?,
+        "csrw" => ops.get("{},{}", |(csr, rs1)| Inst::CSRRW(Gpr::zero, rs1, csr))?,
+        "csrs" => ops.get("{},{}", |(csr, rs1)| Inst::CSRRS(Gpr::zero, rs1, csr))?,
+        "csrc" => ops.get("{},{}", |(csr, rs1)| Inst::CSRRC(Gpr::zero, rs1, csr))?,
+        "csrwi" => ops.get("{},{}", |(csr, imm)| Inst::CSRRWI(Gpr::zero, imm, csr))?,
+        "csrsi" => ops.get("{},{}", |(csr, imm)| Inst::CSRRSI(Gpr::zero, imm, csr))?,
+        "csrci" => ops.get("{},{}", |(csr, imm)| Inst::CSRRCI(Gpr::zero, imm, csr))?,
+
+        "fmv.h" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJH(rd, rs1, rs1))?,
+        "fneg.h" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJNH(rd, rs1, rs1))?,
+        "fabs.h" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJXH(rd, rs1, rs1))?,
+        "fmv.s" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJS(rd, rs1, rs1))?,
+        "fneg.s" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJNS(rd, rs1, rs1))?,
+        "fabs.s" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJXS(rd, rs1, rs1))?,
+        "fmv.d" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJD(rd, rs1, rs1))?,
+        "fneg.d" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJND(rd, rs1, rs1))?,
+        "fabs.d" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJXD(rd, rs1, rs1))?,
+        "fmv.q" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJQ(rd, rs1, rs1))?,
+        "fneg.q" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJNQ(rd, rs1, rs1))?,
+        "fabs.q" => ops.get("{},{}", |(rd, rs1)| Inst::FSGNJXQ(rd, rs1, rs1))?,
+
+        "vnot.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VXORVI(vd, vs2, -1, vm))?,
+        "vneg.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VRSUBVX(vd, vs2, Gpr::zero, vm))?,
+        "vwcvt.x.x.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VWADDVX(vd, vs2, Gpr::zero, vm))?,
+        "vwcvtu.x.x.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VWADDUVX(vd, vs2, Gpr::zero, vm))?,
+        "vncvt.x.x.w" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VNSRLWX(vd, vs2, Gpr::zero, vm))?,
+        "vfneg.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFSGNJNVV(vd, vs2, vs2, vm))?,
+        "vfabs.v" => ops.get("{},{}[,{}]", |(vd, vs2, vm)| Inst::VFSGNJXVV(vd, vs2, vs2, vm))?,
+        "vmclr.m" => ops.get("{}", |(vd,)| Inst::VMXORMM(vd, vd, vd))?,
+        "vmset.m" => ops.get("{}", |(vd,)| Inst::VMXNORMM(vd, vd, vd))?,
+        "vmmv.m" => ops.get("{},{}", |(vd, vs2)| Inst::VMANDMM(vd, vs2, vs2))?,
+        "vmnot.m" => ops.get("{},{}", |(vd, vs2)| Inst::VMNANDMM(vd, vs2, vs2))?,
+        _ => match m.strip_prefix("vl").and_then(|m| m.strip_suffix("r.v")).and_then(|nf| nf.parse::<u8>().ok()) {
+            Some(nf @ (1 | 2 | 4 | 8)) => ops.get("{},({})", |(vd, rs1)| Inst::VLRE(vd, rs1, Sew::E8, nf))?,
+            _ => return Ok(None),
+        },
+    };
+    Ok(Some(vec![inst]))
+}
+
+// Upper and lower parts of a pc-relative offset for an auipc pair.
+fn pcrel(tok: &Tok) -> Result<(u32, i32), ParseError> {
+    let off: i64 = tok.imm()?;
+    if !(-0x8000_0800..0x7fff_f800).contains(&off) {
+        return Err(tok.error(ParseErrorKind::OutOfRange(off)));
+    }
+    Ok(((off + 0x800) as u32 >> 12, (off << 52 >> 52) as i32))
+}
+
+// Shortest LUI/ADDI(W)/SLLI sequence that leaves `imm` in `rd`, built the way
+// LLVM builds it.
+fn li(rd: Gpr, imm: i64, xlen: u8) -> Vec<Inst> {
+    let mut seq = Vec::new();
+    li_seq(&mut seq, rd, imm, xlen);
+    // an even constant may be cheaper with its trailing zeros shifted in last
+    if imm & 0xfff != 0 && imm & 1 == 0 && seq.len() > 2 {
+        let zeros = imm.trailing_zeros();
+        let mut shifted = Vec::new();
+        li_seq(&mut shifted, rd, imm >> zeros, xlen);
+        shifted.push(Inst::SLLI(rd, rd, zeros as u8));
+        if shifted.len() < seq.len() {
+            seq = shifted;
+        }
+    }
+    seq
+}
+
+fn li_seq(seq: &mut Vec<Inst>, rd: Gpr, imm: i64, xlen: u8) {
+    let lo12 = (imm << 52 >> 52) as i32;
+    if i32::try_from(imm).is_ok() {
+        let hi20 = ((imm + 0x800) >> 12 & 0xfffff) as u32;
+        if hi20 != 0 {
+            seq.push(Inst::LUI(rd, hi20));
+        }
+        match hi20 {
+            0 => seq.push(Inst::ADDI(rd, Gpr::zero, lo12)),
+            _ if lo12 == 0 => {}
+            _ if xlen == 32 => seq.push(Inst::ADDI(rd, rd, lo12)),
+            _ => seq.push(Inst::ADDIW(rd, rd, lo12)),
+        }
+        return;
+    }
+    // the upper 52 bits, rounded for the final addi, without their trailing
+    // zeros; they are shifted back in
+    let hi52 = (imm as u64).wrapping_add(0x800) >> 12;
+    let mut shift = 12 + hi52.trailing_zeros();
+    let mut hi = ((hi52 >> (shift - 12)) << shift) as i64 >> shift;
+    // a lui can supply 12 of the zeros
+    if shift > 12 && !(-0x800..0x800).contains(&hi) && i32::try_from(hi.wrapping_shl(12)).is_ok() {
+        shift -= 12;
+        hi <<= 12;
+    }
+    li_seq(seq, rd, hi, xlen);
+    seq.push(Inst::SLLI(rd, rd, shift as u8));
+    if lo12 != 0 {
+        seq.push(Inst::ADDI(rd, rd, lo12));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, Gpr, Inst, Isa, ParseErrorKind};
+
+    // Runs an li sequence on an `xlen`-bit machine, returning a0.
+    fn run(insts: &[Inst], xlen: u8) -> i64 {
+        let mut x = [0i64; 32];
+        for inst in insts {
+            let (rd, v) = match *inst {
+                Inst::LUI(rd, imm) => (rd, (imm << 12) as i32 as i64),
+                Inst::ADDI(rd, rs1, imm) => (rd, x[rs1 as usize].wrapping_add(imm as i64)),
+                Inst::ADDIW(rd, rs1, imm) => (rd, x[rs1 as usize].wrapping_add(imm as i64) as i32 as i64),
+                Inst::SLLI(rd, rs1, sh) => (rd, x[rs1 as usize] << sh),
+                Inst::SRLI(rd, rs1, sh) => (rd, ((x[rs1 as usize] as u64) >> sh) as i64),
+                _ => panic!("{:?} in li", inst),
+            };
+            x[rd as usize] = if xlen == 32 { v as i32 as i64 } else { v };
+            x[0] = 0;
+        }
+        x[Gpr::a0 as usize]
+    }
+
+    #[test]
+    fn li() {
+        let rv32: Isa = "rv32gc".parse().unwrap();
+        let rv64: Isa = "rv64gc".parse().unwrap();
+        let li = |v: i64, isa: &Isa| parse(&format!("li a0, {}", v), isa);
+        for v in [0x7fffffff, 0x80000000, 0xffffffff, -0x80000000, 0x800, -0x801, 0] {
+            let insts = li(v, &rv32).unwrap();
+            assert!(insts.len() <= 2, "{:#x}: {:?}", v, insts);
+            assert_eq!(run(&insts, 32), v as i32 as i64, "{:#x}: {:?}", v, insts);
+        }
+        for v in [i64::MIN, i64::MAX, 0x1_0000_0000] {
+            let err = li(v, &rv32).unwrap_err();
+            assert_eq!(err.kind, ParseErrorKind::OutOfRange(v));
+        }
+        for v in [0x7fffffff, 0x80000000, 0xffffffff, -0x80000000, i64::MIN, i64::MAX, 0x1234_5678_9abc_def0, -2] {
+            let insts = li(v, &rv64).unwrap();
+            assert!(insts.len() <= 8, "{:#x}: {:?}", v, insts);
+            assert_eq!(run(&insts, 64), v, "{:#x}: {:?}", v, insts);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// Register list of the Zcmp push/pop instructions: ra plus s0 up to s11
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rlist(u8);
+
+impl Rlist {
+    /// 4 is `{ra}`, 5 `{ra,s0}`, ..., 14 `{ra,s0-s9}` and 15 `{ra,s0-s11}`;
+    /// values below 4 are reserved.
+    pub fn from_u8(r: u8) -> Option<Rlist> {
+        if (4..16).contains(&r) {
+            Some(Rlist(r))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Number of registers saved or restored, ra included.
+    pub fn count(self) -> u32 {
+        match self.0 {
+            15 => 13, // s10 and s11 always go together
+            r => r as u32 - 3,
+        }
+    }
+
+    /// Smallest stack adjustment that holds the registers, a multiple of 16.
+    pub fn stack_adj_base(self, xlen: u8) -> u32 {
+        (self.count() * xlen as u32 / 8).next_multiple_of(16)
+    }
+}
+
+// `{ra}`, `{ra,s0}`, `{ra,s0-s11}`.
+impl std::fmt::Display for Rlist {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.count() {
+            1 => write!(f, "{{ra}}"),
+            2 => write!(f, "{{ra,s0}}"),
+            n => write!(f, "{{ra,s0-s{}}}", n - 2),
+        }
+    }
+}
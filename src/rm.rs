@@ -0,0 +1,40 @@
+// Floating-point rounding mode (funct3 of OP-FP and the fused multiply-add opcodes)
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Rm {
+    Rne = 0, // round to nearest, ties to even
+    Rtz,     // round towards zero
+    Rdn,     // round down (towards -inf)
+    Rup,     // round up (towards +inf)
+    Rmm,     // round to nearest, ties to max magnitude
+    Dyn = 7, // dynamic, from the frm CSR
+}
+
+impl Rm {
+    pub fn from_u8(r: u8) -> Option<Rm> {
+        match r {
+            0 => Some(Rm::Rne),
+            1 => Some(Rm::Rtz),
+            2 => Some(Rm::Rdn),
+            3 => Some(Rm::Rup),
+            4 => Some(Rm::Rmm),
+            7 => Some(Rm::Dyn),
+            _ => None, // 101 and 110 are reserved
+        }
+    }
+}
+
+impl std::fmt::Display for Rm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rm::Rne => write!(f, "rne"),
+            Rm::Rtz => write!(f, "rtz"),
+            Rm::Rdn => write!(f, "rdn"),
+            Rm::Rup => write!(f, "rup"),
+            Rm::Rmm => write!(f, "rmm"),
+            Rm::Dyn => write!(f, "dyn"),
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// T-Head vendor extensions of the C9xx cores (XThead*), all in custom-0
+
+use crate::extr::{rd_bits, rs1_bits, rs2_bits};
+use crate::{Arg, Custom, EncodeError, Ext, Fpr, Gpr, Isa, Vendor};
+
+use std::fmt::Formatter;
+
+/// The scalar T-Head extensions; `Custom::op` indexes their opcode table.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum XThead {
+    Ba,
+    Bb,
+    Bs,
+    Cmo,
+    CondMov,
+    FMemIdx,
+    Fmv,
+    Int,
+    Mac,
+    MemIdx,
+    MemPair,
+    Sync,
+}
+
+// Operand fields, in assembly order.
+#[derive(Copy, Clone, PartialEq)]
+enum Slot {
+    Rd,
+    Rs1,
+    Rs2,
+    Frd,
+    Frs1,
+    Imm2,   // 26:25
+    Shamt,  // 25:20, 24:20 on RV32
+    ShamtW, // 24:20
+    Msb,    // 31:26
+    Lsb,    // 25:20
+    Imm5,   // 24:20, signed
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Form {
+    None,     // th.sync
+    Rs1,      // th.dcache.cva   rs1
+    Rs1Rs2,   // th.sfence.vmas  rs1,rs2
+    RdRs1,    // th.rev          rd,rs1
+    RdRs1Rs2, // th.mula         rd,rs1,rs2
+    Indexed,  // th.lrw          rd,rs1,rs2,imm2
+    FIndexed, // th.flrw         fd,rs1,rs2,imm2
+    Shamt,    // th.srri         rd,rs1,shamt
+    ShamtW,   // th.srriw        rd,rs1,shamt
+    Ext,      // th.ext          rd,rs1,msb,lsb
+    Inc,      // th.lwia         rd,(rs1),imm5,imm2
+    Pair(u8), // th.ldd          rd1,rd2,(rs1),imm2,shift
+    FdRs1,    // th.fmv.hw.x     fd,rs1
+    RdFs1,    // th.fmv.x.hw     rd,fs1
+}
+
+impl Form {
+    fn slots(self) -> &'static [Slot] {
+        match self {
+            Form::None => &[],
+            Form::Rs1 => &[Slot::Rs1],
+            Form::Rs1Rs2 => &[Slot::Rs1, Slot::Rs2],
+            Form::RdRs1 => &[Slot::Rd, Slot::Rs1],
+            Form::RdRs1Rs2 => &[Slot::Rd, Slot::Rs1, Slot::Rs2],
+            Form::Indexed => &[Slot::Rd, Slot::Rs1, Slot::Rs2, Slot::Imm2],
+            Form::FIndexed => &[Slot::Frd, Slot::Rs1, Slot::Rs2, Slot::Imm2],
+            Form::Shamt => &[Slot::Rd, Slot::Rs1, Slot::Shamt],
+            Form::ShamtW => &[Slot::Rd, Slot::Rs1, Slot::ShamtW],
+            Form::Ext => &[Slot::Rd, Slot::Rs1, Slot::Msb, Slot::Lsb],
+            Form::Inc => &[Slot::Rd, Slot::Rs1, Slot::Imm5, Slot::Imm2],
+            Form::Pair(_) => &[Slot::Rd, Slot::Rs2, Slot::Rs1, Slot::Imm2],
+            Form::FdRs1 => &[Slot::Frd, Slot::Rs1],
+            Form::RdFs1 => &[Slot::Rd, Slot::Frs1],
+        }
+    }
+
+    // Bits not taken by operands.
+    fn mask(self) -> u32 {
+        match self {
+            Form::None => 0xffffffff,
+            Form::Rs1 => 0xfff07fff,
+            Form::Rs1Rs2 => 0xfe007fff,
+            Form::RdRs1 | Form::FdRs1 | Form::RdFs1 => 0xfff0707f,
+            Form::RdRs1Rs2 | Form::ShamtW => 0xfe00707f,
+            Form::Indexed | Form::FIndexed | Form::Inc | Form::Pair(_) => 0xf800707f,
+            Form::Shamt => 0xfc00707f,
+            Form::Ext => 0x0000707f,
+        }
+    }
+}
+
+struct Op {
+    name: &'static str,
+    ext: XThead,
+    bits: u32,
+    form: Form,
+    /// 32 or 64 for instructions of one XLEN only.
+    xlen: u8,
+    needs: Option<Ext>,
+}
+
+const fn op(name: &'static str, ext: XThead, bits: u32, form: Form) -> Op {
+    Op { name, ext, bits, form, xlen: 0, needs: None }
+}
+
+const fn rv(xlen: u8, o: Op) -> Op {
+    Op { xlen, ..o }
+}
+
+const fn with(needs: Ext, o: Op) -> Op {
+    Op { needs: Some(needs), ..o }
+}
+
+// Indexed memory accesses share funct5 (31:27) as size << 2 | mode, the mode
+// being lr (00), ib (01), lur (10) or ia (11).
+const OPS: &[Op] = &[
+    op("th.addsl", XThead::Ba, 0x0000100b, Form::Indexed),
+
+    op("th.srri", XThead::Bb, 0x1000100b, Form::Shamt),
+    rv(64, op("th.srriw", XThead::Bb, 0x1400100b, Form::ShamtW)),
+    op("th.ext", XThead::Bb, 0x0000200b, Form::Ext),
+    op("th.extu", XThead::Bb, 0x0000300b, Form::Ext),
+    op("th.ff0", XThead::Bb, 0x8400100b, Form::RdRs1),
+    op("th.ff1", XThead::Bb, 0x8600100b, Form::RdRs1),
+    op("th.rev", XThead::Bb, 0x8200100b, Form::RdRs1),
+    rv(64, op("th.revw", XThead::Bb, 0x9000100b, Form::RdRs1)),
+    op("th.tstnbz", XThead::Bb, 0x8000100b, Form::RdRs1),
+
+    op("th.tst", XThead::Bs, 0x8800100b, Form::Shamt),
+
+    op("th.dcache.call", XThead::Cmo, 0x0010000b, Form::None),
+    op("th.dcache.ciall", XThead::Cmo, 0x0030000b, Form::None),
+    op("th.dcache.iall", XThead::Cmo, 0x0020000b, Form::None),
+    op("th.dcache.cpa", XThead::Cmo, 0x0290000b, Form::Rs1),
+    op("th.dcache.cipa", XThead::Cmo, 0x02b0000b, Form::Rs1),
+    op("th.dcache.ipa", XThead::Cmo, 0x02a0000b, Form::Rs1),
+    op("th.dcache.cva", XThead::Cmo, 0x0250000b, Form::Rs1),
+    op("th.dcache.civa", XThead::Cmo, 0x0270000b, Form::Rs1),
+    op("th.dcache.iva", XThead::Cmo, 0x0260000b, Form::Rs1),
+    op("th.dcache.csw", XThead::Cmo, 0x0210000b, Form::Rs1),
+    op("th.dcache.cisw", XThead::Cmo, 0x0230000b, Form::Rs1),
+    op("th.dcache.isw", XThead::Cmo, 0x0220000b, Form::Rs1),
+    op("th.dcache.cpal1", XThead::Cmo, 0x0280000b, Form::Rs1),
+    op("th.dcache.cval1", XThead::Cmo, 0x0240000b, Form::Rs1),
+    op("th.icache.iall", XThead::Cmo, 0x0100000b, Form::None),
+    op("th.icache.ialls", XThead::Cmo, 0x0110000b, Form::None),
+    op("th.icache.ipa", XThead::Cmo, 0x0380000b, Form::Rs1),
+    op("th.icache.iva", XThead::Cmo, 0x0300000b, Form::Rs1),
+    op("th.l2cache.call", XThead::Cmo, 0x0150000b, Form::None),
+    op("th.l2cache.ciall", XThead::Cmo, 0x0170000b, Form::None),
+    op("th.l2cache.iall", XThead::Cmo, 0x0160000b, Form::None),
+
+    op("th.mveqz", XThead::CondMov, 0x4000100b, Form::RdRs1Rs2),
+    op("th.mvnez", XThead::CondMov, 0x4200100b, Form::RdRs1Rs2),
+
+    with(Ext::F, op("th.flrw", XThead::FMemIdx, 0x4000600b, Form::FIndexed)),
+    with(Ext::D, op("th.flrd", XThead::FMemIdx, 0x6000600b, Form::FIndexed)),
+    with(Ext::F, op("th.fsrw", XThead::FMemIdx, 0x4000700b, Form::FIndexed)),
+    with(Ext::D, op("th.fsrd", XThead::FMemIdx, 0x6000700b, Form::FIndexed)),
+    with(Ext::F, rv(64, op("th.flurw", XThead::FMemIdx, 0x5000600b, Form::FIndexed))),
+    with(Ext::D, rv(64, op("th.flurd", XThead::FMemIdx, 0x7000600b, Form::FIndexed))),
+    with(Ext::F, rv(64, op("th.fsurw", XThead::FMemIdx, 0x5000700b, Form::FIndexed))),
+    with(Ext::D, rv(64, op("th.fsurd", XThead::FMemIdx, 0x7000700b, Form::FIndexed))),
+
+    with(Ext::D, rv(32, op("th.fmv.hw.x", XThead::Fmv, 0xa000100b, Form::FdRs1))),
+    with(Ext::D, rv(32, op("th.fmv.x.hw", XThead::Fmv, 0xc000100b, Form::RdFs1))),
+
+    op("th.ipush", XThead::Int, 0x0040000b, Form::None),
+    op("th.ipop", XThead::Int, 0x0050000b, Form::None),
+
+    op("th.mula", XThead::Mac, 0x2000100b, Form::RdRs1Rs2),
+    op("th.muls", XThead::Mac, 0x2200100b, Form::RdRs1Rs2),
+    rv(64, op("th.mulaw", XThead::Mac, 0x2400100b, Form::RdRs1Rs2)),
+    rv(64, op("th.mulsw", XThead::Mac, 0x2600100b, Form::RdRs1Rs2)),
+    op("th.mulah", XThead::Mac, 0x2800100b, Form::RdRs1Rs2),
+    op("th.mulsh", XThead::Mac, 0x2a00100b, Form::RdRs1Rs2),
+
+    op("th.lrb", XThead::MemIdx, 0x0000400b, Form::Indexed),
+    op("th.lbib", XThead::MemIdx, 0x0800400b, Form::Inc),
+    rv(64, op("th.lurb", XThead::MemIdx, 0x1000400b, Form::Indexed)),
+    op("th.lbia", XThead::MemIdx, 0x1800400b, Form::Inc),
+    op("th.lrh", XThead::MemIdx, 0x2000400b, Form::Indexed),
+    op("th.lhib", XThead::MemIdx, 0x2800400b, Form::Inc),
+    rv(64, op("th.lurh", XThead::MemIdx, 0x3000400b, Form::Indexed)),
+    op("th.lhia", XThead::MemIdx, 0x3800400b, Form::Inc),
+    op("th.lrw", XThead::MemIdx, 0x4000400b, Form::Indexed),
+    op("th.lwib", XThead::MemIdx, 0x4800400b, Form::Inc),
+    rv(64, op("th.lurw", XThead::MemIdx, 0x5000400b, Form::Indexed)),
+    op("th.lwia", XThead::MemIdx, 0x5800400b, Form::Inc),
+    rv(64, op("th.lrd", XThead::MemIdx, 0x6000400b, Form::Indexed)),
+    rv(64, op("th.ldib", XThead::MemIdx, 0x6800400b, Form::Inc)),
+    rv(64, op("th.lurd", XThead::MemIdx, 0x7000400b, Form::Indexed)),
+    rv(64, op("th.ldia", XThead::MemIdx, 0x7800400b, Form::Inc)),
+    op("th.lrbu", XThead::MemIdx, 0x8000400b, Form::Indexed),
+    op("th.lbuib", XThead::MemIdx, 0x8800400b, Form::Inc),
+    rv(64, op("th.lurbu", XThead::MemIdx, 0x9000400b, Form::Indexed)),
+    op("th.lbuia", XThead::MemIdx, 0x9800400b, Form::Inc),
+    op("th.lrhu", XThead::MemIdx, 0xa000400b, Form::Indexed),
+    op("th.lhuib", XThead::MemIdx, 0xa800400b, Form::Inc),
+    rv(64, op("th.lurhu", XThead::MemIdx, 0xb000400b, Form::Indexed)),
+    op("th.lhuia", XThead::MemIdx, 0xb800400b, Form::Inc),
+    rv(64, op("th.lrwu", XThead::MemIdx, 0xc000400b, Form::Indexed)),
+    rv(64, op("th.lwuib", XThead::MemIdx, 0xc800400b, Form::Inc)),
+    rv(64, op("th.lurwu", XThead::MemIdx, 0xd000400b, Form::Indexed)),
+    rv(64, op("th.lwuia", XThead::MemIdx, 0xd800400b, Form::Inc)),
+    op("th.srb", XThead::MemIdx, 0x0000500b, Form::Indexed),
+    op("th.sbib", XThead::MemIdx, 0x0800500b, Form::Inc),
+    rv(64, op("th.surb", XThead::MemIdx, 0x1000500b, Form::Indexed)),
+    op("th.sbia", XThead::MemIdx, 0x1800500b, Form::Inc),
+    op("th.srh", XThead::MemIdx, 0x2000500b, Form::Indexed),
+    op("th.shib", XThead::MemIdx, 0x2800500b, Form::Inc),
+    rv(64, op("th.surh", XThead::MemIdx, 0x3000500b, Form::Indexed)),
+    op("th.shia", XThead::MemIdx, 0x3800500b, Form::Inc),
+    op("th.srw", XThead::MemIdx, 0x4000500b, Form::Indexed),
+    op("th.swib", XThead::MemIdx, 0x4800500b, Form::Inc),
+    rv(64, op("th.surw", XThead::MemIdx, 0x5000500b, Form::Indexed)),
+    op("th.swia", XThead::MemIdx, 0x5800500b, Form::Inc),
+    rv(64, op("th.srd", XThead::MemIdx, 0x6000500b, Form::Indexed)),
+    rv(64, op("th.sdib", XThead::MemIdx, 0x6800500b, Form::Inc)),
+    rv(64, op("th.surd", XThead::MemIdx, 0x7000500b, Form::Indexed)),
+    rv(64, op("th.sdia", XThead::MemIdx, 0x7800500b, Form::Inc)),
+
+    op("th.lwd", XThead::MemPair, 0xe000400b, Form::Pair(3)),
+    rv(64, op("th.lwud", XThead::MemPair, 0xf000400b, Form::Pair(3))),
+    rv(64, op("th.ldd", XThead::MemPair, 0xf800400b, Form::Pair(4))),
+    op("th.swd", XThead::MemPair, 0xe000500b, Form::Pair(3)),
+    rv(64, op("th.sdd", XThead::MemPair, 0xf800500b, Form::Pair(4))),
+
+    op("th.sfence.vmas", XThead::Sync, 0x0400000b, Form::Rs1Rs2),
+    op("th.sync", XThead::Sync, 0x0180000b, Form::None),
+    op("th.sync.s", XThead::Sync, 0x0190000b, Form::None),
+    op("th.sync.i", XThead::Sync, 0x01a0000b, Form::None),
+    op("th.sync.is", XThead::Sync, 0x01b0000b, Form::None),
+];
+
+impl XThead {
+    // Table entry of `inst`, if it is one of ours.
+    fn op(self, inst: &Custom) -> Option<&'static Op> {
+        OPS.get(inst.op as usize).filter(|o| o.ext == self)
+    }
+}
+
+impl Vendor for XThead {
+    fn name(&self) -> &'static str {
+        match self {
+            XThead::Ba => "xtheadba",
+            XThead::Bb => "xtheadbb",
+            XThead::Bs => "xtheadbs",
+            XThead::Cmo => "xtheadcmo",
+            XThead::CondMov => "xtheadcondmov",
+            XThead::FMemIdx => "xtheadfmemidx",
+            XThead::Fmv => "xtheadfmv",
+            XThead::Int => "xtheadint",
+            XThead::Mac => "xtheadmac",
+            XThead::MemIdx => "xtheadmemidx",
+            XThead::MemPair => "xtheadmempair",
+            XThead::Sync => "xtheadsync",
+        }
+    }
+
+    fn decode(&'static self, w: u32, isa: &Isa) -> Option<Custom> {
+        let i = OPS.iter().position(|o| o.ext == *self && w & o.form.mask() == o.bits)?;
+        let args = OPS[i].form.slots().iter().map(|&s| field(s, w)).collect();
+        let inst = Custom { vendor: self, op: i as u16, args };
+        // the encoder checks XLEN, operand ranges and register constraints
+        match self.encode(&inst, isa) {
+            Ok(e) if e == w => Some(inst),
+            _ => None,
+        }
+    }
+
+    fn encode(&self, inst: &Custom, isa: &Isa) -> Result<u32, EncodeError> {
+        let o = self.op(inst).ok_or(EncodeError::Reserved)?;
+        let bits = isa.xlen();
+        if o.xlen != 0 && o.xlen != bits {
+            return Err(EncodeError::BadXlen(bits));
+        }
+        if let Some(e) = o.needs.filter(|&e| !isa.has(e)) {
+            return Err(EncodeError::Disabled(e));
+        }
+        let slots = o.form.slots();
+        if slots.len() != inst.args.len() {
+            return Err(EncodeError::Reserved);
+        }
+        let mut w = o.bits;
+        for (&s, &a) in slots.iter().zip(&inst.args) {
+            w |= place(s, a, bits)?;
+        }
+        // loads that write back their base, or a pair of registers, need
+        // distinct registers
+        let load = (w >> 12) & 0b111 == 0b100;
+        let (rd, rs1, rs2) = (rd_bits(w), rs1_bits(w), rs2_bits(w));
+        match o.form {
+            Form::Inc if load && rd == rs1 => return Err(EncodeError::Reserved),
+            Form::Pair(_) if load && (rd == rs2 || rd == rs1 || rs2 == rs1) => return Err(EncodeError::Reserved),
+            _ => {}
+        }
+        Ok(w)
+    }
+
+    fn fmt(&self, inst: &Custom, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let o = match self.op(inst) {
+            Some(o) => o,
+            None => return write!(f, "{}.{}", self.name(), inst.op),
+        };
+        write!(f, "{}", o.name)?;
+        let memory = matches!(o.form, Form::Inc | Form::Pair(_));
+        for (i, (&s, a)) in o.form.slots().iter().zip(&inst.args).enumerate() {
+            write!(f, "{}", if i == 0 { '\t' } else { ',' })?;
+            match (s, a) {
+                (Slot::Rs1, Arg::Gpr(r)) if memory => write!(f, "({})", r)?,
+                (Slot::Shamt | Slot::ShamtW, Arg::Imm(v)) => write!(f, "0x{:x}", v)?,
+                (_, Arg::Gpr(r)) => write!(f, "{}", r)?,
+                (_, Arg::Fpr(r)) => write!(f, "{}", r)?,
+                (_, Arg::Vr(r)) => write!(f, "{}", r)?,
+                (_, Arg::Imm(v)) => write!(f, "{}", v)?,
+            }
+        }
+        if let Form::Pair(shift) = o.form {
+            write!(f, ",{}", shift)?;
+        }
+        Ok(())
+    }
+
+    // th.l*ib/th.s*ib access rs1 + (imm5 << imm2), th.l*ia/th.s*ia rs1 itself
+    // before the update; register-indexed forms have no fixed offset.
+    fn mem(&self, inst: &Custom) -> Option<(Gpr, i32)> {
+        let o = self.op(inst)?;
+        match (o.form, inst.args.as_slice()) {
+            (Form::Inc, &[_, Arg::Gpr(rs1), Arg::Imm(imm5), Arg::Imm(imm2)]) => {
+                let after = o.bits & (1 << 28) != 0;
+                Some((rs1, if after { 0 } else { (imm5 << imm2) as i32 }))
+            }
+            (Form::Pair(shift), &[_, _, Arg::Gpr(rs1), Arg::Imm(imm2)]) => Some((rs1, (imm2 << shift) as i32)),
+            _ => None,
+        }
+    }
+}
+
+fn field(s: Slot, w: u32) -> Arg {
+    match s {
+        Slot::Rd => Arg::Gpr(Gpr::from_u8(rd_bits(w)).unwrap()),
+        Slot::Rs1 => Arg::Gpr(Gpr::from_u8(rs1_bits(w)).unwrap()),
+        Slot::Rs2 => Arg::Gpr(Gpr::from_u8(rs2_bits(w)).unwrap()),
+        Slot::Frd => Arg::Fpr(Fpr::from_u8(rd_bits(w)).unwrap()),
+        Slot::Frs1 => Arg::Fpr(Fpr::from_u8(rs1_bits(w)).unwrap()),
+        Slot::Imm2 => Arg::Imm((w >> 25 & 0b11) as i64),
+        Slot::Shamt | Slot::Lsb => Arg::Imm((w >> 20 & 0x3f) as i64),
+        Slot::ShamtW => Arg::Imm((w >> 20 & 0x1f) as i64),
+        Slot::Msb => Arg::Imm((w >> 26) as i64),
+        Slot::Imm5 => Arg::Imm(((w as i32) << 7 >> 27) as i64),
+    }
+}
+
+fn place(s: Slot, a: Arg, bits: u8) -> Result<u32, EncodeError> {
+    let max = bits.min(64) as i64;
+    let v = match (s, a) {
+        (Slot::Rd | Slot::Rs1 | Slot::Rs2, Arg::Gpr(r)) => r as u32,
+        (Slot::Frd | Slot::Frs1, Arg::Fpr(r)) => r as u32,
+        (Slot::Imm2, Arg::Imm(v @ 0..=3)) => v as u32,
+        (Slot::Shamt | Slot::Msb | Slot::Lsb, Arg::Imm(v)) if (0..max).contains(&v) => v as u32,
+        (Slot::ShamtW, Arg::Imm(v @ 0..=31)) => v as u32,
+        (Slot::Imm5, Arg::Imm(v @ -16..=15)) => v as u32 & 0x1f,
+        (_, Arg::Imm(v)) => return Err(EncodeError::ImmOutOfRange(v)),
+        _ => return Err(EncodeError::Reserved),
+    };
+    let at = match s {
+        Slot::Rd | Slot::Frd => 7,
+        Slot::Rs1 | Slot::Frs1 => 15,
+        Slot::Imm2 => 25,
+        Slot::Msb => 26,
+        _ => 20,
+    };
+    Ok(v << at)
+}
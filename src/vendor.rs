@@ -0,0 +1,119 @@
+// Vendor extensions: plug-in decoders, encoders and formatters for the custom
+// opcode spaces, whose instructions appear as `Inst::Custom`
+
+use crate::{EncodeError, Fpr, Gpr, Isa, Vr, XThead};
+
+use std::fmt::Formatter;
+use std::sync::RwLock;
+
+/// A vendor extension such as T-Head's XTheadBb.
+///
+/// The decoder offers a vendor every 32-bit word that no enabled standard
+/// extension defines, the custom-0 to custom-3 major opcodes in particular.
+/// Enable one with `Isa::with_vendor`, or by name in an ISA string once it is
+/// built in or passed to `register_vendor`.
+pub trait Vendor: Sync {
+    /// Name in ISA strings, lowercase: `xtheadbb`.
+    fn name(&self) -> &'static str;
+
+    /// The instruction in `w`, or `None` if the extension does not define it.
+    fn decode(&'static self, w: u32, isa: &Isa) -> Option<Custom>;
+
+    /// Machine code of an instruction this vendor decoded or built.
+    fn encode(&self, inst: &Custom, isa: &Isa) -> Result<u32, EncodeError>;
+
+    /// Mnemonic, a tab, then the operands, as objdump prints them.
+    fn fmt(&self, inst: &Custom, f: &mut Formatter<'_>) -> std::fmt::Result;
+
+    /// Base register and offset of a memory access, for the addresses the
+    /// `Disassembler` annotates.
+    fn mem(&self, _inst: &Custom) -> Option<(Gpr, i32)> {
+        None
+    }
+}
+
+/// Operand of a vendor instruction.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Arg {
+    Gpr(Gpr),
+    Fpr(Fpr),
+    Vr(Vr),
+    Imm(i64),
+}
+
+/// Instruction of a vendor extension; `op` and the order of `args` are the
+/// vendor's choice.
+#[derive(Clone)]
+pub struct Custom {
+    pub vendor: &'static dyn Vendor,
+    pub op: u16,
+    pub args: Vec<Arg>,
+}
+
+impl PartialEq for Custom {
+    fn eq(&self, other: &Custom) -> bool {
+        self.vendor.name() == other.vendor.name() && self.op == other.op && self.args == other.args
+    }
+}
+
+impl std::fmt::Debug for Custom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Custom")
+            .field("vendor", &self.vendor.name())
+            .field("op", &self.op)
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+// Built-in vendors come first, then registered ones; an `Isa` keeps one bit
+// per entry.
+const BUILTIN: [&dyn Vendor; 12] = [
+    &XThead::Ba,
+    &XThead::Bb,
+    &XThead::Bs,
+    &XThead::Cmo,
+    &XThead::CondMov,
+    &XThead::FMemIdx,
+    &XThead::Fmv,
+    &XThead::Int,
+    &XThead::Mac,
+    &XThead::MemIdx,
+    &XThead::MemPair,
+    &XThead::Sync,
+];
+
+const MAX: usize = 64;
+
+static REGISTERED: RwLock<Vec<&'static dyn Vendor>> = RwLock::new(Vec::new());
+
+/// Makes `vendor` known to ISA string parsing. Returns `false` if its name is
+/// already taken or 64 vendor extensions are known.
+pub fn register_vendor(vendor: &'static dyn Vendor) -> bool {
+    let mut registered = REGISTERED.write().unwrap();
+    let taken = BUILTIN.iter().chain(registered.iter()).any(|v| v.name() == vendor.name());
+    if taken || BUILTIN.len() + registered.len() == MAX {
+        return false;
+    }
+    registered.push(vendor);
+    true
+}
+
+// Registry slot of the vendor called `name`.
+pub(crate) fn index(name: &str) -> Option<u8> {
+    let registered = REGISTERED.read().unwrap();
+    BUILTIN.iter().chain(registered.iter()).position(|v| v.name() == name).map(|i| i as u8)
+}
+
+// Slot of `vendor`, registering it on first use.
+pub(crate) fn index_or_register(vendor: &'static dyn Vendor) -> Option<u8> {
+    register_vendor(vendor);
+    index(vendor.name())
+}
+
+pub(crate) fn get(i: u8) -> &'static dyn Vendor {
+    match BUILTIN.get(i as usize) {
+        Some(&v) => v,
+        None => REGISTERED.read().unwrap()[i as usize - BUILTIN.len()],
+    }
+}
@@ -0,0 +1,114 @@
+#[allow(dead_code)]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Vr {
+    v0 = 0,
+    v1,
+    v2,
+    v3,
+    v4,
+    v5,
+    v6,
+    v7,
+    v8,
+    v9,
+    v10,
+    v11,
+    v12,
+    v13,
+    v14,
+    v15,
+    v16,
+    v17,
+    v18,
+    v19,
+    v20,
+    v21,
+    v22,
+    v23,
+    v24,
+    v25,
+    v26,
+    v27,
+    v28,
+    v29,
+    v30,
+    v31,
+}
+
+impl Vr {
+    pub fn from_u8(r: u8) -> Option<Vr> {
+        match r {
+            0 => Some(Vr::v0),
+            1 => Some(Vr::v1),
+            2 => Some(Vr::v2),
+            3 => Some(Vr::v3),
+            4 => Some(Vr::v4),
+            5 => Some(Vr::v5),
+            6 => Some(Vr::v6),
+            7 => Some(Vr::v7),
+            8 => Some(Vr::v8),
+            9 => Some(Vr::v9),
+            10 => Some(Vr::v10),
+            11 => Some(Vr::v11),
+            12 => Some(Vr::v12),
+            13 => Some(Vr::v13),
+            14 => Some(Vr::v14),
+            15 => Some(Vr::v15),
+            16 => Some(Vr::v16),
+            17 => Some(Vr::v17),
+            18 => Some(Vr::v18),
+            19 => Some(Vr::v19),
+            20 => Some(Vr::v20),
+            21 => Some(Vr::v21),
+            22 => Some(Vr::v22),
+            23 => Some(Vr::v23),
+            24 => Some(Vr::v24),
+            25 => Some(Vr::v25),
+            26 => Some(Vr::v26),
+            27 => Some(Vr::v27),
+            28 => Some(Vr::v28),
+            29 => Some(Vr::v29),
+            30 => Some(Vr::v30),
+            31 => Some(Vr::v31),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Vr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Vector mask bit (bit 25): masked operations only write elements whose bit in v0 is set
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Vm {
+    Masked = 0,   // v0.t
+    Unmasked = 1,
+}
+
+impl Vm {
+    pub fn from_u8(r: u8) -> Option<Vm> {
+        match r {
+            0 => Some(Vm::Masked),
+            1 => Some(Vm::Unmasked),
+            _ => None,
+        }
+    }
+}
+
+// Printed as the trailing operand: `vadd.vv v1,v2,v3,v0.t`.
+impl std::fmt::Display for Vm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Vm::Masked => write!(f, ",v0.t"),
+            Vm::Unmasked => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// vtype operand of vsetvli/vsetivli: vlmul[2:0] vsew[5:3] vta[6] vma[7]
+
+// Element width; also the EEW encoded in vector loads and stores.
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Sew {
+    E8 = 0,
+    E16,
+    E32,
+    E64,
+}
+
+impl Sew {
+    pub fn from_u8(r: u8) -> Option<Sew> {
+        match r {
+            0 => Some(Sew::E8),
+            1 => Some(Sew::E16),
+            2 => Some(Sew::E32),
+            3 => Some(Sew::E64),
+            _ => None,
+        }
+    }
+
+    pub fn bits(self) -> u32 {
+        8 << self as u32
+    }
+}
+
+impl std::fmt::Display for Sew {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "e{}", self.bits())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Lmul {
+    M1 = 0b000,
+    M2 = 0b001,
+    M4 = 0b010,
+    M8 = 0b011,
+    Mf8 = 0b101,
+    Mf4 = 0b110,
+    Mf2 = 0b111,
+}
+
+impl Lmul {
+    pub fn from_u8(r: u8) -> Option<Lmul> {
+        match r {
+            0b000 => Some(Lmul::M1),
+            0b001 => Some(Lmul::M2),
+            0b010 => Some(Lmul::M4),
+            0b011 => Some(Lmul::M8),
+            0b101 => Some(Lmul::Mf8),
+            0b110 => Some(Lmul::Mf4),
+            0b111 => Some(Lmul::Mf2),
+            _ => None, // 0b100 is reserved
+        }
+    }
+}
+
+impl std::fmt::Display for Lmul {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Lmul::M1 => write!(f, "m1"),
+            Lmul::M2 => write!(f, "m2"),
+            Lmul::M4 => write!(f, "m4"),
+            Lmul::M8 => write!(f, "m8"),
+            Lmul::Mf8 => write!(f, "mf8"),
+            Lmul::Mf4 => write!(f, "mf4"),
+            Lmul::Mf2 => write!(f, "mf2"),
+        }
+    }
+}
+
+// Raw 11-bit immediate, so that encodings with reserved values still round-trip.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vtype(u16);
+
+impl Vtype {
+    pub fn new(sew: Sew, lmul: Lmul, ta: bool, ma: bool) -> Vtype {
+        Vtype((ma as u16) << 7 | (ta as u16) << 6 | (sew as u16) << 3 | lmul as u16)
+    }
+
+    pub fn from_u16(r: u16) -> Option<Vtype> {
+        if r < 0x800 {
+            Some(Vtype(r))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_u16(self) -> u16 {
+        self.0
+    }
+
+    /// `None` if the field holds a reserved value.
+    pub fn sew(self) -> Option<Sew> {
+        Sew::from_u8((self.0 >> 3 & 0b111) as u8)
+    }
+
+    /// `None` if the field holds a reserved value.
+    pub fn lmul(self) -> Option<Lmul> {
+        Lmul::from_u8((self.0 & 0b111) as u8)
+    }
+
+    /// Tail agnostic.
+    pub fn ta(self) -> bool {
+        self.0 & 1 << 6 != 0
+    }
+
+    /// Mask agnostic.
+    pub fn ma(self) -> bool {
+        self.0 & 1 << 7 != 0
+    }
+}
+
+// `e32,m1,ta,ma`, or the plain number when it is not a valid setting.
+impl std::fmt::Display for Vtype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.sew(), self.lmul()) {
+            (Some(sew), Some(lmul)) if self.0 >> 8 == 0 => {
+                let ta = if self.ta() { "ta" } else { "tu" };
+                let ma = if self.ma() { "ma" } else { "mu" };
+                write!(f, "{},{},{},{}", sew, lmul, ta, ma)
+            }
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}